@@ -0,0 +1,15 @@
+public class Anonymous {
+    private final Runnable field = new Runnable() {
+        @Override
+        public void run() {
+        }
+    };
+
+    public Runnable runnable() {
+        return new Runnable() {
+            @Override
+            public void run() {
+            }
+        };
+    }
+}
@@ -0,0 +1,6 @@
+public class Debugging {
+    public static int add(int a, int b) {
+        int sum = a + b;
+        return sum;
+    }
+}
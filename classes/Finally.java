@@ -0,0 +1,74 @@
+public class Finally {
+    public static int count;
+
+    static void fail() {
+        try {
+            count += 1;
+            throw new IllegalStateException("fail");
+        } finally {
+            count += 10;
+        }
+    }
+
+    static void nested() {
+        try {
+            try {
+                fail();
+            } finally {
+                count += 100;
+            }
+        } finally {
+            count += 1000;
+        }
+    }
+
+    public static int propagate() {
+        count = 0;
+        try {
+            nested();
+        } catch (IllegalStateException e) {
+            count += 10000;
+        }
+        return count;
+    }
+
+    public static int local() {
+        int value = 0;
+        try {
+            try {
+                try {
+                    value += 1;
+                    throw new IllegalStateException("fail");
+                } catch (IllegalArgumentException e) {
+                    value += 2;
+                } finally {
+                    value += 10;
+                }
+            } finally {
+                value += 100;
+            }
+        } catch (IllegalStateException e) {
+            value += 1000;
+        }
+        return value;
+    }
+
+    static void throwing() {
+        count += 1;
+        throw new IllegalStateException("throwing");
+    }
+
+    public static int finallyThrows() {
+        count = 0;
+        try {
+            try {
+                count += 10;
+            } finally {
+                throwing();
+            }
+        } catch (IllegalStateException e) {
+            count += 100;
+        }
+        return count;
+    }
+}
@@ -0,0 +1,13 @@
+import java.io.Serializable;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+
+public class Generics<T extends Comparable<T>> extends ArrayList<T> implements Serializable {
+    private Map<String, List<? extends Number>> values;
+
+    public static <K, V extends Number & Comparable<V>> Map<K, V> map(List<? super K> keys, V[] values)
+            throws IllegalArgumentException {
+        return null;
+    }
+}
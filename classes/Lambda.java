@@ -0,0 +1,8 @@
+import java.util.function.IntUnaryOperator;
+
+public class Lambda {
+    public static int apply(int value) {
+        IntUnaryOperator increment = x -> x + 1;
+        return increment.applyAsInt(value);
+    }
+}
@@ -0,0 +1,25 @@
+import java.io.IOException;
+import java.nio.file.DirectoryStream;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+
+public class NioFiles {
+    public static boolean exists(String path) {
+        return Files.exists(Paths.get(path));
+    }
+
+    public static long size(String path) throws IOException {
+        return Files.size(Paths.get(path));
+    }
+
+    public static int count(String directory) throws IOException {
+        int count = 0;
+        try (DirectoryStream<Path> stream = Files.newDirectoryStream(Paths.get(directory))) {
+            for (Path path : stream) {
+                count++;
+            }
+        }
+        return count;
+    }
+}
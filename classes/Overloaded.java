@@ -0,0 +1,15 @@
+public class Overloaded {
+    private int value;
+
+    public int add(int a) {
+        return value + a;
+    }
+
+    public long add(long a) {
+        return value + a;
+    }
+
+    public int add(int a, int b) {
+        return a + b;
+    }
+}
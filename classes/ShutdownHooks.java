@@ -0,0 +1,26 @@
+public class ShutdownHooks {
+    static int value = 0;
+
+    static class Hook extends Thread {
+        @Override
+        public void run() {
+            value = 42;
+            System.out.print("shutdown hook");
+        }
+    }
+
+    static class FailingHook extends Thread {
+        @Override
+        public void run() {
+            throw new IllegalStateException("shutdown hook failed");
+        }
+    }
+
+    public static void register() {
+        Runtime.getRuntime().addShutdownHook(new Hook());
+    }
+
+    public static void registerFailing() {
+        Runtime.getRuntime().addShutdownHook(new FailingHook());
+    }
+}
@@ -0,0 +1,27 @@
+public class StackTraces {
+    static class DeepException extends RuntimeException {
+        DeepException(String message) {
+            super(message);
+        }
+    }
+
+    public static RuntimeException create() {
+        return level1();
+    }
+
+    static RuntimeException level1() {
+        return level2();
+    }
+
+    static RuntimeException level2() {
+        return new DeepException("deep");
+    }
+
+    public static RuntimeException caught() {
+        try {
+            throw level2();
+        } catch (RuntimeException e) {
+            return e;
+        }
+    }
+}
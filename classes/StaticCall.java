@@ -0,0 +1,22 @@
+public class StaticCall {
+    public static int add(int a, int b) {
+        return a + b;
+    }
+
+    public static int loop(int count) {
+        int sum = 0;
+        for (int i = 0; i < count; i++) {
+            sum = add(sum, i);
+        }
+        return sum;
+    }
+
+    public static int arithmetic(int count) {
+        int value = 0;
+        for (int i = 0; i < count; i++) {
+            value = value * 31 + i;
+            value ^= value >>> 7;
+        }
+        return value;
+    }
+}
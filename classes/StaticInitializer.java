@@ -0,0 +1,11 @@
+public class StaticInitializer {
+    public static int initializations;
+    public static int value;
+    public static int doubled;
+
+    static {
+        initializations += 1;
+        value = 21;
+        doubled = StaticInitializer.value * 2;
+    }
+}
@@ -0,0 +1,70 @@
+public class VirtualCall {
+    interface Named {
+        String name();
+    }
+
+    static class Base implements Named {
+        public final int value() {
+            return 1;
+        }
+
+        private int secret() {
+            return 10;
+        }
+
+        public String name() {
+            return "base";
+        }
+
+        public int weight() {
+            return 1;
+        }
+
+        public String toString() {
+            return "Base:" + name();
+        }
+    }
+
+    static class Derived extends Base {
+        public int secret() {
+            return 20;
+        }
+
+        public String name() {
+            return "derived";
+        }
+
+        public int weight() {
+            return 2;
+        }
+    }
+
+    public static int finalMethod() {
+        Base base = new Derived();
+        return base.value();
+    }
+
+    public static String virtualMethod() {
+        Named named = new Derived();
+        return named.name();
+    }
+
+    public static boolean interfaceHashCode() {
+        Named named = new Derived();
+        return named.hashCode() == System.identityHashCode(named);
+    }
+
+    public static String interfaceToString() {
+        Named named = new Derived();
+        return named.toString();
+    }
+
+    public static int dispatch(int count) {
+        Base[] values = {new Base(), new Derived()};
+        int sum = 0;
+        for (int i = 0; i < count; i++) {
+            sum += values[i & 1].weight();
+        }
+        return sum;
+    }
+}
@@ -18,7 +18,7 @@ impl Annotation {
     ///
     /// # Errors
     /// If there is an issue deserializing an `AnnotationValuePair`.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<Annotation> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<Annotation> {
         let type_index = bytes.read_u16::<BigEndian>()?;
         let elements_count = bytes.read_u16::<BigEndian>()? as usize;
         let mut elements = Vec::with_capacity(elements_count);
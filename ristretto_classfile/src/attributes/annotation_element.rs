@@ -77,7 +77,7 @@ impl AnnotationElement {
     ///
     /// # Errors
     /// Returns an error if the tag is invalid.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<AnnotationElement> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<AnnotationElement> {
         let tag = bytes.read_u8()?;
 
         let element = match tag {
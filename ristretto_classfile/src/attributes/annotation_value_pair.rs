@@ -18,7 +18,7 @@ impl AnnotationValuePair {
     ///
     /// # Errors
     /// If the annotation element cannot be deserialized.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<AnnotationValuePair> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<AnnotationValuePair> {
         let name_index = bytes.read_u16::<BigEndian>()?;
         let value = AnnotationElement::from_bytes(bytes)?;
         let annotation_value_pair = AnnotationValuePair { name_index, value };
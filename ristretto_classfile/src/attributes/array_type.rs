@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::BaseType;
 use crate::Error::InvalidArrayTypeCode;
 use byteorder::{ReadBytesExt, WriteBytesExt};
 use std::fmt;
@@ -35,11 +36,26 @@ impl ArrayType {
         }
     }
 
+    /// Return the component type of arrays of this type.
+    #[must_use]
+    pub fn base_type(&self) -> BaseType {
+        match self {
+            ArrayType::Boolean => BaseType::Boolean,
+            ArrayType::Char => BaseType::Char,
+            ArrayType::Float => BaseType::Float,
+            ArrayType::Double => BaseType::Double,
+            ArrayType::Byte => BaseType::Byte,
+            ArrayType::Short => BaseType::Short,
+            ArrayType::Int => BaseType::Int,
+            ArrayType::Long => BaseType::Long,
+        }
+    }
+
     /// Deserialize the array type from bytes.
     ///
     /// # Errors
     /// If the code is not a valid array type code.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<ArrayType> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<ArrayType> {
         let code = bytes.read_u8()?;
 
         let array_type = match code {
@@ -95,8 +111,9 @@ mod test {
         );
     }
 
-    fn test_array_type(array_type: &ArrayType, code: u8) -> Result<()> {
+    fn test_array_type(array_type: &ArrayType, code: u8, base_type: &BaseType) -> Result<()> {
         assert_eq!(code, array_type.code());
+        assert_eq!(*base_type, array_type.base_type());
         let expected_bytes = [code];
 
         let mut buffer = Cursor::new(Vec::new());
@@ -113,48 +130,48 @@ mod test {
     #[test]
     fn test_boolean() -> Result<()> {
         assert_eq!("boolean", ArrayType::Boolean.to_string());
-        test_array_type(&ArrayType::Boolean, 4)
+        test_array_type(&ArrayType::Boolean, 4, &BaseType::Boolean)
     }
 
     #[test]
     fn test_char() -> Result<()> {
         assert_eq!("char", ArrayType::Char.to_string());
-        test_array_type(&ArrayType::Char, 5)
+        test_array_type(&ArrayType::Char, 5, &BaseType::Char)
     }
 
     #[test]
     fn test_float() -> Result<()> {
         assert_eq!("float", ArrayType::Float.to_string());
-        test_array_type(&ArrayType::Float, 6)
+        test_array_type(&ArrayType::Float, 6, &BaseType::Float)
     }
 
     #[test]
     fn test_double() -> Result<()> {
         assert_eq!("double", ArrayType::Double.to_string());
-        test_array_type(&ArrayType::Double, 7)
+        test_array_type(&ArrayType::Double, 7, &BaseType::Double)
     }
 
     #[test]
     fn test_byte() -> Result<()> {
         assert_eq!("byte", ArrayType::Byte.to_string());
-        test_array_type(&ArrayType::Byte, 8)
+        test_array_type(&ArrayType::Byte, 8, &BaseType::Byte)
     }
 
     #[test]
     fn test_short() -> Result<()> {
         assert_eq!("short", ArrayType::Short.to_string());
-        test_array_type(&ArrayType::Short, 9)
+        test_array_type(&ArrayType::Short, 9, &BaseType::Short)
     }
 
     #[test]
     fn test_int() -> Result<()> {
         assert_eq!("int", ArrayType::Int.to_string());
-        test_array_type(&ArrayType::Int, 10)
+        test_array_type(&ArrayType::Int, 10, &BaseType::Int)
     }
 
     #[test]
     fn test_long() -> Result<()> {
         assert_eq!("long", ArrayType::Long.to_string());
-        test_array_type(&ArrayType::Long, 11)
+        test_array_type(&ArrayType::Long, 11, &BaseType::Long)
     }
 }
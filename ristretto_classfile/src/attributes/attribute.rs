@@ -254,15 +254,40 @@ impl Attribute {
         }
     }
 
+    /// Get the source line number for a bytecode offset.  The line numbers are read from a
+    /// `LineNumberTable` attribute or from the `LineNumberTable` attributes of a `Code` attribute.
+    /// Returns the line of the entry with the greatest `start_pc` that is less than or equal to
+    /// `pc`, or `None` if there is no such entry.
+    #[must_use]
+    pub fn line_number_for(&self, pc: u16) -> Option<u16> {
+        self.nearest_line_number(pc)
+            .map(|line_number| line_number.line_number)
+    }
+
+    /// Get the line number entry with the greatest `start_pc` that is less than or equal to `pc`.
+    fn nearest_line_number(&self, pc: u16) -> Option<&LineNumber> {
+        match self {
+            Attribute::LineNumberTable { line_numbers, .. } => line_numbers
+                .iter()
+                .filter(|line_number| line_number.start_pc <= pc)
+                .max_by_key(|line_number| line_number.start_pc),
+            Attribute::Code { attributes, .. } => attributes
+                .iter()
+                .filter_map(|attribute| attribute.nearest_line_number(pc))
+                .max_by_key(|line_number| line_number.start_pc),
+            _ => None,
+        }
+    }
+
     /// Deserialize the Attribute from bytes.
     ///
     /// # Errors
     /// - If the attribute name index is invalid.
     /// - If the attribute length is invalid.
     #[expect(clippy::too_many_lines)]
-    pub fn from_bytes(
+    pub fn from_bytes<T: AsRef<[u8]>>(
         constant_pool: &ConstantPool,
-        bytes: &mut Cursor<Vec<u8>>,
+        bytes: &mut Cursor<T>,
     ) -> Result<Attribute> {
         let name_index = bytes.read_u16::<BigEndian>()?;
         let Some(Constant::Utf8(attribute_name)) = constant_pool.get(name_index) else {
@@ -305,12 +330,14 @@ impl Attribute {
                         .ok_or(InvalidInstructionOffset(u32::from(
                             exception.range_pc.start,
                         )))?;
-                    exception.range_pc.end = byte_to_instruction_map
-                        .iter()
-                        .filter(|(&k, _)| k <= exception.range_pc.end)
-                        .max_by_key(|(&k, _)| k)
-                        .map(|(_, &v)| v + 1)
-                        .ok_or(InvalidInstructionOffset(u32::from(exception.range_pc.end)))?;
+                    // The end of the range is exclusive and may be the end of the code
+                    exception.range_pc.end = if u32::from(exception.range_pc.end) == code_length {
+                        u16::try_from(instructions.len())?
+                    } else {
+                        *byte_to_instruction_map
+                            .get(&exception.range_pc.end)
+                            .ok_or(InvalidInstructionOffset(u32::from(exception.range_pc.end)))?
+                    };
                     exception.handler_pc = *byte_to_instruction_map
                         .get(&exception.handler_pc)
                         .ok_or(InvalidInstructionOffset(u32::from(exception.handler_pc)))?;
@@ -351,7 +378,9 @@ impl Attribute {
             }
             "StackMapTable" => {
                 let mut info = vec![0; info_length as usize];
-                bytes.clone().read_exact(&mut info)?;
+                let position = bytes.position();
+                bytes.read_exact(&mut info)?;
+                bytes.set_position(position);
                 let frames_count = bytes.read_u16::<BigEndian>()?;
                 let mut frames = Vec::with_capacity(frames_count as usize);
                 for _ in 0..frames_count {
@@ -726,12 +755,14 @@ impl Attribute {
                         .ok_or(InvalidInstructionOffset(u32::from(
                             exception.range_pc.start,
                         )))?;
-                    exception.range_pc.end = instruction_to_byte_map
-                        .iter()
-                        .filter(|(&k, _)| k <= exception.range_pc.end)
-                        .max_by_key(|(&k, _)| k)
-                        .map(|(_, &v)| v + 1)
-                        .ok_or(InvalidInstructionOffset(u32::from(exception.range_pc.end)))?;
+                    // The end of the range is exclusive and may be the end of the code
+                    exception.range_pc.end = if usize::from(exception.range_pc.end) == code.len() {
+                        u16::try_from(code_length)?
+                    } else {
+                        *instruction_to_byte_map
+                            .get(&exception.range_pc.end)
+                            .ok_or(InvalidInstructionOffset(u32::from(exception.range_pc.end)))?
+                    };
                     exception.handler_pc = *instruction_to_byte_map
                         .get(&exception.handler_pc)
                         .ok_or(InvalidInstructionOffset(u32::from(exception.handler_pc)))?;
@@ -1138,12 +1169,13 @@ impl fmt::Display for Attribute {
                     exception.range_pc.start = *instruction_to_byte_map
                         .get(&exception.range_pc.start)
                         .ok_or(fmt::Error)?;
-                    exception.range_pc.end = instruction_to_byte_map
-                        .iter()
-                        .filter(|(&k, _)| k <= exception.range_pc.end)
-                        .max_by_key(|(&k, _)| k)
-                        .map(|(_, &v)| v + 1)
-                        .ok_or(fmt::Error)?;
+                    exception.range_pc.end = if usize::from(exception.range_pc.end) == code.len() {
+                        u16::try_from(code_length).map_err(|_| fmt::Error)?
+                    } else {
+                        *instruction_to_byte_map
+                            .get(&exception.range_pc.end)
+                            .ok_or(fmt::Error)?
+                    };
                     exception.handler_pc = *instruction_to_byte_map
                         .get(&exception.handler_pc)
                         .ok_or(fmt::Error)?;
@@ -1317,6 +1349,52 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_code_exception_table_range() -> Result<()> {
+        // The end of an exception table range is exclusive; the instruction at the end of the
+        // range must not be covered by the exception handler.
+        let attribute = Attribute::Code {
+            name_index: 1,
+            max_stack: 1,
+            max_locals: 0,
+            code: vec![
+                Instruction::Bipush(1),
+                Instruction::Pop,
+                Instruction::Return,
+            ],
+            exception_table: vec![
+                ExceptionTableEntry {
+                    range_pc: 0..1,
+                    handler_pc: 2,
+                    catch_type: 0,
+                },
+                ExceptionTableEntry {
+                    range_pc: 1..3,
+                    handler_pc: 2,
+                    catch_type: 0,
+                },
+            ],
+            attributes: Vec::new(),
+        };
+        let expected_bytes = [
+            0, 1, 0, 0, 0, 32, 0, 1, 0, 0, 0, 0, 0, 4, 16, 1, 87, 177, 0, 2, 0, 0, 0, 2, 0, 3, 0,
+            0, 0, 2, 0, 4, 0, 3, 0, 0, 0, 0,
+        ];
+
+        let mut constant_pool = ConstantPool::default();
+        constant_pool.add_utf8(attribute.name())?;
+
+        let mut bytes = Vec::new();
+        attribute.to_bytes(&mut bytes)?;
+        assert_eq!(expected_bytes, &bytes[..]);
+        let mut bytes = Cursor::new(expected_bytes.to_vec());
+        assert_eq!(
+            attribute,
+            Attribute::from_bytes(&constant_pool, &mut bytes)?
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_stack_map_table() -> Result<()> {
         let attribute = Attribute::StackMapTable {
@@ -1477,6 +1555,77 @@ mod test {
         test_attribute(&attribute, &expected_bytes, &VERSION_45_3)
     }
 
+    #[test]
+    fn test_line_number_for() {
+        let attribute = Attribute::LineNumberTable {
+            name_index: 1,
+            line_numbers: vec![
+                LineNumber {
+                    start_pc: 4,
+                    line_number: 10,
+                },
+                LineNumber {
+                    start_pc: 12,
+                    line_number: 11,
+                },
+                LineNumber {
+                    start_pc: 20,
+                    line_number: 13,
+                },
+            ],
+        };
+
+        assert_eq!(None, attribute.line_number_for(0));
+        assert_eq!(None, attribute.line_number_for(3));
+        assert_eq!(Some(10), attribute.line_number_for(4));
+        assert_eq!(Some(10), attribute.line_number_for(11));
+        assert_eq!(Some(11), attribute.line_number_for(12));
+        assert_eq!(Some(13), attribute.line_number_for(u16::MAX));
+    }
+
+    #[test]
+    fn test_line_number_for_empty() {
+        let attribute = Attribute::LineNumberTable {
+            name_index: 1,
+            line_numbers: Vec::new(),
+        };
+        assert_eq!(None, attribute.line_number_for(0));
+    }
+
+    #[test]
+    fn test_line_number_for_code() {
+        let line_number_table = Attribute::LineNumberTable {
+            name_index: 2,
+            line_numbers: vec![
+                LineNumber {
+                    start_pc: 0,
+                    line_number: 5,
+                },
+                LineNumber {
+                    start_pc: 2,
+                    line_number: 6,
+                },
+            ],
+        };
+        let attribute = Attribute::Code {
+            name_index: 1,
+            max_stack: 1,
+            max_locals: 1,
+            code: vec![Instruction::Iconst_1, Instruction::Pop, Instruction::Return],
+            exception_table: Vec::new(),
+            attributes: vec![line_number_table],
+        };
+
+        assert_eq!(Some(5), attribute.line_number_for(1));
+        assert_eq!(Some(6), attribute.line_number_for(2));
+    }
+
+    #[test]
+    fn test_line_number_for_unsupported_attribute() {
+        let attribute = Attribute::Synthetic { name_index: 1 };
+        assert_eq!(None, attribute.line_number_for(0));
+    }
+
     #[test]
     fn test_locale_variable_table() -> Result<()> {
         let variable = LocalVariableTable {
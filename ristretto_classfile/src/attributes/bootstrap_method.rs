@@ -17,7 +17,7 @@ impl BootstrapMethod {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<BootstrapMethod> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<BootstrapMethod> {
         let bootstrap_method_ref = bytes.read_u16::<BigEndian>()?;
         let arguments_count = bytes.read_u16::<BigEndian>()? as usize;
         let mut arguments = Vec::with_capacity(arguments_count);
@@ -0,0 +1,739 @@
+use crate::attributes::{Attribute, ExceptionTableEntry, Instruction};
+use crate::constant_pool::invalid_index_type;
+use crate::error::Result;
+use crate::Error::{InvalidFieldTypeDescriptor, InvalidInstructionOffset, VerificationError};
+use crate::{Constant, ConstantPool, MethodDescriptor};
+
+/// Builder for a `Code` attribute that computes `max_stack` and `max_locals` from the
+/// instructions.
+///
+/// `max_stack` is computed by simulating the operand stack depth of every reachable instruction,
+/// following branches, switches and exception handlers; when an instruction is reachable with
+/// different stack depths, the largest depth is used.  `max_locals` is computed from the method
+/// descriptor and the largest local variable index used by a load, store, `iinc` or `ret`
+/// instruction.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.3>
+#[derive(Clone, Debug, PartialEq)]
+pub struct CodeBuilder {
+    name_index: u16,
+    descriptor: String,
+    is_static: bool,
+    code: Vec<Instruction>,
+    exception_table: Vec<ExceptionTableEntry>,
+    attributes: Vec<Attribute>,
+}
+
+impl CodeBuilder {
+    /// Create a new code builder for a method with the given descriptor (e.g. "(I)V"); the
+    /// `name_index` must refer to a "Code" UTF-8 constant.
+    #[must_use]
+    pub fn new<S: AsRef<str>>(name_index: u16, descriptor: S) -> Self {
+        CodeBuilder {
+            name_index,
+            descriptor: descriptor.as_ref().to_string(),
+            is_static: false,
+            code: Vec::new(),
+            exception_table: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Set whether the method is static; instance methods reserve local variable 0 for `this`.
+    #[must_use]
+    pub fn is_static(mut self, is_static: bool) -> Self {
+        self.is_static = is_static;
+        self
+    }
+
+    /// Add an instruction
+    #[must_use]
+    pub fn instruction(mut self, instruction: Instruction) -> Self {
+        self.code.push(instruction);
+        self
+    }
+
+    /// Add instructions
+    #[must_use]
+    pub fn instructions(mut self, instructions: Vec<Instruction>) -> Self {
+        self.code.extend(instructions);
+        self
+    }
+
+    /// Add an exception table entry
+    #[must_use]
+    pub fn exception_table_entry(mut self, entry: ExceptionTableEntry) -> Self {
+        self.exception_table.push(entry);
+        self
+    }
+
+    /// Add an attribute (e.g. a `LineNumberTable`)
+    #[must_use]
+    pub fn attribute(mut self, attribute: Attribute) -> Self {
+        self.attributes.push(attribute);
+        self
+    }
+
+    /// Build the `Code` attribute.
+    ///
+    /// # Errors
+    /// - if the method descriptor or a referenced field or method descriptor is invalid
+    /// - if a referenced constant does not exist or has an unexpected type
+    /// - if a branch target is not a valid instruction index
+    /// - if the operand stack underflows or exceeds the maximum size
+    pub fn build(self, constant_pool: &ConstantPool) -> Result<Attribute> {
+        let max_stack = self.max_stack(constant_pool)?;
+        let max_locals = self.max_locals()?;
+        Ok(Attribute::Code {
+            name_index: self.name_index,
+            max_stack,
+            max_locals,
+            code: self.code,
+            exception_table: self.exception_table,
+            attributes: self.attributes,
+        })
+    }
+
+    /// Compute the maximum operand stack depth of the code.
+    fn max_stack(&self, constant_pool: &ConstantPool) -> Result<u16> {
+        if self.code.is_empty() {
+            return Ok(0);
+        }
+
+        let mut depths: Vec<Option<i32>> = vec![None; self.code.len()];
+        let mut work_list = vec![(0, 0)];
+        for entry in &self.exception_table {
+            // The thrown exception is the only value on the stack when entering a handler
+            work_list.push((usize::from(entry.handler_pc), 1));
+        }
+
+        let mut max_stack = 0;
+        while let Some((index, depth)) = work_list.pop() {
+            let Some(instruction) = self.code.get(index) else {
+                return Err(InvalidInstructionOffset(u32::try_from(index)?));
+            };
+            if depths[index].is_some_and(|seen| seen >= depth) {
+                continue;
+            }
+            depths[index] = Some(depth);
+
+            let (pop, push) = stack_effect(instruction, constant_pool)?;
+            if depth < pop {
+                return Err(verification_error(format!(
+                    "Operand stack underflow at instruction {index}: {instruction}"
+                )));
+            }
+            let depth = depth - pop + push;
+            if depth > i32::from(u16::MAX) {
+                return Err(verification_error(format!(
+                    "Operand stack overflow at instruction {index}: {instruction}"
+                )));
+            }
+            max_stack = max_stack.max(depth);
+
+            for successor in successors(index, instruction)? {
+                // jsr pushes the return address onto the stack before jumping to the subroutine
+                let successor_depth = match instruction {
+                    Instruction::Jsr(_) | Instruction::Jsr_w(_) if successor != index + 1 => {
+                        depth + 1
+                    }
+                    _ => depth,
+                };
+                max_stack = max_stack.max(successor_depth);
+                work_list.push((successor, successor_depth));
+            }
+        }
+
+        Ok(u16::try_from(max_stack)?)
+    }
+
+    /// Compute the number of local variable slots used by the method arguments and the code.
+    fn max_locals(&self) -> Result<u16> {
+        let method_descriptor = MethodDescriptor::parse(&self.descriptor)?;
+        let mut max_locals = u32::from(method_descriptor.argument_slots(self.is_static));
+        for instruction in &self.code {
+            if let Some((index, size)) = local_variable(instruction) {
+                max_locals = max_locals.max(u32::from(index) + size);
+            }
+        }
+        Ok(u16::try_from(max_locals)?)
+    }
+}
+
+/// Create a verification error for the code attribute.
+fn verification_error(message: String) -> crate::Error {
+    VerificationError {
+        context: "Code".to_string(),
+        message,
+    }
+}
+
+/// Return the local variable index and number of slots accessed by an instruction, if any.
+fn local_variable(instruction: &Instruction) -> Option<(u16, u32)> {
+    let local_variable = match instruction {
+        Instruction::Iload(index)
+        | Instruction::Fload(index)
+        | Instruction::Aload(index)
+        | Instruction::Istore(index)
+        | Instruction::Fstore(index)
+        | Instruction::Astore(index)
+        | Instruction::Iinc(index, _)
+        | Instruction::Ret(index) => (u16::from(*index), 1),
+        Instruction::Lload(index)
+        | Instruction::Dload(index)
+        | Instruction::Lstore(index)
+        | Instruction::Dstore(index) => (u16::from(*index), 2),
+        Instruction::Iload_w(index)
+        | Instruction::Fload_w(index)
+        | Instruction::Aload_w(index)
+        | Instruction::Istore_w(index)
+        | Instruction::Fstore_w(index)
+        | Instruction::Astore_w(index)
+        | Instruction::Iinc_w(index, _)
+        | Instruction::Ret_w(index) => (*index, 1),
+        Instruction::Lload_w(index)
+        | Instruction::Dload_w(index)
+        | Instruction::Lstore_w(index)
+        | Instruction::Dstore_w(index) => (*index, 2),
+        Instruction::Iload_0
+        | Instruction::Fload_0
+        | Instruction::Aload_0
+        | Instruction::Istore_0
+        | Instruction::Fstore_0
+        | Instruction::Astore_0 => (0, 1),
+        Instruction::Iload_1
+        | Instruction::Fload_1
+        | Instruction::Aload_1
+        | Instruction::Istore_1
+        | Instruction::Fstore_1
+        | Instruction::Astore_1 => (1, 1),
+        Instruction::Iload_2
+        | Instruction::Fload_2
+        | Instruction::Aload_2
+        | Instruction::Istore_2
+        | Instruction::Fstore_2
+        | Instruction::Astore_2 => (2, 1),
+        Instruction::Iload_3
+        | Instruction::Fload_3
+        | Instruction::Aload_3
+        | Instruction::Istore_3
+        | Instruction::Fstore_3
+        | Instruction::Astore_3 => (3, 1),
+        Instruction::Lload_0
+        | Instruction::Dload_0
+        | Instruction::Lstore_0
+        | Instruction::Dstore_0 => (0, 2),
+        Instruction::Lload_1
+        | Instruction::Dload_1
+        | Instruction::Lstore_1
+        | Instruction::Dstore_1 => (1, 2),
+        Instruction::Lload_2
+        | Instruction::Dload_2
+        | Instruction::Lstore_2
+        | Instruction::Dstore_2 => (2, 2),
+        Instruction::Lload_3
+        | Instruction::Dload_3
+        | Instruction::Lstore_3
+        | Instruction::Dstore_3 => (3, 2),
+        _ => return None,
+    };
+    Some(local_variable)
+}
+
+/// Return the instruction indexes that execution may continue at after an instruction.
+fn successors(index: usize, instruction: &Instruction) -> Result<Vec<usize>> {
+    let next = index + 1;
+    let relative = |offset: i32| -> Result<usize> {
+        let target = i64::try_from(index)? + i64::from(offset);
+        Ok(usize::try_from(target)?)
+    };
+    let successors = match instruction {
+        Instruction::Ifeq(target)
+        | Instruction::Ifne(target)
+        | Instruction::Iflt(target)
+        | Instruction::Ifge(target)
+        | Instruction::Ifgt(target)
+        | Instruction::Ifle(target)
+        | Instruction::If_icmpeq(target)
+        | Instruction::If_icmpne(target)
+        | Instruction::If_icmplt(target)
+        | Instruction::If_icmpge(target)
+        | Instruction::If_icmpgt(target)
+        | Instruction::If_icmple(target)
+        | Instruction::If_acmpeq(target)
+        | Instruction::If_acmpne(target)
+        | Instruction::Ifnull(target)
+        | Instruction::Ifnonnull(target)
+        | Instruction::Jsr(target) => vec![usize::from(*target), next],
+        Instruction::Jsr_w(target) => vec![usize::try_from(*target)?, next],
+        Instruction::Goto(target) => vec![usize::from(*target)],
+        Instruction::Goto_w(target) => vec![usize::try_from(*target)?],
+        Instruction::Tableswitch {
+            default, offsets, ..
+        } => {
+            let mut successors = vec![relative(*default)?];
+            for offset in offsets {
+                successors.push(relative(*offset)?);
+            }
+            successors
+        }
+        Instruction::Lookupswitch { default, pairs } => {
+            let mut successors = vec![relative(*default)?];
+            for offset in pairs.values() {
+                successors.push(relative(*offset)?);
+            }
+            successors
+        }
+        Instruction::Ireturn
+        | Instruction::Lreturn
+        | Instruction::Freturn
+        | Instruction::Dreturn
+        | Instruction::Areturn
+        | Instruction::Return
+        | Instruction::Athrow
+        | Instruction::Ret(_)
+        | Instruction::Ret_w(_) => Vec::new(),
+        _ => vec![next],
+    };
+    Ok(successors)
+}
+
+/// Return the number of operand stack slots popped and pushed by an instruction.
+#[expect(clippy::too_many_lines)]
+fn stack_effect(instruction: &Instruction, constant_pool: &ConstantPool) -> Result<(i32, i32)> {
+    let effect = match instruction {
+        Instruction::Nop
+        | Instruction::Iinc(..)
+        | Instruction::Iinc_w(..)
+        | Instruction::Goto(_)
+        | Instruction::Goto_w(_)
+        | Instruction::Jsr(_)
+        | Instruction::Jsr_w(_)
+        | Instruction::Ret(_)
+        | Instruction::Ret_w(_)
+        | Instruction::Return
+        | Instruction::Wide
+        | Instruction::Breakpoint
+        | Instruction::Impdep1
+        | Instruction::Impdep2 => (0, 0),
+        Instruction::Aconst_null
+        | Instruction::Iconst_m1
+        | Instruction::Iconst_0
+        | Instruction::Iconst_1
+        | Instruction::Iconst_2
+        | Instruction::Iconst_3
+        | Instruction::Iconst_4
+        | Instruction::Iconst_5
+        | Instruction::Fconst_0
+        | Instruction::Fconst_1
+        | Instruction::Fconst_2
+        | Instruction::Bipush(_)
+        | Instruction::Sipush(_)
+        | Instruction::Ldc(_)
+        | Instruction::Ldc_w(_)
+        | Instruction::Iload(_)
+        | Instruction::Fload(_)
+        | Instruction::Aload(_)
+        | Instruction::Iload_w(_)
+        | Instruction::Fload_w(_)
+        | Instruction::Aload_w(_)
+        | Instruction::Iload_0
+        | Instruction::Iload_1
+        | Instruction::Iload_2
+        | Instruction::Iload_3
+        | Instruction::Fload_0
+        | Instruction::Fload_1
+        | Instruction::Fload_2
+        | Instruction::Fload_3
+        | Instruction::Aload_0
+        | Instruction::Aload_1
+        | Instruction::Aload_2
+        | Instruction::Aload_3
+        | Instruction::New(_) => (0, 1),
+        Instruction::Lconst_0
+        | Instruction::Lconst_1
+        | Instruction::Dconst_0
+        | Instruction::Dconst_1
+        | Instruction::Ldc2_w(_)
+        | Instruction::Lload(_)
+        | Instruction::Dload(_)
+        | Instruction::Lload_w(_)
+        | Instruction::Dload_w(_)
+        | Instruction::Lload_0
+        | Instruction::Lload_1
+        | Instruction::Lload_2
+        | Instruction::Lload_3
+        | Instruction::Dload_0
+        | Instruction::Dload_1
+        | Instruction::Dload_2
+        | Instruction::Dload_3 => (0, 2),
+        Instruction::Istore(_)
+        | Instruction::Fstore(_)
+        | Instruction::Astore(_)
+        | Instruction::Istore_w(_)
+        | Instruction::Fstore_w(_)
+        | Instruction::Astore_w(_)
+        | Instruction::Istore_0
+        | Instruction::Istore_1
+        | Instruction::Istore_2
+        | Instruction::Istore_3
+        | Instruction::Fstore_0
+        | Instruction::Fstore_1
+        | Instruction::Fstore_2
+        | Instruction::Fstore_3
+        | Instruction::Astore_0
+        | Instruction::Astore_1
+        | Instruction::Astore_2
+        | Instruction::Astore_3
+        | Instruction::Pop
+        | Instruction::Ifeq(_)
+        | Instruction::Ifne(_)
+        | Instruction::Iflt(_)
+        | Instruction::Ifge(_)
+        | Instruction::Ifgt(_)
+        | Instruction::Ifle(_)
+        | Instruction::Ifnull(_)
+        | Instruction::Ifnonnull(_)
+        | Instruction::Tableswitch { .. }
+        | Instruction::Lookupswitch { .. }
+        | Instruction::Ireturn
+        | Instruction::Freturn
+        | Instruction::Areturn
+        | Instruction::Athrow
+        | Instruction::Monitorenter
+        | Instruction::Monitorexit => (1, 0),
+        Instruction::Lstore(_)
+        | Instruction::Dstore(_)
+        | Instruction::Lstore_w(_)
+        | Instruction::Dstore_w(_)
+        | Instruction::Lstore_0
+        | Instruction::Lstore_1
+        | Instruction::Lstore_2
+        | Instruction::Lstore_3
+        | Instruction::Dstore_0
+        | Instruction::Dstore_1
+        | Instruction::Dstore_2
+        | Instruction::Dstore_3
+        | Instruction::Pop2
+        | Instruction::If_icmpeq(_)
+        | Instruction::If_icmpne(_)
+        | Instruction::If_icmplt(_)
+        | Instruction::If_icmpge(_)
+        | Instruction::If_icmpgt(_)
+        | Instruction::If_icmple(_)
+        | Instruction::If_acmpeq(_)
+        | Instruction::If_acmpne(_)
+        | Instruction::Lreturn
+        | Instruction::Dreturn => (2, 0),
+        Instruction::Iaload
+        | Instruction::Faload
+        | Instruction::Aaload
+        | Instruction::Baload
+        | Instruction::Caload
+        | Instruction::Saload
+        | Instruction::Iadd
+        | Instruction::Fadd
+        | Instruction::Isub
+        | Instruction::Fsub
+        | Instruction::Imul
+        | Instruction::Fmul
+        | Instruction::Idiv
+        | Instruction::Fdiv
+        | Instruction::Irem
+        | Instruction::Frem
+        | Instruction::Ishl
+        | Instruction::Ishr
+        | Instruction::Iushr
+        | Instruction::Iand
+        | Instruction::Ior
+        | Instruction::Ixor
+        | Instruction::Fcmpl
+        | Instruction::Fcmpg
+        | Instruction::L2i
+        | Instruction::L2f
+        | Instruction::D2i
+        | Instruction::D2f => (2, 1),
+        Instruction::Laload
+        | Instruction::Daload
+        | Instruction::Swap
+        | Instruction::Lneg
+        | Instruction::Dneg
+        | Instruction::L2d
+        | Instruction::D2l => (2, 2),
+        Instruction::Iastore
+        | Instruction::Fastore
+        | Instruction::Aastore
+        | Instruction::Bastore
+        | Instruction::Castore
+        | Instruction::Sastore => (3, 0),
+        Instruction::Lastore | Instruction::Dastore => (4, 0),
+        Instruction::Dup
+        | Instruction::I2l
+        | Instruction::I2d
+        | Instruction::F2l
+        | Instruction::F2d => (1, 2),
+        Instruction::Dup_x1 => (2, 3),
+        Instruction::Dup_x2 => (3, 4),
+        Instruction::Dup2 => (2, 4),
+        Instruction::Dup2_x1 => (3, 5),
+        Instruction::Dup2_x2 => (4, 6),
+        Instruction::Ladd
+        | Instruction::Dadd
+        | Instruction::Lsub
+        | Instruction::Dsub
+        | Instruction::Lmul
+        | Instruction::Dmul
+        | Instruction::Ldiv
+        | Instruction::Ddiv
+        | Instruction::Lrem
+        | Instruction::Drem
+        | Instruction::Land
+        | Instruction::Lor
+        | Instruction::Lxor => (4, 2),
+        Instruction::Lshl | Instruction::Lshr | Instruction::Lushr => (3, 2),
+        Instruction::Lcmp | Instruction::Dcmpl | Instruction::Dcmpg => (4, 1),
+        Instruction::Ineg
+        | Instruction::Fneg
+        | Instruction::I2f
+        | Instruction::F2i
+        | Instruction::I2b
+        | Instruction::I2c
+        | Instruction::I2s
+        | Instruction::Newarray(_)
+        | Instruction::Anewarray(_)
+        | Instruction::Arraylength
+        | Instruction::Checkcast(_)
+        | Instruction::Instanceof(_) => (1, 1),
+        Instruction::Multianewarray(_, dimensions) => (i32::from(*dimensions), 1),
+        Instruction::Getstatic(index) => (0, field_slots(constant_pool, *index)?),
+        Instruction::Putstatic(index) => (field_slots(constant_pool, *index)?, 0),
+        Instruction::Getfield(index) => (1, field_slots(constant_pool, *index)?),
+        Instruction::Putfield(index) => (1 + field_slots(constant_pool, *index)?, 0),
+        Instruction::Invokevirtual(index)
+        | Instruction::Invokespecial(index)
+        | Instruction::Invokeinterface(index, _) => {
+            let (arguments, result) = method_slots(constant_pool, *index)?;
+            (arguments + 1, result)
+        }
+        Instruction::Invokestatic(index) | Instruction::Invokedynamic(index) => {
+            method_slots(constant_pool, *index)?
+        }
+    };
+    Ok(effect)
+}
+
+/// Get the descriptor of the field, method or dynamic call site referenced by a constant.
+fn reference_descriptor(constant_pool: &ConstantPool, index: u16) -> Result<&String> {
+    let name_and_type_index = match constant_pool.try_get(index)? {
+        Constant::FieldRef {
+            name_and_type_index,
+            ..
+        }
+        | Constant::MethodRef {
+            name_and_type_index,
+            ..
+        }
+        | Constant::InterfaceMethodRef {
+            name_and_type_index,
+            ..
+        }
+        | Constant::InvokeDynamic {
+            name_and_type_index,
+            ..
+        } => *name_and_type_index,
+        constant => {
+            return Err(invalid_index_type(
+                index,
+                "CONSTANT_Fieldref|CONSTANT_Methodref|CONSTANT_InterfaceMethodref|CONSTANT_InvokeDynamic",
+                constant,
+            ))
+        }
+    };
+    let (_name_index, descriptor_index) =
+        constant_pool.try_get_name_and_type(name_and_type_index)?;
+    constant_pool.try_get_utf8(*descriptor_index)
+}
+
+/// Get the number of stack slots used by the field referenced by a constant.
+fn field_slots(constant_pool: &ConstantPool, index: u16) -> Result<i32> {
+    let descriptor = reference_descriptor(constant_pool, index)?;
+    match descriptor.chars().next() {
+        Some('J' | 'D') => Ok(2),
+        Some(_) => Ok(1),
+        None => Err(InvalidFieldTypeDescriptor(descriptor.clone())),
+    }
+}
+
+/// Get the number of stack slots used by the arguments and the result of the method referenced by
+/// a constant.
+fn method_slots(constant_pool: &ConstantPool, index: u16) -> Result<(i32, i32)> {
+    let descriptor = reference_descriptor(constant_pool, index)?;
+    let method_descriptor = MethodDescriptor::parse(descriptor)?;
+    let arguments = method_descriptor.argument_slots(true);
+    let result = method_descriptor.return_slots();
+    Ok((i32::from(arguments), i32::from(result)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() -> Result<()> {
+        let mut constant_pool = ConstantPool::new();
+        let code_index = constant_pool.add_utf8("Code")?;
+        let method_index = constant_pool.add_method_ref(1, "add", "(JJ)J")?;
+        let attribute = CodeBuilder::new(code_index, "(JJ)J")
+            .instructions(vec![
+                Instruction::Aload_0,
+                Instruction::Lload_1,
+                Instruction::Lload_3,
+                Instruction::Invokevirtual(method_index),
+                Instruction::Lreturn,
+            ])
+            .build(&constant_pool)?;
+        let Attribute::Code {
+            name_index,
+            max_stack,
+            max_locals,
+            code,
+            ..
+        } = attribute
+        else {
+            panic!("Expected Code attribute");
+        };
+        assert_eq!(code_index, name_index);
+        assert_eq!(5, max_stack);
+        assert_eq!(5, max_locals);
+        assert_eq!(5, code.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_branch_stack_heights() -> Result<()> {
+        let constant_pool = ConstantPool::new();
+        // if (i == 0) { push 3 values } else { push 1 value }; both paths return
+        let attribute = CodeBuilder::new(1, "(I)V")
+            .is_static(true)
+            .instructions(vec![
+                Instruction::Iload_0,
+                Instruction::Ifeq(7),
+                Instruction::Iconst_1,
+                Instruction::Iconst_2,
+                Instruction::Iconst_3,
+                Instruction::Pop2,
+                Instruction::Goto(9),
+                Instruction::Iconst_4,
+                Instruction::Pop,
+                Instruction::Return,
+            ])
+            .build(&constant_pool)?;
+        let Attribute::Code {
+            max_stack,
+            max_locals,
+            ..
+        } = attribute
+        else {
+            panic!("Expected Code attribute");
+        };
+        assert_eq!(3, max_stack);
+        assert_eq!(1, max_locals);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_merge_with_different_stack_heights() -> Result<()> {
+        let constant_pool = ConstantPool::new();
+        let attribute = CodeBuilder::new(1, "(I)V")
+            .is_static(true)
+            .instructions(vec![
+                Instruction::Iload_0,
+                Instruction::Ifeq(5),
+                Instruction::Iconst_1,
+                Instruction::Iconst_2,
+                Instruction::Iconst_3,
+                Instruction::Iconst_4,
+                Instruction::Return,
+            ])
+            .build(&constant_pool)?;
+        let Attribute::Code { max_stack, .. } = attribute else {
+            panic!("Expected Code attribute");
+        };
+        assert_eq!(4, max_stack);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_exception_handler() -> Result<()> {
+        let constant_pool = ConstantPool::new();
+        let attribute = CodeBuilder::new(1, "()V")
+            .instructions(vec![
+                Instruction::Return,
+                Instruction::Astore_1,
+                Instruction::Aload_1,
+                Instruction::Aload_1,
+                Instruction::Pop2,
+                Instruction::Return,
+            ])
+            .exception_table_entry(ExceptionTableEntry {
+                range_pc: 0..1,
+                handler_pc: 1,
+                catch_type: 0,
+            })
+            .build(&constant_pool)?;
+        let Attribute::Code {
+            max_stack,
+            max_locals,
+            ..
+        } = attribute
+        else {
+            panic!("Expected Code attribute");
+        };
+        assert_eq!(2, max_stack);
+        assert_eq!(2, max_locals);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_tableswitch() -> Result<()> {
+        let constant_pool = ConstantPool::new();
+        let attribute = CodeBuilder::new(1, "(I)I")
+            .is_static(true)
+            .instructions(vec![
+                Instruction::Iload_0,
+                Instruction::Tableswitch {
+                    default: 2,
+                    low: 0,
+                    high: 0,
+                    offsets: vec![1],
+                },
+                Instruction::Lconst_0,
+                Instruction::Iconst_0,
+                Instruction::Ireturn,
+            ])
+            .build(&constant_pool)?;
+        let Attribute::Code { max_stack, .. } = attribute else {
+            panic!("Expected Code attribute");
+        };
+        assert_eq!(3, max_stack);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_stack_underflow() {
+        let constant_pool = ConstantPool::new();
+        let result = CodeBuilder::new(1, "()V")
+            .instructions(vec![Instruction::Pop, Instruction::Return])
+            .build(&constant_pool);
+        assert!(matches!(result, Err(VerificationError { .. })));
+    }
+
+    #[test]
+    fn test_build_invalid_branch_target() {
+        let constant_pool = ConstantPool::new();
+        let result = CodeBuilder::new(1, "()V")
+            .instructions(vec![Instruction::Goto(5)])
+            .build(&constant_pool);
+        assert_eq!(Err(InvalidInstructionOffset(5)), result);
+    }
+}
@@ -19,7 +19,7 @@ impl ExceptionTableEntry {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<ExceptionTableEntry> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<ExceptionTableEntry> {
         let start_pc = bytes.read_u16::<BigEndian>()?;
         let end_pc = bytes.read_u16::<BigEndian>()?;
         let range_pc = start_pc..end_pc;
@@ -30,7 +30,7 @@ impl ExportsFlags {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<ExportsFlags> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<ExportsFlags> {
         let access_flags = bytes.read_u16::<BigEndian>()?;
         let access_flags = ExportsFlags::from_bits_truncate(access_flags);
         Ok(access_flags)
@@ -20,7 +20,7 @@ impl InnerClass {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<InnerClass> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<InnerClass> {
         let class_info_index = bytes.read_u16::<BigEndian>()?;
         let outer_class_info_index = bytes.read_u16::<BigEndian>()?;
         let name_index = bytes.read_u16::<BigEndian>()?;
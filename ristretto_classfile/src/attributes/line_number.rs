@@ -23,7 +23,7 @@ impl LineNumber {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<LineNumber> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<LineNumber> {
         let line_number = LineNumber {
             start_pc: bytes.read_u16::<BigEndian>()?,
             line_number: bytes.read_u16::<BigEndian>()?,
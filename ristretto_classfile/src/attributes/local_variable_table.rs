@@ -20,7 +20,7 @@ impl LocalVariableTable {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<LocalVariableTable> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<LocalVariableTable> {
         let start_pc = bytes.read_u16::<BigEndian>()?;
         let length = bytes.read_u16::<BigEndian>()?;
         let name_index = bytes.read_u16::<BigEndian>()?;
@@ -18,7 +18,7 @@ impl LocalVariableTarget {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<LocalVariableTarget> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<LocalVariableTarget> {
         let start_pc = bytes.read_u16::<BigEndian>()?;
         let length = bytes.read_u16::<BigEndian>()?;
         let index = bytes.read_u16::<BigEndian>()?;
@@ -18,7 +18,7 @@ impl MethodParameter {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<MethodParameter> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<MethodParameter> {
         let name_index = bytes.read_u16::<BigEndian>()?;
         let access_flags = MethodAccessFlags::from_bytes(bytes)?;
         let bootstrap_method = MethodParameter {
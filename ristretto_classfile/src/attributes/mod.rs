@@ -4,6 +4,7 @@ mod annotation_value_pair;
 mod array_type;
 mod attribute;
 mod bootstrap_method;
+mod code_builder;
 mod exception_table_entry;
 mod exports;
 mod exports_flags;
@@ -36,6 +37,7 @@ pub use annotation_value_pair::AnnotationValuePair;
 pub use array_type::ArrayType;
 pub use attribute::Attribute;
 pub use bootstrap_method::BootstrapMethod;
+pub use code_builder::CodeBuilder;
 pub use exception_table_entry::ExceptionTableEntry;
 pub use exports::Exports;
 pub use exports_flags::ExportsFlags;
@@ -44,7 +44,7 @@ impl NestedClassAccessFlags {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<NestedClassAccessFlags> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<NestedClassAccessFlags> {
         let access_flags = bytes.read_u16::<BigEndian>()?;
         let access_flags = NestedClassAccessFlags::from_bits_truncate(access_flags);
         Ok(access_flags)
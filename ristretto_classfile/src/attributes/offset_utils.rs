@@ -600,4 +600,86 @@ mod tests {
         assert_eq!(instructions, instructions_from_bytes.as_slice());
         Ok(())
     }
+
+    /// All branch instructions with a 16-bit offset.
+    fn branch_instructions() -> Vec<fn(u16) -> Instruction> {
+        vec![
+            Instruction::Ifeq,
+            Instruction::Ifne,
+            Instruction::Iflt,
+            Instruction::Ifge,
+            Instruction::Ifgt,
+            Instruction::Ifle,
+            Instruction::If_icmpeq,
+            Instruction::If_icmpne,
+            Instruction::If_icmplt,
+            Instruction::If_icmpge,
+            Instruction::If_icmpgt,
+            Instruction::If_icmple,
+            Instruction::If_acmpeq,
+            Instruction::If_acmpne,
+            Instruction::Goto,
+            Instruction::Jsr,
+            Instruction::Ifnull,
+            Instruction::Ifnonnull,
+        ]
+    }
+
+    /// Verify that the instructions serialize to the expected bytes and that the bytes deserialize
+    /// to the original instructions and serialize back to the same bytes.
+    fn test_round_trip(instructions: &[Instruction], expected_bytes: &[u8]) -> Result<()> {
+        let (_instruction_to_byte_map, bytes) = instructions_to_bytes(instructions)?;
+        assert_eq!(expected_bytes, bytes.as_slice());
+
+        let (_byte_to_instruction_map, result) =
+            instructions_from_bytes(&mut Cursor::new(bytes.clone()))?;
+        assert_eq!(instructions, result.as_slice());
+
+        let (_instruction_to_byte_map, result_bytes) = instructions_to_bytes(&result)?;
+        assert_eq!(bytes, result_bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_branch_zero_offset() -> Result<()> {
+        for branch in branch_instructions() {
+            let instruction = branch(1);
+            let expected_bytes = [Instruction::Nop.code(), instruction.code(), 0, 0];
+            test_round_trip(&[Instruction::Nop, instruction], &expected_bytes)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_branch_one_offset() -> Result<()> {
+        for branch in branch_instructions() {
+            let instruction = branch(2);
+            let expected_bytes = [
+                Instruction::Nop.code(),
+                instruction.code(),
+                0,
+                3,
+                Instruction::Nop.code(),
+            ];
+            test_round_trip(
+                &[Instruction::Nop, instruction, Instruction::Nop],
+                &expected_bytes,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_wide_branch_zero_and_one_offset() -> Result<()> {
+        for branch in [Instruction::Goto_w, Instruction::Jsr_w] {
+            let instruction = branch(0);
+            let expected_bytes = [instruction.code(), 0, 0, 0, 0];
+            test_round_trip(&[instruction], &expected_bytes)?;
+
+            let instruction = branch(1);
+            let expected_bytes = [instruction.code(), 0, 0, 0, 5, Instruction::Nop.code()];
+            test_round_trip(&[instruction, Instruction::Nop], &expected_bytes)?;
+        }
+        Ok(())
+    }
 }
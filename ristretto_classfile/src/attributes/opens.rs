@@ -19,7 +19,7 @@ impl Opens {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<Opens> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<Opens> {
         let index = bytes.read_u16::<BigEndian>()?;
         let flags = OpensFlags::from_bytes(bytes)?;
         let to_index_count = bytes.read_u16::<BigEndian>()?;
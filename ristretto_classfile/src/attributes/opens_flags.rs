@@ -30,7 +30,7 @@ impl OpensFlags {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<OpensFlags> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<OpensFlags> {
         let access_flags = bytes.read_u16::<BigEndian>()?;
         let access_flags = OpensFlags::from_bits_truncate(access_flags);
         Ok(access_flags)
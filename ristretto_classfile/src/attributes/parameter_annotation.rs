@@ -17,7 +17,7 @@ impl ParameterAnnotation {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<ParameterAnnotation> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<ParameterAnnotation> {
         let annotations_count = bytes.read_u16::<BigEndian>()? as usize;
         let mut annotations = Vec::with_capacity(annotations_count);
         for _ in 0..annotations_count {
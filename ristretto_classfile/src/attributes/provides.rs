@@ -17,7 +17,7 @@ impl Provides {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<Provides> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<Provides> {
         let index = bytes.read_u16::<BigEndian>()?;
         let to_index_count = bytes.read_u16::<BigEndian>()?;
         let mut with_index = Vec::with_capacity(to_index_count as usize);
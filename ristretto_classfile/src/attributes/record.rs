@@ -20,7 +20,10 @@ impl Record {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(constant_pool: &ConstantPool, bytes: &mut Cursor<Vec<u8>>) -> Result<Record> {
+    pub fn from_bytes<T: AsRef<[u8]>>(
+        constant_pool: &ConstantPool,
+        bytes: &mut Cursor<T>,
+    ) -> Result<Record> {
         let name_index = bytes.read_u16::<BigEndian>()?;
         let descriptor_index = bytes.read_u16::<BigEndian>()?;
         let attributes_count = bytes.read_u16::<BigEndian>()? as usize;
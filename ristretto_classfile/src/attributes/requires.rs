@@ -19,7 +19,7 @@ impl Requires {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<Requires> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<Requires> {
         let index = bytes.read_u16::<BigEndian>()?;
         let flags = RequiresFlags::from_bytes(bytes)?;
         let version_index = bytes.read_u16::<BigEndian>()?;
@@ -36,7 +36,7 @@ impl RequiresFlags {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<RequiresFlags> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<RequiresFlags> {
         let access_flags = bytes.read_u16::<BigEndian>()?;
         let access_flags = RequiresFlags::from_bits_truncate(access_flags);
         Ok(access_flags)
@@ -62,7 +62,7 @@ impl StackFrame {
     ///
     /// # Errors
     /// Returns an error if the frame type is invalid.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<StackFrame> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<StackFrame> {
         let frame_type = bytes.read_u8()?;
         let frame = match frame_type {
             0..=63 => StackFrame::SameFrame { frame_type },
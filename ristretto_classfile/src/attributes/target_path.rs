@@ -17,7 +17,7 @@ impl TargetPath {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<TargetPath> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<TargetPath> {
         let type_path_kind = bytes.read_u8()?;
         let type_argument_index = bytes.read_u8()?;
 
@@ -75,7 +75,7 @@ impl TargetType {
     ///
     /// # Errors
     /// If the target type is not a valid `TargetType`.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<TargetType> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<TargetType> {
         let target_type = bytes.read_u8()?;
 
         let array_type = match target_type {
@@ -20,7 +20,7 @@ impl TypeAnnotation {
     ///
     /// # Errors
     /// - If the target type fails to deserialize.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<TypeAnnotation> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<TypeAnnotation> {
         let target_type = TargetType::from_bytes(bytes)?;
 
         let type_path_count = bytes.read_u8()? as usize;
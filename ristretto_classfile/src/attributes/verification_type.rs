@@ -41,7 +41,7 @@ impl VerificationType {
     ///
     /// # Errors
     /// Returns an error if the tag is invalid.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<VerificationType> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<VerificationType> {
         let tag = bytes.read_u8()?;
 
         let verification_type = match tag {
@@ -0,0 +1,16 @@
+use crate::reference_kind::ReferenceKind;
+
+/// Resolved information from an entry of the `BootstrapMethods` attribute of a class.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.23>
+#[derive(Clone, Debug, PartialEq)]
+pub struct BootstrapMethodInfo {
+    /// The constant pool index of the `CONSTANT_MethodHandle` of the bootstrap method.
+    pub method_handle_index: u16,
+    /// The kind of the bootstrap method handle; usually `InvokeStatic` or `NewInvokeSpecial`.
+    pub reference_kind: ReferenceKind,
+    /// The constant pool index of the method referenced by the method handle.
+    pub reference_index: u16,
+    /// The constant pool indexes of the static arguments; each references a loadable constant.
+    pub arguments: Vec<u16>,
+}
@@ -1,12 +1,16 @@
 use crate::attributes::Attribute;
+use crate::bootstrap_method_info::BootstrapMethodInfo;
 use crate::class_access_flags::ClassAccessFlags;
 use crate::constant_pool::ConstantPool;
 use crate::display::indent_lines;
+use crate::enclosing_method::EnclosingMethodInfo;
 use crate::error::Error::{InvalidMagicNumber, VerificationError};
 use crate::error::Result;
 use crate::field::Field;
+use crate::javap;
 use crate::method::Method;
-use crate::verifiers::verifier;
+use crate::signature;
+use crate::verifiers::{bootstrap_methods, verifier};
 use crate::version::Version;
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use std::fmt;
@@ -39,6 +43,127 @@ impl ClassFile {
         self.constant_pool.try_get_class(self.this_class)
     }
 
+    /// Get the method with the given name and descriptor (e.g. "add" and "(II)I").  Returns `None`
+    /// if the class does not declare the method.
+    #[must_use]
+    pub fn method(&self, name: &str, descriptor: &str) -> Option<&Method> {
+        self.methods.iter().find(|method| {
+            self.utf8_equals(method.name_index, name)
+                && self.utf8_equals(method.descriptor_index, descriptor)
+        })
+    }
+
+    /// Get all methods with the given name (e.g. the overloads of a method), in the order they
+    /// are declared.
+    #[must_use]
+    pub fn methods_named(&self, name: &str) -> Vec<&Method> {
+        self.methods
+            .iter()
+            .filter(|method| self.utf8_equals(method.name_index, name))
+            .collect()
+    }
+
+    /// Get the field with the given name.  Returns `None` if the class does not declare the field.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.fields
+            .iter()
+            .find(|field| self.utf8_equals(field.name_index, name))
+    }
+
+    /// Returns true if the constant pool entry at the index is a UTF-8 constant equal to the value.
+    fn utf8_equals(&self, index: u16, value: &str) -> bool {
+        self.constant_pool
+            .try_get_utf8(index)
+            .is_ok_and(|utf8| utf8 == value)
+    }
+
+    /// Get the enclosing method of a local or anonymous class from the `EnclosingMethod`
+    /// attribute.  Returns `None` if the class does not have an `EnclosingMethod` attribute.
+    ///
+    /// # Errors
+    /// Returns an error if the constant pool entries referenced by the attribute are invalid.
+    pub fn enclosing_method(&self) -> Result<Option<EnclosingMethodInfo>> {
+        let Some((class_index, method_index)) =
+            self.attributes
+                .iter()
+                .find_map(|attribute| match attribute {
+                    Attribute::EnclosingMethod {
+                        class_index,
+                        method_index,
+                        ..
+                    } => Some((*class_index, *method_index)),
+                    _ => None,
+                })
+        else {
+            return Ok(None);
+        };
+
+        let class_name = self.constant_pool.try_get_class(class_index)?.clone();
+        let (method_name, method_descriptor) = if method_index == 0 {
+            (None, None)
+        } else {
+            let (name_index, descriptor_index) =
+                self.constant_pool.try_get_name_and_type(method_index)?;
+            let method_name = self.constant_pool.try_get_utf8(*name_index)?;
+            let method_descriptor = self.constant_pool.try_get_utf8(*descriptor_index)?;
+            (Some(method_name.clone()), Some(method_descriptor.clone()))
+        };
+
+        Ok(Some(EnclosingMethodInfo {
+            class_name,
+            method_name,
+            method_descriptor,
+        }))
+    }
+
+    /// Get the bootstrap methods of the class from the `BootstrapMethods` attribute, in the order
+    /// they are referenced by `CONSTANT_Dynamic` and `CONSTANT_InvokeDynamic` constants.  Returns
+    /// an empty vector if the class does not have a `BootstrapMethods` attribute.
+    ///
+    /// # Errors
+    /// Returns an error if a bootstrap method does not reference a method handle or a static
+    /// argument does not reference a loadable constant.
+    pub fn bootstrap_methods(&self) -> Result<Vec<BootstrapMethodInfo>> {
+        let Some(methods) = self
+            .attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::BootstrapMethods { methods, .. } => Some(methods),
+                _ => None,
+            })
+        else {
+            return Ok(Vec::new());
+        };
+
+        let mut bootstrap_methods = Vec::with_capacity(methods.len());
+        for bootstrap_method in methods {
+            bootstrap_methods::verify_bootstrap_method(&self.constant_pool, bootstrap_method)?;
+            let method_handle_index = bootstrap_method.bootstrap_method_ref;
+            let (reference_kind, reference_index) = self
+                .constant_pool
+                .try_get_method_handle(method_handle_index)?;
+            bootstrap_methods.push(BootstrapMethodInfo {
+                method_handle_index,
+                reference_kind: reference_kind.clone(),
+                reference_index: *reference_index,
+                arguments: bootstrap_method.arguments.clone(),
+            });
+        }
+        Ok(bootstrap_methods)
+    }
+
+    /// Get the generic signature of the class (e.g. `<T:Ljava/lang/Object;>Ljava/lang/Object;`)
+    /// from the `Signature` attribute; the signature can be parsed with
+    /// [`ClassSignature::parse`](crate::ClassSignature::parse).  Returns `None` if the class does
+    /// not have a `Signature` attribute.
+    ///
+    /// # Errors
+    /// Returns an error if the signature index of the attribute is invalid.
+    pub fn signature(&self) -> Result<Option<&String>> {
+        signature::signature(&self.constant_pool, &self.attributes)
+    }
+
     /// Verify the `ClassFile`.
     ///
     /// # Errors
@@ -61,7 +186,7 @@ impl ClassFile {
     ///
     /// # Errors
     /// Returns an error if the bytes are not a valid class file.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<ClassFile> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<ClassFile> {
         let magic = bytes.read_u32::<BigEndian>()?;
         if magic != MAGIC {
             return Err(InvalidMagicNumber(magic));
@@ -114,6 +239,16 @@ impl ClassFile {
         Ok(class_file)
     }
 
+    /// Format the `ClassFile` in the style of `javap -v`: the class declaration, version, access
+    /// flags, constant pool (with `#n` indexes and resolved `//` comments), and the fields and
+    /// methods with their descriptors and attributes.
+    ///
+    /// # Errors
+    /// Returns an error if a constant pool entry or descriptor referenced by the class is invalid.
+    pub fn to_javap_string(&self) -> Result<String> {
+        javap::to_javap_string(self)
+    }
+
     /// Serialize the `ClassFile` to bytes.
     ///
     /// # Errors
@@ -219,8 +354,8 @@ impl fmt::Display for ClassFile {
 mod test {
     use super::*;
     use crate::error::Result;
-    use crate::Constant;
-    use crate::Error::{InvalidConstantPoolIndexType, IoError};
+    use crate::Error::{InvalidConstantPoolIndex, InvalidConstantPoolIndexType, IoError};
+    use crate::{BaseType, Constant, FieldType, ReferenceKind};
     use indoc::indoc;
 
     #[test]
@@ -254,12 +389,157 @@ mod test {
         };
 
         assert_eq!(
-            Err(InvalidConstantPoolIndexType(1)),
+            Err(InvalidConstantPoolIndexType {
+                index: 1,
+                expected: "CONSTANT_Class".to_string(),
+                actual: "Utf8 Test".to_string(),
+            }),
             class_file.class_name()
         );
         Ok(())
     }
 
+    fn overloaded_class_file() -> Result<ClassFile> {
+        let class_bytes = include_bytes!("../../classes/Overloaded.class");
+        ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))
+    }
+
+    #[test]
+    fn test_method() -> Result<()> {
+        let class_file = overloaded_class_file()?;
+        let constant_pool = &class_file.constant_pool;
+
+        let method = class_file.method("add", "(J)J").expect("add(J)J");
+        assert_eq!("add", constant_pool.try_get_utf8(method.name_index)?);
+        assert_eq!("(J)J", constant_pool.try_get_utf8(method.descriptor_index)?);
+        assert!(class_file.method("<init>", "()V").is_some());
+        assert!(class_file.method("add", "(D)D").is_none());
+        assert!(class_file.method("subtract", "(I)I").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_methods_named() -> Result<()> {
+        let class_file = overloaded_class_file()?;
+        let constant_pool = &class_file.constant_pool;
+
+        let descriptors = class_file
+            .methods_named("add")
+            .iter()
+            .map(|method| constant_pool.try_get_utf8(method.descriptor_index))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(vec!["(I)I", "(J)J", "(II)I"], descriptors);
+        assert!(class_file.methods_named("subtract").is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_field() -> Result<()> {
+        let class_file = overloaded_class_file()?;
+        let field = class_file.field("value").expect("value");
+        assert_eq!(FieldType::Base(BaseType::Int), field.field_type);
+        assert!(class_file.field("missing").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bootstrap_methods() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Lambda.class");
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let constant_pool = &class_file.constant_pool;
+
+        let bootstrap_methods = class_file.bootstrap_methods()?;
+        assert_eq!(1, bootstrap_methods.len());
+        let bootstrap_method = &bootstrap_methods[0];
+        assert_eq!(ReferenceKind::InvokeStatic, bootstrap_method.reference_kind);
+        let (class_index, name_and_type_index) =
+            constant_pool.try_get_method_ref(bootstrap_method.reference_index)?;
+        assert_eq!(
+            "java/lang/invoke/LambdaMetafactory",
+            constant_pool.try_get_class(*class_index)?
+        );
+        let (name_index, _descriptor_index) =
+            constant_pool.try_get_name_and_type(*name_and_type_index)?;
+        assert_eq!("metafactory", constant_pool.try_get_utf8(*name_index)?);
+
+        // The static arguments are the erased method type, the implementation method handle and
+        // the instantiated method type of the lambda
+        let arguments = &bootstrap_method.arguments;
+        assert_eq!(3, arguments.len());
+        let descriptor_index = constant_pool.try_get_method_type(arguments[0])?;
+        assert_eq!("(I)I", constant_pool.try_get_utf8(*descriptor_index)?);
+        let (reference_kind, _reference_index) =
+            constant_pool.try_get_method_handle(arguments[1])?;
+        assert_eq!(&ReferenceKind::InvokeStatic, reference_kind);
+        assert_eq!(arguments[0], arguments[2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bootstrap_methods_none() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Simple.class");
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        assert!(class_file.bootstrap_methods()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bootstrap_methods_invalid_argument() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Lambda.class");
+        let mut class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+        let Some(Attribute::BootstrapMethods { methods, .. }) = class_file
+            .attributes
+            .iter_mut()
+            .find(|attribute| matches!(attribute, Attribute::BootstrapMethods { .. }))
+        else {
+            panic!("expected BootstrapMethods attribute");
+        };
+        methods[0].arguments.push(u16::MAX);
+
+        assert_eq!(
+            Err(InvalidConstantPoolIndex(u16::MAX)),
+            class_file.bootstrap_methods()
+        );
+        assert!(class_file.verify().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_enclosing_method() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Anonymous$2.class");
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+
+        let enclosing_method = class_file.enclosing_method()?.expect("enclosing method");
+        assert_eq!("Anonymous", enclosing_method.class_name);
+        assert_eq!(Some("runnable".to_string()), enclosing_method.method_name);
+        assert_eq!(
+            Some("()Ljava/lang/Runnable;".to_string()),
+            enclosing_method.method_descriptor
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_enclosing_method_field_initializer() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Anonymous$1.class");
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+
+        let enclosing_method = class_file.enclosing_method()?.expect("enclosing method");
+        assert_eq!("Anonymous", enclosing_method.class_name);
+        assert_eq!(None, enclosing_method.method_name);
+        assert_eq!(None, enclosing_method.method_descriptor);
+        Ok(())
+    }
+
+    #[test]
+    fn test_enclosing_method_none() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Simple.class");
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))?;
+
+        assert_eq!(None, class_file.enclosing_method()?);
+        Ok(())
+    }
+
     #[test]
     fn test_verify() -> Result<()> {
         let class_bytes = include_bytes!("../../classes/Simple.class");
@@ -378,6 +658,14 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_from_bytes_slice() -> Result<()> {
+        let class_bytes = include_bytes!("../../classes/Simple.class");
+        let class_file = ClassFile::from_bytes(&mut Cursor::new(&class_bytes[..]))?;
+        assert_eq!("Simple", class_file.class_name()?);
+        Ok(())
+    }
+
     #[test]
     fn test_from_bytes_invalid() {
         let bytes = vec![
@@ -100,7 +100,7 @@ impl Constant {
     ///
     /// # Errors
     /// Returns an error if the tag is invalid.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<Constant> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<Constant> {
         let tag = bytes.read_u8()?;
         let constant = match tag {
             1 => {
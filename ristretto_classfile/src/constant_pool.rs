@@ -1,11 +1,22 @@
 use crate::constant::Constant;
 use crate::error::Result;
+use crate::Error;
 use crate::Error::{InvalidConstantPoolIndex, InvalidConstantPoolIndexType};
 use crate::ReferenceKind;
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use std::io::Cursor;
 use std::{fmt, io};
 
+/// Create the error for a constant at the index that is not of the expected type; `expected`
+/// names the expected constant tag(s) (e.g. `CONSTANT_Utf8`).
+pub(crate) fn invalid_index_type(index: u16, expected: &str, constant: &Constant) -> Error {
+    InvalidConstantPoolIndexType {
+        index,
+        expected: expected.to_string(),
+        actual: constant.to_string(),
+    }
+}
+
 /// Constant pool.
 ///
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.4>
@@ -93,7 +104,7 @@ impl ConstantPool {
     ///
     /// # Errors
     /// Returns an error if the bytes are not a valid constant pool.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<ConstantPool> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<ConstantPool> {
         let mut constant_pool = ConstantPool::default();
         let constant_pool_count =
             bytes
@@ -143,7 +154,7 @@ impl ConstantPool {
     pub fn try_get_utf8(&self, index: u16) -> Result<&String> {
         match self.try_get(index)? {
             Constant::Utf8(value) => Ok(value),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Utf8", constant)),
         }
     }
 
@@ -164,7 +175,7 @@ impl ConstantPool {
     pub fn try_get_integer(&self, index: u16) -> Result<&i32> {
         match self.try_get(index)? {
             Constant::Integer(value) => Ok(value),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Integer", constant)),
         }
     }
 
@@ -185,7 +196,7 @@ impl ConstantPool {
     pub fn try_get_float(&self, index: u16) -> Result<&f32> {
         match self.try_get(index)? {
             Constant::Float(value) => Ok(value),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Float", constant)),
         }
     }
 
@@ -206,7 +217,7 @@ impl ConstantPool {
     pub fn try_get_long(&self, index: u16) -> Result<&i64> {
         match self.try_get(index)? {
             Constant::Long(value) => Ok(value),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Long", constant)),
         }
     }
 
@@ -227,7 +238,7 @@ impl ConstantPool {
     pub fn try_get_double(&self, index: u16) -> Result<&f64> {
         match self.try_get(index)? {
             Constant::Double(value) => Ok(value),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Double", constant)),
         }
     }
 
@@ -249,7 +260,7 @@ impl ConstantPool {
     pub fn try_get_class(&self, index: u16) -> Result<&String> {
         match self.try_get(index)? {
             Constant::Class(utf8_index) => self.try_get_utf8(*utf8_index),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Class", constant)),
         }
     }
 
@@ -271,7 +282,7 @@ impl ConstantPool {
     pub fn try_get_string(&self, index: u16) -> Result<&String> {
         match self.try_get(index)? {
             Constant::String(value) => self.try_get_utf8(*value),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_String", constant)),
         }
     }
 
@@ -304,7 +315,7 @@ impl ConstantPool {
                 class_index,
                 name_and_type_index,
             } => Ok((class_index, name_and_type_index)),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Fieldref", constant)),
         }
     }
 
@@ -337,7 +348,7 @@ impl ConstantPool {
                 class_index,
                 name_and_type_index,
             } => Ok((class_index, name_and_type_index)),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Methodref", constant)),
         }
     }
 
@@ -371,7 +382,11 @@ impl ConstantPool {
                 class_index,
                 name_and_type_index,
             } => Ok((class_index, name_and_type_index)),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(
+                index,
+                "CONSTANT_InterfaceMethodref",
+                constant,
+            )),
         }
     }
 
@@ -401,7 +416,7 @@ impl ConstantPool {
                 name_index,
                 descriptor_index,
             } => Ok((name_index, descriptor_index)),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_NameAndType", constant)),
         }
     }
 
@@ -433,7 +448,7 @@ impl ConstantPool {
                 reference_kind,
                 reference_index,
             } => Ok((reference_kind, reference_index)),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_MethodHandle", constant)),
         }
     }
 
@@ -456,7 +471,7 @@ impl ConstantPool {
     pub fn try_get_method_type(&self, index: u16) -> Result<&u16> {
         match self.try_get(index)? {
             Constant::MethodType(name_and_type_index) => Ok(name_and_type_index),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_MethodType", constant)),
         }
     }
 
@@ -489,7 +504,7 @@ impl ConstantPool {
                 bootstrap_method_attr_index,
                 name_and_type_index,
             } => Ok((bootstrap_method_attr_index, name_and_type_index)),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Dynamic", constant)),
         }
     }
 
@@ -523,7 +538,11 @@ impl ConstantPool {
                 bootstrap_method_attr_index,
                 name_and_type_index,
             } => Ok((bootstrap_method_attr_index, name_and_type_index)),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(
+                index,
+                "CONSTANT_InvokeDynamic",
+                constant,
+            )),
         }
     }
 
@@ -545,7 +564,7 @@ impl ConstantPool {
     pub fn try_get_module(&self, index: u16) -> Result<&String> {
         match self.try_get(index)? {
             Constant::Module(name_index) => self.try_get_utf8(*name_index),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Module", constant)),
         }
     }
 
@@ -567,7 +586,7 @@ impl ConstantPool {
     pub fn try_get_package(&self, index: u16) -> Result<&String> {
         match self.try_get(index)? {
             Constant::Package(name_index) => self.try_get_utf8(*name_index),
-            _ => Err(InvalidConstantPoolIndexType(index)),
+            constant => Err(invalid_index_type(index, "CONSTANT_Package", constant)),
         }
     }
 
@@ -900,6 +919,29 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_try_get_invalid_type() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let index = constant_pool.add_integer(42)?;
+        let result = constant_pool.try_get_utf8(index);
+        assert_eq!(
+            Err(InvalidConstantPoolIndexType {
+                index,
+                expected: "CONSTANT_Utf8".to_string(),
+                actual: "Integer 42".to_string(),
+            }),
+            result
+        );
+        let Err(error) = result else {
+            panic!("expected error");
+        };
+        assert_eq!(
+            "Invalid constant pool index type 1; expected CONSTANT_Utf8, found Integer 42",
+            error.to_string()
+        );
+        Ok(())
+    }
+
     fn test_try_get_constant<T>(f: fn(&ConstantPool, u16) -> Result<&T>, constant: Constant)
     where
         T: Debug + PartialEq,
@@ -912,7 +954,10 @@ mod test {
         }
         constant_pool.push(constant);
         assert_eq!(Err(InvalidConstantPoolIndex(0)), f(&constant_pool, 0));
-        assert_eq!(Err(InvalidConstantPoolIndexType(1)), f(&constant_pool, 1));
+        assert!(matches!(
+            f(&constant_pool, 1),
+            Err(InvalidConstantPoolIndexType { index: 1, .. })
+        ));
         assert!(f(&constant_pool, 2).is_ok());
     }
 
@@ -927,7 +972,10 @@ mod test {
         constant_pool.push(Constant::Utf8("foo".to_string()));
         constant_pool.push(constant);
         assert_eq!(Err(InvalidConstantPoolIndex(0)), f(&constant_pool, 0));
-        assert_eq!(Err(InvalidConstantPoolIndexType(1)), f(&constant_pool, 1));
+        assert!(matches!(
+            f(&constant_pool, 1),
+            Err(InvalidConstantPoolIndexType { index: 1, .. })
+        ));
         assert!(f(&constant_pool, 2).is_ok());
     }
 
@@ -0,0 +1,13 @@
+/// Resolved information from an `EnclosingMethod` attribute of a local or anonymous class.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.7>
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnclosingMethodInfo {
+    /// The fully qualified name of the innermost class that encloses the declaration.
+    pub class_name: String,
+    /// The name of the enclosing method; `None` if the class is not immediately enclosed by a
+    /// method or constructor (e.g. an instance, static or field initializer).
+    pub method_name: Option<String>,
+    /// The descriptor of the enclosing method; `None` if `method_name` is `None`.
+    pub method_descriptor: Option<String>,
+}
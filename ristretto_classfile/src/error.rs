@@ -34,9 +34,13 @@ pub enum Error {
     /// Invalid constant pool index
     #[error("Invalid constant pool index {0}")]
     InvalidConstantPoolIndex(u16),
-    /// Invalid constant pool index type
-    #[error("Invalid constant pool index type {0}")]
-    InvalidConstantPoolIndexType(u16),
+    /// The constant at a constant pool index is not of the expected type
+    #[error("Invalid constant pool index type {index}; expected {expected}, found {actual}")]
+    InvalidConstantPoolIndexType {
+        index: u16,
+        expected: String,
+        actual: String,
+    },
     /// Invalid constant tag
     #[error("Invalid constant tag: {0}")]
     InvalidConstantTag(u8),
@@ -61,9 +65,15 @@ pub enum Error {
     /// Invalid method access flags
     #[error("Invalid method access flags: {0}")]
     InvalidMethodAccessFlags(u16),
+    /// Invalid method descriptor
+    #[error("Invalid method descriptor: {0}")]
+    InvalidMethodDescriptor(String),
     /// Error when creating a reference kind
     #[error("Invalid reference kind: {0}")]
     InvalidReferenceKind(u8),
+    /// Invalid generic signature
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
     /// Invalid stack frame type
     #[error("Invalid stack frame type: {0}")]
     InvalidStackFrameType(u8),
@@ -79,6 +89,9 @@ pub enum Error {
     /// Invalid tag in the constant pool for the class file version
     #[error("Class file version does not support constant tag {0}")]
     InvalidVersionConstant(u8),
+    /// Invalid instruction for the class file version
+    #[error("Class file version does not support instruction {0}")]
+    InvalidVersionInstruction(String),
     /// Invalid wide instruction
     #[error("Invalid wide instruction: {0}")]
     InvalidWideInstruction(u8),
@@ -3,6 +3,7 @@ use crate::constant_pool::ConstantPool;
 use crate::display::indent_lines;
 use crate::error::Result;
 use crate::field_access_flags::FieldAccessFlags;
+use crate::signature;
 use crate::FieldType;
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use std::fmt;
@@ -25,7 +26,10 @@ impl Field {
     ///
     /// # Errors
     /// Returns an error if the bytes do not represent a valid Field.
-    pub fn from_bytes(constant_pool: &ConstantPool, bytes: &mut Cursor<Vec<u8>>) -> Result<Field> {
+    pub fn from_bytes<T: AsRef<[u8]>>(
+        constant_pool: &ConstantPool,
+        bytes: &mut Cursor<T>,
+    ) -> Result<Field> {
         let access_flags = FieldAccessFlags::from_bytes(bytes)?;
         let name_index = bytes.read_u16::<BigEndian>()?;
         let descriptor_index = bytes.read_u16::<BigEndian>()?;
@@ -49,6 +53,17 @@ impl Field {
         Ok(field)
     }
 
+    /// Get the generic signature of the field (e.g. `Ljava/util/List<Ljava/lang/String;>;`) from
+    /// the `Signature` attribute; the signature can be parsed with
+    /// [`TypeSignature::parse`](crate::TypeSignature::parse).  Returns `None` if the field does not
+    /// have a `Signature` attribute.
+    ///
+    /// # Errors
+    /// Returns an error if the signature index of the attribute is invalid.
+    pub fn signature<'a>(&self, constant_pool: &'a ConstantPool) -> Result<Option<&'a String>> {
+        signature::signature(constant_pool, &self.attributes)
+    }
+
     /// Serialize the Field to bytes.
     ///
     /// # Errors
@@ -42,7 +42,7 @@ impl FieldAccessFlags {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<FieldAccessFlags> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<FieldAccessFlags> {
         let access_flags = bytes.read_u16::<BigEndian>()?;
         let access_flags = FieldAccessFlags::from_bits_truncate(access_flags);
         Ok(access_flags)
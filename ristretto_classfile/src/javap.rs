@@ -0,0 +1,479 @@
+use crate::attributes::Attribute;
+use crate::class_access_flags::ClassAccessFlags;
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::constant_pool::ConstantPool;
+use crate::display::indent_lines;
+use crate::error::Result;
+use crate::field::Field;
+use crate::field_type::FieldType;
+use crate::method::Method;
+use crate::method_access_flags::MethodAccessFlags;
+use crate::method_descriptor::MethodDescriptor;
+
+/// The width of the header lines and constant pool operands that precede a `//` comment.
+const HEADER_WIDTH: usize = 40;
+const OPERANDS_WIDTH: usize = 14;
+
+/// Format the class file in the style of `javap -v`.
+pub(crate) fn to_javap_string(class_file: &ClassFile) -> Result<String> {
+    let constant_pool = &class_file.constant_pool;
+    let mut lines = vec![class_declaration(class_file)?];
+    lines.push(format!("  minor version: {}", class_file.version.minor()));
+    lines.push(format!(
+        "  major version: {} ({})",
+        class_file.version.major(),
+        class_file.version
+    ));
+    lines.push(format!("  flags: {}", class_file.access_flags));
+    lines.push(with_comment(
+        &format!("  this_class: #{}", class_file.this_class),
+        HEADER_WIDTH,
+        constant_pool.try_get_class(class_file.this_class)?,
+    ));
+    if class_file.super_class == 0 {
+        lines.push("  super_class: #0".to_string());
+    } else {
+        lines.push(with_comment(
+            &format!("  super_class: #{}", class_file.super_class),
+            HEADER_WIDTH,
+            constant_pool.try_get_class(class_file.super_class)?,
+        ));
+    }
+    lines.push(format!(
+        "  interfaces: {}, fields: {}, methods: {}, attributes: {}",
+        class_file.interfaces.len(),
+        class_file.fields.len(),
+        class_file.methods.len(),
+        class_file.attributes.len()
+    ));
+
+    lines.push("Constant pool:".to_string());
+    let constant_pool_length = u16::try_from(constant_pool.len())?;
+    for index in 1..=constant_pool_length {
+        // Long and double constants are followed by an unusable placeholder entry
+        if let Some(constant) = constant_pool.get(index) {
+            lines.push(constant_line(constant_pool, index, constant)?);
+        }
+    }
+
+    lines.push("{".to_string());
+    let mut members = Vec::new();
+    for field in &class_file.fields {
+        members.push(field_string(constant_pool, field)?);
+    }
+    let class_name = class_file.class_name()?;
+    for method in &class_file.methods {
+        members.push(method_string(constant_pool, class_name, method)?);
+    }
+    if !members.is_empty() {
+        lines.push(members.join("\n\n"));
+    }
+    lines.push("}".to_string());
+
+    for attribute in &class_file.attributes {
+        lines.push(attribute_string(constant_pool, attribute)?);
+    }
+
+    let mut javap = lines.join("\n");
+    javap.push('\n');
+    Ok(javap)
+}
+
+/// Get the declaration of the class (e.g. `public class Foo extends Bar implements Baz`).
+fn class_declaration(class_file: &ClassFile) -> Result<String> {
+    let constant_pool = &class_file.constant_pool;
+    let class_name = java_name(class_file.class_name()?);
+    let mut declaration = vec![class_file.access_flags.as_code(), class_name];
+
+    let mut interfaces = Vec::with_capacity(class_file.interfaces.len());
+    for interface in &class_file.interfaces {
+        interfaces.push(java_name(constant_pool.try_get_class(*interface)?));
+    }
+    let interfaces = interfaces.join(", ");
+
+    if class_file
+        .access_flags
+        .contains(ClassAccessFlags::INTERFACE)
+    {
+        if !interfaces.is_empty() {
+            declaration.push(format!("extends {interfaces}"));
+        }
+        return Ok(declaration.join(" "));
+    }
+
+    if class_file.super_class != 0 {
+        let super_class = constant_pool.try_get_class(class_file.super_class)?;
+        if super_class != "java/lang/Object" {
+            declaration.push(format!("extends {}", java_name(super_class)));
+        }
+    }
+    if !interfaces.is_empty() {
+        declaration.push(format!("implements {interfaces}"));
+    }
+    Ok(declaration.join(" "))
+}
+
+/// Get the constant pool entry line for a constant (e.g.
+/// `#1 = Methodref          #2.#3          // java/lang/Object."<init>":()V`).
+fn constant_line(constant_pool: &ConstantPool, index: u16, constant: &Constant) -> Result<String> {
+    let (tag, operands) = match constant {
+        Constant::Utf8(value) => ("Utf8", value.clone()),
+        Constant::Integer(value) => ("Integer", value.to_string()),
+        Constant::Float(value) => ("Float", format!("{value:?}f")),
+        Constant::Long(value) => ("Long", format!("{value}l")),
+        Constant::Double(value) => ("Double", format!("{value:?}d")),
+        Constant::Class(name_index) => ("Class", format!("#{name_index}")),
+        Constant::String(string_index) => ("String", format!("#{string_index}")),
+        Constant::FieldRef {
+            class_index,
+            name_and_type_index,
+        } => ("Fieldref", format!("#{class_index}.#{name_and_type_index}")),
+        Constant::MethodRef {
+            class_index,
+            name_and_type_index,
+        } => (
+            "Methodref",
+            format!("#{class_index}.#{name_and_type_index}"),
+        ),
+        Constant::InterfaceMethodRef {
+            class_index,
+            name_and_type_index,
+        } => (
+            "InterfaceMethodref",
+            format!("#{class_index}.#{name_and_type_index}"),
+        ),
+        Constant::NameAndType {
+            name_index,
+            descriptor_index,
+        } => ("NameAndType", format!("#{name_index}:#{descriptor_index}")),
+        Constant::MethodHandle {
+            reference_kind,
+            reference_index,
+        } => (
+            "MethodHandle",
+            format!("{}:#{reference_index}", reference_kind.kind()),
+        ),
+        Constant::MethodType(descriptor_index) => ("MethodType", format!("#{descriptor_index}")),
+        Constant::Dynamic {
+            bootstrap_method_attr_index,
+            name_and_type_index,
+        } => (
+            "Dynamic",
+            format!("#{bootstrap_method_attr_index}:#{name_and_type_index}"),
+        ),
+        Constant::InvokeDynamic {
+            bootstrap_method_attr_index,
+            name_and_type_index,
+        } => (
+            "InvokeDynamic",
+            format!("#{bootstrap_method_attr_index}:#{name_and_type_index}"),
+        ),
+        Constant::Module(name_index) => ("Module", format!("#{name_index}")),
+        Constant::Package(name_index) => ("Package", format!("#{name_index}")),
+    };
+
+    let line = format!("{:>5} = {tag:<18} {operands}", format!("#{index}"));
+    let line = match constant_comment(constant_pool, constant)? {
+        Some(comment) => {
+            let width = line.len() - operands.len() + OPERANDS_WIDTH;
+            with_comment(&line, width, &comment)
+        }
+        None => line,
+    };
+    Ok(line)
+}
+
+/// Get the `//` comment that resolves the constant pool references of a constant; returns `None`
+/// for constants that do not reference other constants.
+fn constant_comment(constant_pool: &ConstantPool, constant: &Constant) -> Result<Option<String>> {
+    let comment = match constant {
+        Constant::Utf8(_)
+        | Constant::Integer(_)
+        | Constant::Float(_)
+        | Constant::Long(_)
+        | Constant::Double(_) => return Ok(None),
+        Constant::Class(name_index) => quote_name(constant_pool.try_get_utf8(*name_index)?),
+        Constant::String(string_index) => constant_pool.try_get_utf8(*string_index)?.clone(),
+        Constant::FieldRef {
+            class_index,
+            name_and_type_index,
+        }
+        | Constant::MethodRef {
+            class_index,
+            name_and_type_index,
+        }
+        | Constant::InterfaceMethodRef {
+            class_index,
+            name_and_type_index,
+        } => {
+            let class_name = quote_name(constant_pool.try_get_class(*class_index)?);
+            let name_and_type = name_and_type(constant_pool, *name_and_type_index)?;
+            format!("{class_name}.{name_and_type}")
+        }
+        Constant::NameAndType {
+            name_index,
+            descriptor_index,
+        } => {
+            let name = quote_name(constant_pool.try_get_utf8(*name_index)?);
+            let descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
+            format!("{name}:{descriptor}")
+        }
+        Constant::MethodHandle {
+            reference_kind,
+            reference_index,
+        } => {
+            let reference = constant_pool.try_get(*reference_index)?;
+            let reference = constant_comment(constant_pool, reference)?.unwrap_or_default();
+            format!("REF_{reference_kind} {reference}")
+        }
+        Constant::MethodType(descriptor_index) => {
+            constant_pool.try_get_utf8(*descriptor_index)?.clone()
+        }
+        Constant::Dynamic {
+            bootstrap_method_attr_index,
+            name_and_type_index,
+        }
+        | Constant::InvokeDynamic {
+            bootstrap_method_attr_index,
+            name_and_type_index,
+        } => {
+            let name_and_type = name_and_type(constant_pool, *name_and_type_index)?;
+            format!("#{bootstrap_method_attr_index}:{name_and_type}")
+        }
+        Constant::Module(name_index) | Constant::Package(name_index) => {
+            constant_pool.try_get_utf8(*name_index)?.clone()
+        }
+    };
+    Ok(Some(comment))
+}
+
+/// Get the `name:descriptor` of a name and type constant.
+fn name_and_type(constant_pool: &ConstantPool, index: u16) -> Result<String> {
+    let (name_index, descriptor_index) = constant_pool.try_get_name_and_type(index)?;
+    let name = quote_name(constant_pool.try_get_utf8(*name_index)?);
+    let descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
+    Ok(format!("{name}:{descriptor}"))
+}
+
+/// Quote names that are not valid Java identifiers (e.g. `"<init>"` and `"[Ljava/lang/String;"`).
+fn quote_name(name: &str) -> String {
+    if name.starts_with('<') || name.starts_with('[') {
+        format!("\"{name}\"")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Pad the line to the width and append the comment.
+fn with_comment(line: &str, width: usize, comment: &str) -> String {
+    format!("{line:<width$} // {comment}")
+}
+
+/// Convert an internal class name (e.g. `java/lang/String`) to a Java name (`java.lang.String`).
+fn java_name(class_name: &str) -> String {
+    class_name.replace('/', ".")
+}
+
+/// Get the Java source name of a field type (e.g. `java.lang.String[]`).
+fn type_name(field_type: &FieldType) -> String {
+    java_name(&field_type.to_string())
+}
+
+/// Format a field with its declaration, descriptor, flags and attributes.
+fn field_string(constant_pool: &ConstantPool, field: &Field) -> Result<String> {
+    let name = constant_pool.try_get_utf8(field.name_index)?;
+    let descriptor = constant_pool.try_get_utf8(field.descriptor_index)?;
+    let modifiers = field.access_flags.as_code();
+    let declaration = [modifiers.as_str(), &type_name(&field.field_type), name]
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut lines = vec![format!("  {declaration};")];
+    lines.push(format!("    descriptor: {descriptor}"));
+    lines.push(format!("    flags: {}", field.access_flags));
+    for attribute in &field.attributes {
+        let attribute = attribute_string(constant_pool, attribute)?;
+        lines.push(indent_lines(&attribute, "    "));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Format a method with its signature, descriptor, flags and attributes.
+fn method_string(
+    constant_pool: &ConstantPool,
+    class_name: &str,
+    method: &Method,
+) -> Result<String> {
+    let name = constant_pool.try_get_utf8(method.name_index)?;
+    let descriptor = constant_pool.try_get_utf8(method.descriptor_index)?;
+    let modifiers = method.access_flags.as_code();
+
+    let signature = if name == "<clinit>" {
+        "static {}".to_string()
+    } else {
+        let method_descriptor = MethodDescriptor::parse(descriptor)?;
+        let mut parameters = method_descriptor
+            .parameters
+            .iter()
+            .map(type_name)
+            .collect::<Vec<_>>();
+        if method.access_flags.contains(MethodAccessFlags::VARARGS) {
+            if let Some(parameter) = parameters.last_mut() {
+                if let Some(component_type) = parameter.strip_suffix("[]") {
+                    *parameter = format!("{component_type}...");
+                }
+            }
+        }
+        let parameters = parameters.join(", ");
+        if name == "<init>" {
+            format!("{}({parameters})", java_name(class_name))
+        } else {
+            let return_type = method_descriptor
+                .return_type
+                .as_ref()
+                .map_or_else(|| "void".to_string(), type_name);
+            format!("{return_type} {name}({parameters})")
+        }
+    };
+    let declaration = if modifiers.is_empty() {
+        signature
+    } else {
+        format!("{modifiers} {signature}")
+    };
+
+    let mut lines = vec![format!("  {declaration};")];
+    lines.push(format!("    descriptor: {descriptor}"));
+    lines.push(format!("    flags: {}", method.access_flags));
+    for attribute in &method.attributes {
+        let attribute = attribute_string(constant_pool, attribute)?;
+        lines.push(indent_lines(&attribute, "    "));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Format an attribute; attributes that name a constant are resolved against the constant pool.
+fn attribute_string(constant_pool: &ConstantPool, attribute: &Attribute) -> Result<String> {
+    let value = match attribute {
+        Attribute::SourceFile {
+            source_file_index, ..
+        } => {
+            let source_file = constant_pool.try_get_utf8(*source_file_index)?;
+            format!("SourceFile: \"{source_file}\"")
+        }
+        Attribute::ConstantValue {
+            constant_value_index,
+            ..
+        } => {
+            let constant_value = constant_pool.try_get_formatted_string(*constant_value_index)?;
+            format!("ConstantValue: {constant_value}")
+        }
+        Attribute::Signature {
+            signature_index, ..
+        } => {
+            let signature = constant_pool.try_get_utf8(*signature_index)?;
+            with_comment(
+                &format!("Signature: #{signature_index}"),
+                HEADER_WIDTH - 4,
+                signature,
+            )
+        }
+        _ => attribute.to_string().trim_end().to_string(),
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    fn hello_world() -> Result<ClassFile> {
+        let class_bytes = include_bytes!("../../classes/HelloWorld.class");
+        ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))
+    }
+
+    #[test]
+    fn test_to_javap_string() -> Result<()> {
+        let javap = to_javap_string(&hello_world()?)?;
+
+        assert!(javap.starts_with("public class HelloWorld\n"));
+        assert!(javap.contains("  public static void main(java.lang.String...);\n"));
+        assert!(javap.contains("    descriptor: ([Ljava/lang/String;)V\n"));
+        assert!(javap.contains("    flags: (0x0089) ACC_PUBLIC, ACC_STATIC, ACC_VARARGS\n"));
+        assert!(javap.contains("  public HelloWorld();\n"));
+        assert!(javap.contains("// java/lang/Object.\"<init>\":()V\n"));
+        assert!(javap.contains("SourceFile: \"HelloWorld.java\"\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_declaration() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("foo/Foo")?;
+        let super_class = constant_pool.add_class("foo/Bar")?;
+        let interface = constant_pool.add_class("java/lang/Runnable")?;
+        let mut class_file = ClassFile {
+            constant_pool,
+            access_flags: ClassAccessFlags::PUBLIC | ClassAccessFlags::FINAL,
+            this_class,
+            super_class,
+            interfaces: vec![interface],
+            ..Default::default()
+        };
+        assert_eq!(
+            "public final class foo.Foo extends foo.Bar implements java.lang.Runnable",
+            class_declaration(&class_file)?
+        );
+
+        class_file.access_flags = ClassAccessFlags::INTERFACE | ClassAccessFlags::ABSTRACT;
+        assert_eq!(
+            "abstract interface foo.Foo extends java.lang.Runnable",
+            class_declaration(&class_file)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_constant_line() -> Result<()> {
+        let mut constant_pool = ConstantPool::default();
+        let class_index = constant_pool.add_class("java/lang/Object")?;
+        let method_index = constant_pool.add_method_ref(class_index, "<init>", "()V")?;
+        let long_index = constant_pool.add_long(42)?;
+        assert_eq!(
+            "   #1 = Utf8               java/lang/Object",
+            constant_line(&constant_pool, 1, constant_pool.try_get(1)?)?
+        );
+        assert_eq!(
+            "   #2 = Class              #1             // java/lang/Object",
+            constant_line(
+                &constant_pool,
+                class_index,
+                constant_pool.try_get(class_index)?
+            )?
+        );
+        assert_eq!(
+            format!(
+                "   #{method_index} = Methodref          #{class_index}.#{}          // java/lang/Object.\"<init>\":()V",
+                method_index - 1
+            ),
+            constant_line(
+                &constant_pool,
+                method_index,
+                constant_pool.try_get(method_index)?
+            )?
+        );
+        assert_eq!(
+            format!("   #{long_index} = Long               42l"),
+            constant_line(
+                &constant_pool,
+                long_index,
+                constant_pool.try_get(long_index)?
+            )?
+        );
+        Ok(())
+    }
+}
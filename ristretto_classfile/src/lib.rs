@@ -45,32 +45,44 @@ extern crate core;
 
 pub mod attributes;
 mod base_type;
+mod bootstrap_method_info;
 mod class_access_flags;
 mod class_file;
 mod constant;
 mod constant_pool;
 mod display;
+mod enclosing_method;
 mod error;
 mod field;
 mod field_access_flags;
 mod field_type;
+mod javap;
 mod method;
 mod method_access_flags;
+mod method_descriptor;
 pub mod mutf8;
 mod reference_kind;
+mod signature;
 mod verifiers;
 mod version;
 
 pub use base_type::BaseType;
+pub use bootstrap_method_info::BootstrapMethodInfo;
 pub use class_access_flags::ClassAccessFlags;
 pub use class_file::ClassFile;
 pub use constant::Constant;
 pub use constant_pool::ConstantPool;
+pub use enclosing_method::EnclosingMethodInfo;
 pub use error::{Error, Result};
 pub use field::Field;
 pub use field_access_flags::FieldAccessFlags;
 pub use field_type::FieldType;
 pub use method::Method;
 pub use method_access_flags::MethodAccessFlags;
+pub use method_descriptor::MethodDescriptor;
 pub use reference_kind::ReferenceKind;
+pub use signature::{
+    ClassSignature, ClassTypeSignature, MethodSignature, SimpleClassTypeSignature, TypeArgument,
+    TypeParameter, TypeSignature,
+};
 pub use version::Version;
@@ -3,6 +3,7 @@ use crate::constant_pool::ConstantPool;
 use crate::display::indent_lines;
 use crate::error::Result;
 use crate::method_access_flags::MethodAccessFlags;
+use crate::signature;
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use std::fmt;
 use std::io::Cursor;
@@ -23,7 +24,10 @@ impl Method {
     ///
     /// # Errors
     /// Returns an error if the bytes do not represent a valid Method.
-    pub fn from_bytes(constant_pool: &ConstantPool, bytes: &mut Cursor<Vec<u8>>) -> Result<Method> {
+    pub fn from_bytes<T: AsRef<[u8]>>(
+        constant_pool: &ConstantPool,
+        bytes: &mut Cursor<T>,
+    ) -> Result<Method> {
         let access_flags = MethodAccessFlags::from_bytes(bytes)?;
         let name_index = bytes.read_u16::<BigEndian>()?;
         let descriptor_index = bytes.read_u16::<BigEndian>()?;
@@ -44,6 +48,17 @@ impl Method {
         Ok(method)
     }
 
+    /// Get the generic signature of the method (e.g. `<T:Ljava/lang/Object;>(TT;)V`) from the
+    /// `Signature` attribute; the signature can be parsed with
+    /// [`MethodSignature::parse`](crate::MethodSignature::parse).  Returns `None` if the method
+    /// does not have a `Signature` attribute.
+    ///
+    /// # Errors
+    /// Returns an error if the signature index of the attribute is invalid.
+    pub fn signature<'a>(&self, constant_pool: &'a ConstantPool) -> Result<Option<&'a String>> {
+        signature::signature(constant_pool, &self.attributes)
+    }
+
     /// Serialize the `Method` to bytes.
     ///
     /// # Errors
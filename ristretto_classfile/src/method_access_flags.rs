@@ -48,7 +48,7 @@ impl MethodAccessFlags {
     ///
     /// # Errors
     /// Should not occur; reserved for future use.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<MethodAccessFlags> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<MethodAccessFlags> {
         let access_flags = bytes.read_u16::<BigEndian>()?;
         let method_access_flags = MethodAccessFlags::from_bits_truncate(access_flags);
         Ok(method_access_flags)
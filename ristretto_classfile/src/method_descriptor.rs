@@ -0,0 +1,256 @@
+use crate::base_type::BaseType;
+use crate::error::Result;
+use crate::Error::InvalidMethodDescriptor;
+use crate::FieldType;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// The maximum number of dimensions of an array type in a descriptor.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.4.1>
+const MAX_ARRAY_DIMENSIONS: usize = 255;
+
+/// Implementation of a `MethodDescriptor`.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.3.3>
+#[derive(Clone, Debug, PartialEq)]
+pub struct MethodDescriptor {
+    /// The parameter types of the method.
+    pub parameters: Vec<FieldType>,
+    /// The return type of the method; `None` if the method returns `void`.
+    pub return_type: Option<FieldType>,
+}
+
+impl MethodDescriptor {
+    /// Parse a method descriptor (e.g. "(ILjava/lang/String;)V").
+    ///
+    /// # Errors
+    /// Returns an error if the descriptor is invalid.
+    pub fn parse(descriptor: &str) -> Result<MethodDescriptor> {
+        let invalid_descriptor = || InvalidMethodDescriptor(descriptor.to_string());
+        let mut chars = descriptor.chars().peekable();
+        if chars.next() != Some('(') {
+            return Err(invalid_descriptor());
+        }
+
+        let mut parameters = Vec::new();
+        loop {
+            match chars.peek() {
+                Some(')') => {
+                    chars.next();
+                    break;
+                }
+                Some(_) => parameters.push(Self::parse_field_type(descriptor, &mut chars)?),
+                None => return Err(invalid_descriptor()),
+            }
+        }
+
+        let return_type = if chars.next_if_eq(&'V').is_some() {
+            None
+        } else {
+            Some(Self::parse_field_type(descriptor, &mut chars)?)
+        };
+        if chars.next().is_some() {
+            return Err(invalid_descriptor());
+        }
+
+        Ok(MethodDescriptor {
+            parameters,
+            return_type,
+        })
+    }
+
+    /// Parse the next field type of the descriptor.  The array dimensions are counted rather than
+    /// parsed recursively so that a malformed descriptor cannot exhaust the stack.
+    fn parse_field_type(descriptor: &str, chars: &mut Peekable<Chars>) -> Result<FieldType> {
+        let invalid_descriptor = || InvalidMethodDescriptor(descriptor.to_string());
+        let mut dimensions = 0;
+        while chars.next_if_eq(&'[').is_some() {
+            dimensions += 1;
+            if dimensions > MAX_ARRAY_DIMENSIONS {
+                return Err(invalid_descriptor());
+            }
+        }
+
+        let mut field_type = match chars.next() {
+            Some('L') => {
+                let mut class_name = String::new();
+                loop {
+                    match chars.next() {
+                        Some(';') if !class_name.is_empty() => break,
+                        Some(';') | None => return Err(invalid_descriptor()),
+                        Some(ch) => class_name.push(ch),
+                    }
+                }
+                FieldType::Object(class_name)
+            }
+            Some(code) => FieldType::Base(BaseType::parse(code).map_err(|_| invalid_descriptor())?),
+            None => return Err(invalid_descriptor()),
+        };
+        for _ in 0..dimensions {
+            field_type = FieldType::Array(Box::new(field_type));
+        }
+        Ok(field_type)
+    }
+
+    /// Get the number of local variable slots occupied by the arguments of the method; `long` and
+    /// `double` arguments occupy two slots and instance methods have an additional slot for
+    /// `this`.
+    ///
+    /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-2.html#jvms-2.6.1>
+    #[must_use]
+    pub fn argument_slots(&self, is_static: bool) -> u16 {
+        let mut slots: u16 = u16::from(!is_static);
+        for parameter in &self.parameters {
+            let size = match parameter {
+                FieldType::Base(BaseType::Long | BaseType::Double) => 2,
+                _ => 1,
+            };
+            slots = slots.saturating_add(size);
+        }
+        slots
+    }
+
+    /// Get the number of operand stack slots occupied by the return value of the method.
+    #[must_use]
+    pub fn return_slots(&self) -> u16 {
+        match self.return_type {
+            None => 0,
+            Some(FieldType::Base(BaseType::Long | BaseType::Double)) => 2,
+            Some(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for MethodDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for parameter in &self.parameters {
+            write!(f, "{}", parameter.descriptor())?;
+        }
+        write!(f, ")")?;
+        match &self.return_type {
+            Some(return_type) => write!(f, "{}", return_type.descriptor()),
+            None => write!(f, "V"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let descriptor = MethodDescriptor::parse("(I[JLjava/lang/String;)D")?;
+        assert_eq!(
+            vec![
+                FieldType::Base(BaseType::Int),
+                FieldType::Array(Box::new(FieldType::Base(BaseType::Long))),
+                FieldType::Object("java/lang/String".to_string()),
+            ],
+            descriptor.parameters
+        );
+        assert_eq!(
+            Some(FieldType::Base(BaseType::Double)),
+            descriptor.return_type
+        );
+        assert_eq!("(I[JLjava/lang/String;)D", descriptor.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_void() -> Result<()> {
+        let descriptor = MethodDescriptor::parse("()V")?;
+        assert!(descriptor.parameters.is_empty());
+        assert_eq!(None, descriptor.return_type);
+        assert_eq!("()V", descriptor.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for descriptor in [
+            "",
+            "()",
+            "I)V",
+            "(I",
+            "(Q)V",
+            "(Ljava/lang/String)V",
+            "(L;)V",
+            "([)V",
+            "()II",
+            "()VV",
+            "(V)V",
+        ] {
+            assert_eq!(
+                Err(InvalidMethodDescriptor(descriptor.to_string())),
+                MethodDescriptor::parse(descriptor)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_class_name_with_parenthesis() -> Result<()> {
+        let descriptor = MethodDescriptor::parse("(La)b;)V")?;
+        assert_eq!(
+            vec![FieldType::Object("a)b".to_string())],
+            descriptor.parameters
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_array_dimensions() -> Result<()> {
+        let descriptor = format!("({}I)V", "[".repeat(MAX_ARRAY_DIMENSIONS));
+        let mut field_type = &MethodDescriptor::parse(&descriptor)?.parameters[0];
+        let mut dimensions = 0;
+        while let FieldType::Array(component_type) = field_type {
+            field_type = component_type;
+            dimensions += 1;
+        }
+        assert_eq!(MAX_ARRAY_DIMENSIONS, dimensions);
+        assert_eq!(&FieldType::Base(BaseType::Int), field_type);
+
+        let descriptor = format!("({}I)V", "[".repeat(MAX_ARRAY_DIMENSIONS + 1));
+        assert_eq!(
+            Err(InvalidMethodDescriptor(descriptor.clone())),
+            MethodDescriptor::parse(&descriptor)
+        );
+
+        // A descriptor that fits in a constant pool entry must not exhaust the stack
+        let descriptor = format!("({}I)V", "[".repeat(65_000));
+        assert_eq!(
+            Err(InvalidMethodDescriptor(descriptor.clone())),
+            MethodDescriptor::parse(&descriptor)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_argument_slots() -> Result<()> {
+        let descriptor = MethodDescriptor::parse("(JID)V")?;
+        assert_eq!(6, descriptor.argument_slots(false));
+        assert_eq!(5, descriptor.argument_slots(true));
+
+        let descriptor = MethodDescriptor::parse("([J[DLjava/lang/Object;)V")?;
+        assert_eq!(4, descriptor.argument_slots(false));
+        assert_eq!(3, descriptor.argument_slots(true));
+
+        let descriptor = MethodDescriptor::parse("()V")?;
+        assert_eq!(1, descriptor.argument_slots(false));
+        assert_eq!(0, descriptor.argument_slots(true));
+        Ok(())
+    }
+
+    #[test]
+    fn test_return_slots() -> Result<()> {
+        assert_eq!(0, MethodDescriptor::parse("()V")?.return_slots());
+        assert_eq!(1, MethodDescriptor::parse("()I")?.return_slots());
+        assert_eq!(2, MethodDescriptor::parse("()J")?.return_slots());
+        assert_eq!(2, MethodDescriptor::parse("()D")?.return_slots());
+        assert_eq!(1, MethodDescriptor::parse("()[D")?.return_slots());
+        Ok(())
+    }
+}
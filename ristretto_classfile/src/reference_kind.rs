@@ -25,7 +25,7 @@ impl ReferenceKind {
     ///
     /// # Errors
     /// Returns an error if the bytes do not represent a valid `ReferenceKind`.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<ReferenceKind> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<ReferenceKind> {
         let reference_kind = match bytes.read_u8()? {
             1 => ReferenceKind::GetField,
             2 => ReferenceKind::GetStatic,
@@ -0,0 +1,693 @@
+use crate::attributes::Attribute;
+use crate::base_type::BaseType;
+use crate::constant_pool::ConstantPool;
+use crate::error::Result;
+use crate::Error::InvalidSignature;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A Java type in a generic signature; a primitive type, a parameterized class type, a type
+/// variable or an array.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.7.9.1>
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypeSignature {
+    /// A primitive type (e.g. `I`).
+    Base(BaseType),
+    /// A class or interface type (e.g. `Ljava/util/List<Ljava/lang/String;>;`).
+    Class(ClassTypeSignature),
+    /// A type variable (e.g. `TT;`).
+    TypeVariable(String),
+    /// An array type (e.g. `[TT;`).
+    Array(Box<TypeSignature>),
+}
+
+/// A class or interface type with its type arguments (e.g. `Ljava/util/Map<TK;TV;>;`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassTypeSignature {
+    /// The fully qualified name of the class (e.g. `java/util/Map`).
+    pub name: String,
+    /// The type arguments of the class; empty if the type is not parameterized.
+    pub type_arguments: Vec<TypeArgument>,
+    /// The inner classes selected from the class (e.g. `Entry` in
+    /// `Ljava/util/Map<TK;TV;>.Entry<TK;TV;>;`), outermost first.
+    pub inner_classes: Vec<SimpleClassTypeSignature>,
+}
+
+/// An inner class selected from a [`ClassTypeSignature`], with its type arguments.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimpleClassTypeSignature {
+    /// The simple name of the inner class (e.g. `Entry`).
+    pub name: String,
+    /// The type arguments of the inner class; empty if the type is not parameterized.
+    pub type_arguments: Vec<TypeArgument>,
+}
+
+/// A type argument of a parameterized class type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypeArgument {
+    /// The unbounded wildcard `*` (i.e. `?`).
+    Any,
+    /// A type (e.g. `Ljava/lang/String;`).
+    Exact(TypeSignature),
+    /// A wildcard with an upper bound (e.g. `+Ljava/lang/Number;`, i.e. `? extends Number`).
+    Extends(TypeSignature),
+    /// A wildcard with a lower bound (e.g. `-TK;`, i.e. `? super K`).
+    Super(TypeSignature),
+}
+
+/// A type parameter of a generic class or method with its bounds (e.g.
+/// `T::Ljava/lang/Comparable<TT;>;`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeParameter {
+    /// The name of the type parameter (e.g. `T`).
+    pub name: String,
+    /// The class bound of the type parameter; `None` if the type parameter only has interface
+    /// bounds.
+    pub class_bound: Option<TypeSignature>,
+    /// The interface bounds of the type parameter.
+    pub interface_bounds: Vec<TypeSignature>,
+}
+
+/// The generic signature of a class (e.g. `<T:Ljava/lang/Object;>Ljava/lang/Object;`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassSignature {
+    /// The type parameters of the class.
+    pub type_parameters: Vec<TypeParameter>,
+    /// The super class of the class.
+    pub super_class: ClassTypeSignature,
+    /// The interfaces implemented by the class.
+    pub interfaces: Vec<ClassTypeSignature>,
+}
+
+/// The generic signature of a method (e.g. `<T:Ljava/lang/Object;>(TT;)Ljava/util/List<TT;>;`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct MethodSignature {
+    /// The type parameters of the method.
+    pub type_parameters: Vec<TypeParameter>,
+    /// The parameter types of the method.
+    pub parameters: Vec<TypeSignature>,
+    /// The return type of the method; `None` if the method returns `void`.
+    pub return_type: Option<TypeSignature>,
+    /// The exceptions thrown by the method.
+    pub exceptions: Vec<TypeSignature>,
+}
+
+impl TypeSignature {
+    /// Parse a Java type signature, such as the signature of a field (e.g.
+    /// `Ljava/util/List<Ljava/lang/String;>;`).
+    ///
+    /// # Errors
+    /// Returns an error if the signature is invalid.
+    pub fn parse(signature: &str) -> Result<TypeSignature> {
+        let mut parser = Parser::new(signature);
+        let type_signature = parser.type_signature()?;
+        parser.end()?;
+        Ok(type_signature)
+    }
+}
+
+impl ClassSignature {
+    /// Parse the generic signature of a class.
+    ///
+    /// # Errors
+    /// Returns an error if the signature is invalid.
+    pub fn parse(signature: &str) -> Result<ClassSignature> {
+        let mut parser = Parser::new(signature);
+        let type_parameters = parser.type_parameters()?;
+        let super_class = parser.class_type_signature()?;
+        let mut interfaces = Vec::new();
+        while parser.peek().is_some() {
+            interfaces.push(parser.class_type_signature()?);
+        }
+        Ok(ClassSignature {
+            type_parameters,
+            super_class,
+            interfaces,
+        })
+    }
+}
+
+impl MethodSignature {
+    /// Parse the generic signature of a method.
+    ///
+    /// # Errors
+    /// Returns an error if the signature is invalid.
+    pub fn parse(signature: &str) -> Result<MethodSignature> {
+        let mut parser = Parser::new(signature);
+        let type_parameters = parser.type_parameters()?;
+        parser.expect('(')?;
+        let mut parameters = Vec::new();
+        while parser.peek() != Some(')') {
+            parameters.push(parser.type_signature()?);
+        }
+        parser.expect(')')?;
+        let return_type = if parser.peek() == Some('V') {
+            parser.next()?;
+            None
+        } else {
+            Some(parser.type_signature()?)
+        };
+        let mut exceptions = Vec::new();
+        while parser.peek().is_some() {
+            parser.expect('^')?;
+            let exception = parser.type_signature()?;
+            if !matches!(
+                exception,
+                TypeSignature::Class(_) | TypeSignature::TypeVariable(_)
+            ) {
+                return Err(parser.invalid());
+            }
+            exceptions.push(exception);
+        }
+        Ok(MethodSignature {
+            type_parameters,
+            parameters,
+            return_type,
+            exceptions,
+        })
+    }
+}
+
+/// Get the signature from the `Signature` attribute in the attributes of a class, field or method.
+/// Returns `None` if there is no `Signature` attribute.
+///
+/// # Errors
+/// Returns an error if the signature index of the attribute is not a UTF-8 constant.
+pub(crate) fn signature<'a>(
+    constant_pool: &'a ConstantPool,
+    attributes: &[Attribute],
+) -> Result<Option<&'a String>> {
+    let Some(signature_index) = attributes.iter().find_map(|attribute| match attribute {
+        Attribute::Signature {
+            signature_index, ..
+        } => Some(*signature_index),
+        _ => None,
+    }) else {
+        return Ok(None);
+    };
+    let signature = constant_pool.try_get_utf8(signature_index)?;
+    Ok(Some(signature))
+}
+
+/// The maximum nesting depth of the types in a signature; arrays are limited to 255 dimensions
+/// by the JVM, and the limit keeps malformed signatures from overflowing the stack.
+const MAX_DEPTH: usize = 256;
+
+/// Recursive descent parser for generic signatures.
+struct Parser<'a> {
+    signature: &'a str,
+    chars: Peekable<Chars<'a>>,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(signature: &'a str) -> Self {
+        Self {
+            signature,
+            chars: signature.chars().peekable(),
+            depth: 0,
+        }
+    }
+
+    fn invalid(&self) -> crate::Error {
+        InvalidSignature(self.signature.to_string())
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn next(&mut self) -> Result<char> {
+        self.chars.next().ok_or_else(|| self.invalid())
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        if self.next()? == expected {
+            Ok(())
+        } else {
+            Err(self.invalid())
+        }
+    }
+
+    fn end(&mut self) -> Result<()> {
+        if self.peek().is_some() {
+            return Err(self.invalid());
+        }
+        Ok(())
+    }
+
+    /// Parse an identifier, ending at (and not consuming) any of the terminators.
+    fn identifier(&mut self, terminators: &[char]) -> Result<String> {
+        let mut identifier = String::new();
+        while let Some(character) = self.peek() {
+            if terminators.contains(&character) {
+                break;
+            }
+            identifier.push(character);
+            self.chars.next();
+        }
+        if identifier.is_empty() {
+            return Err(self.invalid());
+        }
+        Ok(identifier)
+    }
+
+    /// Parse optional type parameters (e.g. `<K:Ljava/lang/Object;V:Ljava/lang/Object;>`).
+    fn type_parameters(&mut self) -> Result<Vec<TypeParameter>> {
+        let mut type_parameters = Vec::new();
+        if self.peek() != Some('<') {
+            return Ok(type_parameters);
+        }
+        self.next()?;
+        while self.peek() != Some('>') {
+            let name = self.identifier(&[':', '>'])?;
+            self.expect(':')?;
+            let class_bound = match self.peek() {
+                Some(':' | '>') => None,
+                _ => Some(self.type_signature()?),
+            };
+            let mut interface_bounds = Vec::new();
+            while self.peek() == Some(':') {
+                self.next()?;
+                interface_bounds.push(self.type_signature()?);
+            }
+            type_parameters.push(TypeParameter {
+                name,
+                class_bound,
+                interface_bounds,
+            });
+        }
+        self.expect('>')?;
+        if type_parameters.is_empty() {
+            return Err(self.invalid());
+        }
+        Ok(type_parameters)
+    }
+
+    /// Parse a Java type signature.
+    fn type_signature(&mut self) -> Result<TypeSignature> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.invalid());
+        }
+        self.depth += 1;
+        let type_signature = match self.peek() {
+            Some('L') => TypeSignature::Class(self.class_type_signature()?),
+            Some('T') => {
+                self.next()?;
+                let name = self.identifier(&[';'])?;
+                self.expect(';')?;
+                TypeSignature::TypeVariable(name)
+            }
+            Some('[') => {
+                self.next()?;
+                TypeSignature::Array(Box::new(self.type_signature()?))
+            }
+            Some(code) => {
+                self.next()?;
+                let base_type = BaseType::parse(code).map_err(|_| self.invalid())?;
+                TypeSignature::Base(base_type)
+            }
+            None => return Err(self.invalid()),
+        };
+        self.depth -= 1;
+        Ok(type_signature)
+    }
+
+    /// Parse a class type signature (e.g. `Ljava/util/List<TT;>;`).
+    fn class_type_signature(&mut self) -> Result<ClassTypeSignature> {
+        self.expect('L')?;
+        let name = self.identifier(&['<', '.', ';'])?;
+        let type_arguments = self.type_arguments()?;
+        let mut inner_classes = Vec::new();
+        while self.peek() == Some('.') {
+            self.next()?;
+            let name = self.identifier(&['<', '.', ';'])?;
+            let type_arguments = self.type_arguments()?;
+            inner_classes.push(SimpleClassTypeSignature {
+                name,
+                type_arguments,
+            });
+        }
+        self.expect(';')?;
+        Ok(ClassTypeSignature {
+            name,
+            type_arguments,
+            inner_classes,
+        })
+    }
+
+    /// Parse optional type arguments (e.g. `<*+Ljava/lang/Number;>`).
+    fn type_arguments(&mut self) -> Result<Vec<TypeArgument>> {
+        let mut type_arguments = Vec::new();
+        if self.peek() != Some('<') {
+            return Ok(type_arguments);
+        }
+        self.next()?;
+        while self.peek() != Some('>') {
+            let type_argument = match self.peek() {
+                Some('*') => {
+                    self.next()?;
+                    TypeArgument::Any
+                }
+                Some('+') => {
+                    self.next()?;
+                    TypeArgument::Extends(self.type_signature()?)
+                }
+                Some('-') => {
+                    self.next()?;
+                    TypeArgument::Super(self.type_signature()?)
+                }
+                _ => TypeArgument::Exact(self.type_signature()?),
+            };
+            type_arguments.push(type_argument);
+        }
+        self.expect('>')?;
+        if type_arguments.is_empty() {
+            return Err(self.invalid());
+        }
+        Ok(type_arguments)
+    }
+}
+
+/// Write the type arguments in signature form, if there are any.
+fn fmt_type_arguments(f: &mut fmt::Formatter, type_arguments: &[TypeArgument]) -> fmt::Result {
+    if type_arguments.is_empty() {
+        return Ok(());
+    }
+    write!(f, "<")?;
+    for type_argument in type_arguments {
+        write!(f, "{type_argument}")?;
+    }
+    write!(f, ">")
+}
+
+/// Write the type parameters in signature form, if there are any.
+fn fmt_type_parameters(f: &mut fmt::Formatter, type_parameters: &[TypeParameter]) -> fmt::Result {
+    if type_parameters.is_empty() {
+        return Ok(());
+    }
+    write!(f, "<")?;
+    for type_parameter in type_parameters {
+        write!(f, "{type_parameter}")?;
+    }
+    write!(f, ">")
+}
+
+impl fmt::Display for TypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TypeSignature::Base(base_type) => write!(f, "{}", base_type.code()),
+            TypeSignature::Class(class_type) => write!(f, "{class_type}"),
+            TypeSignature::TypeVariable(name) => write!(f, "T{name};"),
+            TypeSignature::Array(component_type) => write!(f, "[{component_type}"),
+        }
+    }
+}
+
+impl fmt::Display for ClassTypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "L{}", self.name)?;
+        fmt_type_arguments(f, &self.type_arguments)?;
+        for inner_class in &self.inner_classes {
+            write!(f, ".{}", inner_class.name)?;
+            fmt_type_arguments(f, &inner_class.type_arguments)?;
+        }
+        write!(f, ";")
+    }
+}
+
+impl fmt::Display for TypeArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TypeArgument::Any => write!(f, "*"),
+            TypeArgument::Exact(type_signature) => write!(f, "{type_signature}"),
+            TypeArgument::Extends(type_signature) => write!(f, "+{type_signature}"),
+            TypeArgument::Super(type_signature) => write!(f, "-{type_signature}"),
+        }
+    }
+}
+
+impl fmt::Display for TypeParameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:", self.name)?;
+        if let Some(class_bound) = &self.class_bound {
+            write!(f, "{class_bound}")?;
+        }
+        for interface_bound in &self.interface_bounds {
+            write!(f, ":{interface_bound}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ClassSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_type_parameters(f, &self.type_parameters)?;
+        write!(f, "{}", self.super_class)?;
+        for interface in &self.interfaces {
+            write!(f, "{interface}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for MethodSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_type_parameters(f, &self.type_parameters)?;
+        write!(f, "(")?;
+        for parameter in &self.parameters {
+            write!(f, "{parameter}")?;
+        }
+        write!(f, ")")?;
+        match &self.return_type {
+            Some(return_type) => write!(f, "{return_type}")?,
+            None => write!(f, "V")?,
+        }
+        for exception in &self.exceptions {
+            write!(f, "^{exception}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ClassFile;
+    use std::io::Cursor;
+
+    fn generics_class_file() -> Result<ClassFile> {
+        let class_bytes = include_bytes!("../../classes/Generics.class");
+        ClassFile::from_bytes(&mut Cursor::new(class_bytes.to_vec()))
+    }
+
+    fn class_type(name: &str, type_arguments: Vec<TypeArgument>) -> ClassTypeSignature {
+        ClassTypeSignature {
+            name: name.to_string(),
+            type_arguments,
+            inner_classes: Vec::new(),
+        }
+    }
+
+    fn type_variable(name: &str) -> TypeSignature {
+        TypeSignature::TypeVariable(name.to_string())
+    }
+
+    #[test]
+    fn test_class_signature() -> Result<()> {
+        let class_file = generics_class_file()?;
+        let signature = class_file.signature()?.expect("signature");
+        assert_eq!(
+            "<T::Ljava/lang/Comparable<TT;>;>Ljava/util/ArrayList<TT;>;Ljava/io/Serializable;",
+            signature
+        );
+
+        let class_signature = ClassSignature::parse(signature)?;
+        assert_eq!(
+            ClassSignature {
+                type_parameters: vec![TypeParameter {
+                    name: "T".to_string(),
+                    class_bound: None,
+                    interface_bounds: vec![TypeSignature::Class(class_type(
+                        "java/lang/Comparable",
+                        vec![TypeArgument::Exact(type_variable("T"))]
+                    ))],
+                }],
+                super_class: class_type(
+                    "java/util/ArrayList",
+                    vec![TypeArgument::Exact(type_variable("T"))]
+                ),
+                interfaces: vec![class_type("java/io/Serializable", Vec::new())],
+            },
+            class_signature
+        );
+        assert_eq!(signature, &class_signature.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_field_signature() -> Result<()> {
+        let class_file = generics_class_file()?;
+        let field = class_file.field("values").expect("values");
+        let signature = field
+            .signature(&class_file.constant_pool)?
+            .expect("signature");
+        assert_eq!(
+            "Ljava/util/Map<Ljava/lang/String;Ljava/util/List<+Ljava/lang/Number;>;>;",
+            signature
+        );
+
+        let type_signature = TypeSignature::parse(signature)?;
+        assert_eq!(
+            TypeSignature::Class(class_type(
+                "java/util/Map",
+                vec![
+                    TypeArgument::Exact(TypeSignature::Class(class_type(
+                        "java/lang/String",
+                        Vec::new()
+                    ))),
+                    TypeArgument::Exact(TypeSignature::Class(class_type(
+                        "java/util/List",
+                        vec![TypeArgument::Extends(TypeSignature::Class(class_type(
+                            "java/lang/Number",
+                            Vec::new()
+                        )))]
+                    ))),
+                ]
+            )),
+            type_signature
+        );
+        assert_eq!(signature, &type_signature.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_method_signature() -> Result<()> {
+        let class_file = generics_class_file()?;
+        let method = class_file.methods_named("map")[0];
+        let signature = method
+            .signature(&class_file.constant_pool)?
+            .expect("signature");
+        assert_eq!(
+            "<K:Ljava/lang/Object;V:Ljava/lang/Number;:Ljava/lang/Comparable<TV;>;>\
+             (Ljava/util/List<-TK;>;[TV;)Ljava/util/Map<TK;TV;>;",
+            signature
+        );
+
+        let method_signature = MethodSignature::parse(signature)?;
+        let type_parameter_names = method_signature
+            .type_parameters
+            .iter()
+            .map(|type_parameter| type_parameter.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["K", "V"], type_parameter_names);
+        let bound = &method_signature.type_parameters[1];
+        assert_eq!(
+            Some(TypeSignature::Class(class_type(
+                "java/lang/Number",
+                Vec::new()
+            ))),
+            bound.class_bound
+        );
+        assert_eq!(1, bound.interface_bounds.len());
+        assert_eq!(
+            vec![
+                TypeSignature::Class(class_type(
+                    "java/util/List",
+                    vec![TypeArgument::Super(type_variable("K"))]
+                )),
+                TypeSignature::Array(Box::new(type_variable("V"))),
+            ],
+            method_signature.parameters
+        );
+        assert_eq!(
+            Some(TypeSignature::Class(class_type(
+                "java/util/Map",
+                vec![
+                    TypeArgument::Exact(type_variable("K")),
+                    TypeArgument::Exact(type_variable("V"))
+                ]
+            ))),
+            method_signature.return_type
+        );
+        assert!(method_signature.exceptions.is_empty());
+        assert_eq!(signature, &method_signature.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_signature() -> Result<()> {
+        let class_file = generics_class_file()?;
+        let method = class_file.method("<init>", "()V").expect("<init>");
+        assert_eq!(None, method.signature(&class_file.constant_pool)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_method_signature_round_trip() -> Result<()> {
+        for signature in [
+            "()V",
+            "(I[J)Z",
+            "<E:Ljava/lang/Exception;>()V^TE;^Ljava/io/IOException;",
+            "(Ljava/util/Map<**>.Entry<TK;[I>;)V",
+        ] {
+            assert_eq!(signature, MethodSignature::parse(signature)?.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_signatures() {
+        for signature in [
+            "",
+            "Ljava/lang/Object",
+            "TT",
+            "Ljava/util/List<>;",
+            "Q",
+            "I;",
+        ] {
+            assert_eq!(
+                Err(InvalidSignature(signature.to_string())),
+                TypeSignature::parse(signature)
+            );
+        }
+        for signature in ["(I", "()", "()V^I", "<>()V"] {
+            assert_eq!(
+                Err(InvalidSignature(signature.to_string())),
+                MethodSignature::parse(signature)
+            );
+        }
+        assert_eq!(
+            Err(InvalidSignature("<T:>".to_string())),
+            ClassSignature::parse("<T:>")
+        );
+    }
+
+    #[test]
+    fn test_nesting_depth() -> Result<()> {
+        let signature = format!("{}I", "[".repeat(255));
+        assert!(matches!(
+            TypeSignature::parse(&signature)?,
+            TypeSignature::Array(_)
+        ));
+
+        // Deeply nested signatures are rejected rather than overflowing the stack
+        let signature = format!("{}I", "[".repeat(100_000));
+        assert_eq!(
+            Err(InvalidSignature(signature.clone())),
+            TypeSignature::parse(&signature)
+        );
+        let signature = format!(
+            "{}I{}",
+            "Ljava/util/List<".repeat(100_000),
+            ">;".repeat(100_000)
+        );
+        assert_eq!(
+            Err(InvalidSignature(signature.clone())),
+            TypeSignature::parse(&signature)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::attributes::{Attribute, BootstrapMethod};
+use crate::class_file::ClassFile;
+use crate::constant::Constant;
+use crate::constant_pool::{invalid_index_type, ConstantPool};
+use crate::Error::InvalidConstantPoolIndex;
+use crate::Result;
+
+/// The tags of the loadable constants, which may be used as static arguments of a bootstrap
+/// method.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.4-310>
+const LOADABLE_CONSTANTS: &str = "CONSTANT_Integer|CONSTANT_Float|CONSTANT_Long|CONSTANT_Double|\
+    CONSTANT_Class|CONSTANT_String|CONSTANT_MethodHandle|CONSTANT_MethodType|CONSTANT_Dynamic";
+
+/// Verify the `ClassFile` `BootstrapMethods` attribute.
+pub fn verify(class_file: &ClassFile) -> Result<()> {
+    for attribute in &class_file.attributes {
+        if let Attribute::BootstrapMethods { methods, .. } = attribute {
+            for bootstrap_method in methods {
+                verify_bootstrap_method(&class_file.constant_pool, bootstrap_method)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Verify that the bootstrap method references a method handle and that its static arguments
+/// reference loadable constants.
+pub(crate) fn verify_bootstrap_method(
+    constant_pool: &ConstantPool,
+    bootstrap_method: &BootstrapMethod,
+) -> Result<()> {
+    let index = bootstrap_method.bootstrap_method_ref;
+    match constant_pool.get(index) {
+        Some(Constant::MethodHandle { .. }) => {} // valid index
+        None => return Err(InvalidConstantPoolIndex(index)),
+        Some(constant) => return Err(invalid_index_type(index, "CONSTANT_MethodHandle", constant)),
+    }
+
+    for index in &bootstrap_method.arguments {
+        match constant_pool.get(*index) {
+            Some(
+                Constant::Integer(_)
+                | Constant::Float(_)
+                | Constant::Long(_)
+                | Constant::Double(_)
+                | Constant::Class(_)
+                | Constant::String(_)
+                | Constant::MethodHandle { .. }
+                | Constant::MethodType(_)
+                | Constant::Dynamic { .. },
+            ) => {} // valid index
+            None => return Err(InvalidConstantPoolIndex(*index)),
+            Some(constant) => return Err(invalid_index_type(*index, LOADABLE_CONSTANTS, constant)),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::reference_kind::ReferenceKind;
+    use crate::Error::InvalidConstantPoolIndexType;
+
+    fn class_file(arguments: Vec<u16>) -> Result<ClassFile> {
+        let mut class_file = ClassFile::default();
+        let constant_pool = &mut class_file.constant_pool;
+        let name_index = constant_pool.add_utf8("BootstrapMethods")?;
+        let class_index = constant_pool.add_class("java/lang/invoke/LambdaMetafactory")?;
+        let method_index = constant_pool.add_method_ref(class_index, "metafactory", "()V")?;
+        let bootstrap_method_ref =
+            constant_pool.add_method_handle(ReferenceKind::InvokeStatic, method_index)?;
+        class_file.attributes.push(Attribute::BootstrapMethods {
+            name_index,
+            methods: vec![BootstrapMethod {
+                bootstrap_method_ref,
+                arguments,
+            }],
+        });
+        Ok(class_file)
+    }
+
+    #[test]
+    fn test_verify_success() -> Result<()> {
+        let mut class_file = class_file(Vec::new())?;
+        let constant_pool = &mut class_file.constant_pool;
+        let arguments = vec![
+            constant_pool.add_integer(42)?,
+            constant_pool.add_long(42)?,
+            constant_pool.add_string("foo")?,
+            constant_pool.add_class("Foo")?,
+        ];
+        let Some(Attribute::BootstrapMethods { methods, .. }) = class_file.attributes.first_mut()
+        else {
+            panic!("expected BootstrapMethods attribute");
+        };
+        methods[0].arguments = arguments;
+
+        assert_eq!(Ok(()), verify(&class_file));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_invalid_method_handle_index() -> Result<()> {
+        let mut class_file = class_file(Vec::new())?;
+        let Some(Attribute::BootstrapMethods { methods, .. }) = class_file.attributes.first_mut()
+        else {
+            panic!("expected BootstrapMethods attribute");
+        };
+        methods[0].bootstrap_method_ref = u16::MAX;
+
+        assert_eq!(Err(InvalidConstantPoolIndex(u16::MAX)), verify(&class_file));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_invalid_method_handle_type() -> Result<()> {
+        let mut class_file = class_file(Vec::new())?;
+        let index = class_file.constant_pool.add_integer(42)?;
+        let Some(Attribute::BootstrapMethods { methods, .. }) = class_file.attributes.first_mut()
+        else {
+            panic!("expected BootstrapMethods attribute");
+        };
+        methods[0].bootstrap_method_ref = index;
+
+        assert!(matches!(
+            verify(&class_file),
+            Err(InvalidConstantPoolIndexType { index: error_index, ref expected, .. })
+                if error_index == index && expected == "CONSTANT_MethodHandle"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_invalid_argument_index() -> Result<()> {
+        let class_file = class_file(vec![u16::MAX])?;
+        assert_eq!(Err(InvalidConstantPoolIndex(u16::MAX)), verify(&class_file));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_invalid_argument_type() -> Result<()> {
+        let mut class_file = class_file(Vec::new())?;
+        let index = class_file.constant_pool.add_utf8("foo")?;
+        let Some(Attribute::BootstrapMethods { methods, .. }) = class_file.attributes.first_mut()
+        else {
+            panic!("expected BootstrapMethods attribute");
+        };
+        methods[0].arguments = vec![index];
+
+        assert!(matches!(
+            verify(&class_file),
+            Err(InvalidConstantPoolIndexType { index: error_index, ref expected, .. })
+                if error_index == index && expected == LOADABLE_CONSTANTS
+        ));
+        Ok(())
+    }
+}
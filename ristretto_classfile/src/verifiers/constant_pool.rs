@@ -1,12 +1,13 @@
 use crate::attributes::Attribute;
 use crate::class_file::ClassFile;
 use crate::constant::Constant;
+use crate::constant_pool::invalid_index_type;
 use crate::reference_kind::ReferenceKind;
 use crate::version::Version;
+use crate::Error::InvalidConstantPoolIndex;
 use crate::Error::{
     BootstrapMethodsNotDefined, InvalidBootstrapMethodIndex, InvalidVersionConstant,
 };
-use crate::Error::{InvalidConstantPoolIndex, InvalidConstantPoolIndexType};
 use crate::Result;
 
 const JAVA_8: Version = Version::Java8 { minor: 0 };
@@ -44,14 +45,18 @@ fn verify_constant_indexes(class_file: &ClassFile) -> Result<()> {
                 match constant_pool.get(*name_index) {
                     Some(Constant::Utf8 { .. }) => {} // valid index
                     None => return Err(InvalidConstantPoolIndex(index)),
-                    _ => return Err(InvalidConstantPoolIndexType(index)),
+                    Some(constant) => {
+                        return Err(invalid_index_type(index, "CONSTANT_Utf8", constant))
+                    }
                 }
             }
             Constant::String(string_index) => {
                 match constant_pool.get(*string_index) {
                     Some(Constant::Utf8 { .. }) => {} // valid index
                     None => return Err(InvalidConstantPoolIndex(index)),
-                    _ => return Err(InvalidConstantPoolIndexType(index)),
+                    Some(constant) => {
+                        return Err(invalid_index_type(index, "CONSTANT_Utf8", constant))
+                    }
                 }
             }
             Constant::FieldRef {
@@ -69,12 +74,16 @@ fn verify_constant_indexes(class_file: &ClassFile) -> Result<()> {
                 match constant_pool.get(*class_index) {
                     Some(Constant::Class { .. }) => {} // valid index
                     None => return Err(InvalidConstantPoolIndex(index)),
-                    _ => return Err(InvalidConstantPoolIndexType(index)),
+                    Some(constant) => {
+                        return Err(invalid_index_type(index, "CONSTANT_Class", constant))
+                    }
                 }
                 match constant_pool.get(*name_and_type_index) {
                     Some(Constant::NameAndType { .. }) => {} // valid index
                     None => return Err(InvalidConstantPoolIndex(index)),
-                    _ => return Err(InvalidConstantPoolIndexType(index)),
+                    Some(constant) => {
+                        return Err(invalid_index_type(index, "CONSTANT_NameAndType", constant))
+                    }
                 }
             }
             Constant::NameAndType {
@@ -84,12 +93,16 @@ fn verify_constant_indexes(class_file: &ClassFile) -> Result<()> {
                 match constant_pool.get(*name_index) {
                     Some(Constant::Utf8 { .. }) => {} // valid index
                     None => return Err(InvalidConstantPoolIndex(index)),
-                    _ => return Err(InvalidConstantPoolIndexType(index)),
+                    Some(constant) => {
+                        return Err(invalid_index_type(index, "CONSTANT_Utf8", constant))
+                    }
                 }
                 match constant_pool.get(*descriptor_index) {
                     Some(Constant::Utf8 { .. }) => {} // valid index
                     None => return Err(InvalidConstantPoolIndex(index)),
-                    _ => return Err(InvalidConstantPoolIndexType(index)),
+                    Some(constant) => {
+                        return Err(invalid_index_type(index, "CONSTANT_Utf8", constant))
+                    }
                 }
             }
             Constant::MethodHandle {
@@ -103,14 +116,18 @@ fn verify_constant_indexes(class_file: &ClassFile) -> Result<()> {
                     match constant_pool.get(*reference_index) {
                         Some(Constant::FieldRef { .. }) => {} // valid index
                         None => return Err(InvalidConstantPoolIndex(index)),
-                        _ => return Err(InvalidConstantPoolIndexType(index)),
+                        Some(constant) => {
+                            return Err(invalid_index_type(index, "CONSTANT_Fieldref", constant))
+                        }
                     }
                 }
                 ReferenceKind::InvokeVirtual | ReferenceKind::NewInvokeSpecial => {
                     match constant_pool.get(*reference_index) {
                         Some(Constant::MethodRef { .. }) => {} // valid index
                         None => return Err(InvalidConstantPoolIndex(index)),
-                        _ => return Err(InvalidConstantPoolIndexType(index)),
+                        Some(constant) => {
+                            return Err(invalid_index_type(index, "CONSTANT_Methodref", constant))
+                        }
                     }
                 }
                 ReferenceKind::InvokeStatic | ReferenceKind::InvokeSpecial => {
@@ -119,14 +136,26 @@ fn verify_constant_indexes(class_file: &ClassFile) -> Result<()> {
                         Some(Constant::InterfaceMethodRef { .. })
                             if class_file.version >= JAVA_8 => {} // valid index
                         None => return Err(InvalidConstantPoolIndex(index)),
-                        _ => return Err(InvalidConstantPoolIndexType(index)),
+                        Some(constant) => {
+                            return Err(invalid_index_type(
+                                index,
+                                "CONSTANT_Methodref|CONSTANT_InterfaceMethodref",
+                                constant,
+                            ))
+                        }
                     }
                 }
                 ReferenceKind::InvokeInterface => {
                     match constant_pool.get(*reference_index) {
                         Some(Constant::InterfaceMethodRef { .. }) => {} // valid index
                         None => return Err(InvalidConstantPoolIndex(index)),
-                        _ => return Err(InvalidConstantPoolIndexType(index)),
+                        Some(constant) => {
+                            return Err(invalid_index_type(
+                                index,
+                                "CONSTANT_InterfaceMethodref",
+                                constant,
+                            ))
+                        }
                     }
                 }
             },
@@ -134,7 +163,9 @@ fn verify_constant_indexes(class_file: &ClassFile) -> Result<()> {
                 match constant_pool.get(*descriptor_index) {
                     Some(Constant::Utf8 { .. }) => {} // valid index
                     None => return Err(InvalidConstantPoolIndex(index)),
-                    _ => return Err(InvalidConstantPoolIndexType(index)),
+                    Some(constant) => {
+                        return Err(invalid_index_type(index, "CONSTANT_Utf8", constant))
+                    }
                 }
             }
             Constant::Dynamic {
@@ -164,7 +195,9 @@ fn verify_constant_indexes(class_file: &ClassFile) -> Result<()> {
                 match constant_pool.get(*name_and_type_index) {
                     Some(Constant::NameAndType { .. }) => {} // valid index
                     None => return Err(InvalidConstantPoolIndex(index)),
-                    _ => return Err(InvalidConstantPoolIndexType(index)),
+                    Some(constant) => {
+                        return Err(invalid_index_type(index, "CONSTANT_NameAndType", constant))
+                    }
                 }
             }
             _ => continue,
@@ -180,6 +213,7 @@ mod test {
     use crate::attributes::BootstrapMethod;
     use crate::class_file::ClassFile;
     use crate::constant::Constant;
+    use crate::Error::InvalidConstantPoolIndexType;
     use std::io::Cursor;
 
     fn get_class_file() -> Result<ClassFile> {
@@ -302,10 +336,10 @@ mod test {
     fn test_indexes_index_type_error(mut class_file: ClassFile, constant: Constant) -> Result<()> {
         class_file.constant_pool.push(constant);
         let index = u16::try_from(class_file.constant_pool.len() - 1)?;
-        assert_eq!(
+        assert!(matches!(
             verify_constant_indexes(&class_file),
-            Err(InvalidConstantPoolIndexType(index))
-        );
+            Err(InvalidConstantPoolIndexType { index: error_index, .. }) if error_index == index
+        ));
         Ok(())
     }
 
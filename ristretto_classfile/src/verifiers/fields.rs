@@ -1,8 +1,9 @@
 use crate::class_file::ClassFile;
 use crate::constant::Constant;
+use crate::constant_pool::invalid_index_type;
 use crate::field::Field;
 use crate::verifiers::field_access_flags;
-use crate::Error::{InvalidConstantPoolIndex, InvalidConstantPoolIndexType};
+use crate::Error::InvalidConstantPoolIndex;
 use crate::Result;
 
 /// Verify the `ClassFile` fields.
@@ -21,7 +22,7 @@ fn verify_name_index(class_file: &ClassFile, field: &Field) -> Result<()> {
     match class_file.constant_pool.get(name_index) {
         Some(Constant::Utf8 { .. }) => {} // valid constant
         None => return Err(InvalidConstantPoolIndex(name_index)),
-        _ => return Err(InvalidConstantPoolIndexType(name_index)),
+        Some(constant) => return Err(invalid_index_type(name_index, "CONSTANT_Utf8", constant)),
     }
     Ok(())
 }
@@ -31,7 +32,13 @@ fn verify_descriptor_index(class_file: &ClassFile, field: &Field) -> Result<()>
     match class_file.constant_pool.get(descriptor_index) {
         Some(Constant::Utf8 { .. }) => {} // valid constant
         None => return Err(InvalidConstantPoolIndex(descriptor_index)),
-        _ => return Err(InvalidConstantPoolIndexType(descriptor_index)),
+        Some(constant) => {
+            return Err(invalid_index_type(
+                descriptor_index,
+                "CONSTANT_Utf8",
+                constant,
+            ))
+        }
     }
     Ok(())
 }
@@ -40,6 +47,7 @@ fn verify_descriptor_index(class_file: &ClassFile, field: &Field) -> Result<()>
 mod test {
     use super::*;
     use crate::field_access_flags::FieldAccessFlags;
+    use crate::Error::InvalidConstantPoolIndexType;
     use crate::{BaseType, FieldType};
 
     fn get_test_class_file_and_field() -> (ClassFile, Field) {
@@ -86,10 +94,10 @@ mod test {
         let constant_pool = &mut class_file.constant_pool;
         constant_pool.push(Constant::Class(field.name_index));
         field.name_index = u16::try_from(constant_pool.len())?;
-        assert_eq!(
-            Err(InvalidConstantPoolIndexType(field.name_index)),
-            verify_name_index(&class_file, &field)
-        );
+        assert!(matches!(
+            verify_name_index(&class_file, &field),
+            Err(InvalidConstantPoolIndexType { index: error_index, .. }) if error_index == field.name_index
+        ));
         Ok(())
     }
 
@@ -109,10 +117,10 @@ mod test {
         let constant_pool = &mut class_file.constant_pool;
         constant_pool.push(Constant::Class(field.descriptor_index));
         field.descriptor_index = u16::try_from(constant_pool.len())?;
-        assert_eq!(
-            Err(InvalidConstantPoolIndexType(field.descriptor_index)),
-            verify_descriptor_index(&class_file, &field)
-        );
+        assert!(matches!(
+            verify_descriptor_index(&class_file, &field),
+            Err(InvalidConstantPoolIndexType { index: error_index, .. }) if error_index == field.descriptor_index
+        ));
         Ok(())
     }
 }
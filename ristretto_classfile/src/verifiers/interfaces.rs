@@ -1,6 +1,7 @@
 use crate::class_file::ClassFile;
 use crate::constant::Constant;
-use crate::Error::{InvalidConstantPoolIndex, InvalidConstantPoolIndexType};
+use crate::constant_pool::invalid_index_type;
+use crate::Error::InvalidConstantPoolIndex;
 use crate::Result;
 
 /// Verify the `ClassFile` interfaces.
@@ -10,7 +11,9 @@ pub fn verify(class_file: &ClassFile) -> Result<()> {
         match constant_pool.get(*interface) {
             Some(Constant::Class { .. }) => {} // valid constant
             None => return Err(InvalidConstantPoolIndex(*interface)),
-            _ => return Err(InvalidConstantPoolIndexType(*interface)),
+            Some(constant) => {
+                return Err(invalid_index_type(*interface, "CONSTANT_Class", constant))
+            }
         }
     }
     Ok(())
@@ -19,6 +22,7 @@ pub fn verify(class_file: &ClassFile) -> Result<()> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::Error::InvalidConstantPoolIndexType;
 
     #[test]
     fn test_verify_success() -> Result<()> {
@@ -47,10 +51,10 @@ mod test {
         let index = constant_pool.add_integer(42)?;
         class_file.interfaces.push(index);
 
-        assert_eq!(
-            Err(InvalidConstantPoolIndexType(index)),
-            verify(&class_file)
-        );
+        assert!(matches!(
+            verify(&class_file),
+            Err(InvalidConstantPoolIndexType { index: error_index, .. }) if error_index == index
+        ));
         Ok(())
     }
 }
@@ -1,16 +1,22 @@
+use crate::attributes::{Attribute, Instruction};
 use crate::class_file::ClassFile;
 use crate::constant::Constant;
+use crate::constant_pool::invalid_index_type;
 use crate::method::Method;
 use crate::verifiers::method_access_flags;
-use crate::Error::{InvalidConstantPoolIndex, InvalidConstantPoolIndexType};
+use crate::version::Version;
+use crate::Error::{InvalidConstantPoolIndex, InvalidVersionInstruction};
 use crate::Result;
 
+const VERSION_51_0: Version = Version::Java7 { minor: 0 };
+
 /// Verify the `ClassFile` methods.
 pub fn verify(class_file: &ClassFile) -> Result<()> {
     for method in &class_file.methods {
         method_access_flags::verify(class_file, method)?;
         verify_name_index(class_file, method)?;
         verify_descriptor_index(class_file, method)?;
+        verify_code(class_file, method)?;
 
         // TODO: verify instructions match method return type:
         // See: https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.ireturn
@@ -25,7 +31,7 @@ fn verify_name_index(class_file: &ClassFile, method: &Method) -> Result<()> {
     match class_file.constant_pool.get(name_index) {
         Some(Constant::Utf8 { .. }) => {} // valid constant
         None => return Err(InvalidConstantPoolIndex(name_index)),
-        _ => return Err(InvalidConstantPoolIndexType(name_index)),
+        Some(constant) => return Err(invalid_index_type(name_index, "CONSTANT_Utf8", constant)),
     }
     Ok(())
 }
@@ -35,7 +41,41 @@ fn verify_descriptor_index(class_file: &ClassFile, method: &Method) -> Result<()
     match class_file.constant_pool.get(descriptor_index) {
         Some(Constant::Utf8 { .. }) => {} // valid constant
         None => return Err(InvalidConstantPoolIndex(descriptor_index)),
-        _ => return Err(InvalidConstantPoolIndexType(descriptor_index)),
+        Some(constant) => {
+            return Err(invalid_index_type(
+                descriptor_index,
+                "CONSTANT_Utf8",
+                constant,
+            ))
+        }
+    }
+    Ok(())
+}
+
+/// Verify the method code does not contain the `jsr`, `jsr_w`, `ret` or `ret_w` instructions;
+/// subroutines are not permitted in class files with a version number of 51.0 or above.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.9.1>
+fn verify_code(class_file: &ClassFile, method: &Method) -> Result<()> {
+    if class_file.version < VERSION_51_0 {
+        return Ok(());
+    }
+
+    for attribute in &method.attributes {
+        let Attribute::Code { code, .. } = attribute else {
+            continue;
+        };
+        for instruction in code {
+            if matches!(
+                instruction,
+                Instruction::Jsr(..)
+                    | Instruction::Jsr_w(..)
+                    | Instruction::Ret(..)
+                    | Instruction::Ret_w(..)
+            ) {
+                return Err(InvalidVersionInstruction(instruction.to_string()));
+            }
+        }
     }
     Ok(())
 }
@@ -44,6 +84,7 @@ fn verify_descriptor_index(class_file: &ClassFile, method: &Method) -> Result<()
 mod test {
     use super::*;
     use crate::method_access_flags::MethodAccessFlags;
+    use crate::Error::InvalidConstantPoolIndexType;
 
     fn get_test_class_file_and_method() -> (ClassFile, Method) {
         let mut class_file = ClassFile::default();
@@ -88,10 +129,10 @@ mod test {
         let constant_pool = &mut class_file.constant_pool;
         constant_pool.push(Constant::Class(method.name_index));
         method.name_index = u16::try_from(constant_pool.len())?;
-        assert_eq!(
-            Err(InvalidConstantPoolIndexType(method.name_index)),
-            verify_name_index(&class_file, &method)
-        );
+        assert!(matches!(
+            verify_name_index(&class_file, &method),
+            Err(InvalidConstantPoolIndexType { index: error_index, .. }) if error_index == method.name_index
+        ));
         Ok(())
     }
 
@@ -111,10 +152,61 @@ mod test {
         let constant_pool = &mut class_file.constant_pool;
         constant_pool.push(Constant::Class(method.descriptor_index));
         method.descriptor_index = u16::try_from(constant_pool.len())?;
+        assert!(matches!(
+            verify_descriptor_index(&class_file, &method),
+            Err(InvalidConstantPoolIndexType { index: error_index, .. }) if error_index == method.descriptor_index
+        ));
+        Ok(())
+    }
+
+    fn get_code_method(code: Vec<Instruction>) -> Method {
+        Method {
+            access_flags: MethodAccessFlags::PUBLIC,
+            name_index: 1,
+            descriptor_index: 2,
+            attributes: vec![Attribute::Code {
+                name_index: 3,
+                max_stack: 1,
+                max_locals: 1,
+                code,
+                exception_table: vec![],
+                attributes: vec![],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_verify_code_jsr_java6() {
+        let (mut class_file, _method) = get_test_class_file_and_method();
+        class_file.version = Version::Java6 { minor: 0 };
+        let method = get_code_method(vec![
+            Instruction::Jsr(2),
+            Instruction::Return,
+            Instruction::Astore_1,
+            Instruction::Ret(1),
+        ]);
+        assert_eq!(Ok(()), verify_code(&class_file, &method));
+    }
+
+    #[test]
+    fn test_verify_code_jsr_java7_error() {
+        let (mut class_file, _method) = get_test_class_file_and_method();
+        class_file.version = Version::Java7 { minor: 0 };
+        let method = get_code_method(vec![Instruction::Jsr(1), Instruction::Return]);
         assert_eq!(
-            Err(InvalidConstantPoolIndexType(method.descriptor_index)),
-            verify_descriptor_index(&class_file, &method)
+            Err(InvalidVersionInstruction("jsr 1".to_string())),
+            verify_code(&class_file, &method)
+        );
+    }
+
+    #[test]
+    fn test_verify_code_ret_java7_error() {
+        let (mut class_file, _method) = get_test_class_file_and_method();
+        class_file.version = Version::Java7 { minor: 0 };
+        let method = get_code_method(vec![Instruction::Ret(1)]);
+        assert_eq!(
+            Err(InvalidVersionInstruction("ret 1".to_string())),
+            verify_code(&class_file, &method)
         );
-        Ok(())
     }
 }
@@ -1,3 +1,4 @@
+pub mod bootstrap_methods;
 pub mod class_access_flags;
 pub mod constant_pool;
 pub mod field_access_flags;
@@ -1,8 +1,9 @@
 use crate::class_access_flags::ClassAccessFlags;
 use crate::class_file::ClassFile;
 use crate::constant::Constant;
-use crate::verifiers::{class_access_flags, constant_pool, fields, methods};
-use crate::Error::{InvalidConstantPoolIndex, InvalidConstantPoolIndexType};
+use crate::constant_pool::invalid_index_type;
+use crate::verifiers::{bootstrap_methods, class_access_flags, constant_pool, fields, methods};
+use crate::Error::InvalidConstantPoolIndex;
 use crate::Result;
 
 /// Verify the `ClassFile`.
@@ -13,6 +14,7 @@ pub fn verify(class_file: &ClassFile) -> Result<()> {
     verify_super_class(class_file)?;
     fields::verify(class_file)?;
     methods::verify(class_file)?;
+    bootstrap_methods::verify(class_file)?;
     // TODO: verify attributes
     Ok(())
 }
@@ -23,7 +25,7 @@ fn verify_this_class(class_file: &ClassFile) -> Result<()> {
     match constant_pool.get(class_file.this_class) {
         Some(Constant::Class { .. }) => {} // valid constant
         None => return Err(InvalidConstantPoolIndex(this_class)),
-        _ => return Err(InvalidConstantPoolIndexType(this_class)),
+        Some(constant) => return Err(invalid_index_type(this_class, "CONSTANT_Class", constant)),
     }
     Ok(())
 }
@@ -43,7 +45,7 @@ fn verify_super_class(class_file: &ClassFile) -> Result<()> {
     match constant_pool.get(class_file.super_class) {
         Some(Constant::Class { .. }) => {} // valid constant
         None => return Err(InvalidConstantPoolIndex(super_class)),
-        _ => return Err(InvalidConstantPoolIndexType(super_class)),
+        Some(constant) => return Err(invalid_index_type(super_class, "CONSTANT_Class", constant)),
     }
     Ok(())
 }
@@ -52,6 +54,7 @@ fn verify_super_class(class_file: &ClassFile) -> Result<()> {
 mod test {
     use super::*;
     use crate::class_file::ClassFile;
+    use crate::Error::InvalidConstantPoolIndexType;
 
     #[test]
     fn test_verify_this_class_success() -> Result<()> {
@@ -82,10 +85,10 @@ mod test {
         let index = constant_pool.add_integer(42)?;
         class_file.this_class = index;
 
-        assert_eq!(
-            Err(InvalidConstantPoolIndexType(index)),
-            verify_this_class(&class_file)
-        );
+        assert!(matches!(
+            verify_this_class(&class_file),
+            Err(InvalidConstantPoolIndexType { index: error_index, .. }) if error_index == index
+        ));
         Ok(())
     }
 
@@ -127,10 +130,10 @@ mod test {
         let index = constant_pool.add_integer(42)?;
         class_file.super_class = index;
 
-        assert_eq!(
-            Err(InvalidConstantPoolIndexType(index)),
-            verify_super_class(&class_file)
-        );
+        assert!(matches!(
+            verify_super_class(&class_file),
+            Err(InvalidConstantPoolIndexType { index: error_index, .. }) if error_index == index
+        ));
         Ok(())
     }
 
@@ -172,7 +172,7 @@ impl Version {
     ///
     /// # Errors
     /// Returns an error if the bytes do not represent a valid version.
-    pub fn from_bytes(bytes: &mut Cursor<Vec<u8>>) -> Result<Version> {
+    pub fn from_bytes<T: AsRef<[u8]>>(bytes: &mut Cursor<T>) -> Result<Version> {
         let minor = bytes.read_u16::<BigEndian>()?;
         let major = bytes.read_u16::<BigEndian>()?;
         Version::from(major, minor)
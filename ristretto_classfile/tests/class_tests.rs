@@ -26,6 +26,11 @@ pub fn test_expressions() -> Result<()> {
     test_class(include_bytes!("../../classes/Expressions.class"))
 }
 
+#[test]
+pub fn test_generics() -> Result<()> {
+    test_class(include_bytes!("../../classes/Generics.class"))
+}
+
 #[test]
 pub fn test_minimum() -> Result<()> {
     test_class(include_bytes!("../../classes/Minimum.class"))
@@ -1,10 +1,13 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use ristretto_classloader::{runtime, Result};
+use ristretto_classloader::{runtime, ClassLoader, ClassPath, Result};
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::runtime::Runtime;
 
 fn benchmarks(criterion: &mut Criterion) {
     bench_lifecycle(criterion).ok();
+    bench_jar(criterion).ok();
+    bench_method(criterion).ok();
 }
 
 fn bench_lifecycle(criterion: &mut Criterion) -> Result<()> {
@@ -59,6 +62,53 @@ fn bench_lifecycle(criterion: &mut Criterion) -> Result<()> {
     Ok(())
 }
 
+fn bench_jar(criterion: &mut Criterion) -> Result<()> {
+    let runtime = Runtime::new()?;
+    let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let classes_jar = cargo_manifest.join("../classes/classes.jar");
+    let classes_jar = classes_jar.to_string_lossy();
+
+    // A new class path and class loader open and read the jar on every iteration; cloning a class
+    // path would share the jar that was already read
+    criterion.bench_function("load_jar_cold", |bencher| {
+        bencher.iter(|| {
+            runtime.block_on(async {
+                let class_path = ClassPath::from(&classes_jar);
+                let class_loader = ClassLoader::new("bench", class_path);
+                let _ = class_loader.load("HelloWorld").await.ok();
+            });
+        });
+    });
+
+    // The class is loaded once and then served from the class loader cache
+    let class_loader = ClassLoader::new("bench", ClassPath::from(&classes_jar));
+    runtime.block_on(async { class_loader.load("HelloWorld").await })?;
+    criterion.bench_function("load_jar_warm", |bencher| {
+        bencher.iter(|| {
+            runtime.block_on(async {
+                let _ = class_loader.load("HelloWorld").await.ok();
+            });
+        });
+    });
+
+    Ok(())
+}
+
+fn bench_method(criterion: &mut Criterion) -> Result<()> {
+    let runtime = Runtime::new()?;
+    let (_java_home, _version, class_loader) =
+        runtime.block_on(async { runtime::version_class_loader("21.0.5.11.1").await })?;
+    let class = runtime.block_on(async { class_loader.load("java.lang.String").await })?;
+
+    criterion.bench_function("try_get_method", |bencher| {
+        bencher.iter(|| {
+            let _ = class.try_get_method("isEmpty", "()Z").ok();
+        });
+    });
+
+    Ok(())
+}
+
 async fn boot_class_loader(version: &str) -> Result<()> {
     let (_java_home, _java_version, class_loader) = runtime::version_class_loader(version).await?;
     let _class = class_loader.load("java.lang.Object").await?;
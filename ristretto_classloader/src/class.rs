@@ -7,7 +7,21 @@ use ristretto_classfile::{
 };
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock, Weak};
+
+/// The layout of a field in the class hierarchy of a class; see [`Class::field_layout`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FieldLayout {
+    /// The name of the field (e.g. "value")
+    pub name: String,
+    /// The descriptor of the field (e.g. "[B")
+    pub descriptor: String,
+    /// The offset of the field, as used by `Unsafe`
+    pub offset: usize,
+    /// True if the field is static
+    pub is_static: bool,
+}
 
 /// A representation of a Java class.
 #[derive(Debug)]
@@ -18,7 +32,42 @@ pub struct Class {
     parent: Arc<RwLock<Option<Arc<Class>>>>,
     interfaces: Arc<RwLock<Vec<Arc<Class>>>>,
     fields: IndexMap<String, Arc<Field>>,
-    methods: HashMap<String, Arc<Method>>,
+    methods: MethodTable,
+    resolved_constants: Arc<RwLock<HashMap<u16, ResolvedConstant>>>,
+    resolution_count: Arc<AtomicUsize>,
+    initialization_state: Arc<RwLock<InitializationState>>,
+    initialization_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+/// The methods of a class keyed by name and then by descriptor, so that a method can be looked up
+/// by borrowed name and descriptor without allocating a key.
+type MethodTable = HashMap<String, HashMap<String, Arc<Method>>>;
+
+/// The initialization state of a class.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.5>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InitializationState {
+    /// The class has not been initialized.
+    #[default]
+    NotInitialized,
+    /// The class is being initialized by the thread with the given identifier.
+    BeingInitialized(u64),
+    /// The class has been initialized.
+    Initialized,
+    /// The class initializer failed; the class cannot be initialized.
+    Erroneous,
+}
+
+/// A symbolic reference in the constant pool of a class that has been resolved; classes are held
+/// weakly to avoid reference cycles between classes that reference each other.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.4.3>
+#[derive(Clone, Debug)]
+enum ResolvedConstant {
+    Class(Weak<Class>),
+    Field(Weak<Class>, Arc<Field>),
+    Method(Weak<Class>, Arc<Method>),
 }
 
 impl Class {
@@ -39,12 +88,6 @@ impl Class {
             fields_map.insert(field_name, Arc::new(field));
         }
 
-        let mut methods_map = HashMap::new();
-        for method in methods {
-            let method_identifier = method.identifier();
-            methods_map.insert(method_identifier, Arc::new(method));
-        }
-
         Self {
             name,
             source_file,
@@ -52,7 +95,11 @@ impl Class {
             parent: Arc::new(RwLock::new(parent)),
             interfaces: Arc::new(RwLock::new(interfaces)),
             fields: fields_map,
-            methods: methods_map,
+            methods: Self::method_table(methods),
+            resolved_constants: Arc::new(RwLock::new(HashMap::new())),
+            resolution_count: Arc::new(AtomicUsize::new(0)),
+            initialization_state: Arc::new(RwLock::new(InitializationState::NotInitialized)),
+            initialization_lock: Arc::new(tokio::sync::Mutex::new(())),
         }
     }
 
@@ -69,7 +116,7 @@ impl Class {
             this_class: class_index,
             ..Default::default()
         };
-        let methods = HashMap::new();
+        let methods = MethodTable::new();
         Ok(Self {
             name,
             source_file: None,
@@ -78,6 +125,10 @@ impl Class {
             interfaces: Arc::new(RwLock::new(Vec::new())),
             fields: IndexMap::new(),
             methods,
+            resolved_constants: Arc::new(RwLock::new(HashMap::new())),
+            resolution_count: Arc::new(AtomicUsize::new(0)),
+            initialization_state: Arc::new(RwLock::new(InitializationState::NotInitialized)),
+            initialization_lock: Arc::new(tokio::sync::Mutex::new(())),
         })
     }
 
@@ -108,12 +159,12 @@ impl Class {
             fields.insert(field_name, Arc::new(field));
         }
 
-        let mut methods = HashMap::new();
-        for class_file_method in &class_file.methods {
-            let method = Method::from(&class_file, class_file_method)?;
-            let method_identifier = method.identifier();
-            methods.insert(method_identifier, Arc::new(method));
-        }
+        let methods = class_file
+            .methods
+            .iter()
+            .map(|method| Method::from(&class_file, method))
+            .collect::<Result<Vec<_>>>()?;
+        let methods = Self::method_table(methods);
 
         Ok(Self {
             name,
@@ -123,9 +174,25 @@ impl Class {
             interfaces: Arc::new(RwLock::new(Vec::new())),
             fields,
             methods,
+            resolved_constants: Arc::new(RwLock::new(HashMap::new())),
+            resolution_count: Arc::new(AtomicUsize::new(0)),
+            initialization_state: Arc::new(RwLock::new(InitializationState::NotInitialized)),
+            initialization_lock: Arc::new(tokio::sync::Mutex::new(())),
         })
     }
 
+    /// Create the method table for the methods of a class.
+    fn method_table(methods: Vec<Method>) -> MethodTable {
+        let mut method_table = MethodTable::new();
+        for method in methods {
+            method_table
+                .entry(method.name().to_string())
+                .or_default()
+                .insert(method.descriptor().to_string(), Arc::new(method));
+        }
+        method_table
+    }
+
     /// Get the class name.
     #[must_use]
     pub fn name(&self) -> &str {
@@ -308,26 +375,63 @@ impl Class {
         Ok(field)
     }
 
-    /// Get a list of field names in the class hierarchy.
+    /// Get the fields in the class hierarchy; the fields of the root class are first and the
+    /// fields of this class are last, each in the order they are defined in the class file.
     ///
     /// # Errors
     /// if there is an issue accessing the parent class.
-    fn field_names(&self) -> Result<Vec<String>> {
-        let mut field_names = Vec::new();
+    fn hierarchy_fields(&self) -> Result<Vec<Arc<Field>>> {
+        let mut fields = Vec::new();
         let mut parent = self.parent()?;
         while let Some(class) = parent {
-            for field_name in class.fields.keys().rev() {
-                field_names.insert(0, field_name.clone());
+            for field in class.fields.values().rev() {
+                fields.insert(0, field.clone());
             }
             parent = class.parent()?;
         }
 
-        for field_name in self.fields.keys() {
-            field_names.push(field_name.clone());
+        for field in self.fields.values() {
+            fields.push(field.clone());
         }
+        Ok(fields)
+    }
+
+    /// Get a list of field names in the class hierarchy.
+    ///
+    /// # Errors
+    /// if there is an issue accessing the parent class.
+    fn field_names(&self) -> Result<Vec<String>> {
+        let field_names = self
+            .hierarchy_fields()?
+            .iter()
+            .map(|field| field.name().to_string())
+            .collect();
         Ok(field_names)
     }
 
+    /// Get the layout of the fields in the class hierarchy, ordered by offset.  The offsets are
+    /// those used by [`Class::field_offset`] and [`Class::field_name`]; instance and static fields
+    /// share the same offsets and are distinguished by [`FieldLayout::is_static`].  A field that
+    /// hides a field of a superclass has its own offset, but [`Class::field_offset`] resolves the
+    /// name to the field of the superclass.
+    ///
+    /// # Errors
+    /// if there is an issue accessing the parent class.
+    pub fn field_layout(&self) -> Result<Vec<FieldLayout>> {
+        let field_layout = self
+            .hierarchy_fields()?
+            .iter()
+            .enumerate()
+            .map(|(offset, field)| FieldLayout {
+                name: field.name().to_string(),
+                descriptor: field.field_type().descriptor(),
+                offset,
+                is_static: field.access_flags().contains(FieldAccessFlags::STATIC),
+            })
+            .collect();
+        Ok(field_layout)
+    }
+
     /// Field offset by name.  This is primarily used by the Unsafe class that references fields by
     /// offset.
     ///
@@ -379,7 +483,11 @@ impl Class {
     /// Get all methods.
     #[must_use]
     pub fn methods(&self) -> Vec<Arc<Method>> {
-        self.methods.values().cloned().collect()
+        self.methods
+            .values()
+            .flat_map(HashMap::values)
+            .cloned()
+            .collect()
     }
 
     /// Get the main method.
@@ -402,11 +510,8 @@ impl Class {
         N: AsRef<str>,
         D: AsRef<str>,
     {
-        let name = name.as_ref();
-        let descriptor = descriptor.as_ref();
-        let method_identifier = format!("{name}:{descriptor}");
-        let method = self.methods.get(&method_identifier);
-        method.cloned()
+        let methods = self.methods.get(name.as_ref())?;
+        methods.get(descriptor.as_ref()).cloned()
     }
 
     /// Get a method by name and descriptor.
@@ -430,6 +535,152 @@ impl Class {
         Ok(method)
     }
 
+    /// Get the resolved constant at the given constant pool index of this class.
+    ///
+    /// # Errors
+    /// if the resolved constants lock is poisoned.
+    fn resolved_constant(&self, index: u16) -> Result<Option<ResolvedConstant>> {
+        let resolved_constants = self
+            .resolved_constants
+            .read()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        Ok(resolved_constants.get(&index).cloned())
+    }
+
+    /// Cache the resolved constant at the given constant pool index of this class.
+    ///
+    /// # Errors
+    /// if the resolved constants lock is poisoned.
+    fn set_resolved_constant(&self, index: u16, constant: ResolvedConstant) -> Result<()> {
+        let mut resolved_constants = self
+            .resolved_constants
+            .write()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        resolved_constants.insert(index, constant);
+        self.resolution_count.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Get the number of times a constant pool entry of this class has been resolved and cached.
+    /// Each entry should only be resolved once, so this can be used to verify that resolved
+    /// entries are reused.
+    #[must_use]
+    pub fn resolution_count(&self) -> usize {
+        self.resolution_count.load(Ordering::Relaxed)
+    }
+
+    /// Get the class previously resolved for the class reference at the given constant pool index
+    /// of this class; returns `None` if the class reference has not been resolved.
+    ///
+    /// # Errors
+    /// if the resolved constants lock is poisoned.
+    pub fn resolved_class(&self, index: u16) -> Result<Option<Arc<Class>>> {
+        let Some(ResolvedConstant::Class(class)) = self.resolved_constant(index)? else {
+            return Ok(None);
+        };
+        Ok(class.upgrade())
+    }
+
+    /// Cache the class resolved for the class reference at the given constant pool index of this
+    /// class.
+    ///
+    /// # Errors
+    /// if the resolved constants lock is poisoned.
+    pub fn set_resolved_class(&self, index: u16, class: &Arc<Class>) -> Result<()> {
+        self.set_resolved_constant(index, ResolvedConstant::Class(Arc::downgrade(class)))
+    }
+
+    /// Get the class and field previously resolved for the field reference at the given
+    /// constant pool index of this class; returns `None` if the field reference has not been
+    /// resolved.
+    ///
+    /// # Errors
+    /// if the resolved constants lock is poisoned.
+    pub fn resolved_field(&self, index: u16) -> Result<Option<(Arc<Class>, Arc<Field>)>> {
+        let Some(ResolvedConstant::Field(class, field)) = self.resolved_constant(index)? else {
+            return Ok(None);
+        };
+        Ok(class.upgrade().map(|class| (class, field)))
+    }
+
+    /// Cache the class and field resolved for the field reference at the given constant
+    /// pool index of this class.
+    ///
+    /// # Errors
+    /// if the resolved constants lock is poisoned.
+    pub fn set_resolved_field(
+        &self,
+        index: u16,
+        class: &Arc<Class>,
+        field: Arc<Field>,
+    ) -> Result<()> {
+        self.set_resolved_constant(index, ResolvedConstant::Field(Arc::downgrade(class), field))
+    }
+
+    /// Get the class and method previously resolved for the method reference at the given
+    /// constant pool index of this class; returns `None` if the method reference has not been
+    /// resolved.
+    ///
+    /// # Errors
+    /// if the resolved constants lock is poisoned.
+    pub fn resolved_method(&self, index: u16) -> Result<Option<(Arc<Class>, Arc<Method>)>> {
+        let Some(ResolvedConstant::Method(class, method)) = self.resolved_constant(index)? else {
+            return Ok(None);
+        };
+        Ok(class.upgrade().map(|class| (class, method)))
+    }
+
+    /// Cache the class and method resolved for the method reference at the given constant pool
+    /// index of this class.
+    ///
+    /// # Errors
+    /// if the resolved constants lock is poisoned.
+    pub fn set_resolved_method(
+        &self,
+        index: u16,
+        class: &Arc<Class>,
+        method: Arc<Method>,
+    ) -> Result<()> {
+        self.set_resolved_constant(
+            index,
+            ResolvedConstant::Method(Arc::downgrade(class), method),
+        )
+    }
+
+    /// Get the initialization state of this class.
+    ///
+    /// # Errors
+    /// if the initialization state lock is poisoned.
+    pub fn initialization_state(&self) -> Result<InitializationState> {
+        let initialization_state = self
+            .initialization_state
+            .read()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        Ok(*initialization_state)
+    }
+
+    /// Set the initialization state of this class.
+    ///
+    /// # Errors
+    /// if the initialization state lock is poisoned.
+    pub fn set_initialization_state(&self, state: InitializationState) -> Result<()> {
+        let mut initialization_state = self
+            .initialization_state
+            .write()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        *initialization_state = state;
+        Ok(())
+    }
+
+    /// Get the lock that must be held while the class initializer of this class is executed, so
+    /// that the class is initialized by at most one thread.
+    ///
+    /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.5>
+    #[must_use]
+    pub fn initialization_lock(&self) -> Arc<tokio::sync::Mutex<()>> {
+        self.initialization_lock.clone()
+    }
+
     /// Determine if this class is assignable from the given class.
     ///
     /// # Errors
@@ -500,8 +751,9 @@ impl Display for Class {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{runtime, Error, Result};
+    use crate::{runtime, ClassLoader, ClassPath, Error, Result};
     use std::io::Cursor;
+    use std::path::PathBuf;
 
     async fn object_class() -> Result<Arc<Class>> {
         let (_java_home, _java_version, class_loader) = runtime::default_class_loader().await?;
@@ -769,6 +1021,84 @@ mod tests {
         Ok(())
     }
 
+    /// Load the test class and link it to its superclasses in the test classes directory.
+    async fn test_class(class_name: &str) -> Result<Arc<Class>> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("../classes");
+        let class_path = ClassPath::from(classes_directory.to_string_lossy());
+        let class_loader = ClassLoader::new("test", class_path);
+        let class = class_loader.load(class_name).await?;
+        let mut current_class = class.clone();
+        loop {
+            let class_file = current_class.class_file();
+            let super_class = class_file
+                .constant_pool
+                .try_get_class(class_file.super_class)?;
+            if super_class == "java/lang/Object" {
+                break;
+            }
+            let parent = class_loader.load(super_class).await?;
+            current_class.set_parent(Some(parent.clone()))?;
+            current_class = parent;
+        }
+        Ok(class)
+    }
+
+    #[tokio::test]
+    async fn test_field_layout() -> Result<()> {
+        let class = test_class("Child").await?;
+        let field_layout = class.field_layout()?;
+        let fields = field_layout
+            .iter()
+            .map(|field| (field.name.as_str(), field.offset, field.is_static))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("zero", 0, false),
+                ("one", 1, false),
+                ("one", 2, false),
+                ("two", 3, false),
+                ("three", 4, false),
+            ],
+            fields
+        );
+        assert!(field_layout.iter().all(|field| field.descriptor == "I"));
+        for field in &field_layout {
+            assert_eq!(field.name, class.field_name(field.offset)?);
+        }
+        // The hidden field of the superclass is resolved by name
+        assert_eq!(1, class.field_offset("one")?);
+        assert_eq!(4, class.field_offset("three")?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_field_layout_static() -> Result<()> {
+        let class = test_class("Simple").await?;
+        let field_layout = class.field_layout()?;
+        for field in &field_layout {
+            assert_eq!(field.offset, class.field_offset(&field.name)?);
+        }
+        let Some(answer) = field_layout.iter().find(|field| field.name == "ANSWER") else {
+            panic!("expected ANSWER field");
+        };
+        assert!(answer.is_static);
+        assert_eq!("I", answer.descriptor);
+        let Some(string) = field_layout.iter().find(|field| field.name == "STRING") else {
+            panic!("expected STRING field");
+        };
+        assert!(string.is_static);
+        assert_eq!("Ljava/lang/String;", string.descriptor);
+        let Some(public_value) = field_layout
+            .iter()
+            .find(|field| field.name == "publicValue")
+        else {
+            panic!("expected publicValue field");
+        };
+        assert!(!public_value.is_static);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_field_offset() -> Result<()> {
         let class = string_class().await?;
@@ -866,6 +1196,26 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_try_get_method_same_method() -> Result<()> {
+        let class = string_class().await?;
+        let method = class.try_get_method("isEmpty", "()Z")?;
+        let method_again = class.try_get_method("isEmpty", "()Z")?;
+        assert!(Arc::ptr_eq(&method, &method_again));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_try_get_method_overloaded() -> Result<()> {
+        let class = string_class().await?;
+        let int_method = class.try_get_method("valueOf", "(I)Ljava/lang/String;")?;
+        let long_method = class.try_get_method("valueOf", "(J)Ljava/lang/String;")?;
+        assert_eq!("(I)Ljava/lang/String;", int_method.descriptor());
+        assert_eq!("(J)Ljava/lang/String;", long_method.descriptor());
+        assert!(class.method("valueOf", "(S)Ljava/lang/String;").is_none());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_try_get_method_not_fount() -> Result<()> {
         let class = string_class().await?;
@@ -885,6 +1235,83 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_resolved_class() -> Result<()> {
+        let class = object_class().await?;
+        assert!(class.resolved_class(1)?.is_none());
+
+        let string_class = string_class().await?;
+        class.set_resolved_class(1, &string_class)?;
+        let resolved_class = class.resolved_class(1)?.expect("resolved class");
+        assert!(Arc::ptr_eq(&string_class, &resolved_class));
+        assert!(class.resolved_field(1)?.is_none());
+        assert!(class.resolved_method(1)?.is_none());
+        assert_eq!(1, class.resolution_count());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_resolved_field() -> Result<()> {
+        let class = object_class().await?;
+        assert!(class.resolved_field(1)?.is_none());
+
+        let string_class = string_class().await?;
+        let field = string_class.static_field("serialVersionUID")?;
+        class.set_resolved_field(1, &string_class, field.clone())?;
+        let (resolved_class, resolved_field) = class.resolved_field(1)?.expect("resolved field");
+        assert!(Arc::ptr_eq(&string_class, &resolved_class));
+        assert!(Arc::ptr_eq(&field, &resolved_field));
+        assert!(class.resolved_field(2)?.is_none());
+        assert_eq!(1, class.resolution_count());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_resolved_method() -> Result<()> {
+        let class = object_class().await?;
+        assert!(class.resolved_method(1)?.is_none());
+
+        let string_class = string_class().await?;
+        let method = string_class.try_get_method("isEmpty", "()Z")?;
+        class.set_resolved_method(1, &string_class, method.clone())?;
+        let (resolved_class, resolved_method) = class.resolved_method(1)?.expect("resolved method");
+        assert!(Arc::ptr_eq(&string_class, &resolved_class));
+        assert!(Arc::ptr_eq(&method, &resolved_method));
+        assert!(class.resolved_method(2)?.is_none());
+        assert_eq!(1, class.resolution_count());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_resolved_class_dropped() -> Result<()> {
+        let class = object_class().await?;
+        let resolved_class = Arc::new(Class::new_named("Foo")?);
+        class.set_resolved_class(1, &resolved_class)?;
+        drop(resolved_class);
+        assert!(class.resolved_class(1)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_initialization_state() -> Result<()> {
+        let class = Class::new_named("Foo")?;
+        assert_eq!(
+            InitializationState::NotInitialized,
+            class.initialization_state()?
+        );
+        class.set_initialization_state(InitializationState::BeingInitialized(1))?;
+        assert_eq!(
+            InitializationState::BeingInitialized(1),
+            class.initialization_state()?
+        );
+        class.set_initialization_state(InitializationState::Initialized)?;
+        assert_eq!(
+            InitializationState::Initialized,
+            class.initialization_state()?
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_object_is_assignable_from_object() -> Result<()> {
         let object_class = object_class().await?;
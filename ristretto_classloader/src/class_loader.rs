@@ -1,9 +1,19 @@
-use crate::Error::ClassNotFound;
+use crate::Error::{ClassFileError, ClassNotFound, PoisonedLock};
 use crate::{Class, ClassPath, Result};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Display;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
+
+/// The order in which a class loader and its parents are searched when loading a class.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Delegation {
+    /// Search the parent class loaders before this class loader, as the JVM does.
+    #[default]
+    ParentFirst,
+    /// Search this class loader before its parent class loaders (also known as parent-last).
+    ChildFirst,
+}
 
 /// Implementation of a Java class loader.
 ///
@@ -13,7 +23,11 @@ pub struct ClassLoader {
     name: String,
     class_path: ClassPath,
     parent: Arc<Option<ClassLoader>>,
+    delegation: Delegation,
     classes: Arc<RwLock<HashMap<String, Arc<Class>>>>,
+    /// A lock for each class that is being loaded, so that concurrent loads of a class read and
+    /// parse the class file once.
+    loading: Arc<std::sync::Mutex<HashMap<String, Arc<Mutex<()>>>>>,
 }
 
 impl ClassLoader {
@@ -23,7 +37,9 @@ impl ClassLoader {
             name: name.as_ref().to_string(),
             class_path,
             parent: Arc::new(None),
+            delegation: Delegation::default(),
             classes: Arc::new(RwLock::new(HashMap::new())),
+            loading: Arc::new(std::sync::Mutex::new(HashMap::new())),
         }
     }
 
@@ -53,6 +69,17 @@ impl ClassLoader {
         self.parent = Arc::new(parent);
     }
 
+    /// Get the delegation strategy.
+    #[must_use]
+    pub fn delegation(&self) -> Delegation {
+        self.delegation
+    }
+
+    /// Set the delegation strategy.
+    pub fn set_delegation(&mut self, delegation: Delegation) {
+        self.delegation = delegation;
+    }
+
     /// Load a class by name.
     ///
     /// # Errors
@@ -62,6 +89,8 @@ impl ClassLoader {
     }
 
     /// Load a class by name with a boolean status indicating if the class was loaded previously.
+    /// Concurrent loads of the same class are serialized so that the class file is read and
+    /// parsed once and every load returns the same class.
     ///
     /// # Errors
     /// if the class file cannot be read.
@@ -70,37 +99,101 @@ impl ClassLoader {
         class_name: S,
     ) -> Result<(Arc<Class>, bool)> {
         let class_name = class_name.as_ref();
+        if let Some(class) = self.loaded_class(class_name).await {
+            return Ok((class, true));
+        }
+
+        let loading_lock = {
+            let mut loading = self
+                .loading
+                .lock()
+                .map_err(|error| PoisonedLock(error.to_string()))?;
+            Arc::clone(loading.entry(class_name.to_string()).or_default())
+        };
+        let result = {
+            let _loading_guard = loading_lock.lock().await;
+            self.load_class_file(class_name).await
+        };
+        // Waiting loads hold the lock and find the class once it is released; loads that start
+        // after the lock is removed find the class without waiting.
+        let mut loading = self
+            .loading
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        if loading
+            .get(class_name)
+            .is_some_and(|lock| Arc::ptr_eq(lock, &loading_lock))
         {
-            let classes = self.classes.read().await;
+            loading.remove(class_name);
+        }
+        result
+    }
+
+    /// Get a class that has already been loaded by this class loader.
+    async fn loaded_class(&self, class_name: &str) -> Option<Arc<Class>> {
+        let classes = self.classes.read().await;
+        classes.get(class_name).cloned()
+    }
+
+    /// Read, parse and register a class that has not been loaded, unless it was loaded while
+    /// waiting for the loading lock of the class.
+    async fn load_class_file(&self, class_name: &str) -> Result<(Arc<Class>, bool)> {
+        if let Some(class) = self.loaded_class(class_name).await {
+            return Ok((class, true));
+        }
+
+        for class_loader in self.search_order() {
+            let class_path = class_loader.class_path();
+            let class_file = match class_path.read_class(class_name).await {
+                Ok(class_file) => class_file,
+                Err(error @ ClassFileError(_)) => return Err(error),
+                Err(_) => continue,
+            };
+            let mut classes = self.classes.write().await;
+            // A class may be registered while the class file is read
             if let Some(class) = classes.get(class_name) {
-                return Ok((Arc::clone(class), true));
+                return Ok((class.clone(), true));
             }
+            let class = Arc::new(Class::from(class_file)?);
+            classes.insert(class_name.to_string(), class.clone());
+            return Ok((class, false));
         }
 
-        // Convert hierarchy of class loaders to a flat list so that we can iterate over them from
-        // the boot class loader to the current class loader.
+        Err(ClassNotFound(class_name.to_string()))
+    }
+
+    /// Convert the hierarchy of class loaders to a flat list in the order they are searched for a
+    /// class.  Starting from the boot class loader, each class loader is searched after its
+    /// parents when it delegates parent first and before them when it delegates child first.
+    fn search_order(&self) -> VecDeque<&ClassLoader> {
         let mut class_loader = self;
-        let mut class_loaders = vec![class_loader];
+        let mut hierarchy = vec![class_loader];
         while let Some(parent) = class_loader.parent() {
             class_loader = parent;
-            class_loaders.push(parent);
+            hierarchy.push(parent);
         }
 
-        for class_loader in class_loaders.into_iter().rev() {
-            let class_path = class_loader.class_path();
-            if let Ok(class_file) = class_path.read_class(class_name).await {
-                let mut classes = self.classes.write().await;
-                // Check if the class was loaded while waiting for the lock.
-                if let Some(class) = classes.get(class_name) {
-                    return Ok((class.clone(), true));
-                }
-                let class = Arc::new(Class::from(class_file)?);
-                classes.insert(class_name.to_string(), class.clone());
-                return Ok((class, false));
+        let mut class_loaders = VecDeque::with_capacity(hierarchy.len());
+        for class_loader in hierarchy.into_iter().rev() {
+            match class_loader.delegation {
+                Delegation::ParentFirst => class_loaders.push_back(class_loader),
+                Delegation::ChildFirst => class_loaders.push_front(class_loader),
             }
         }
+        class_loaders
+    }
 
-        Err(ClassNotFound(class_name.to_string()))
+    /// Determine if a class has already been loaded by this class loader.
+    pub async fn is_loaded<S: AsRef<str>>(&self, class_name: S) -> bool {
+        let classes = self.classes.read().await;
+        classes.contains_key(class_name.as_ref())
+    }
+
+    /// Get the classes that have been loaded by this class loader; classes that have only been
+    /// loaded by a parent class loader are not included.
+    pub async fn classes(&self) -> Vec<Arc<Class>> {
+        let classes = self.classes.read().await;
+        classes.values().cloned().collect()
     }
 
     /// Register a class with the class loader.
@@ -122,7 +215,9 @@ impl Clone for ClassLoader {
             name: self.name.clone(),
             class_path: self.class_path.clone(),
             parent: Arc::clone(&self.parent),
+            delegation: self.delegation,
             classes: Arc::clone(&self.classes),
+            loading: Arc::clone(&self.loading),
         }
     }
 }
@@ -153,7 +248,47 @@ impl PartialEq for ClassLoader {
 mod tests {
     use super::*;
     use crate::Value;
+    use ristretto_classfile::{ClassFile, Version};
+    use std::io::Cursor;
     use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tracing::instrument::WithSubscriber;
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// A subscriber that counts the class path reads, which are traced with a `read_class` span.
+    #[derive(Debug, Default)]
+    struct ClassPathReads {
+        reads: AtomicUsize,
+        next_id: AtomicUsize,
+    }
+
+    impl Subscriber for ClassPathReads {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let metadata = span.metadata();
+            if metadata.target() == "ristretto_classloader::class_path"
+                && metadata.name() == "read_class"
+            {
+                self.reads.fetch_add(1, Ordering::SeqCst);
+            }
+            let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+            Id::from_u64(id as u64)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
 
     #[test]
     fn test_new() {
@@ -163,6 +298,15 @@ mod tests {
         assert_eq!(name, class_loader.name());
         assert_eq!(&ClassPath::from("."), class_loader.class_path());
         assert!(class_loader.parent().is_none());
+        assert_eq!(Delegation::ParentFirst, class_loader.delegation());
+    }
+
+    #[test]
+    fn test_set_delegation() {
+        let mut class_loader = ClassLoader::new("test", ClassPath::from("."));
+        class_loader.set_delegation(Delegation::ChildFirst);
+        assert_eq!(Delegation::ChildFirst, class_loader.delegation());
+        assert_eq!(Delegation::ChildFirst, class_loader.clone().delegation());
     }
 
     #[test]
@@ -201,11 +345,14 @@ mod tests {
         let classes_directory = cargo_manifest.join("../classes");
         let class_path_entries = [classes_directory.to_string_lossy().to_string()];
 
-        let class_path = ClassPath::from(class_path_entries.join(":"));
+        let class_path =
+            ClassPath::from(class_path_entries.join(&ClassPath::SEPARATOR.to_string()));
         let class_loader = ClassLoader::new("test", class_path);
         let class_name = "HelloWorld";
+        assert!(!class_loader.is_loaded(class_name).await);
         let class = class_loader.load(class_name).await?;
         assert_eq!(class_name, class.name());
+        assert!(class_loader.is_loaded(class_name).await);
 
         // Load the same class again to test caching
         let class = class_loader.load(class_name).await?;
@@ -213,13 +360,47 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_load_class_concurrently() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("../classes");
+        let class_path = ClassPath::from(classes_directory.to_string_lossy());
+        let class_loader = ClassLoader::new("test", class_path);
+
+        let class_path_reads = Arc::new(ClassPathReads::default());
+
+        let mut tasks = Vec::new();
+        for _ in 0..32 {
+            let class_loader = class_loader.clone();
+            let load = async move { class_loader.load_with_status("HelloWorld").await };
+            tasks.push(tokio::spawn(
+                load.with_subscriber(Arc::clone(&class_path_reads)),
+            ));
+        }
+        let mut classes = Vec::new();
+        for task in tasks {
+            let result = task.await.expect("task");
+            classes.push(result?);
+        }
+
+        assert_eq!(1, class_path_reads.reads.load(Ordering::SeqCst));
+        assert_eq!(1, classes.iter().filter(|(_, loaded)| !loaded).count());
+        let (class, _) = &classes[0];
+        assert!(classes
+            .iter()
+            .all(|(other_class, _)| Arc::ptr_eq(class, other_class)));
+        assert!(class_loader.loading.lock().expect("loading").is_empty());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_load_class_more_than_once() -> Result<()> {
         let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         let classes_directory = cargo_manifest.join("../classes");
         let class_path_entries = [classes_directory.to_string_lossy().to_string()];
 
-        let class_path = ClassPath::from(class_path_entries.join(":"));
+        let class_path =
+            ClassPath::from(class_path_entries.join(&ClassPath::SEPARATOR.to_string()));
         let class_loader = ClassLoader::new("test", class_path);
         let class_name = "Simple";
 
@@ -242,7 +423,8 @@ mod tests {
         let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         let classes_directory = cargo_manifest.join("../classes");
         let class_path_entries = [classes_directory.to_string_lossy().to_string()];
-        let class_path = ClassPath::from(class_path_entries.join(":"));
+        let class_path =
+            ClassPath::from(class_path_entries.join(&ClassPath::SEPARATOR.to_string()));
         let boot_class_loader = ClassLoader::new("test", class_path);
         let foo_class_path = ClassPath::from("foo");
         let mut class_loader = ClassLoader::new("test", foo_class_path);
@@ -253,6 +435,74 @@ mod tests {
         Ok(())
     }
 
+    /// Create a child class loader with a parent where both contain a `HelloWorld` class; the
+    /// child's class has a different class file version so that the two can be told apart.
+    fn delegation_class_loader(delegation: Delegation) -> Result<(tempfile::TempDir, ClassLoader)> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("../classes");
+        let parent = ClassLoader::new(
+            "parent",
+            ClassPath::from(classes_directory.to_string_lossy()),
+        );
+
+        let directory = tempfile::tempdir()?;
+        let class_bytes = include_bytes!("../../classes/HelloWorld.class").to_vec();
+        let mut class_file = ClassFile::from_bytes(&mut Cursor::new(class_bytes))?;
+        class_file.version = Version::Java21 { minor: 0 };
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+        std::fs::write(directory.path().join("HelloWorld.class"), bytes)?;
+
+        let mut class_loader =
+            ClassLoader::new("child", ClassPath::from(directory.path().to_string_lossy()));
+        class_loader.set_parent(Some(parent));
+        class_loader.set_delegation(delegation);
+        Ok((directory, class_loader))
+    }
+
+    #[tokio::test]
+    async fn test_load_class_parent_first() -> Result<()> {
+        let (_directory, class_loader) = delegation_class_loader(Delegation::ParentFirst)?;
+        let class = class_loader.load("HelloWorld").await?;
+        assert_ne!(Version::Java21 { minor: 0 }, class.class_file().version);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_load_class_child_first() -> Result<()> {
+        let (_directory, class_loader) = delegation_class_loader(Delegation::ChildFirst)?;
+        let class = class_loader.load("HelloWorld").await?;
+        assert_eq!(Version::Java21 { minor: 0 }, class.class_file().version);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_load_class_child_first_from_parent() -> Result<()> {
+        let (_directory, class_loader) = delegation_class_loader(Delegation::ChildFirst)?;
+        let class = class_loader.load("Simple").await?;
+        assert_eq!("Simple", class.name());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_classes() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("../classes");
+        let class_path = ClassPath::from(classes_directory.to_string_lossy());
+        let class_loader = ClassLoader::new("test", class_path);
+        assert!(class_loader.classes().await.is_empty());
+
+        let _class = class_loader.load("HelloWorld").await?;
+        let class_names = class_loader
+            .classes()
+            .await
+            .iter()
+            .map(|class| class.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["HelloWorld".to_string()], class_names);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_load_class_not_found() {
         let class_path = ClassPath::from(".");
@@ -261,6 +511,16 @@ mod tests {
         assert!(matches!(result, Err(ClassNotFound(_))));
     }
 
+    #[cfg(feature = "memory")]
+    #[tokio::test]
+    async fn test_load_class_invalid_class_file() {
+        let mut class_path = ClassPath::new(Vec::new());
+        class_path.add_memory_class("Foo", vec![0xCA, 0xFE, 0xBA, 0xBE, 0x00]);
+        let class_loader = ClassLoader::new("test", class_path);
+        let result = class_loader.load("Foo").await;
+        assert!(matches!(result, Err(ClassFileError(_))));
+    }
+
     #[test]
     fn test_to_string() {
         let class_path = ClassPath::from(".");
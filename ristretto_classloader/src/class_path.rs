@@ -1,10 +1,40 @@
 use crate::class_path_entry::ClassPathEntry;
 use crate::Error::ClassNotFound;
-use crate::Result;
+use crate::{Error, Result};
 use ristretto_classfile::ClassFile;
+#[cfg(feature = "memory")]
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt::Display;
+use std::fs;
+#[cfg(feature = "url")]
+use std::path::Path;
+use std::path::PathBuf;
+#[cfg(feature = "memory")]
+use std::sync::Arc;
 use tracing::{info, instrument};
 
+/// How errors reading class files are handled when reading all the classes in a class path.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseErrorPolicy {
+    /// Stop at the first class that cannot be read and return its error.
+    #[default]
+    FailFast,
+    /// Skip classes that cannot be read and report them alongside the classes that were read.
+    SkipAndCollect,
+}
+
+/// The classes read from a class path with [`ClassPath::read_classes`].
+#[derive(Debug, Default)]
+pub struct ClassFiles {
+    /// The classes that were read, in class path order.
+    pub classes: Vec<ClassFile>,
+    /// The name and error of each class that could not be read; always empty with
+    /// [`ParseErrorPolicy::FailFast`].  If the classes of a class path entry cannot be listed
+    /// (e.g. a corrupt jar), the name is the name of the entry.
+    pub errors: Vec<(String, Error)>,
+}
+
 /// Represents a class path.
 ///
 /// The class path is a list of directories and JAR files that contain class files.
@@ -15,53 +45,217 @@ pub struct ClassPath {
 
 /// Implementation for `ClassPath`.
 impl ClassPath {
+    /// The separator between class path entries on the platform; `;` on Windows and `:` elsewhere.
+    #[cfg(target_os = "windows")]
+    pub const SEPARATOR: char = ';';
+    /// The separator between class path entries on the platform; `;` on Windows and `:` elsewhere.
+    #[cfg(not(target_os = "windows"))]
+    pub const SEPARATOR: char = ':';
+
     /// Creates a new `ClassPath` with the given class path.
     #[must_use]
     pub fn new(class_path: Vec<ClassPathEntry>) -> Self {
         ClassPath { class_path }
     }
 
-    /// Creates a new `ClassPath` from a string.
-    ///
-    /// # Errors
-    /// if the class path is not valid.
+    /// Creates a new `ClassPath` from a string of entries separated by the platform
+    /// [separator](Self::SEPARATOR).
     pub fn from<S: AsRef<str>>(class_path: S) -> Self {
+        Self::from_with_separator(class_path, Self::SEPARATOR)
+    }
+
+    /// Creates a new `ClassPath` from a string of entries separated by the given separator.  An
+    /// entry, or part of an entry, enclosed in double quotes may contain the separator; the quotes
+    /// are not part of the entry.  As with `java`, an empty entry refers to the current directory
+    /// (`.`), and an entry identical to an earlier entry is ignored.  A `:` separator is not a
+    /// separator after a Windows drive letter (e.g. `C:\classes` or `C:/classes`).
+    pub fn from_with_separator<S: AsRef<str>>(class_path: S, separator: char) -> Self {
         let class_path = class_path.as_ref();
-        let class_paths = class_path.split(':').collect::<Vec<_>>();
+        let mut class_paths = Vec::new();
+        let mut path = String::new();
+        let mut quoted = false;
+        let mut characters = class_path.chars().peekable();
+        while let Some(character) = characters.next() {
+            if character == '"' {
+                quoted = !quoted;
+            } else if character == ':' && Self::is_drive_letter(&path, characters.peek()) {
+                path.push(character);
+            } else if character == separator && !quoted {
+                class_paths.push(std::mem::take(&mut path));
+            } else {
+                path.push(character);
+            }
+        }
+        class_paths.push(path);
+
+        let mut paths = HashSet::new();
         let mut class_path_entries = Vec::with_capacity(class_paths.len());
         for path in class_paths {
+            let path = if path.is_empty() {
+                ".".to_string()
+            } else {
+                path
+            };
             #[cfg(feature = "url")]
             let path = path
                 .replace("http//", "http://")
                 .replace("https//", "https://");
-            class_path_entries.push(ClassPathEntry::new(path));
+            if paths.insert(path.clone()) {
+                class_path_entries.push(ClassPathEntry::new(path));
+            }
         }
         ClassPath::new(class_path_entries)
     }
 
-    /// Returns an iterator over the class path.
+    /// Returns true if the path preceding a `:` is a Windows drive letter; i.e. a single letter
+    /// followed by `:\` or `:/`.
+    fn is_drive_letter(path: &str, next: Option<&char>) -> bool {
+        let mut characters = path.chars();
+        let is_letter = characters
+            .next()
+            .is_some_and(|character| character.is_ascii_alphabetic());
+        is_letter && characters.next().is_none() && matches!(next, Some('\\' | '/'))
+    }
+
+    /// Returns an iterator over the class path.  Entries are returned in the order they were added
+    /// to the class path, which is the order in which classes are resolved.
     pub fn iter(&self) -> impl Iterator<Item = &ClassPathEntry> {
         self.class_path.iter()
     }
 
-    /// Read a class from the class path.
+    /// Returns the number of entries in the class path.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.class_path.len()
+    }
+
+    /// Returns true if the class path contains no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.class_path.is_empty()
+    }
+
+    /// Removes entries that refer to the same location as an earlier entry, keeping the first
+    /// occurrence so that the class resolution order is unchanged.  Entries are compared by their
+    /// canonicalized path, or by name if the path cannot be canonicalized (e.g. a URL).
+    pub fn dedup(&mut self) {
+        let mut locations = HashSet::new();
+        self.class_path.retain(|entry| {
+            let name = entry.name();
+            let location = fs::canonicalize(name).unwrap_or_else(|_| PathBuf::from(name));
+            locations.insert(location)
+        });
+    }
+
+    /// Add the class file bytes of a class (e.g. "java/lang/Object" or "java.lang.Object") to the
+    /// first in-memory entry of the class path, appending a new in-memory entry named `memory` if
+    /// there is none.
+    #[cfg(feature = "memory")]
+    pub fn add_memory_class<S: AsRef<str>, B: Into<Arc<Vec<u8>>>>(&mut self, name: S, bytes: B) {
+        let memory = self.class_path.iter_mut().find_map(|entry| match entry {
+            ClassPathEntry::Memory(memory) => Some(memory),
+            _ => None,
+        });
+        if let Some(memory) = memory {
+            memory.add_class(name, bytes);
+            return;
+        }
+        let mut entry = ClassPathEntry::from_memory("memory", HashMap::new());
+        if let ClassPathEntry::Memory(memory) = &mut entry {
+            memory.add_class(name, bytes);
+        }
+        self.class_path.push(entry);
+    }
+
+    /// Returns the entries for remote jars, in class path order.
+    #[must_use]
+    pub fn to_url_entries(&self) -> Vec<ClassPathEntry> {
+        self.iter()
+            .filter(|entry| entry.is_url())
+            .cloned()
+            .collect()
+    }
+
+    /// Download all remote jars in the class path to the cache directory, so that their classes
+    /// are read from disk; see [`ClassPathEntry::prefetch`].  Returns the number of remote jars
+    /// that are read from the cache; a jar that cannot be fetched is fetched when a class is first
+    /// read from it instead.
     ///
     /// # Errors
-    /// if the class file is not found or cannot be read.
+    /// if a jar cannot be written to the cache directory.
+    #[cfg(feature = "url")]
+    pub async fn prefetch_all<P: AsRef<Path>>(&self, cache_dir: P) -> Result<usize> {
+        let cache_dir = cache_dir.as_ref();
+        let mut cached = 0;
+        for entry in self.to_url_entries() {
+            if entry.prefetch(cache_dir).await? {
+                cached += 1;
+            }
+        }
+        Ok(cached)
+    }
+
+    /// Read a class from the class path.  A class that is found but is not a valid class file is
+    /// not searched for in the later entries.
+    ///
+    /// # Errors
+    /// if the class file is not found or cannot be read; [`Error::ClassFileError`] if the class
+    /// file is structurally invalid (e.g. truncated).
     #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
     pub async fn read_class<S: AsRef<str>>(&self, name: S) -> Result<ClassFile> {
         let name = name.as_ref();
 
         for class_path_entry in self.iter() {
-            if let Ok(class_file) = class_path_entry.read_class(name).await {
-                info!("load class {name} source: {}", class_path_entry.name());
-                return Ok(class_file);
+            match class_path_entry.read_class(name).await {
+                Ok(class_file) => {
+                    info!("load class {name} source: {}", class_path_entry.name());
+                    return Ok(class_file);
+                }
+                Err(error @ Error::ClassFileError(_)) => return Err(error),
+                Err(_) => {}
             }
         }
 
         Err(ClassNotFound(name.to_string()))
     }
 
+    /// Read all the classes in the class path.  A class that is found in more than one entry is
+    /// only read from the first entry, which is the entry it is resolved from.  Classes that cannot
+    /// be read are handled according to the policy.
+    ///
+    /// # Errors
+    /// if a class or the classes of an entry cannot be read and the policy is
+    /// [`ParseErrorPolicy::FailFast`].
+    pub async fn read_classes(&self, policy: ParseErrorPolicy) -> Result<ClassFiles> {
+        let mut class_files = ClassFiles::default();
+        let mut read_class_names = HashSet::new();
+        for class_path_entry in self.iter() {
+            let class_names = match class_path_entry.class_names().await {
+                Ok(class_names) => class_names,
+                Err(error) if policy == ParseErrorPolicy::SkipAndCollect => {
+                    let name = class_path_entry.name().clone();
+                    class_files.errors.push((name, error));
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
+
+            for class_name in class_names {
+                if !read_class_names.insert(class_name.clone()) {
+                    continue;
+                }
+                match class_path_entry.read_class(&class_name).await {
+                    Ok(class_file) => class_files.classes.push(class_file),
+                    Err(error) if policy == ParseErrorPolicy::SkipAndCollect => {
+                        class_files.errors.push((class_name, error));
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+        Ok(class_files)
+    }
+
     /// Get the class names in the class path.
     ///
     /// # Errors
@@ -92,9 +286,16 @@ impl Display for ClassPath {
         let class_path = self
             .class_path
             .iter()
-            .map(|entry| entry.name().to_string())
+            .map(|entry| {
+                let name = entry.name();
+                if name.contains(Self::SEPARATOR) {
+                    format!("\"{name}\"")
+                } else {
+                    name.clone()
+                }
+            })
             .collect::<Vec<_>>()
-            .join(":");
+            .join(&Self::SEPARATOR.to_string());
         write!(f, "{class_path}")
     }
 }
@@ -105,29 +306,252 @@ mod tests {
     use crate::Result;
     use std::path::PathBuf;
 
+    #[cfg(feature = "memory")]
+    #[tokio::test]
+    async fn test_add_memory_class() -> Result<()> {
+        let mut constant_pool = ristretto_classfile::ConstantPool::default();
+        let this_class = constant_pool.add_class("com/example/Generated")?;
+        let super_class = constant_pool.add_class("java/lang/Object")?;
+        let class_file = ClassFile {
+            version: ristretto_classfile::Version::Java21 { minor: 0 },
+            constant_pool,
+            this_class,
+            super_class,
+            ..Default::default()
+        };
+        let mut bytes = Vec::new();
+        class_file.to_bytes(&mut bytes)?;
+
+        let mut class_path = ClassPath::new(Vec::new());
+        class_path.add_memory_class("com.example.Generated", bytes);
+        class_path.add_memory_class("Empty", Vec::new());
+        assert_eq!(1, class_path.len());
+        assert_eq!("memory", class_path.to_string());
+        assert_eq!(
+            vec!["Empty".to_string(), "com/example/Generated".to_string()],
+            class_path.class_names().await?
+        );
+        let read_class_file = class_path.read_class("com/example/Generated").await?;
+        assert_eq!(class_file, read_class_file);
+        Ok(())
+    }
+
+    #[cfg(feature = "memory")]
+    #[tokio::test]
+    async fn test_read_class_invalid_class_file() -> Result<()> {
+        // A truncated class file is not searched for in the later entries
+        let bytes = include_bytes!("../../classes/HelloWorld.class")[..16].to_vec();
+        let classes = HashMap::from([("HelloWorld".to_string(), bytes)]);
+        let class_path = ClassPath::new(vec![
+            ClassPathEntry::from_memory("memory", classes),
+            ClassPathEntry::new("../classes"),
+        ]);
+        let result = class_path.read_class("HelloWorld").await;
+        assert!(matches!(result, Err(Error::ClassFileError(_))));
+        Ok(())
+    }
+
+    /// Join the entries with the platform separator.
+    fn join(entries: &[&str]) -> String {
+        entries.join(&ClassPath::SEPARATOR.to_string())
+    }
+
     #[test]
     fn test_new() {
         let class_path = ClassPath::new(vec![ClassPathEntry::new("."), ClassPathEntry::new("..")]);
-        assert_eq!(".:..", class_path.to_string());
+        assert_eq!(join(&[".", ".."]), class_path.to_string());
     }
 
     #[test]
     fn test_from() {
-        let class_path = ClassPath::from(".:..");
-        assert_eq!(".:..", class_path.to_string());
+        let class_path = ClassPath::from(join(&[".", ".."]));
+        assert_eq!(join(&[".", ".."]), class_path.to_string());
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn test_from_platform_separator() {
+        let class_path = ClassPath::from(r"C:\classes;lib\foo.jar");
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![r"C:\classes", r"lib\foo.jar"], names);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn test_from_platform_separator() {
+        let class_path = ClassPath::from("/classes:lib/foo.jar");
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["/classes", "lib/foo.jar"], names);
+    }
+
+    #[test]
+    fn test_from_with_separator() {
+        let class_path = ClassPath::from_with_separator("a;b:c;d", ';');
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["a", "b:c", "d"], names);
+    }
+
+    #[test]
+    fn test_from_windows_separator() {
+        let class_path = ClassPath::from_with_separator("a.jar;b.jar", ';');
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["a.jar", "b.jar"], names);
+    }
+
+    #[test]
+    fn test_from_unix_separator() {
+        let class_path = ClassPath::from_with_separator("a.jar:b.jar", ':');
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["a.jar", "b.jar"], names);
+    }
+
+    #[test]
+    fn test_from_drive_letter() {
+        let class_path = ClassPath::from_with_separator(r"C:\classes;D:\lib\foo.jar", ';');
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![r"C:\classes", r"D:\lib\foo.jar"], names);
+
+        let class_path = ClassPath::from_with_separator(r"C:\classes:lib:ab:\foo", ':');
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![r"C:\classes", "lib", "ab", r"\foo"], names);
+
+        let class_path = ClassPath::from_with_separator("C:/classes:D:/lib/foo.jar:lib", ':');
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["C:/classes", "D:/lib/foo.jar", "lib"], names);
+    }
+
+    #[test]
+    fn test_from_quoted_entry() {
+        let class_path = ClassPath::from_with_separator(r#"a:"b:c":d"e:f".jar"#, ':');
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["a", "b:c", "de:f.jar"], names);
+    }
+
+    #[test]
+    fn test_from_empty_entry() {
+        let class_path = ClassPath::from(join(&["a", "", "b"]));
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["a", ".", "b"], names);
+
+        let class_path = ClassPath::from("");
+        assert_eq!(join(&["."]), class_path.to_string());
+    }
+
+    #[test]
+    fn test_from_duplicate_entries() {
+        let class_path = ClassPath::from(join(&["b.jar", "a.jar", "b.jar", "", "c", "a.jar", "."]));
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["b.jar", "a.jar", ".", "c"], names);
+    }
+
+    #[test]
+    fn test_to_string_quotes_entries_with_separator() {
+        let entry = format!("a{}b", ClassPath::SEPARATOR);
+        let class_path =
+            ClassPath::new(vec![ClassPathEntry::new(&entry), ClassPathEntry::new("c")]);
+        let class_path_string = class_path.to_string();
+        assert_eq!(join(&[&format!("\"{entry}\""), "c"]), class_path_string);
+        assert_eq!(class_path, ClassPath::from(class_path_string));
     }
 
     #[test]
     fn test_iter() {
-        let class_path = ClassPath::from(".:..");
+        let class_path = ClassPath::from(join(&[".", ".."]));
         let mut iter = class_path.iter();
         assert_eq!(".", iter.next().expect("next").name());
         assert_eq!("..", iter.next().expect("next").name());
     }
 
+    #[test]
+    fn test_len() {
+        let class_path = ClassPath::from(join(&[".", ".."]));
+        assert_eq!(2, class_path.len());
+        assert!(!class_path.is_empty());
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let class_path = ClassPath::new(Vec::new());
+        assert_eq!(0, class_path.len());
+        assert!(class_path.is_empty());
+    }
+
+    #[test]
+    fn test_iter_preserves_order() {
+        let class_path = ClassPath::from(join(&["c", "a", "b"]));
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["c", "a", "b"], names);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_directory = cargo_manifest.join("../classes");
+        let classes_directory = classes_directory.to_string_lossy();
+        let classes_jar = cargo_manifest.join("../classes/classes.jar");
+        let classes_jar = classes_jar.to_string_lossy();
+        let mut class_path = ClassPath::from(join(&[
+            &classes_jar,
+            "../classes",
+            &classes_directory,
+            &classes_jar,
+            "does-not-exist",
+            "does-not-exist",
+        ]));
+        class_path.dedup();
+        let names = class_path
+            .iter()
+            .map(|entry| entry.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                classes_jar.to_string(),
+                "../classes".to_string(),
+                "does-not-exist".to_string()
+            ],
+            names
+        );
+    }
+
     #[test]
     fn test_into_iter() {
-        let class_path = ClassPath::from(".:..");
+        let class_path = ClassPath::from(join(&[".", ".."]));
         let mut iter = class_path.into_iter();
         assert_eq!(".", iter.next().expect("next").name());
         assert_eq!("..", iter.next().expect("next").name());
@@ -146,7 +570,7 @@ mod tests {
             "https//repo1.maven.org/maven2/org/springframework/boot/spring-boot/3.3.0/spring-boot-3.3.0.jar".to_string(),
         ];
 
-        let class_path = class_path_entries.join(":");
+        let class_path = class_path_entries.join(&ClassPath::SEPARATOR.to_string());
         let class_path_entry = ClassPath::from(&class_path);
 
         let class_file = class_path_entry.read_class("HelloWorld").await?;
@@ -178,7 +602,7 @@ mod tests {
             "https//repo1.maven.org/maven2/org/springframework/boot/spring-boot/3.3.0/spring-boot-3.3.0.jar".to_string(),
         ];
 
-        let class_path = class_path_entries.join(":");
+        let class_path = class_path_entries.join(&ClassPath::SEPARATOR.to_string());
         let class_path_entry = ClassPath::from(&class_path);
 
         let class_names = class_path_entry.class_names().await?;
@@ -190,4 +614,102 @@ mod tests {
         }
         Ok(())
     }
+
+    /// Create a class path directory containing a valid class and a truncated class.
+    fn corrupt_classes_directory() -> Result<tempfile::TempDir> {
+        let directory = tempfile::tempdir()?;
+        let class_bytes = include_bytes!("../../classes/HelloWorld.class");
+        fs::write(directory.path().join("HelloWorld.class"), class_bytes)?;
+        fs::write(directory.path().join("Truncated.class"), &class_bytes[..16])?;
+        Ok(directory)
+    }
+
+    #[tokio::test]
+    async fn test_read_classes_skip_and_collect() -> Result<()> {
+        let directory = corrupt_classes_directory()?;
+        let class_path = ClassPath::from(directory.path().to_string_lossy());
+
+        let class_files = class_path
+            .read_classes(ParseErrorPolicy::SkipAndCollect)
+            .await?;
+        let class_names = class_files
+            .classes
+            .iter()
+            .map(ClassFile::class_name)
+            .collect::<ristretto_classfile::Result<Vec<_>>>()?;
+        assert_eq!(vec!["HelloWorld"], class_names);
+        assert_eq!(1, class_files.errors.len());
+        let (class_name, error) = &class_files.errors[0];
+        assert_eq!("Truncated", class_name);
+        assert!(matches!(error, Error::ClassFileError(_)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_classes_fail_fast() -> Result<()> {
+        let directory = corrupt_classes_directory()?;
+        let class_path = ClassPath::from(directory.path().to_string_lossy());
+
+        let result = class_path.read_classes(ParseErrorPolicy::FailFast).await;
+        assert!(matches!(result, Err(Error::ClassFileError(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_classes_shadowed() -> Result<()> {
+        let directory = corrupt_classes_directory()?;
+        fs::remove_file(directory.path().join("Truncated.class"))?;
+        let directory = directory.path().to_string_lossy();
+        let class_path = ClassPath::new(vec![
+            ClassPathEntry::new(&directory),
+            ClassPathEntry::new(&directory),
+        ]);
+
+        let class_files = class_path.read_classes(ParseErrorPolicy::default()).await?;
+        assert_eq!(1, class_files.classes.len());
+        assert!(class_files.errors.is_empty());
+        Ok(())
+    }
+
+    /// A fetcher that serves the test classes jar.
+    #[cfg(feature = "url")]
+    #[derive(Debug)]
+    struct ClassesJarFetcher;
+
+    #[cfg(feature = "url")]
+    impl crate::UrlFetcher for ClassesJarFetcher {
+        fn fetch<'a>(&'a self, _url: &'a str) -> crate::FetchFuture<'a> {
+            Box::pin(async move {
+                let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+                let bytes = fs::read(cargo_manifest.join("../classes/classes.jar"))?;
+                Ok(bytes)
+            })
+        }
+    }
+
+    #[cfg(feature = "url")]
+    #[tokio::test]
+    async fn test_prefetch_all() -> Result<()> {
+        let cache_dir = tempfile::tempdir()?;
+        let url = "https://example.com/classes.jar";
+        let class_path = ClassPath::new(vec![
+            ClassPathEntry::new("."),
+            ClassPathEntry::from_url_with_fetcher(url, std::sync::Arc::new(ClassesJarFetcher)),
+        ]);
+        let url_entries = class_path.to_url_entries();
+        assert_eq!(1, url_entries.len());
+        assert_eq!(url, url_entries[0].name());
+
+        assert_eq!(1, class_path.prefetch_all(cache_dir.path()).await?);
+        assert_eq!(1, fs::read_dir(cache_dir.path())?.count());
+        let class_file = class_path.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_url_entries_none() {
+        let class_path = ClassPath::from(join(&[".", ".."]));
+        assert!(class_path.to_url_entries().is_empty());
+    }
 }
@@ -52,23 +52,29 @@ impl Directory {
         Ok(class_file)
     }
 
-    /// Get the class names in the directory.
+    /// Get the class names in the directory; the name of a class in a subdirectory is qualified by
+    /// the path of the subdirectory relative to the directory (e.g. `java/lang/Object`).
     ///
     /// # Errors
     /// if the class names cannot be read.
     #[expect(clippy::unused_async)]
     pub async fn class_names(&self) -> Result<Vec<String>> {
-        let path = self.path.clone();
         let mut classes = Vec::new();
-        for entry in WalkDir::new(path)
+        for entry in WalkDir::new(&self.path)
             .follow_links(true)
             .into_iter()
             .filter_map(Result::ok)
         {
-            let file_name = entry.file_name().to_string_lossy();
-            if file_name.ends_with(".class") {
-                let class_name = file_name.replace(".class", "");
-                classes.push(class_name);
+            let Ok(relative_path) = entry.path().strip_prefix(&self.path) else {
+                continue;
+            };
+            let relative_path = relative_path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if let Some(class_name) = relative_path.strip_suffix(".class") {
+                classes.push(class_name.to_string());
             }
         }
         Ok(classes)
@@ -143,6 +149,7 @@ mod tests {
         let directory = Directory::new(classes_directory.to_string_lossy());
         let class_names = directory.class_names().await?;
         assert!(class_names.contains(&"HelloWorld".to_string()));
+        assert!(class_names.contains(&"VirtualCall$Base".to_string()));
         Ok(())
     }
 }
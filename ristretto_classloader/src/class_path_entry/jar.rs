@@ -1,15 +1,15 @@
 use crate::class_path_entry::manifest::Manifest;
+use crate::class_path_entry::url::{self, UrlFetcher};
 use crate::Error::{ArchiveError, ClassNotFound, FileNotFound, ParseError};
 use crate::Result;
-use reqwest::Client;
 use ristretto_classfile::ClassFile;
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::{fs, io};
 use tokio::sync::RwLock;
-use tracing::instrument;
+use tracing::{instrument, warn};
 use zip::ZipArchive;
 
 /// A jar or zip in the class path.
@@ -17,6 +17,7 @@ use zip::ZipArchive;
 #[derive(Debug)]
 pub struct Jar {
     name: String,
+    is_url: bool,
     archive: Arc<RwLock<Archive>>,
 }
 
@@ -29,6 +30,7 @@ impl Jar {
 
         Self {
             name: path.to_string(),
+            is_url: false,
             archive: Arc::new(RwLock::new(archive)),
         }
     }
@@ -36,11 +38,18 @@ impl Jar {
     /// Create new jar from url.
     #[cfg(feature = "url")]
     pub fn from_url<S: AsRef<str>>(url: S) -> Self {
+        Self::from_url_with_fetcher(url, Arc::new(url::HttpFetcher))
+    }
+
+    /// Create new jar from url that is downloaded with the given fetcher.
+    #[cfg(feature = "url")]
+    pub fn from_url_with_fetcher<S: AsRef<str>>(url: S, fetcher: Arc<dyn UrlFetcher>) -> Self {
         let url = url.as_ref();
-        let archive = Archive::from_url(url);
+        let archive = Archive::from_url(url, fetcher);
 
         Self {
             name: url.to_string(),
+            is_url: true,
             archive: Arc::new(RwLock::new(archive)),
         }
     }
@@ -51,6 +60,7 @@ impl Jar {
 
         Self {
             name: name.as_ref().to_string(),
+            is_url: false,
             archive: Arc::new(RwLock::new(archive)),
         }
     }
@@ -60,6 +70,25 @@ impl Jar {
         &self.name
     }
 
+    /// Returns true if the jar is downloaded from a url.
+    pub fn is_url(&self) -> bool {
+        self.is_url
+    }
+
+    /// Download a remote jar to the cache directory, or reuse a copy cached by an earlier
+    /// prefetch of the same url, so that classes are read from disk instead of the network.
+    /// Returns true if the jar is read from the cache; if the jar cannot be fetched, false is
+    /// returned and the jar is fetched when a class is first read instead.  A jar that is not
+    /// remote is never cached.
+    ///
+    /// # Errors
+    /// if the jar cannot be written to the cache directory.
+    #[cfg(feature = "url")]
+    pub async fn prefetch<P: AsRef<Path>>(&self, cache_dir: P) -> Result<bool> {
+        let mut archive = self.archive.write().await;
+        archive.prefetch(cache_dir.as_ref()).await
+    }
+
     /// Get the manifest of the jar.
     ///
     /// # Errors
@@ -104,6 +133,17 @@ impl Jar {
         Ok(class_file)
     }
 
+    /// Get the names of all files in the jar.
+    ///
+    /// # Errors
+    /// if the file names cannot be read.
+    pub(crate) async fn file_names(&self) -> Result<Vec<String>> {
+        let mut archive = self.archive.write().await;
+        let zip_archive = archive.zip_archive().await?;
+        let file_names = zip_archive.file_names().map(ToString::to_string).collect();
+        Ok(file_names)
+    }
+
     /// Get the class names in the jar.
     ///
     /// # Errors
@@ -112,20 +152,16 @@ impl Jar {
         let mut archive = self.archive.write().await;
         let is_module = archive.is_module().await?;
         let zip_archive = archive.zip_archive().await?;
+        // The names are read from the central directory of the archive; no entry is decompressed
         let mut classes = Vec::new();
-        for i in 0..zip_archive.len() {
-            let file = zip_archive.by_index(i)?;
-            let file_name = file.name();
-            if file_name.ends_with("class") {
-                let mut class_name = file_name.replace(".class", "");
-                if is_module {
-                    class_name = class_name
-                        .strip_prefix("classes/")
-                        .unwrap_or_default()
-                        .to_string();
-                }
-                classes.push(class_name);
+        for file_name in zip_archive.file_names() {
+            let Some(mut class_name) = file_name.strip_suffix(".class") else {
+                continue;
+            };
+            if is_module {
+                class_name = class_name.strip_prefix("classes/").unwrap_or_default();
             }
+            classes.push(class_name.to_string());
         }
         Ok(classes)
     }
@@ -139,14 +175,49 @@ impl PartialEq for Jar {
     }
 }
 
+/// A remote archive and the fetcher used to download it.
+#[derive(Debug)]
+struct RemoteArchive {
+    url: String,
+    fetcher: Arc<dyn UrlFetcher>,
+}
+
+/// The reader of an archive.  An archive on disk is read from the file as entries are read; only
+/// the central directory is read when the archive is opened and reading an entry seeks to it and
+/// inflates only that entry, so the archive is never held in memory.  Archives that are
+/// downloaded or created from bytes are held in memory.
+#[derive(Debug)]
+enum ArchiveReader {
+    File(io::BufReader<fs::File>),
+    Memory(io::Cursor<Vec<u8>>),
+}
+
+impl io::Read for ArchiveReader {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        match self {
+            ArchiveReader::File(reader) => reader.read(buffer),
+            ArchiveReader::Memory(reader) => reader.read(buffer),
+        }
+    }
+}
+
+impl io::Seek for ArchiveReader {
+    fn seek(&mut self, position: io::SeekFrom) -> io::Result<u64> {
+        match self {
+            ArchiveReader::File(reader) => reader.seek(position),
+            ArchiveReader::Memory(reader) => reader.seek(position),
+        }
+    }
+}
+
 /// The source of the archive.
 #[expect(clippy::struct_field_names)]
 #[derive(Debug)]
 struct Archive {
     path: Option<PathBuf>,
-    url: Option<String>,
+    url: Option<RemoteArchive>,
     bytes: Option<Arc<Vec<u8>>>,
-    zip_archive: Option<ZipArchive<io::Cursor<Vec<u8>>>>,
+    zip_archive: Option<ZipArchive<ArchiveReader>>,
     is_module: Option<bool>,
 }
 
@@ -165,11 +236,11 @@ impl Archive {
     }
 
     /// Create a new archive source from a url.
-    fn from_url<S: AsRef<str>>(url: S) -> Self {
+    fn from_url<S: AsRef<str>>(url: S, fetcher: Arc<dyn UrlFetcher>) -> Self {
         let url = url.as_ref().to_string();
         Self {
             path: None,
-            url: Some(url),
+            url: Some(RemoteArchive { url, fetcher }),
             bytes: None,
             zip_archive: None,
             is_module: None,
@@ -194,26 +265,24 @@ impl Archive {
     ///
     /// # Errors
     /// if the archive cannot be read.
-    async fn zip_archive(&mut self) -> Result<&mut ZipArchive<io::Cursor<Vec<u8>>>> {
+    async fn zip_archive(&mut self) -> Result<&mut ZipArchive<ArchiveReader>> {
         if let Some(ref mut zip_archive) = self.zip_archive {
             return Ok(zip_archive);
         }
 
         if let Some(path) = &self.path {
-            let bytes = fs::read(path)?;
-            let cursor = io::Cursor::new(bytes);
-            let archive = ZipArchive::new(cursor)?;
+            let reader = ArchiveReader::File(io::BufReader::new(fs::File::open(path)?));
+            let archive = ZipArchive::new(reader)?;
             self.zip_archive = Some(archive);
-        } else if let Some(url) = &self.url {
-            let client = Client::new();
-            let bytes = client.get(url).send().await?.bytes().await?.to_vec();
-            let cursor = io::Cursor::new(bytes);
-            let archive = ZipArchive::new(cursor)?;
+        } else if let Some(RemoteArchive { url, fetcher }) = &self.url {
+            let bytes = fetcher.fetch(url).await?;
+            let reader = ArchiveReader::Memory(io::Cursor::new(bytes));
+            let archive = ZipArchive::new(reader)?;
             self.zip_archive = Some(archive);
         } else if let Some(bytes) = &self.bytes {
             let bytes = bytes.to_vec();
-            let cursor = io::Cursor::new(bytes);
-            let archive = ZipArchive::new(cursor)?;
+            let reader = ArchiveReader::Memory(io::Cursor::new(bytes));
+            let archive = ZipArchive::new(reader)?;
             self.bytes = None;
             self.zip_archive = Some(archive);
         }
@@ -225,6 +294,42 @@ impl Archive {
         }
     }
 
+    /// Download a remote archive to the cache directory, or use a copy cached by an earlier
+    /// prefetch; once cached, the archive is read from the cached copy.  Returns false without
+    /// caching the archive if it is not remote or cannot be fetched.
+    ///
+    /// # Errors
+    /// if the archive cannot be written to the cache directory.
+    async fn prefetch(&mut self, cache_dir: &Path) -> Result<bool> {
+        let Some(RemoteArchive { url, fetcher }) = &self.url else {
+            return Ok(false);
+        };
+        if self.path.is_some() {
+            return Ok(true);
+        }
+
+        let path = if let Some(path) = url::cached_jar(cache_dir, url) {
+            path
+        } else {
+            let bytes = match fetcher.fetch(url).await {
+                Ok(bytes) => bytes,
+                Err(error) => {
+                    warn!("unable to prefetch {url}: {error}");
+                    return Ok(false);
+                }
+            };
+            // Do not cache a response that is not an archive (e.g. an error page)
+            if let Err(error) = ZipArchive::new(io::Cursor::new(&bytes)) {
+                warn!("unable to prefetch {url}: {error}");
+                return Ok(false);
+            }
+            url::cache_jar(cache_dir, url, &bytes)?
+        };
+        self.path = Some(path);
+        self.zip_archive = None;
+        Ok(true)
+    }
+
     /// Load class file from a jar.
     ///
     /// # Errors
@@ -280,6 +385,7 @@ impl Clone for Jar {
     fn clone(&self) -> Self {
         Self {
             name: self.name.clone(),
+            is_url: self.is_url,
             archive: Arc::clone(&self.archive),
         }
     }
@@ -289,10 +395,39 @@ impl Clone for Jar {
 mod tests {
     use super::*;
     use crate::class_path_entry::manifest::{MAIN_CLASS, MANIFEST_VERSION};
+    #[cfg(feature = "url")]
+    use crate::class_path_entry::url::FetchFuture;
     use crate::Error::ClassFileError;
     use std::io::Write;
     use std::path::PathBuf;
+    #[cfg(feature = "url")]
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use zip::write::SimpleFileOptions;
+    use zip::CompressionMethod;
+
+    /// A fetcher that serves the test classes jar and counts the fetches; the first `failures`
+    /// fetches fail.
+    #[cfg(feature = "url")]
+    #[derive(Debug, Default)]
+    struct TestFetcher {
+        fetches: AtomicUsize,
+        failures: usize,
+    }
+
+    #[cfg(feature = "url")]
+    impl UrlFetcher for TestFetcher {
+        fn fetch<'a>(&'a self, url: &'a str) -> FetchFuture<'a> {
+            Box::pin(async move {
+                let fetch = self.fetches.fetch_add(1, Ordering::SeqCst);
+                if fetch < self.failures {
+                    return Err(FileNotFound(url.to_string()));
+                }
+                let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+                let bytes = fs::read(cargo_manifest.join("../classes/classes.jar"))?;
+                Ok(bytes)
+            })
+        }
+    }
 
     #[test]
     fn test_new() {
@@ -414,6 +549,48 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_read_file_streamed() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let class_bytes = fs::read(cargo_manifest.join("../classes/HelloWorld.class"))?;
+
+        // Create a jar with stored and deflated entries and a large entry that is never read
+        let jar_path = temp_dir.path().join("streamed.jar");
+        let mut archive = zip::ZipWriter::new(std::fs::File::create(&jar_path)?);
+        let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        archive.start_file("stored/HelloWorld.class", stored)?;
+        archive.write_all(&class_bytes)?;
+        archive.start_file("HelloWorld.class", deflated)?;
+        archive.write_all(&class_bytes)?;
+        archive.start_file("large.bin", stored)?;
+        archive.write_all(&vec![42u8; 4 * 1024 * 1024])?;
+        for index in 0..100 {
+            archive.start_file(format!("file{index}.txt"), deflated)?;
+            archive.write_all(format!("file {index}").as_bytes())?;
+        }
+        archive.finish()?;
+
+        let jar = Jar::new(jar_path.to_string_lossy());
+        let class_file = jar.read_class("stored/HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        let class_file = jar.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        assert_eq!(
+            Some(b"file 42".to_vec()),
+            jar.read_file("file42.txt").await?
+        );
+        assert_eq!(None, jar.read_file("missing.txt").await?);
+
+        // The archive is read from the file rather than being held in memory
+        let mut archive = jar.archive.write().await;
+        assert_eq!(103, archive.zip_archive().await?.len());
+        let reader = archive.zip_archive.take().map(ZipArchive::into_inner);
+        assert!(matches!(reader, Some(ArchiveReader::File(_))));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_archive_zip_archive_error() {
         let mut archive = Archive {
@@ -458,4 +635,64 @@ mod tests {
         assert!(class_names.contains(&"org/springframework/boot/SpringApplication".to_string()));
         Ok(())
     }
+
+    #[cfg(feature = "url")]
+    #[tokio::test]
+    async fn test_prefetch() -> Result<()> {
+        let url = "https://example.com/classes.jar";
+        let cache_dir = tempfile::tempdir()?;
+        let fetcher = Arc::new(TestFetcher::default());
+        let jar = Jar::from_url_with_fetcher(url, fetcher.clone());
+        assert!(jar.is_url());
+        assert!(jar.prefetch(cache_dir.path()).await?);
+        assert_eq!(1, fetcher.fetches.load(Ordering::SeqCst));
+
+        // Classes are read from the cached jar without fetching the jar again
+        for _ in 0..2 {
+            let class_file = jar.read_class("HelloWorld").await?;
+            assert_eq!("HelloWorld", class_file.class_name()?);
+        }
+        assert_eq!(1, fetcher.fetches.load(Ordering::SeqCst));
+
+        // A jar for the same url reuses the cached copy
+        let fetcher = Arc::new(TestFetcher::default());
+        let jar = Jar::from_url_with_fetcher(url, fetcher.clone());
+        assert!(jar.prefetch(cache_dir.path()).await?);
+        let class_file = jar.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        assert_eq!(0, fetcher.fetches.load(Ordering::SeqCst));
+        Ok(())
+    }
+
+    #[cfg(feature = "url")]
+    #[tokio::test]
+    async fn test_prefetch_fetch_failure() -> Result<()> {
+        let cache_dir = tempfile::tempdir()?;
+        let fetcher = Arc::new(TestFetcher {
+            failures: 1,
+            ..Default::default()
+        });
+        let jar = Jar::from_url_with_fetcher("https://example.com/classes.jar", fetcher.clone());
+        assert!(!jar.prefetch(cache_dir.path()).await?);
+        assert_eq!(0, fs::read_dir(cache_dir.path())?.count());
+
+        // The jar is fetched when a class is first read instead
+        let class_file = jar.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        assert_eq!(2, fetcher.fetches.load(Ordering::SeqCst));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_not_url() -> Result<()> {
+        let cache_dir = tempfile::tempdir()?;
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar = cargo_manifest.join("../classes/classes.jar");
+        let jar = Jar::new(classes_jar.to_string_lossy());
+        assert!(!jar.is_url());
+        let mut archive = jar.archive.write().await;
+        assert!(!archive.prefetch(cache_dir.path()).await?);
+        assert_eq!(0, fs::read_dir(cache_dir.path())?.count());
+        Ok(())
+    }
 }
@@ -0,0 +1,151 @@
+use crate::class_path_entry::jar::Jar;
+use crate::Error::ClassNotFound;
+use crate::Result;
+use ristretto_classfile::ClassFile;
+use std::fmt::Debug;
+use std::io;
+use tracing::instrument;
+
+/// The directory in a jmod that contains the class files of the module.
+const CLASSES_PREFIX: &str = "classes/";
+
+/// A jmod in the class path.
+///
+/// A jmod is a zip archive (preceded by a short `JM` header) that packages a module of the JDK
+/// (e.g. `jmods/java.base.jmod`).  Class files are stored under the `classes/` directory of the
+/// archive alongside other module content (e.g. `bin/`, `conf/` and `lib/`), so the `classes/`
+/// prefix is added when reading a class and removed when listing class names; `java/lang/Object`
+/// is read from `classes/java/lang/Object.class`.
+///
+/// See: <https://openjdk.org/jeps/261#Packaging:-JMOD-files>
+#[derive(Clone, Debug, PartialEq)]
+pub struct Jmod {
+    jar: Jar,
+}
+
+/// Implement the `Jmod` struct.
+impl Jmod {
+    /// Create new jmod from a path.
+    pub fn new<S: AsRef<str>>(path: S) -> Self {
+        Self {
+            jar: Jar::new(path),
+        }
+    }
+
+    /// Create new jmod from bytes.
+    pub fn from_bytes<S: AsRef<str>>(name: S, bytes: Vec<u8>) -> Self {
+        Self {
+            jar: Jar::from_bytes(name, bytes),
+        }
+    }
+
+    /// Get the name of the jmod.
+    pub fn name(&self) -> &String {
+        self.jar.name()
+    }
+
+    /// Read a class from the jmod.
+    ///
+    /// # Errors
+    /// if the class file is not found or cannot be read.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub async fn read_class<S: AsRef<str>>(&self, name: S) -> Result<ClassFile> {
+        let name = name.as_ref();
+        let file_name = format!("{CLASSES_PREFIX}{name}.class");
+        let Some(bytes) = self.jar.read_file(file_name).await? else {
+            return Err(ClassNotFound(name.to_string()));
+        };
+        let mut cursor = io::Cursor::new(bytes);
+        let class_file = ClassFile::from_bytes(&mut cursor)?;
+        class_file.verify()?;
+        Ok(class_file)
+    }
+
+    /// Get the class names in the jmod.
+    ///
+    /// # Errors
+    /// if the class names cannot be read.
+    pub async fn class_names(&self) -> Result<Vec<String>> {
+        let class_names = self
+            .jar
+            .file_names()
+            .await?
+            .iter()
+            .filter_map(|file_name| {
+                let class_name = file_name.strip_prefix(CLASSES_PREFIX)?;
+                let class_name = class_name.strip_suffix(".class")?;
+                Some(class_name.to_string())
+            })
+            .collect();
+        Ok(class_names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    /// Create a jmod containing the `HelloWorld` class.
+    fn jmod_bytes() -> Result<Vec<u8>> {
+        let mut zip = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        zip.start_file("classes/HelloWorld.class", SimpleFileOptions::default())?;
+        zip.write_all(include_bytes!("../../../classes/HelloWorld.class"))?;
+        zip.start_file("conf/test.properties", SimpleFileOptions::default())?;
+        zip.write_all(b"foo=bar")?;
+        let zip_bytes = zip.finish()?.into_inner();
+
+        let mut bytes = b"JM\x01\x00".to_vec();
+        bytes.extend(zip_bytes);
+        Ok(bytes)
+    }
+
+    #[test]
+    fn test_new() {
+        let jmod = Jmod::new("java.base.jmod");
+        assert_eq!("java.base.jmod", jmod.name());
+    }
+
+    #[test]
+    fn test_equality() {
+        let jmod1 = Jmod::new("java.base.jmod");
+        let jmod2 = Jmod::new("java.base.jmod");
+        assert_eq!(jmod1, jmod2);
+    }
+
+    #[tokio::test]
+    async fn test_read_class() -> Result<()> {
+        let jmod = Jmod::from_bytes("test.jmod", jmod_bytes()?);
+        let class_file = jmod.read_class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_class_invalid_class_name() -> Result<()> {
+        let jmod = Jmod::from_bytes("test.jmod", jmod_bytes()?);
+        let result = jmod.read_class("Foo").await;
+        assert!(matches!(result, Err(ClassNotFound(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_names() -> Result<()> {
+        let jmod = Jmod::from_bytes("test.jmod", jmod_bytes()?);
+        let class_names = jmod.class_names().await?;
+        assert_eq!(vec!["HelloWorld".to_string()], class_names);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_class_runtime() -> Result<()> {
+        let (java_home, _java_version, _class_loader) = runtime::default_class_loader().await?;
+        let path = java_home.join("jmods").join("java.base.jmod");
+        let jmod = Jmod::new(path.to_string_lossy());
+        let class_file = jmod.read_class("java/lang/Object").await?;
+        assert_eq!("java/lang/Object", class_file.class_name()?);
+        Ok(())
+    }
+}
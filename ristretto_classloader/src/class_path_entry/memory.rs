@@ -0,0 +1,165 @@
+use crate::Error::ClassNotFound;
+use crate::Result;
+use ristretto_classfile::ClassFile;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::io;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// An in-memory map of class names to class file bytes in the class path; this allows classes
+/// that are embedded in the binary (e.g. with `include_bytes!`) or generated at runtime to be
+/// loaded without a file system.  The bytes are shared, so cloning the entry is cheap.
+#[derive(Clone, Debug)]
+pub struct Memory {
+    name: String,
+    classes: HashMap<String, Arc<Vec<u8>>>,
+}
+
+/// Implement the `Memory` struct.
+impl Memory {
+    /// Create a new in-memory class path entry from a map of class names (e.g.
+    /// "java/lang/Object" or "java.lang.Object") to class file bytes.
+    #[cfg(feature = "memory")]
+    pub fn new<S: AsRef<str>>(name: S, classes: HashMap<String, Vec<u8>>) -> Self {
+        let classes = classes
+            .into_iter()
+            .map(|(class_name, bytes)| (class_name.replace('.', "/"), Arc::new(bytes)))
+            .collect();
+        Self {
+            name: name.as_ref().to_string(),
+            classes,
+        }
+    }
+
+    /// Get the name of the in-memory class path entry.
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Add the class file bytes of a class (e.g. "java/lang/Object" or "java.lang.Object"),
+    /// replacing the bytes of a class with the same name.
+    #[cfg(feature = "memory")]
+    pub fn add_class<S: AsRef<str>, B: Into<Arc<Vec<u8>>>>(&mut self, name: S, bytes: B) {
+        let name = name.as_ref().replace('.', "/");
+        self.classes.insert(name, bytes.into());
+    }
+
+    /// Read a class from memory.
+    ///
+    /// # Errors
+    /// if the class file is not found or cannot be parsed.
+    #[instrument(level = "trace", fields(name = ?name.as_ref()), skip(self))]
+    pub fn read_class<S: AsRef<str>>(&self, name: S) -> Result<ClassFile> {
+        let name = name.as_ref();
+        let Some(bytes) = self.classes.get(&name.replace('.', "/")) else {
+            return Err(ClassNotFound(name.to_string()));
+        };
+        let mut cursor = io::Cursor::new(bytes.as_slice());
+        let class_file = ClassFile::from_bytes(&mut cursor)?;
+        Ok(class_file)
+    }
+
+    /// Get the class names in memory.
+    ///
+    /// # Errors
+    /// if the class names cannot be read.
+    #[expect(clippy::unused_async)]
+    pub async fn class_names(&self) -> Result<Vec<String>> {
+        let mut classes = self.classes.keys().cloned().collect::<Vec<_>>();
+        classes.sort();
+        Ok(classes)
+    }
+}
+
+/// Implement the `PartialEq` trait for `Memory`.
+impl PartialEq for Memory {
+    /// Compare two in-memory class path entries by their names and classes.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.classes == other.classes
+    }
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+
+    fn classes() -> HashMap<String, Vec<u8>> {
+        let mut classes = HashMap::new();
+        classes.insert(
+            "HelloWorld".to_string(),
+            include_bytes!("../../../classes/HelloWorld.class").to_vec(),
+        );
+        classes
+    }
+
+    #[test]
+    fn test_new() {
+        let memory = Memory::new("test", HashMap::new());
+        assert_eq!("test", memory.name());
+    }
+
+    #[test]
+    fn test_equality() {
+        let memory1 = Memory::new("test", classes());
+        let memory2 = Memory::new("test", classes());
+        assert_eq!(memory1, memory2);
+    }
+
+    #[test]
+    fn test_inequality() {
+        let memory1 = Memory::new("test", classes());
+        let memory2 = Memory::new("test", HashMap::new());
+        assert_ne!(memory1, memory2);
+    }
+
+    #[test]
+    fn test_read_class() -> Result<()> {
+        let memory = Memory::new("test", classes());
+        let class_file = memory.read_class("HelloWorld")?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_class_invalid_class_name() {
+        let memory = Memory::new("test", classes());
+        let result = memory.read_class("Foo");
+        assert!(matches!(result, Err(ClassNotFound(_))));
+    }
+
+    #[test]
+    fn test_read_class_invalid_bytes() {
+        let mut classes = HashMap::new();
+        classes.insert("Foo".to_string(), vec![0, 1, 2, 3]);
+        let memory = Memory::new("test", classes);
+        let result = memory.read_class("Foo");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_add_class() -> Result<()> {
+        let mut memory = Memory::new("test", HashMap::new());
+        let bytes = Arc::new(include_bytes!("../../../classes/HelloWorld.class").to_vec());
+        memory.add_class("HelloWorld", bytes.clone());
+        assert_eq!(memory, Memory::new("test", classes()));
+        let class_file = memory.read_class("HelloWorld")?;
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        memory.add_class("java.lang.Foo", Vec::new());
+        assert!(memory.read_class("java/lang/Foo").is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_names() -> Result<()> {
+        let mut classes = classes();
+        classes.insert("java.lang.Foo".to_string(), Vec::new());
+        let memory = Memory::new("test", classes);
+        let class_names = memory.class_names().await?;
+        assert_eq!(
+            vec!["HelloWorld".to_string(), "java/lang/Foo".to_string()],
+            class_names
+        );
+        Ok(())
+    }
+}
@@ -1,7 +1,12 @@
 mod directory;
 mod jar;
+mod jmod;
 pub mod manifest;
+mod memory;
 mod model;
+mod url;
 
 pub use manifest::Manifest;
 pub use model::ClassPathEntry;
+#[cfg(feature = "url")]
+pub use url::{FetchFuture, HttpFetcher, UrlFetcher};
@@ -1,9 +1,19 @@
 use crate::class_path_entry::directory::Directory;
 use crate::class_path_entry::jar::Jar;
+use crate::class_path_entry::jmod::Jmod;
+use crate::class_path_entry::memory::Memory;
+#[cfg(feature = "url")]
+use crate::class_path_entry::url::UrlFetcher;
 use crate::Result;
 use ristretto_classfile::ClassFile;
+#[cfg(feature = "memory")]
+use std::collections::HashMap;
 use std::fmt::Debug;
+#[cfg(feature = "url")]
+use std::path::Path;
 use std::path::PathBuf;
+#[cfg(feature = "url")]
+use std::sync::Arc;
 use tracing::instrument;
 
 /// Represents a class path entry.
@@ -11,6 +21,8 @@ use tracing::instrument;
 pub enum ClassPathEntry {
     Directory(Directory),
     Jar(Jar),
+    Jmod(Jmod),
+    Memory(Memory),
 }
 
 /// Implementation for `ClassPathEntry`.
@@ -23,19 +35,45 @@ impl ClassPathEntry {
             return ClassPathEntry::Jar(Jar::from_url(path));
         }
 
-        if PathBuf::from(path).is_file() {
+        let path_buf = PathBuf::from(path);
+        if path_buf
+            .extension()
+            .is_some_and(|extension| extension == "jmod")
+        {
+            ClassPathEntry::Jmod(Jmod::new(path))
+        } else if path_buf.is_file() {
             ClassPathEntry::Jar(Jar::new(path))
         } else {
             ClassPathEntry::Directory(Directory::new(path))
         }
     }
 
+    /// Create a new in-memory class path entry from a map of class names to class file bytes.
+    #[cfg(feature = "memory")]
+    pub fn from_memory<S: AsRef<str>>(name: S, classes: HashMap<String, Vec<u8>>) -> Self {
+        ClassPathEntry::Memory(Memory::new(name, classes))
+    }
+
+    /// Create a new class path entry for a remote jar that is downloaded with the given fetcher.
+    #[cfg(feature = "url")]
+    pub fn from_url_with_fetcher<S: AsRef<str>>(url: S, fetcher: Arc<dyn UrlFetcher>) -> Self {
+        ClassPathEntry::Jar(Jar::from_url_with_fetcher(url, fetcher))
+    }
+
+    /// Returns true if the class path entry is a remote jar.
+    #[must_use]
+    pub fn is_url(&self) -> bool {
+        matches!(self, ClassPathEntry::Jar(jar) if jar.is_url())
+    }
+
     /// Get the name of the class path entry.
     #[must_use]
     pub fn name(&self) -> &String {
         match self {
             ClassPathEntry::Directory(directory) => directory.name(),
             ClassPathEntry::Jar(jar) => jar.name(),
+            ClassPathEntry::Jmod(jmod) => jmod.name(),
+            ClassPathEntry::Memory(memory) => memory.name(),
         }
     }
 
@@ -48,6 +86,21 @@ impl ClassPathEntry {
         match self {
             ClassPathEntry::Directory(directory) => directory.read_class(name),
             ClassPathEntry::Jar(jar) => jar.read_class(name).await,
+            ClassPathEntry::Jmod(jmod) => jmod.read_class(name).await,
+            ClassPathEntry::Memory(memory) => memory.read_class(name),
+        }
+    }
+
+    /// Download a remote jar to the cache directory so that its classes are read from disk; see
+    /// [`Jar::prefetch`].  Returns false for entries that are not remote jars.
+    ///
+    /// # Errors
+    /// if the jar cannot be written to the cache directory.
+    #[cfg(feature = "url")]
+    pub async fn prefetch<P: AsRef<Path>>(&self, cache_dir: P) -> Result<bool> {
+        match self {
+            ClassPathEntry::Jar(jar) => jar.prefetch(cache_dir).await,
+            _ => Ok(false),
         }
     }
 
@@ -59,8 +112,35 @@ impl ClassPathEntry {
         match self {
             ClassPathEntry::Directory(directory) => directory.class_names().await,
             ClassPathEntry::Jar(jar) => jar.class_names().await,
+            ClassPathEntry::Jmod(jmod) => jmod.class_names().await,
+            ClassPathEntry::Memory(memory) => memory.class_names().await,
         }
     }
+
+    /// List the classes that can be loaded from the class path entry, without reading them.  The
+    /// names are internal names (e.g. `java/lang/Object`), sorted and without duplicates;
+    /// `module-info` and `package-info` entries and the versioned classes of multi-release jars
+    /// are not included.  The classes of a remote jar are listed once the jar is downloaded.
+    ///
+    /// # Errors
+    /// if the classes cannot be listed.
+    pub async fn list_classes(&self) -> Result<Vec<String>> {
+        let mut class_names: Vec<String> = self
+            .class_names()
+            .await?
+            .into_iter()
+            .filter(|class_name| {
+                let simple_name = class_name.rsplit('/').next().unwrap_or_default();
+                !class_name.is_empty()
+                    && !class_name.starts_with("META-INF/")
+                    && simple_name != "module-info"
+                    && simple_name != "package-info"
+            })
+            .collect();
+        class_names.sort();
+        class_names.dedup();
+        Ok(class_names)
+    }
 }
 
 /// Represents a Jar manifest.
@@ -118,6 +198,51 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_list_classes_directory() -> Result<()> {
+        let directory = tempfile::tempdir()?;
+        let package_directory = directory.path().join("com").join("example");
+        std::fs::create_dir_all(&package_directory)?;
+        let class_bytes = include_bytes!("../../../classes/HelloWorld.class");
+        std::fs::write(package_directory.join("Foo.class"), class_bytes)?;
+        std::fs::write(package_directory.join("package-info.class"), class_bytes)?;
+        std::fs::write(directory.path().join("Bar.class"), class_bytes)?;
+        std::fs::write(directory.path().join("Bar.java"), "class Bar {}")?;
+
+        let class_path_entry = ClassPathEntry::new(directory.path().to_string_lossy());
+        let class_names = class_path_entry.list_classes().await?;
+        assert_eq!(
+            vec!["Bar".to_string(), "com/example/Foo".to_string()],
+            class_names
+        );
+        Ok(())
+    }
+
+    //
+    // Memory Tests
+    //
+
+    #[cfg(feature = "memory")]
+    #[tokio::test]
+    async fn test_read_class_memory() -> Result<()> {
+        let mut classes = HashMap::new();
+        classes.insert(
+            "HelloWorld".to_string(),
+            include_bytes!("../../../classes/HelloWorld.class").to_vec(),
+        );
+        let class_path_entry = ClassPathEntry::from_memory("memory", classes);
+        let class_file = class_path_entry.read_class("HelloWorld").await?;
+
+        assert!(matches!(class_path_entry, ClassPathEntry::Memory(_)));
+        assert_eq!("memory", class_path_entry.name());
+        assert_eq!("HelloWorld", class_file.class_name()?);
+        assert_eq!(
+            vec!["HelloWorld".to_string()],
+            class_path_entry.class_names().await?
+        );
+        Ok(())
+    }
+
     //
     // Jar Tests
     //
@@ -161,6 +286,35 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_list_classes_jar() -> Result<()> {
+        let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let classes_jar = cargo_manifest.join("../classes/classes.jar");
+        let class_path_entry = ClassPathEntry::new(classes_jar.to_string_lossy());
+        let class_names = class_path_entry.list_classes().await?;
+        assert!(class_names.contains(&"HelloWorld".to_string()));
+        assert!(class_names.windows(2).all(|names| names[0] < names[1]));
+        Ok(())
+    }
+
+    //
+    // Jmod Tests
+    //
+
+    #[tokio::test]
+    async fn test_read_class_jmod() -> Result<()> {
+        let (java_home, _java_version, _class_loader) =
+            crate::runtime::default_class_loader().await?;
+        let path = java_home.join("jmods").join("java.base.jmod");
+        let class_path_entry = ClassPathEntry::new(path.to_string_lossy());
+        let class_file = class_path_entry.read_class("java/lang/Object").await?;
+
+        assert!(matches!(class_path_entry, ClassPathEntry::Jmod(_)));
+        assert_eq!(path.to_string_lossy(), class_path_entry.name().as_str());
+        assert_eq!("java/lang/Object", class_file.class_name()?);
+        Ok(())
+    }
+
     //
     // Url Tests
     //
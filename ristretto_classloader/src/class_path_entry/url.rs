@@ -0,0 +1,118 @@
+use crate::Result;
+use reqwest::Client;
+use sha2::{Digest, Sha256};
+use std::fmt::Debug;
+use std::fs;
+use std::future::Future;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use tracing::{debug, warn};
+
+/// The future returned by [`UrlFetcher::fetch`].
+pub type FetchFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<u8>>> + Send + 'a>>;
+
+/// Fetches the contents of remote jars in the class path.  Jars created with `Jar::from_url` use
+/// [`HttpFetcher`]; an embedder can supply its own implementation (e.g. to add authentication or
+/// to serve jars from a mirror) with `Jar::from_url_with_fetcher`.
+pub trait UrlFetcher: Debug + Send + Sync {
+    /// Fetch the contents of the url.
+    ///
+    /// # Errors
+    /// if the contents cannot be fetched.
+    fn fetch<'a>(&'a self, url: &'a str) -> FetchFuture<'a>;
+}
+
+/// Fetches remote jars over HTTP(S).
+#[derive(Debug, Default)]
+pub struct HttpFetcher;
+
+impl UrlFetcher for HttpFetcher {
+    fn fetch<'a>(&'a self, url: &'a str) -> FetchFuture<'a> {
+        Box::pin(async move {
+            let client = Client::new();
+            let response = client.get(url).send().await?.error_for_status()?;
+            let bytes = response.bytes().await?;
+            Ok(bytes.to_vec())
+        })
+    }
+}
+
+/// Get the hex encoded SHA-256 hash of the bytes.
+fn hash<B: AsRef<[u8]>>(bytes: B) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Get the prefix of the file names of the cached copies of the url; the full file name is the
+/// prefix followed by the hash of the contents (e.g. `<url hash>-<content hash>.jar`).
+fn cache_file_prefix(url: &str) -> String {
+    format!("{}-", hash(url))
+}
+
+/// Find a cached copy of the url in the cache directory.  A cached copy whose contents do not
+/// match the hash in its file name (e.g. a partially written or modified file) is removed.
+pub(crate) fn cached_jar(cache_dir: &Path, url: &str) -> Option<PathBuf> {
+    let prefix = cache_file_prefix(url);
+    let entries = fs::read_dir(cache_dir).ok()?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(content_hash) = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .and_then(|file_name| file_name.strip_prefix(prefix.as_str()))
+            .and_then(|file_name| file_name.strip_suffix(".jar"))
+        else {
+            continue;
+        };
+        match fs::read(&path) {
+            Ok(bytes) if hash(&bytes) == content_hash => return Some(path),
+            _ => {
+                warn!("removing invalid cached jar {}", path.to_string_lossy());
+                let _ = fs::remove_file(&path);
+            }
+        }
+    }
+    None
+}
+
+/// Write the contents of the url to the cache directory and return the path of the cached copy.
+/// The contents are written to a temporary file that is renamed once complete, so that a reader
+/// never observes a partially written jar.
+///
+/// # Errors
+/// if the cache directory cannot be created or the file cannot be written.
+pub(crate) fn cache_jar(cache_dir: &Path, url: &str, bytes: &[u8]) -> Result<PathBuf> {
+    fs::create_dir_all(cache_dir)?;
+    let file_name = format!("{}{}.jar", cache_file_prefix(url), hash(bytes));
+    let path = cache_dir.join(file_name);
+    let mut file = tempfile::NamedTempFile::new_in(cache_dir)?;
+    file.write_all(bytes)?;
+    file.persist(&path).map_err(|error| error.error)?;
+    debug!("cached {url} as {}", path.to_string_lossy());
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_jar() -> Result<()> {
+        let cache_dir = tempfile::tempdir()?;
+        let url = "https://example.com/example.jar";
+        assert_eq!(None, cached_jar(cache_dir.path(), url));
+
+        let path = cache_jar(cache_dir.path(), url, b"jar")?;
+        assert_eq!(Some(path.clone()), cached_jar(cache_dir.path(), url));
+        assert_eq!(
+            None,
+            cached_jar(cache_dir.path(), "https://example.com/other.jar")
+        );
+
+        // A cached copy that no longer matches its content hash is discarded
+        fs::write(&path, b"modified")?;
+        assert_eq!(None, cached_jar(cache_dir.path(), url));
+        assert!(!path.exists());
+        Ok(())
+    }
+}
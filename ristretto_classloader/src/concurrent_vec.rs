@@ -2,6 +2,7 @@ use crate::Error::PoisonedLock;
 use crate::Result;
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
+use std::ops::Range;
 use std::sync::{Arc, RwLock};
 
 /// A concurrent vector.
@@ -30,6 +31,17 @@ impl<T: Clone + Debug + PartialEq> ConcurrentVec<T> {
         }
     }
 
+    /// Returns true if both vectors share the same underlying storage.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Get a pointer to the underlying storage.
+    pub(crate) fn as_ptr(&self) -> *const RwLock<Vec<T>> {
+        Arc::as_ptr(&self.inner)
+    }
+
     /// Push a value onto the vector.
     ///
     /// # Errors
@@ -87,6 +99,104 @@ impl<T: Clone + Debug + PartialEq> ConcurrentVec<T> {
         Ok(value)
     }
 
+    /// Set all values in the range to the given value; returns false if the range is not within
+    /// the vector, in which case the vector is not modified.
+    ///
+    /// # Errors
+    /// if the lock is poisoned.
+    pub fn fill(&self, range: Range<usize>, value: T) -> Result<bool> {
+        let mut vec = self
+            .inner
+            .write()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let Some(values) = vec.get_mut(range) else {
+            return Ok(false);
+        };
+        values.fill(value);
+        Ok(true)
+    }
+
+    /// Copy the values in the source range of the vector to the vector starting at the destination
+    /// index with a single lock acquisition; the ranges may overlap.  Returns false if either range
+    /// is not within the vector, in which case the vector is not modified.
+    ///
+    /// # Errors
+    /// if the lock is poisoned.
+    pub fn copy_within(&self, source: Range<usize>, destination: usize) -> Result<bool> {
+        let mut vec = self
+            .inner
+            .write()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let Some(values) = vec.get(source) else {
+            return Ok(false);
+        };
+        // Copy the source values first; the ranges may overlap and values are only `Clone`
+        let values = values.to_vec();
+        let Some(destination) = destination
+            .checked_add(values.len())
+            .and_then(|end| vec.get_mut(destination..end))
+        else {
+            return Ok(false);
+        };
+        destination.clone_from_slice(&values);
+        Ok(true)
+    }
+
+    /// Copy the values in the range of the source vector to the vector starting at the destination
+    /// index; the source may be this vector, in which case the ranges may overlap.  Each vector is
+    /// locked once, and never both at the same time, so concurrent copies in opposite directions
+    /// cannot deadlock.  Returns false if either range is not within its vector, in which case the
+    /// vector is not modified.
+    ///
+    /// # Errors
+    /// if a lock is poisoned.
+    pub fn copy_from(
+        &self,
+        source: &ConcurrentVec<T>,
+        source_range: Range<usize>,
+        destination: usize,
+    ) -> Result<bool> {
+        if self.ptr_eq(source) {
+            return self.copy_within(source_range, destination);
+        }
+
+        let values = {
+            let source = source
+                .inner
+                .read()
+                .map_err(|error| PoisonedLock(error.to_string()))?;
+            let Some(values) = source.get(source_range) else {
+                return Ok(false);
+            };
+            values.to_vec()
+        };
+        let mut vec = self
+            .inner
+            .write()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let Some(destination) = destination
+            .checked_add(values.len())
+            .and_then(|end| vec.get_mut(destination..end))
+        else {
+            return Ok(false);
+        };
+        destination.clone_from_slice(&values);
+        Ok(true)
+    }
+
+    /// Remove all values from the vector.
+    ///
+    /// # Errors
+    /// if the lock is poisoned.
+    pub fn clear(&self) -> Result<()> {
+        let mut vec = self
+            .inner
+            .write()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        vec.clear();
+        Ok(())
+    }
+
     /// Get the length of the vector.
     ///
     /// # Errors
@@ -256,6 +366,76 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fill() -> Result<()> {
+        let vec = ConcurrentVec::from(vec![1, 2, 3, 4]);
+        assert!(vec.fill(1..3, 0)?);
+        assert_eq!(vec.to_vec()?, vec![1, 0, 0, 4]);
+        assert!(!vec.fill(3..5, 0)?);
+        assert_eq!(vec.to_vec()?, vec![1, 0, 0, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_within() -> Result<()> {
+        let vec = ConcurrentVec::from(vec![1, 2, 3, 4, 5]);
+        assert!(vec.copy_within(0..3, 2)?);
+        assert_eq!(vec.to_vec()?, vec![1, 2, 1, 2, 3]);
+        assert!(vec.copy_within(2..5, 0)?);
+        assert_eq!(vec.to_vec()?, vec![1, 2, 3, 2, 3]);
+        assert!(vec.copy_within(1..1, 5)?);
+        assert!(!vec.copy_within(3..6, 0)?);
+        assert!(!vec.copy_within(0..3, 3)?);
+        assert!(!vec.copy_within(0..1, usize::MAX)?);
+        assert_eq!(vec.to_vec()?, vec![1, 2, 3, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_from() -> Result<()> {
+        let source = ConcurrentVec::from(vec![1, 2, 3]);
+        let destination = ConcurrentVec::from(vec![0; 5]);
+        assert!(destination.copy_from(&source, 1..3, 2)?);
+        assert_eq!(destination.to_vec()?, vec![0, 0, 2, 3, 0]);
+        assert!(!destination.copy_from(&source, 2..4, 0)?);
+        assert!(!destination.copy_from(&source, 0..3, 3)?);
+        assert_eq!(destination.to_vec()?, vec![0, 0, 2, 3, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_from_same_vec_overlapping() -> Result<()> {
+        let values = (0..100_000).collect::<Vec<i32>>();
+        let vec = ConcurrentVec::from(values.clone());
+        assert!(vec.copy_from(&vec.clone(), 0..99_000, 1_000)?);
+        let mut expected = values.clone();
+        expected.copy_within(0..99_000, 1_000);
+        assert_eq!(vec.to_vec()?, expected);
+
+        assert!(vec.copy_from(&vec.clone(), 1_000..100_000, 0)?);
+        expected.copy_within(1_000..100_000, 0);
+        assert_eq!(vec.to_vec()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_from_large() -> Result<()> {
+        let values = (0..1_000_000).map(Some).collect::<Vec<Option<i64>>>();
+        let source = ConcurrentVec::from(values.clone());
+        let destination = ConcurrentVec::from(vec![None; 1_000_000]);
+        assert!(destination.copy_from(&source, 0..1_000_000, 0)?);
+        assert_eq!(destination.to_vec()?, values);
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear() -> Result<()> {
+        let vec = ConcurrentVec::from(vec![1, 2, 3]);
+        vec.clear()?;
+        assert!(vec.is_empty()?);
+        Ok(())
+    }
+
     #[test]
     fn test_len() -> Result<()> {
         let vec = ConcurrentVec::new();
@@ -26,6 +26,7 @@ pub enum Error {
     #[error("Illegal access: {0}")]
     IllegalAccessError(String),
     /// An error occurred while parsing a method descriptor
+    #[deprecated(note = "malformed descriptors are reported as `MalformedDescriptor`")]
     #[error("Invalid method descriptor: {0}")]
     InvalidMethodDescriptor(String),
     /// Invalid value type
@@ -34,6 +35,9 @@ pub enum Error {
     /// An error occurred while performing an IO operation
     #[error(transparent)]
     IoError(#[from] std::io::Error),
+    /// A method or field descriptor is malformed; for example, in a corrupt class file
+    #[error("Malformed descriptor: {descriptor}")]
+    MalformedDescriptor { descriptor: String },
     /// Specified method not found
     #[error("Method not found: {class_name}.{method_name}{method_descriptor}")]
     MethodNotFound {
@@ -50,6 +54,9 @@ pub enum Error {
     /// An error occurred while performing a request
     #[error(transparent)]
     RequestError(#[from] reqwest::Error),
+    /// The requested runtime version is not installed and cannot be downloaded
+    #[error("Java runtime {version} is not installed at {path}")]
+    RuntimeNotInstalled { version: String, path: String },
     /// Error serializing or deserializing data
     #[error("Serde error: {0}")]
     SerdeError(String),
@@ -40,9 +40,10 @@
 //!
 //! The following features are available:
 //!
-//! | Name      | Description                    | Default? |
-//! |-----------|--------------------------------|----------|
-//! | `url`     | Enables url class path entries | No       |
+//! | Name      | Description                          | Default? |
+//! |-----------|--------------------------------------|----------|
+//! | `memory`  | Enables in-memory class path entries | Yes      |
+//! | `url`     | Enables url class path entries       | No       |
 //!
 //! ## Safety
 //!
@@ -68,10 +69,12 @@ mod reference;
 pub mod runtime;
 mod value;
 
-pub use class::Class;
-pub use class_loader::ClassLoader;
-pub use class_path::ClassPath;
+pub use class::{Class, FieldLayout, InitializationState};
+pub use class_loader::{ClassLoader, Delegation};
+pub use class_path::{ClassFiles, ClassPath, ParseErrorPolicy};
 pub use class_path_entry::{manifest, ClassPathEntry, Manifest};
+#[cfg(feature = "url")]
+pub use class_path_entry::{FetchFuture, HttpFetcher, UrlFetcher};
 pub use concurrent_vec::ConcurrentVec;
 pub use error::{Error, Result};
 pub use field::Field;
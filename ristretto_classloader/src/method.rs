@@ -1,7 +1,7 @@
-use crate::Error::InvalidMethodDescriptor;
+use crate::Error::MalformedDescriptor;
 use crate::Result;
 use ristretto_classfile::attributes::{Attribute, ExceptionTableEntry, Instruction, LineNumber};
-use ristretto_classfile::{BaseType, ClassFile, FieldType, MethodAccessFlags};
+use ristretto_classfile::{ClassFile, FieldType, MethodAccessFlags, MethodDescriptor};
 use std::fmt::Display;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -11,6 +11,7 @@ pub struct Method {
     descriptor: String,
     parameters: Vec<FieldType>,
     return_type: Option<FieldType>,
+    argument_slots: u16,
     max_stack: usize,
     max_locals: usize,
     code: Vec<Instruction>,
@@ -34,13 +35,24 @@ impl Method {
         line_numbers: Vec<LineNumber>,
         exception_table: Vec<ExceptionTableEntry>,
     ) -> Result<Self> {
-        let (parameters, return_type) = Method::parse_descriptor(descriptor.as_ref())?;
+        let method_descriptor = Method::parse_method_descriptor(descriptor.as_ref())?;
+        let argument_slots =
+            method_descriptor.argument_slots(access_flags.contains(MethodAccessFlags::STATIC));
+        let MethodDescriptor {
+            parameters,
+            return_type,
+        } = method_descriptor;
+        // The line number table entries are not required to be in any particular order; sort them
+        // by `start_pc` so that program counter lookups can use a binary search.
+        let mut line_numbers = line_numbers;
+        line_numbers.sort_by_key(|line_number| line_number.start_pc);
         Ok(Self {
             access_flags,
             name: name.as_ref().to_string(),
             descriptor: descriptor.as_ref().to_string(),
             parameters,
             return_type,
+            argument_slots,
             max_stack,
             max_locals,
             code,
@@ -70,16 +82,16 @@ impl Method {
                 exception_table,
                 ..
             }) => {
-                let line_numbers = match attributes
+                // A code attribute may contain multiple line number tables
+                let line_numbers = attributes
                     .iter()
-                    .find(|attribute| matches!(attribute, Attribute::LineNumberTable { .. }))
-                {
-                    Some(Attribute::LineNumberTable { line_numbers, .. }) => {
-                        // TODO: avoid cloning line numbers
-                        line_numbers.clone()
-                    }
-                    _ => Vec::new(),
-                };
+                    .filter_map(|attribute| match attribute {
+                        Attribute::LineNumberTable { line_numbers, .. } => Some(line_numbers),
+                        _ => None,
+                    })
+                    .flatten()
+                    .cloned() // TODO: avoid cloning line numbers
+                    .collect();
                 (
                     usize::from(*max_stack),
                     usize::from(*max_locals),
@@ -121,6 +133,18 @@ impl Method {
         self.access_flags.contains(MethodAccessFlags::STATIC)
     }
 
+    /// Check if the method is private.
+    #[must_use]
+    pub fn is_private(&self) -> bool {
+        self.access_flags.contains(MethodAccessFlags::PRIVATE)
+    }
+
+    /// Check if the method is final.
+    #[must_use]
+    pub fn is_final(&self) -> bool {
+        self.access_flags.contains(MethodAccessFlags::FINAL)
+    }
+
     /// Get the method name.
     #[must_use]
     pub fn name(&self) -> &str {
@@ -145,6 +169,13 @@ impl Method {
         self.return_type.as_ref()
     }
 
+    /// Get the number of local variable slots occupied by the method arguments, including `this`
+    /// for instance methods.
+    #[must_use]
+    pub fn argument_slots(&self) -> u16 {
+        self.argument_slots
+    }
+
     /// Get the method identifier.
     #[must_use]
     pub fn identifier(&self) -> String {
@@ -169,20 +200,19 @@ impl Method {
         &self.code
     }
 
-    /// Get the line number for a given program counter.
-    ///
-    /// # Errors
-    /// if the program counter does not index into a valid line number
+    /// Get the line number for a given program counter; this is the line number of the entry with
+    /// the greatest `start_pc` that is less than or equal to the program counter.  If there is no
+    /// such entry, 0 is returned.
     #[must_use]
     pub fn line_number(&self, program_counter: usize) -> usize {
         let program_counter = u16::try_from(program_counter).unwrap_or(0);
+        // Number of entries with a `start_pc` less than or equal to the program counter
         let index = self
             .line_numbers
-            .binary_search_by(|line_number| line_number.start_pc.cmp(&program_counter))
-            .unwrap_or_else(|index| index.saturating_sub(1));
-        let line_number = self
-            .line_numbers
-            .get(index)
+            .partition_point(|line_number| line_number.start_pc <= program_counter);
+        let line_number = index
+            .checked_sub(1)
+            .and_then(|index| self.line_numbers.get(index))
             .map_or(0, |line_number| line_number.line_number);
         usize::from(line_number)
     }
@@ -199,67 +229,23 @@ impl Method {
     /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-4.html#jvms-4.3.3>
     ///
     /// # Errors
-    /// if the descriptor cannot be parsed
+    /// if the descriptor is malformed
     pub fn parse_descriptor(descriptor: &str) -> Result<(Vec<FieldType>, Option<FieldType>)> {
-        let mut chars = descriptor.chars().peekable();
-        let mut parameters = Vec::new();
-        let mut return_type = None;
-
-        if chars.next() != Some('(') {
-            return Err(InvalidMethodDescriptor(descriptor.to_string()));
-        }
-
-        while let Some(&ch) = chars.peek() {
-            if ch == ')' {
-                chars.next();
-                break;
-            }
-            parameters.push(Self::parse_field_type(descriptor, &mut chars)?);
-        }
-
-        match chars.next() {
-            Some('V') => {}
-            Some(ch) => {
-                return_type = Some(Self::parse_field_type(
-                    descriptor,
-                    &mut std::iter::once(ch).chain(chars),
-                )?);
-            }
-            None => return Err(InvalidMethodDescriptor(descriptor.to_string())),
-        }
-
+        let MethodDescriptor {
+            parameters,
+            return_type,
+        } = Self::parse_method_descriptor(descriptor)?;
         Ok((parameters, return_type))
     }
 
-    /// Parse the field type.
+    /// Parse the method descriptor with [`MethodDescriptor::parse`].
     ///
     /// # Errors
-    /// if the field type cannot be parsed
-    fn parse_field_type<I>(descriptor: &str, chars: &mut I) -> Result<FieldType>
-    where
-        I: Iterator<Item = char>,
-    {
-        match chars.next() {
-            Some('L') => {
-                let mut class_name = String::new();
-                for ch in chars.by_ref() {
-                    if ch == ';' {
-                        break;
-                    }
-                    class_name.push(ch);
-                }
-                Ok(FieldType::Object(class_name))
-            }
-            Some('[') => {
-                let component_type = Self::parse_field_type(descriptor, chars)?;
-                Ok(FieldType::Array(Box::new(component_type)))
-            }
-            Some(value) => {
-                let base_type = BaseType::parse(value)?;
-                Ok(FieldType::Base(base_type))
-            }
-            None => Err(InvalidMethodDescriptor(descriptor.to_string())),
-        }
+    /// if the descriptor is malformed
+    fn parse_method_descriptor(descriptor: &str) -> Result<MethodDescriptor> {
+        MethodDescriptor::parse(descriptor).map_err(|_| MalformedDescriptor {
+            descriptor: descriptor.to_string(),
+        })
     }
 }
 
@@ -282,7 +268,7 @@ impl Display for Method {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ristretto_classfile::ConstantPool;
+    use ristretto_classfile::{BaseType, ConstantPool};
 
     #[test]
     fn test_method() -> Result<()> {
@@ -315,6 +301,7 @@ mod tests {
         assert_eq!(method.identifier(), "test:()V");
         assert!(method.parameters().is_empty());
         assert_eq!(method.return_type(), None);
+        assert_eq!(method.argument_slots(), 1);
         assert_eq!(method.max_stack, 1);
         assert_eq!(method.max_locals, 2);
         assert!(method.code.is_empty());
@@ -322,6 +309,123 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_argument_slots() -> Result<()> {
+        let method = Method::new(
+            MethodAccessFlags::PUBLIC,
+            "test",
+            "(JID)V",
+            0,
+            0,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )?;
+        assert_eq!(6, method.argument_slots());
+
+        let method = Method::new(
+            MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+            "test",
+            "(JID)V",
+            0,
+            0,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )?;
+        assert_eq!(5, method.argument_slots());
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_number_unordered_entries() -> Result<()> {
+        let line_numbers = vec![
+            LineNumber {
+                start_pc: 8,
+                line_number: 12,
+            },
+            LineNumber {
+                start_pc: 2,
+                line_number: 10,
+            },
+            LineNumber {
+                start_pc: 12,
+                line_number: 10,
+            },
+            LineNumber {
+                start_pc: 5,
+                line_number: 11,
+            },
+        ];
+        let method = Method::new(
+            MethodAccessFlags::empty(),
+            "test",
+            "()V",
+            0,
+            0,
+            Vec::new(),
+            line_numbers,
+            Vec::new(),
+        )?;
+        assert_eq!(method.line_number(0), 0);
+        assert_eq!(method.line_number(1), 0);
+        assert_eq!(method.line_number(2), 10);
+        assert_eq!(method.line_number(4), 10);
+        assert_eq!(method.line_number(5), 11);
+        assert_eq!(method.line_number(7), 11);
+        assert_eq!(method.line_number(8), 12);
+        assert_eq!(method.line_number(11), 12);
+        assert_eq!(method.line_number(12), 10);
+        assert_eq!(method.line_number(100), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_number_multiple_tables() -> Result<()> {
+        let mut constant_pool = ConstantPool::new();
+        let name_index = constant_pool.add_utf8("test")?;
+        let descriptor_index = constant_pool.add_utf8("()V")?;
+        let code_attribute = Attribute::Code {
+            name_index: 0,
+            max_stack: 0,
+            max_locals: 0,
+            code: Vec::new(),
+            exception_table: Vec::new(),
+            attributes: vec![
+                Attribute::LineNumberTable {
+                    name_index: 0,
+                    line_numbers: vec![LineNumber {
+                        start_pc: 4,
+                        line_number: 21,
+                    }],
+                },
+                Attribute::LineNumberTable {
+                    name_index: 0,
+                    line_numbers: vec![LineNumber {
+                        start_pc: 0,
+                        line_number: 20,
+                    }],
+                },
+            ],
+        };
+        let method = ristretto_classfile::Method {
+            name_index,
+            descriptor_index,
+            attributes: vec![code_attribute],
+            ..Default::default()
+        };
+        let class_file = ClassFile {
+            constant_pool,
+            methods: vec![method.clone()],
+            ..Default::default()
+        };
+        let method = Method::from(&class_file, &method)?;
+        assert_eq!(method.line_number(0), 20);
+        assert_eq!(method.line_number(3), 20);
+        assert_eq!(method.line_number(4), 21);
+        Ok(())
+    }
+
     #[test]
     fn test_parse_descriptor() -> Result<()> {
         let (parameters, return_type) = Method::parse_descriptor("()V")?;
@@ -368,57 +472,66 @@ mod tests {
 
     #[test]
     fn test_parse_descriptor_invalid() {
-        let descriptor = String::new();
-        assert!(matches!(
-            Method::parse_descriptor(&descriptor),
-            Err(InvalidMethodDescriptor(_))
-        ));
+        for descriptor in [
+            "",
+            "()",
+            "(I",
+            "I)V",
+            "(X)V",
+            "()VV",
+            "(V)V",
+            "(L)V",
+            "(Ljava/lang/String)V",
+            "([)V",
+        ] {
+            let result = Method::parse_descriptor(descriptor);
+            assert!(
+                matches!(
+                    &result,
+                    Err(MalformedDescriptor { descriptor: value }) if value == descriptor
+                ),
+                "{descriptor}: {result:?}"
+            );
+        }
+    }
 
-        let descriptor = "()";
+    #[test]
+    fn test_new_malformed_descriptor() {
+        let result = Method::new(
+            MethodAccessFlags::PUBLIC,
+            "test",
+            "(Q)V",
+            0,
+            0,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        );
         assert!(matches!(
-            Method::parse_descriptor(descriptor),
-            Err(InvalidMethodDescriptor(_))
+            result,
+            Err(MalformedDescriptor { descriptor }) if descriptor == "(Q)V"
         ));
     }
 
     #[test]
-    fn test_parse_field_type() -> Result<()> {
-        assert_eq!(
-            Method::parse_field_type("", &mut "I".chars())?,
-            FieldType::Base(BaseType::Int)
-        );
-        assert_eq!(
-            Method::parse_field_type("", &mut "J".chars())?,
-            FieldType::Base(BaseType::Long)
-        );
-        assert_eq!(
-            Method::parse_field_type("", &mut "S".chars())?,
-            FieldType::Base(BaseType::Short)
-        );
-        assert_eq!(
-            Method::parse_field_type("", &mut "Z".chars())?,
-            FieldType::Base(BaseType::Boolean)
-        );
-        assert_eq!(
-            Method::parse_field_type("", &mut "Ljava.lang.String;".chars())?,
-            FieldType::Object("java.lang.String".to_string())
-        );
+    fn test_parse_descriptor_field_types() -> Result<()> {
+        let (parameters, return_type) =
+            Method::parse_descriptor("(IJSZLjava.lang.String;[Ljava.lang.String;)V")?;
         assert_eq!(
-            Method::parse_field_type("", &mut "[Ljava.lang.String;".chars())?,
-            FieldType::Array(Box::new(FieldType::Object("java.lang.String".to_string())))
+            parameters,
+            vec![
+                FieldType::Base(BaseType::Int),
+                FieldType::Base(BaseType::Long),
+                FieldType::Base(BaseType::Short),
+                FieldType::Base(BaseType::Boolean),
+                FieldType::Object("java.lang.String".to_string()),
+                FieldType::Array(Box::new(FieldType::Object("java.lang.String".to_string()))),
+            ]
         );
+        assert_eq!(return_type, None);
         Ok(())
     }
 
-    #[test]
-    fn test_parse_field_type_invalid() {
-        let descriptor = String::new();
-        assert!(matches!(
-            Method::parse_field_type(&descriptor, &mut descriptor.chars()),
-            Err(InvalidMethodDescriptor(_))
-        ));
-    }
-
     #[test]
     fn test_to_string() {
         let method = Method {
@@ -427,6 +540,7 @@ mod tests {
             descriptor: "()V".to_string(),
             parameters: Vec::new(),
             return_type: None,
+            argument_slots: 1,
             max_stack: 1,
             max_locals: 2,
             code: Vec::new(),
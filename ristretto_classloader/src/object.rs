@@ -1,13 +1,19 @@
+use crate::reference::fmt_nested;
 use crate::Error::{FieldNotFound, InvalidValueType, ParseError};
 use crate::Reference::{ByteArray, CharArray};
 use crate::{Class, Field, Reference, Result, Value};
-use ristretto_classfile::{mutf8, FieldAccessFlags, Version};
+use ristretto_classfile::{FieldAccessFlags, Version};
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Display};
 use std::sync::Arc;
 
 const JAVA_8: Version = Version::Java8 { minor: 0 };
 
+/// The `java.lang.String` coder for strings with one byte per character
+const LATIN1: i32 = 0;
+/// The `java.lang.String` coder for strings with two bytes per character
+const UTF16: i32 = 1;
+
 /// Represents an object in the Ristretto VM.
 #[derive(Clone)]
 pub struct Object {
@@ -114,6 +120,53 @@ impl Object {
         self.value("value")
     }
 
+    /// Get the address of the fields shared by all clones of the object; the address identifies
+    /// the object for as long as it is alive.
+    pub(crate) fn address(&self) -> usize {
+        Arc::as_ptr(&self.fields) as usize
+    }
+
+    /// Returns true if both objects share the same underlying fields.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Object) -> bool {
+        Arc::ptr_eq(&self.fields, &other.fields)
+    }
+
+    /// Compare two objects structurally; `visited` contains the pairs of arrays and objects that
+    /// are already being compared.
+    pub(crate) fn structural_eq_with_visited(
+        &self,
+        other: &Object,
+        visited: &mut HashSet<(usize, usize)>,
+    ) -> bool {
+        if self.class.name() != other.class.name() {
+            return false;
+        }
+        if self.ptr_eq(other) {
+            return true;
+        }
+        let pair = (self.address(), other.address());
+        if !visited.insert(pair) {
+            return true;
+        }
+        if self.fields.len() != other.fields.len() {
+            return false;
+        }
+
+        for (name, field) in self.fields.iter() {
+            let Some(other_field) = other.fields.get(name) else {
+                return false;
+            };
+            let (Ok(value), Ok(other_value)) = (field.value(), other_field.value()) else {
+                return false;
+            };
+            if !value.structural_eq_with_visited(&other_value, visited) {
+                return false;
+            }
+        }
+        true
+    }
+
     /// Recursively compare two `Object` instances for equality and avoid cycles.
     #[expect(clippy::type_complexity)]
     fn equal_with_visited(
@@ -209,23 +262,41 @@ impl Object {
     }
 }
 
-impl Debug for Object {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Object({})", self.class.name())?;
-        if !self.fields.is_empty() {
-            writeln!(f)?;
-        }
-
-        // Print fields by name to ensure consistent output
+impl Object {
+    /// Get the names and values of the fields, sorted by name, for formatting.
+    fn field_values(&self) -> std::result::Result<Vec<(&String, Value)>, std::fmt::Error> {
         let mut names = self.fields.keys().collect::<Vec<_>>();
         names.sort();
+        let mut values = Vec::with_capacity(names.len());
         for name in names {
             let field = self.fields.get(name).ok_or(std::fmt::Error)?;
             let value = field.value().map_err(|_| std::fmt::Error)?;
-            writeln!(f, "  {name}={value}")?;
+            values.push((name, value));
         }
+        Ok(values)
+    }
+}
 
-        Ok(())
+impl Debug for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let class_name = self.class.name();
+        fmt_nested(
+            f,
+            self.address(),
+            |f| {
+                write!(f, "Object({class_name})")?;
+                if !self.fields.is_empty() {
+                    writeln!(f)?;
+                }
+
+                // Print fields by name to ensure consistent output
+                for (name, value) in self.field_values()? {
+                    writeln!(f, "  {name}={value}")?;
+                }
+                Ok(())
+            },
+            |f| write!(f, "Object({class_name})"),
+        )
     }
 }
 
@@ -284,7 +355,25 @@ impl Display for Object {
                 let value: String = value.try_into().unwrap_or_default();
                 write!(f, "Class({value})")
             }
-            _ => write!(f, "Object(class {class_name})"),
+            // The fields are only written with the alternate format (`{:#}`)
+            _ if !f.alternate() => write!(f, "Object(class {class_name})"),
+            _ => fmt_nested(
+                f,
+                self.address(),
+                |f| {
+                    write!(f, "Object(class {class_name})")?;
+                    if self.fields.is_empty() {
+                        return Ok(());
+                    }
+                    let fields = self
+                        .field_values()?
+                        .into_iter()
+                        .map(|(name, value)| format!("{name}={value:#}"))
+                        .collect::<Vec<_>>();
+                    write!(f, "{{{}}}", fields.join(", "))
+                },
+                |f| write!(f, "Object(class {class_name})"),
+            ),
         }
     }
 }
@@ -458,8 +547,18 @@ impl TryInto<String> for Object {
                 let bytes = bytes.to_vec()?;
                 #[expect(clippy::cast_sign_loss)]
                 let bytes: Vec<u8> = bytes.iter().map(|&b| b as u8).collect();
-                let value = mutf8::from_bytes(&bytes)?;
-                Ok(value)
+                // Compact strings (Java 9+) store LATIN1 strings with one byte per character and
+                // all other strings as UTF-16 with two bytes per character.
+                let coder = self.value("coder").unwrap_or(Value::Int(LATIN1));
+                if coder == Value::Int(UTF16) {
+                    let chars: Vec<u16> = bytes
+                        .chunks_exact(2)
+                        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                        .collect();
+                    String::from_utf16(&chars).map_err(|error| ParseError(error.to_string()))
+                } else {
+                    Ok(bytes.iter().map(|&byte| char::from(byte)).collect())
+                }
             }
             CharArray(bytes) => {
                 let bytes = bytes.to_vec()?;
@@ -710,6 +809,45 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_to_string_fields() -> Result<()> {
+        let class = load_class("java/util/AbstractMap$SimpleEntry").await?;
+        let object = Object::new(class.clone())?;
+        let nested_object = Object::new(class.clone())?;
+        let deeply_nested_object = Object::new(class)?;
+        nested_object.set_value("value", Value::from(deeply_nested_object))?;
+        object.set_value("value", Value::from(nested_object))?;
+        assert_eq!(
+            "Object(class java/util/AbstractMap$SimpleEntry){key=Object(null), \
+             value=Object(class java/util/AbstractMap$SimpleEntry){key=Object(null), \
+             value=Object(class java/util/AbstractMap$SimpleEntry)}}",
+            format!("{object:#}")
+        );
+        assert_eq!(
+            "Object(class java/util/AbstractMap$SimpleEntry)",
+            object.to_string()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_to_string_cycle() -> Result<()> {
+        let class = load_class("java/util/AbstractMap$SimpleEntry").await?;
+        let object = Object::new(class)?;
+        let value = Value::from(object.clone());
+        object.set_value("key", value.clone())?;
+        object.set_value("value", value.clone())?;
+        assert_eq!(
+            "Object(class java/util/AbstractMap$SimpleEntry){key=<cycle>, value=<cycle>}",
+            format!("{value:#}")
+        );
+        assert_eq!(
+            "Object(java/util/AbstractMap$SimpleEntry)\n  key=<cycle>\n  value=<cycle>\n",
+            format!("{object:?}")
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_to_string() -> Result<()> {
         let class_name = "java/lang/Object";
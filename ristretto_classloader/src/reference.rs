@@ -2,12 +2,59 @@ use crate::concurrent_vec::ConcurrentVec;
 use crate::Error::InvalidValueType;
 use crate::{Class, Object, Result, Value};
 use ristretto_classfile::{ClassFile, ConstantPool};
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
 use std::fmt;
-use std::fmt::Display;
+use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+/// The maximum number of nested arrays and objects that are formatted in full; deeper arrays and
+/// objects are abbreviated.
+const MAX_FORMAT_DEPTH: usize = 2;
+
+thread_local! {
+    /// The addresses of the arrays and objects being formatted by the current thread, outermost
+    /// first.
+    static FORMATTING: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Removes the innermost address from [`FORMATTING`] when dropped.
+struct FormattingGuard;
+
+impl Drop for FormattingGuard {
+    fn drop(&mut self) {
+        FORMATTING.with_borrow_mut(Vec::pop);
+    }
+}
+
+/// Format an array or object, identified by the address of its storage, that may be nested in
+/// another array or object being formatted.  An array or object that is already being formatted
+/// is part of a cycle and is written as `<cycle>`; beyond the maximum depth, `abbreviated` is
+/// used instead of `format`.
+pub(crate) fn fmt_nested(
+    f: &mut fmt::Formatter<'_>,
+    address: usize,
+    format: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+    abbreviated: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let (cycle, depth) =
+        FORMATTING.with_borrow(|addresses| (addresses.contains(&address), addresses.len()));
+    if cycle {
+        return write!(f, "<cycle>");
+    }
+    if depth >= MAX_FORMAT_DEPTH {
+        return abbreviated(f);
+    }
+
+    FORMATTING.with_borrow_mut(|addresses| addresses.push(address));
+    let _guard = FormattingGuard;
+    format(f)
+}
+
 /// Represents a reference to an object in the Ristretto VM.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, PartialEq)]
 pub enum Reference {
     ByteArray(ConcurrentVec<i8>),
     CharArray(ConcurrentVec<u16>),
@@ -157,6 +204,154 @@ impl Reference {
             _ => Err(InvalidValueType("Expected array".to_string())),
         }
     }
+
+    /// Returns true if both references point to the same underlying object or array.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Reference) -> bool {
+        match (self, other) {
+            (Reference::ByteArray(array), Reference::ByteArray(other_array)) => {
+                array.ptr_eq(other_array)
+            }
+            (Reference::CharArray(array), Reference::CharArray(other_array)) => {
+                array.ptr_eq(other_array)
+            }
+            (Reference::ShortArray(array), Reference::ShortArray(other_array)) => {
+                array.ptr_eq(other_array)
+            }
+            (Reference::IntArray(array), Reference::IntArray(other_array)) => {
+                array.ptr_eq(other_array)
+            }
+            (Reference::LongArray(array), Reference::LongArray(other_array)) => {
+                array.ptr_eq(other_array)
+            }
+            (Reference::FloatArray(array), Reference::FloatArray(other_array)) => {
+                array.ptr_eq(other_array)
+            }
+            (Reference::DoubleArray(array), Reference::DoubleArray(other_array)) => {
+                array.ptr_eq(other_array)
+            }
+            (Reference::Array(_, array), Reference::Array(_, other_array)) => {
+                array.ptr_eq(other_array)
+            }
+            (Reference::Object(object), Reference::Object(other_object)) => {
+                object.ptr_eq(other_object)
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the address of the storage shared by all clones of the reference; the address
+    /// identifies the array or object for as long as it is alive.
+    fn address(&self) -> usize {
+        match self {
+            Reference::ByteArray(array) => array.as_ptr() as usize,
+            Reference::CharArray(array) => array.as_ptr() as usize,
+            Reference::ShortArray(array) => array.as_ptr() as usize,
+            Reference::IntArray(array) => array.as_ptr() as usize,
+            Reference::LongArray(array) => array.as_ptr() as usize,
+            Reference::FloatArray(array) => array.as_ptr() as usize,
+            Reference::DoubleArray(array) => array.as_ptr() as usize,
+            Reference::Array(_, array) => array.as_ptr() as usize,
+            Reference::Object(object) => object.address(),
+        }
+    }
+
+    /// Get the identity hash code of the array or object, as returned by `Object.hashCode` and
+    /// `System.identityHashCode`.  The hash code is derived from the identity of the array or
+    /// object rather than its contents, so it is the same for every clone of the reference and
+    /// does not change when the array or object is modified.
+    #[must_use]
+    pub fn identity_hash_code(&self) -> i32 {
+        let mut hasher = DefaultHasher::new();
+        self.address().hash(&mut hasher);
+        let hash_code = hasher.finish();
+        #[expect(clippy::cast_possible_truncation)]
+        let hash_code = hash_code as i32;
+        hash_code
+    }
+
+    /// Compare two references structurally by recursively comparing array elements and object
+    /// fields.  Cycles in the object graph are detected and treated as equal.
+    #[must_use]
+    pub fn structural_eq(&self, other: &Reference) -> bool {
+        let mut visited = HashSet::new();
+        self.structural_eq_with_visited(other, &mut visited)
+    }
+
+    /// Compare two references structurally; `visited` contains the pairs of arrays and objects
+    /// that are already being compared.
+    pub(crate) fn structural_eq_with_visited(
+        &self,
+        other: &Reference,
+        visited: &mut HashSet<(usize, usize)>,
+    ) -> bool {
+        match (self, other) {
+            (Reference::Array(class, array), Reference::Array(other_class, other_array)) => {
+                if class.name() != other_class.name() {
+                    return false;
+                }
+                if array.ptr_eq(other_array) {
+                    return true;
+                }
+                let pair = (array.as_ptr() as usize, other_array.as_ptr() as usize);
+                if !visited.insert(pair) {
+                    return true;
+                }
+                let (Ok(array), Ok(other_array)) = (array.to_vec(), other_array.to_vec()) else {
+                    return false;
+                };
+                if array.len() != other_array.len() {
+                    return false;
+                }
+                array
+                    .iter()
+                    .zip(other_array.iter())
+                    .all(|(element, other_element)| match (element, other_element) {
+                        (Some(element), Some(other_element)) => {
+                            element.structural_eq_with_visited(other_element, visited)
+                        }
+                        (None, None) => true,
+                        _ => false,
+                    })
+            }
+            (Reference::Object(object), Reference::Object(other_object)) => {
+                object.structural_eq_with_visited(other_object, visited)
+            }
+            _ => self == other,
+        }
+    }
+}
+
+impl Debug for Reference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reference::ByteArray(value) => write!(f, "ByteArray({value:?})"),
+            Reference::CharArray(value) => write!(f, "CharArray({value:?})"),
+            Reference::ShortArray(value) => write!(f, "ShortArray({value:?})"),
+            Reference::IntArray(value) => write!(f, "IntArray({value:?})"),
+            Reference::LongArray(value) => write!(f, "LongArray({value:?})"),
+            Reference::FloatArray(value) => write!(f, "FloatArray({value:?})"),
+            Reference::DoubleArray(value) => write!(f, "DoubleArray({value:?})"),
+            Reference::Array(class, value) => {
+                let class_name = class.name();
+                fmt_nested(
+                    f,
+                    value.as_ptr() as usize,
+                    |f| {
+                        // Format a copy of the elements so the array is not locked while nested
+                        // arrays and objects are formatted
+                        let elements = value.to_vec().map_err(|_| fmt::Error)?;
+                        write!(f, "Array({class_name}, {elements:?})")
+                    },
+                    |f| {
+                        let length = value.len().unwrap_or_default();
+                        write!(f, "Array({class_name}, [...; {length}])")
+                    },
+                )
+            }
+            Reference::Object(value) => write!(f, "Object({value:?})"),
+        }
+    }
 }
 
 impl Display for Reference {
@@ -173,9 +368,7 @@ impl Display for Reference {
                 let length = value.len().unwrap_or_default();
                 write!(f, "{}[{length}]", class.array_component_type())
             }
-            Reference::Object(value) => {
-                write!(f, "{value}")
-            }
+            Reference::Object(value) => Display::fmt(value, f),
         }
     }
 }
@@ -364,7 +557,11 @@ impl TryInto<Vec<i16>> for Reference {
 impl TryInto<Vec<u16>> for Reference {
     type Error = crate::Error;
 
+    /// Converts a `char[]` to its UTF-16 code units; a `short[]` is reinterpreted as unsigned.
     fn try_into(self) -> Result<Vec<u16>> {
+        if let Reference::CharArray(_) = self {
+            return self.to_char_vec();
+        }
         let value = self.to_short_vec()?;
         #[expect(clippy::cast_sign_loss)]
         let value = value.into_iter().map(|v| v as u16).collect();
@@ -613,6 +810,33 @@ mod tests {
         Ok(class)
     }
 
+    #[test]
+    fn test_debug_array_cycle() -> Result<()> {
+        let class = Arc::new(Class::new_named("[Ljava/lang/Object;")?);
+        let array = ConcurrentVec::from(vec![None]);
+        let reference = Reference::Array(class, array.clone());
+        array.set(0, Some(reference.clone()))?;
+        assert_eq!(
+            "Array([Ljava/lang/Object;, [Some(<cycle>)])",
+            format!("{reference:?}")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_debug_array_depth() -> Result<()> {
+        let class = Arc::new(Class::new_named("[Ljava/lang/Object;")?);
+        let mut reference = Reference::Array(class.clone(), ConcurrentVec::from(vec![None]));
+        for _ in 0..3 {
+            reference = Reference::Array(class.clone(), ConcurrentVec::from(vec![Some(reference)]));
+        }
+        assert_eq!(
+            "Array([Ljava/lang/Object;, [Some(Array([Ljava/lang/Object;, [Some(Array([Ljava/lang/Object;, [...; 1]))]))])",
+            format!("{reference:?}")
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_display_byte_array() -> Result<()> {
         let reference = Reference::from(vec![1i8, 2i8, 3i8]);
@@ -854,6 +1078,69 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_identity_hash_code() -> Result<()> {
+        let reference = Reference::from(vec![1i32, 2i32]);
+        let equal_reference = Reference::from(vec![1i32, 2i32]);
+        let hash_code = reference.identity_hash_code();
+        assert_eq!(hash_code, reference.identity_hash_code());
+        assert_eq!(hash_code, reference.clone().identity_hash_code());
+        assert_ne!(hash_code, equal_reference.identity_hash_code());
+
+        // The hash code does not depend on the contents
+        let Reference::IntArray(array) = &reference else {
+            panic!("expected int array");
+        };
+        array.set(0, 42)?;
+        assert_eq!(hash_code, reference.identity_hash_code());
+        Ok(())
+    }
+
+    #[test]
+    fn test_structural_eq() -> Result<()> {
+        let class = Arc::new(Class::new_named("[Ljava/lang/Object;")?);
+        let ref1 = Reference::Array(
+            class.clone(),
+            ConcurrentVec::from(vec![Some(Reference::from(vec![1i32, 2i32])), None]),
+        );
+        let ref2 = Reference::Array(
+            class,
+            ConcurrentVec::from(vec![Some(Reference::from(vec![1i32, 2i32])), None]),
+        );
+        assert!(ref1.structural_eq(&ref2));
+        assert!(!ref1.ptr_eq(&ref2));
+        assert!(ref1.ptr_eq(&ref1.clone()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_structural_eq_ne() -> Result<()> {
+        let class = Arc::new(Class::new_named("[Ljava/lang/Object;")?);
+        let ref1 = Reference::Array(
+            class.clone(),
+            ConcurrentVec::from(vec![Some(Reference::from(vec![1i32, 2i32]))]),
+        );
+        let ref2 = Reference::Array(
+            class,
+            ConcurrentVec::from(vec![Some(Reference::from(vec![1i32, 3i32]))]),
+        );
+        assert!(!ref1.structural_eq(&ref2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_structural_eq_cycle() -> Result<()> {
+        let class = Arc::new(Class::new_named("[Ljava/lang/Object;")?);
+        let array1 = ConcurrentVec::from(vec![None]);
+        let ref1 = Reference::Array(class.clone(), array1.clone());
+        array1.set(0, Some(ref1.clone()))?;
+        let array2 = ConcurrentVec::from(vec![None]);
+        let ref2 = Reference::Array(class, array2.clone());
+        array2.set(0, Some(ref2.clone()))?;
+        assert!(ref1.structural_eq(&ref2));
+        Ok(())
+    }
+
     #[test]
     fn test_array_eq_class_ne() -> Result<()> {
         let minimum_class = minimum_class()?;
@@ -1177,6 +1464,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_into_vec_u16_from_char_array() -> Result<()> {
+        let reference = Reference::from(vec!['a', '\u{00e9}']);
+        let value: Vec<u16> = reference.try_into()?;
+        assert_eq!(vec![0x61, 0xe9], value);
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_into_vec_type_mismatch() {
+        let reference = Reference::from(vec![1i8, 2i8]);
+        let result: Result<Vec<i32>> = reference.clone().try_into();
+        assert!(matches!(result, Err(InvalidValueType(_))));
+        let result: Result<Vec<u16>> = reference.clone().try_into();
+        assert!(matches!(result, Err(InvalidValueType(_))));
+        let result: Result<Vec<char>> = reference.try_into();
+        assert!(matches!(result, Err(InvalidValueType(_))));
+        let reference = Reference::from(vec![1i32]);
+        let result: Result<Vec<i8>> = reference.try_into();
+        assert!(matches!(result, Err(InvalidValueType(_))));
+    }
+
     #[tokio::test]
     async fn test_try_into_vec_i32() -> Result<()> {
         let original_value = vec![42i32];
@@ -71,14 +71,7 @@ pub async fn home_class_loader(java_home: &PathBuf) -> Result<(PathBuf, String,
 /// An error will be returned if the class loader cannot be created.
 #[instrument(level = "debug")]
 pub async fn version_class_loader(version: &str) -> Result<(PathBuf, String, ClassLoader)> {
-    let current_dir = env::current_dir().unwrap_or_default();
-
-    #[cfg(target_arch = "wasm32")]
-    let home_dir = current_dir;
-    #[cfg(not(target_arch = "wasm32"))]
-    let home_dir = home::home_dir().unwrap_or(current_dir);
-
-    let base_path = home_dir.join(".ristretto");
+    let base_path = base_path();
     let mut installation_dir = base_path.join(version);
     if !installation_dir.exists() {
         let (version, file_name, archive) = util::get_runtime_archive(version).await?;
@@ -92,6 +85,44 @@ pub async fn version_class_loader(version: &str) -> Result<(PathBuf, String, Cla
     home_class_loader(&installation_dir).await
 }
 
+/// Get a class loader for the given Java runtime version without downloading it. Unlike
+/// [`version_class_loader`], the version must exactly match an installed runtime (e.g.
+/// "21.0.5.11.1"), since resolving a partial version requires fetching the list of available
+/// releases.
+///
+/// # Errors
+/// An error will be returned if the version is not installed or the class loader cannot be
+/// created.
+#[instrument(level = "debug")]
+pub async fn installed_version_class_loader(
+    version: &str,
+) -> Result<(PathBuf, String, ClassLoader)> {
+    let installation_dir = base_path().join(version);
+    if !installation_dir.exists() {
+        return Err(Error::RuntimeNotInstalled {
+            version: version.to_string(),
+            path: installation_dir.to_string_lossy().to_string(),
+        });
+    }
+
+    #[cfg(target_os = "macos")]
+    let installation_dir = installation_dir.join("Contents").join("Home");
+
+    home_class_loader(&installation_dir).await
+}
+
+/// Get the directory that runtimes are installed in (e.g. `~/.ristretto`).
+fn base_path() -> PathBuf {
+    let current_dir = env::current_dir().unwrap_or_default();
+
+    #[cfg(target_arch = "wasm32")]
+    let home_dir = current_dir;
+    #[cfg(not(target_arch = "wasm32"))]
+    let home_dir = home::home_dir().unwrap_or(current_dir);
+
+    home_dir.join(".ristretto")
+}
+
 /// Get the class path for the given version.
 ///
 /// # Errors
@@ -119,7 +150,7 @@ fn get_class_path(version: &str, installation_dir: &Path) -> Result<ClassPath> {
             .map(|path| path.to_string_lossy().to_string())
             .collect::<Vec<_>>();
         class_paths.sort_by(Ord::cmp);
-        class_paths.join(":")
+        class_paths.join(&ClassPath::SEPARATOR.to_string())
     };
     Ok(ClassPath::from(class_path))
 }
@@ -239,4 +270,24 @@ mod tests {
         assert_eq!("bootstrap", class_loader.name());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_installed_version_class_loader() -> Result<()> {
+        let (java_home, java_version, _class_loader) = default_class_loader().await?;
+        let (installed_java_home, installed_java_version, class_loader) =
+            installed_version_class_loader(DEFAULT_JAVA_VERSION).await?;
+        assert_eq!(java_home, installed_java_home);
+        assert_eq!(java_version, installed_java_version);
+        assert_eq!("bootstrap", class_loader.name());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_installed_version_class_loader_not_installed() {
+        let result = installed_version_class_loader("0.0.0").await;
+        assert!(matches!(
+            result,
+            Err(Error::RuntimeNotInstalled { version, .. }) if version == "0.0.0"
+        ));
+    }
 }
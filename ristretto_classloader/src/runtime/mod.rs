@@ -3,5 +3,6 @@ mod models;
 mod util;
 
 pub use bootstrap::{
-    default_class_loader, home_class_loader, version_class_loader, DEFAULT_JAVA_VERSION,
+    default_class_loader, home_class_loader, installed_version_class_loader, version_class_loader,
+    DEFAULT_JAVA_VERSION,
 };
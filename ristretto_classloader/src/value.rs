@@ -1,6 +1,8 @@
 use crate::reference::Reference;
 use crate::Error::InvalidValueType;
 use crate::{Class, Object, Result};
+use ristretto_classfile::{BaseType, FieldType};
+use std::collections::HashSet;
 use std::fmt;
 use std::fmt::Display;
 use std::sync::Arc;
@@ -13,10 +15,45 @@ pub enum Value {
     Float(f32),
     Double(f64),
     Object(Option<Reference>),
+    /// A return address pushed by the `jsr` and `jsr_w` instructions; this is the index of the
+    /// instruction to resume at when the subroutine returns with `ret`.
+    ReturnAddress(u16),
     Unused,
 }
 
 impl Value {
+    /// Get the default value of a field of the given type: `0` for `boolean`, `byte`, `char`,
+    /// `short` and `int` (which are represented as `Int` values), `0L` for `long`, `0.0` for
+    /// `float` and `double`, and `null` for object and array types.
+    ///
+    /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-2.html#jvms-2.3>
+    #[must_use]
+    pub fn default_for_field_type(field_type: &FieldType) -> Value {
+        match field_type {
+            FieldType::Base(
+                BaseType::Boolean
+                | BaseType::Byte
+                | BaseType::Char
+                | BaseType::Int
+                | BaseType::Short,
+            ) => Value::Int(0),
+            FieldType::Base(BaseType::Double) => Value::Double(0.0),
+            FieldType::Base(BaseType::Float) => Value::Float(0.0),
+            FieldType::Base(BaseType::Long) => Value::Long(0),
+            FieldType::Object(_) | FieldType::Array(_) => Value::Object(None),
+        }
+    }
+
+    /// Get the default value of a field with the given descriptor (e.g. `J` or
+    /// `Ljava/lang/String;`); see [`Value::default_for_field_type`].
+    ///
+    /// # Errors
+    /// if the descriptor is not a valid field descriptor
+    pub fn default_for_descriptor(descriptor: &str) -> Result<Value> {
+        let field_type = FieldType::parse(&descriptor.to_string())?;
+        Ok(Value::default_for_field_type(&field_type))
+    }
+
     /// Returns the value as an `i32`.
     ///
     /// # Errors
@@ -72,6 +109,30 @@ impl Value {
         }
     }
 
+    /// Compare two values structurally; primitives are compared by value, and objects and arrays
+    /// are compared by recursively comparing their fields and elements.  Cycles in the object
+    /// graph are detected and treated as equal.  This is intended as a test and debug utility.
+    #[must_use]
+    pub fn structural_eq(&self, other: &Value) -> bool {
+        let mut visited = HashSet::new();
+        self.structural_eq_with_visited(other, &mut visited)
+    }
+
+    /// Compare two values structurally; `visited` contains the pairs of arrays and objects that
+    /// are already being compared.
+    pub(crate) fn structural_eq_with_visited(
+        &self,
+        other: &Value,
+        visited: &mut HashSet<(usize, usize)>,
+    ) -> bool {
+        match (self, other) {
+            (Value::Object(Some(reference)), Value::Object(Some(other_reference))) => {
+                reference.structural_eq_with_visited(other_reference, visited)
+            }
+            _ => self == other,
+        }
+    }
+
     /// Returns true if the value is a category 1 value.
     #[must_use]
     pub fn is_category_1(&self) -> bool {
@@ -94,11 +155,12 @@ impl Display for Value {
             Value::Double(value) => write!(f, "double({value})"),
             Value::Object(value) => {
                 if let Some(value) = value {
-                    write!(f, "{value}")
+                    Display::fmt(value, f)
                 } else {
                     write!(f, "Object(null)")
                 }
             }
+            Value::ReturnAddress(address) => write!(f, "returnAddress({address})"),
             Value::Unused => write!(f, "unused"),
         }
     }
@@ -637,6 +699,41 @@ mod tests {
         class_loader.load(class).await
     }
 
+    #[test]
+    fn test_default_for_descriptor() -> Result<()> {
+        for descriptor in ["Z", "B", "C", "S", "I"] {
+            assert_eq!(Value::Int(0), Value::default_for_descriptor(descriptor)?);
+        }
+        assert_eq!(Value::Long(0), Value::default_for_descriptor("J")?);
+        assert_eq!(Value::Float(0.0), Value::default_for_descriptor("F")?);
+        assert_eq!(Value::Double(0.0), Value::default_for_descriptor("D")?);
+        for descriptor in ["Ljava/lang/String;", "[I", "[[Ljava/lang/Object;"] {
+            assert_eq!(
+                Value::Object(None),
+                Value::default_for_descriptor(descriptor)?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_for_descriptor_invalid() {
+        assert!(Value::default_for_descriptor("").is_err());
+        assert!(Value::default_for_descriptor("X").is_err());
+        assert!(Value::default_for_descriptor("Ljava/lang/String").is_err());
+    }
+
+    #[test]
+    fn test_structural_eq() {
+        let value1 = Value::from(vec![1i32, 2i32, 3i32]);
+        let value2 = Value::from(vec![1i32, 2i32, 3i32]);
+        assert!(value1.structural_eq(&value2));
+        assert!(!value1.structural_eq(&Value::from(vec![1i32, 2i32])));
+        assert!(Value::Int(42).structural_eq(&Value::Int(42)));
+        assert!(!Value::Int(42).structural_eq(&Value::Long(42)));
+        assert!(Value::Object(None).structural_eq(&Value::Object(None)));
+    }
+
     #[test]
     fn test_int_format() -> Result<()> {
         let value = Value::Int(42);
@@ -745,6 +842,16 @@ mod tests {
         assert!(matches!(result, Err(InvalidValueType(_))));
     }
 
+    #[test]
+    fn test_return_address_format() {
+        let value = Value::ReturnAddress(3);
+        assert_eq!("returnAddress(3)", value.to_string());
+        assert!(value.is_category_1());
+        assert!(!value.is_category_2());
+        assert!(value.to_int().is_err());
+        assert!(value.to_reference().is_err());
+    }
+
     #[test]
     fn test_unused_format() {
         let value = Value::Unused;
@@ -1057,6 +1164,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_into_vec_type_mismatch() {
+        let value = Value::from(vec![42i8]);
+        let result: Result<Vec<i32>> = value.try_into();
+        assert!(result.is_err());
+        let result: Result<Vec<i8>> = Value::Int(42).try_into();
+        assert!(result.is_err());
+        let result: Result<Vec<char>> = Value::Object(None).try_into();
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn test_try_into_vec_i32() -> Result<()> {
         let original_value = vec![42i32];
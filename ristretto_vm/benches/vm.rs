@@ -0,0 +1,83 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use ristretto_classloader::ClassPath;
+use ristretto_vm::Error::InternalError;
+use ristretto_vm::{Configuration, ConfigurationBuilder, Result, VM};
+use std::path::PathBuf;
+use tokio::runtime::Runtime;
+
+fn benchmarks(criterion: &mut Criterion) {
+    bench_boot(criterion).ok();
+    bench_invoke(criterion).ok();
+}
+
+/// Get a configuration with the test classes on the class path.
+fn configuration() -> Result<Configuration> {
+    let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let classes_directory = cargo_manifest.join("../classes");
+    let class_path = ClassPath::from(classes_directory.to_string_lossy());
+    ConfigurationBuilder::new().class_path(class_path).build()
+}
+
+fn bench_boot(criterion: &mut Criterion) -> Result<()> {
+    let runtime = Runtime::new().map_err(|error| InternalError(error.to_string()))?;
+    let mut group = criterion.benchmark_group("boot");
+    // Booting initializes the JDK and takes long enough that fewer samples are needed
+    group.sample_size(10);
+    group.bench_function("vm_boot", |bencher| {
+        bencher.iter(|| {
+            runtime.block_on(async {
+                let Ok(configuration) = configuration() else {
+                    return;
+                };
+                let _ = VM::new(configuration).await.ok();
+            });
+        });
+    });
+    group.finish();
+    Ok(())
+}
+
+fn bench_invoke(criterion: &mut Criterion) -> Result<()> {
+    let runtime = Runtime::new().map_err(|error| InternalError(error.to_string()))?;
+    let vm = runtime.block_on(async { VM::new(configuration()?).await })?;
+
+    criterion.bench_function("invokestatic_loop", |bencher| {
+        bencher.iter(|| {
+            runtime.block_on(async {
+                let _ = vm
+                    .invoke("StaticCall", "loop", "(I)I", vec![1_000])
+                    .await
+                    .ok();
+            });
+        });
+    });
+    criterion.bench_function("arithmetic_loop", |bencher| {
+        bencher.iter(|| {
+            runtime.block_on(async {
+                let _ = vm
+                    .invoke("StaticCall", "arithmetic", "(I)I", vec![1_000])
+                    .await
+                    .ok();
+            });
+        });
+    });
+    criterion.bench_function("invokevirtual_loop", |bencher| {
+        bencher.iter(|| {
+            runtime.block_on(async {
+                let _ = vm
+                    .invoke("VirtualCall", "dispatch", "(I)I", vec![1_000])
+                    .await
+                    .ok();
+            });
+        });
+    });
+
+    Ok(())
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default();
+    targets = benchmarks
+);
+criterion_main!(benches);
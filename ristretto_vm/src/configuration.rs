@@ -1,12 +1,20 @@
 use crate::Error::InternalError;
-use crate::Result;
+use crate::{HostIo, Result, StdHostIo};
 use ristretto_classloader::{ClassPath, DEFAULT_JAVA_VERSION};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::string::ToString;
+use std::sync::Arc;
+use sysinfo::{MemoryRefreshKind, RefreshKind, System};
+
+/// The default maximum number of elements in an array; this matches the limit used by `HotSpot`.
+const DEFAULT_MAX_ARRAY_LENGTH: usize = 2_147_483_645;
+
+/// The maximum heap size used when the physical memory of the host cannot be determined.
+const FALLBACK_MAX_MEMORY: u64 = 256 * 1024 * 1024;
 
 /// Configuration
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub struct Configuration {
     class_path: ClassPath,
     main_class: Option<String>,
@@ -14,6 +22,11 @@ pub struct Configuration {
     java_home: Option<PathBuf>,
     java_version: Option<String>,
     system_properties: HashMap<String, String>,
+    preload_classes: Vec<String>,
+    max_array_length: usize,
+    max_memory: u64,
+    offline: bool,
+    host_io: Arc<dyn HostIo>,
 }
 
 /// Configuration
@@ -53,6 +66,54 @@ impl Configuration {
     pub fn system_properties(&self) -> &HashMap<String, String> {
         &self.system_properties
     }
+
+    /// Get the classes to preload when the VM is initialized
+    #[must_use]
+    pub fn preload_classes(&self) -> &[String] {
+        &self.preload_classes
+    }
+
+    /// Get the maximum number of elements that can be allocated for an array
+    #[must_use]
+    pub fn max_array_length(&self) -> usize {
+        self.max_array_length
+    }
+
+    /// Get the maximum heap size in bytes
+    #[must_use]
+    pub fn max_memory(&self) -> u64 {
+        self.max_memory
+    }
+
+    /// Returns true if the Java runtime must already be installed; when false, a runtime that is
+    /// not installed will be downloaded
+    #[must_use]
+    pub fn offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Get the host I/O used for file and standard stream access
+    #[must_use]
+    pub fn host_io(&self) -> &Arc<dyn HostIo> {
+        &self.host_io
+    }
+}
+
+/// Configurations are equal if all settings are equal and they share the same host I/O
+impl PartialEq for Configuration {
+    fn eq(&self, other: &Self) -> bool {
+        self.class_path == other.class_path
+            && self.main_class == other.main_class
+            && self.jar == other.jar
+            && self.java_home == other.java_home
+            && self.java_version == other.java_version
+            && self.system_properties == other.system_properties
+            && self.preload_classes == other.preload_classes
+            && self.max_array_length == other.max_array_length
+            && self.max_memory == other.max_memory
+            && self.offline == other.offline
+            && Arc::ptr_eq(&self.host_io, &other.host_io)
+    }
 }
 
 /// Configuration builder
@@ -64,6 +125,11 @@ pub struct ConfigurationBuilder {
     java_home: Option<PathBuf>,
     java_version: Option<String>,
     system_properties: HashMap<String, String>,
+    preload_classes: Vec<String>,
+    max_array_length: usize,
+    max_memory: Option<u64>,
+    offline: bool,
+    host_io: Option<Arc<dyn HostIo>>,
 }
 
 /// Configuration builder
@@ -78,6 +144,11 @@ impl ConfigurationBuilder {
             java_home: None,
             java_version: None,
             system_properties: HashMap::new(),
+            preload_classes: Vec::new(),
+            max_array_length: DEFAULT_MAX_ARRAY_LENGTH,
+            max_memory: None,
+            offline: false,
+            host_io: None,
         }
     }
 
@@ -136,6 +207,47 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// Set the classes to preload when the VM is initialized (e.g. "java.util.ArrayList")
+    #[must_use]
+    pub fn preload_classes(mut self, classes: Vec<String>) -> Self {
+        self.preload_classes = classes;
+        self
+    }
+
+    /// Set the maximum number of elements that can be allocated for an array; larger requests
+    /// throw an `OutOfMemoryError`
+    #[must_use]
+    pub fn max_array_length(mut self, max_array_length: usize) -> Self {
+        self.max_array_length = max_array_length;
+        self
+    }
+
+    /// Set the maximum heap size in bytes (e.g. the value of `-Xmx`); defaults to one quarter of
+    /// the physical memory of the host, as `HotSpot` does
+    #[must_use]
+    pub fn max_memory(mut self, max_memory: u64) -> Self {
+        self.max_memory = Some(max_memory);
+        self
+    }
+
+    /// Set whether the VM must run without network access; when enabled, creating the VM fails
+    /// if the Java version is not already installed instead of downloading it.  The Java version
+    /// must exactly match the installed version (e.g. "21.0.5.11.1").  A Java home is always used
+    /// as is.
+    #[must_use]
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Set the host I/O used for file and standard stream access; defaults to [`StdHostIo`],
+    /// which uses the real file system and the standard streams of the process
+    #[must_use]
+    pub fn host_io(mut self, host_io: Arc<dyn HostIo>) -> Self {
+        self.host_io = Some(host_io);
+        self
+    }
+
     /// Build the configuration
     ///
     /// # Errors
@@ -168,6 +280,11 @@ impl ConfigurationBuilder {
             java_home,
             java_version,
             system_properties: self.system_properties,
+            preload_classes: self.preload_classes,
+            max_array_length: self.max_array_length,
+            max_memory: self.max_memory.unwrap_or_else(default_max_memory),
+            offline: self.offline,
+            host_io: self.host_io.unwrap_or_else(|| Arc::new(StdHostIo)),
         })
     }
 }
@@ -180,9 +297,21 @@ impl Default for ConfigurationBuilder {
     }
 }
 
+/// Get the default maximum heap size; one quarter of the physical memory of the host.
+fn default_max_memory() -> u64 {
+    let refresh_kind = RefreshKind::nothing().with_memory(MemoryRefreshKind::nothing().with_ram());
+    let total_memory = System::new_with_specifics(refresh_kind).total_memory();
+    if total_memory == 0 {
+        FALLBACK_MAX_MEMORY
+    } else {
+        total_memory / 4
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test::MemoryHostIo;
 
     #[test]
     fn test_configuration_builder() -> Result<()> {
@@ -196,6 +325,10 @@ mod tests {
         assert_eq!(Some(&"Foo".to_string()), configuration.main_class());
         assert_eq!(Some(&PathBuf::from("test.jar")), configuration.jar());
         assert_eq!(Some(&"21".to_string()), configuration.java_version());
+        assert_eq!(DEFAULT_MAX_ARRAY_LENGTH, configuration.max_array_length());
+        assert_eq!(default_max_memory(), configuration.max_memory());
+        assert!(configuration.max_memory() > 0);
+        assert!(!configuration.offline());
         Ok(())
     }
 
@@ -254,4 +387,47 @@ mod tests {
         assert_eq!(Some(&"2".to_string()), system_properties.get("b"));
         Ok(())
     }
+
+    #[test]
+    fn test_configuration_builder_preload_classes() -> Result<()> {
+        let configuration = ConfigurationBuilder::new()
+            .preload_classes(vec!["java.util.ArrayList".to_string()])
+            .build()?;
+        assert_eq!(
+            &["java.util.ArrayList".to_string()],
+            configuration.preload_classes()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration_builder_max_array_length() -> Result<()> {
+        let configuration = ConfigurationBuilder::new().max_array_length(42).build()?;
+        assert_eq!(42, configuration.max_array_length());
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration_builder_max_memory() -> Result<()> {
+        let configuration = ConfigurationBuilder::new().max_memory(1024).build()?;
+        assert_eq!(1024, configuration.max_memory());
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration_builder_offline() -> Result<()> {
+        let configuration = ConfigurationBuilder::new().offline(true).build()?;
+        assert!(configuration.offline());
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration_builder_host_io() -> Result<()> {
+        let host_io: Arc<dyn HostIo> = Arc::new(MemoryHostIo::default());
+        let configuration = ConfigurationBuilder::new()
+            .host_io(host_io.clone())
+            .build()?;
+        assert!(Arc::ptr_eq(&host_io, configuration.host_io()));
+        Ok(())
+    }
 }
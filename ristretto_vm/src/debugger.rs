@@ -0,0 +1,320 @@
+use crate::{Frame, Result, Thread};
+use ristretto_classfile::attributes::Instruction;
+use ristretto_classloader::Value;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fmt::Debug;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// The location of an instruction at which execution stops and control is passed to the
+/// [`Debugger`] of the VM.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Breakpoint {
+    /// The name of the class (e.g. "java/lang/Object")
+    pub class_name: String,
+    /// The name of the method (e.g. "hashCode")
+    pub method_name: String,
+    /// The descriptor of the method (e.g. "()I")
+    pub method_descriptor: String,
+    /// The index of the instruction in the code of the method
+    pub program_counter: usize,
+}
+
+impl Breakpoint {
+    /// Create a new breakpoint at the instruction of a method.
+    pub fn new<C, M, D>(
+        class_name: C,
+        method_name: M,
+        method_descriptor: D,
+        program_counter: usize,
+    ) -> Self
+    where
+        C: AsRef<str>,
+        M: AsRef<str>,
+        D: AsRef<str>,
+    {
+        Self {
+            class_name: class_name.as_ref().to_string(),
+            method_name: method_name.as_ref().to_string(),
+            method_descriptor: method_descriptor.as_ref().to_string(),
+            program_counter,
+        }
+    }
+}
+
+/// What a thread does after the [`Debugger`] returns control to it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DebugAction {
+    /// Execute the instruction and stop at the next breakpoint.
+    #[default]
+    Continue,
+    /// Execute the instruction and stop before the next instruction the thread executes.
+    Step,
+    /// Execute the instruction and run without stopping; breakpoints are ignored by the thread.
+    Resume,
+}
+
+/// The state of a frame that is stopped before executing an instruction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebugFrame {
+    /// The identifier of the thread executing the frame
+    pub thread_id: u64,
+    /// The name of the class of the method
+    pub class_name: String,
+    /// The name of the method
+    pub method_name: String,
+    /// The descriptor of the method
+    pub method_descriptor: String,
+    /// The index of the instruction that is about to be executed
+    pub program_counter: usize,
+    /// The instruction that is about to be executed
+    pub instruction: Instruction,
+    /// The local variables of the frame
+    pub locals: Vec<Value>,
+    /// The names and values of the local variables in scope, ordered by slot; empty if the class
+    /// was compiled without a `LocalVariableTable` (e.g. without `javac -g`)
+    pub named_locals: Vec<(String, Value)>,
+    /// The operand stack of the frame, from the bottom to the top of the stack
+    pub stack: Vec<Value>,
+}
+
+/// Receives control when a thread stops at a breakpoint or while single-stepping.
+///
+/// The debugger is called synchronously from the executor of the stopped thread, which does not
+/// continue until the debugger returns; the debugger must not call back into the VM.
+pub trait Debugger: Send + Sync {
+    /// Called before the instruction of the frame is executed; returns what the thread does next.
+    fn on_stop(&self, frame: &DebugFrame) -> DebugAction;
+}
+
+/// The program counters of the breakpoints, by class name, method name and method descriptor.
+type Breakpoints = HashMap<String, HashMap<String, HashMap<String, HashSet<usize>>>>;
+
+/// The debugger and breakpoints of a VM.
+#[derive(Default)]
+pub(crate) struct DebugState {
+    debugger: RwLock<Option<Arc<dyn Debugger>>>,
+    breakpoints: RwLock<Breakpoints>,
+}
+
+impl DebugState {
+    /// Create a new debug state with no debugger and no breakpoints.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the debugger that receives control when a thread stops.
+    pub(crate) fn set_debugger(&self, debugger: Arc<dyn Debugger>) {
+        let mut current = self
+            .debugger
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        *current = Some(debugger);
+    }
+
+    /// Add a breakpoint; returns false if the breakpoint already exists.
+    pub(crate) fn add_breakpoint(&self, breakpoint: Breakpoint) -> bool {
+        let mut breakpoints = self
+            .breakpoints
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        breakpoints
+            .entry(breakpoint.class_name)
+            .or_default()
+            .entry(breakpoint.method_name)
+            .or_default()
+            .entry(breakpoint.method_descriptor)
+            .or_default()
+            .insert(breakpoint.program_counter)
+    }
+
+    /// Remove a breakpoint; returns false if the breakpoint does not exist.
+    pub(crate) fn remove_breakpoint(&self, breakpoint: &Breakpoint) -> bool {
+        let mut breakpoints = self
+            .breakpoints
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        breakpoints
+            .get_mut(&breakpoint.class_name)
+            .and_then(|methods| methods.get_mut(&breakpoint.method_name))
+            .and_then(|descriptors| descriptors.get_mut(&breakpoint.method_descriptor))
+            .is_some_and(|program_counters| program_counters.remove(&breakpoint.program_counter))
+    }
+
+    /// Returns true if there is a breakpoint at the instruction of the method.
+    fn is_breakpoint(
+        &self,
+        class_name: &str,
+        method_name: &str,
+        method_descriptor: &str,
+        program_counter: usize,
+    ) -> bool {
+        let breakpoints = self
+            .breakpoints
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        breakpoints
+            .get(class_name)
+            .and_then(|methods| methods.get(method_name))
+            .and_then(|descriptors| descriptors.get(method_descriptor))
+            .is_some_and(|program_counters| program_counters.contains(&program_counter))
+    }
+
+    /// Pass control to the debugger if the thread stops before the instruction of the frame; the
+    /// thread stops at breakpoints, or at every instruction while it is single-stepping.
+    ///
+    /// # Errors
+    /// if the state of the frame cannot be read.
+    pub(crate) fn stop_at(
+        &self,
+        thread: &Thread,
+        frame: &Frame,
+        program_counter: usize,
+        instruction: &Instruction,
+    ) -> Result<()> {
+        let Some(debugger) = self
+            .debugger
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+        else {
+            return Ok(());
+        };
+
+        let class = frame.class();
+        let method = frame.method();
+        let stop = match thread.debug_action() {
+            DebugAction::Continue => self.is_breakpoint(
+                class.name(),
+                method.name(),
+                method.descriptor(),
+                program_counter,
+            ),
+            DebugAction::Step => true,
+            DebugAction::Resume => false,
+        };
+        if !stop {
+            return Ok(());
+        }
+
+        let debug_frame = DebugFrame {
+            thread_id: thread.id(),
+            class_name: class.name().to_string(),
+            method_name: method.name().to_string(),
+            method_descriptor: method.descriptor().to_string(),
+            program_counter,
+            instruction: instruction.clone(),
+            locals: frame.local_values()?,
+            named_locals: frame.named_locals()?,
+            stack: frame.stack_values()?,
+        };
+        let action = debugger.on_stop(&debug_frame);
+        thread.set_debug_action(action);
+        Ok(())
+    }
+}
+
+impl Debug for DebugState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let debugger = self
+            .debugger
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_some();
+        let breakpoints = self
+            .breakpoints
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .flat_map(HashMap::values)
+            .flat_map(HashMap::values)
+            .map(HashSet::len)
+            .sum::<usize>();
+        f.debug_struct("DebugState")
+            .field("debugger", &debugger)
+            .field("breakpoints", &breakpoints)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::isolated_vm;
+    use crate::StdHostIo;
+    use std::sync::Mutex;
+
+    /// A debugger that records the frames it is stopped at and returns the actions in order.
+    #[derive(Default)]
+    struct RecordingDebugger {
+        frames: Mutex<Vec<DebugFrame>>,
+        actions: Mutex<Vec<DebugAction>>,
+    }
+
+    impl Debugger for RecordingDebugger {
+        fn on_stop(&self, frame: &DebugFrame) -> DebugAction {
+            self.frames.lock().expect("frames").push(frame.clone());
+            let mut actions = self.actions.lock().expect("actions");
+            if actions.is_empty() {
+                DebugAction::Continue
+            } else {
+                actions.remove(0)
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_breakpoint() {
+        let debug_state = DebugState::new();
+        let breakpoint = Breakpoint::new("Debugging", "add", "(II)I", 2);
+        assert!(debug_state.add_breakpoint(breakpoint.clone()));
+        assert!(!debug_state.add_breakpoint(breakpoint.clone()));
+        assert!(debug_state.is_breakpoint("Debugging", "add", "(II)I", 2));
+        assert!(!debug_state.is_breakpoint("Debugging", "add", "(II)I", 3));
+        assert!(debug_state.remove_breakpoint(&breakpoint));
+        assert!(!debug_state.remove_breakpoint(&breakpoint));
+        assert!(!debug_state.is_breakpoint("Debugging", "add", "(II)I", 2));
+    }
+
+    #[tokio::test]
+    async fn test_breakpoint_and_step() -> Result<()> {
+        let vm = isolated_vm(Arc::new(StdHostIo)).await?;
+        let debugger = Arc::new(RecordingDebugger {
+            actions: Mutex::new(vec![DebugAction::Step, DebugAction::Continue]),
+            ..Default::default()
+        });
+        vm.set_debugger(debugger.clone());
+        // Stop before the iadd instruction of `int sum = a + b`
+        vm.add_breakpoint(Breakpoint::new("Debugging", "add", "(II)I", 2));
+
+        let result = vm
+            .invoke(
+                "Debugging",
+                "add",
+                "(II)I",
+                vec![Value::Int(1), Value::Int(2)],
+            )
+            .await?;
+        assert_eq!(Some(Value::Int(3)), result);
+
+        let frames = debugger.frames.lock().expect("frames");
+        assert_eq!(2, frames.len());
+        let frame = &frames[0];
+        assert_eq!("Debugging", frame.class_name);
+        assert_eq!("add", frame.method_name);
+        assert_eq!("(II)I", frame.method_descriptor);
+        assert_eq!(2, frame.program_counter);
+        assert_eq!(Instruction::Iadd, frame.instruction);
+        assert_eq!(vec![Value::Int(1), Value::Int(2)], frame.locals[..2]);
+        // Debugging is compiled without debug information
+        assert!(frame.named_locals.is_empty());
+        assert_eq!(vec![Value::Int(1), Value::Int(2)], frame.stack);
+
+        // The step stops at the next instruction, after which the thread continues
+        let frame = &frames[1];
+        assert_eq!(3, frame.program_counter);
+        assert_eq!(Instruction::Istore_2, frame.instruction);
+        assert_eq!(vec![Value::Int(3)], frame.stack);
+        Ok(())
+    }
+}
@@ -15,18 +15,46 @@ pub enum Error {
     /// An error occurred while loading a class
     #[error(transparent)]
     ClassLoaderError(#[from] ristretto_classloader::Error),
+    /// One or more classes could not be preloaded
+    #[error("Unable to preload classes: {}", .0.join(", "))]
+    ClassPreloadError(Vec<String>),
     /// Configuration error
     #[error("Configuration error: {0}")]
     ConfigurationError(String),
     /// Internal error
     #[error("Internal error: {0}")]
     InternalError(String),
-    /// Invalid constant
-    #[error("Invalid constant; expected {expected}, found {actual}")]
-    InvalidConstant { expected: String, actual: String },
-    /// Invalid constant index
-    #[error("Invalid instant index: {0}")]
-    InvalidConstantIndex(u16),
+    /// Invalid method argument; for instance methods, argument 0 is the object the method is
+    /// invoked on
+    #[error("Invalid argument {index} for {method}; expected {expected}, found {actual}")]
+    InvalidArgument {
+        method: String,
+        index: usize,
+        expected: String,
+        actual: String,
+    },
+    /// Invalid number of method arguments
+    #[error("Invalid number of arguments for {method}; expected {expected}, found {actual}")]
+    InvalidArgumentCount {
+        method: String,
+        expected: usize,
+        actual: usize,
+    },
+    /// Invalid constant; the class name is the class whose constant pool contains the constant,
+    /// if known
+    #[error("Invalid constant{}; expected {expected}, found {actual}", in_class(.class_name.as_deref()))]
+    InvalidConstant {
+        expected: String,
+        actual: String,
+        class_name: Option<String>,
+    },
+    /// Invalid constant index; the class name is the class whose constant pool was indexed, if
+    /// known
+    #[error("Invalid constant index {index}{}", in_class(.class_name.as_deref()))]
+    InvalidConstantIndex {
+        index: u16,
+        class_name: Option<String>,
+    },
     /// Invalid local variable
     #[error("Invalid local variable; expected {expected}, found {actual}")]
     InvalidLocalVariable { expected: String, actual: String },
@@ -67,3 +95,10 @@ pub enum Error {
     #[error("Unsupported class file version: {0}")]
     UnsupportedClassFileVersion(u16),
 }
+
+/// Format the class an error occurred in, if known.
+fn in_class(class_name: Option<&str>) -> String {
+    class_name
+        .map(|class_name| format!(" in class {class_name}"))
+        .unwrap_or_default()
+}
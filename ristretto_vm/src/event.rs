@@ -0,0 +1,124 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// An event emitted by the VM.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VmEvent {
+    /// A class was loaded (e.g. "java/lang/Object")
+    ClassLoaded { class_name: String },
+    /// The static initializer of a class completed
+    ClassInitialized { class_name: String },
+    /// A thread was created
+    ThreadStarted { thread_id: u64 },
+    /// An exception was thrown by the instruction at `program_counter` of a method
+    ExceptionThrown {
+        exception_class_name: String,
+        class_name: String,
+        method_name: String,
+        program_counter: usize,
+    },
+}
+
+/// A listener that receives events from the VM.
+///
+/// Events are delivered synchronously on the thread that emits them, so listeners should return
+/// quickly and must not call back into the VM.
+pub trait VmEventListener: Send + Sync {
+    /// Called when the VM emits an event.
+    fn on_event(&self, event: &VmEvent);
+}
+
+/// Delivers VM events to the subscribed listeners.
+#[derive(Default)]
+pub(crate) struct EventBus {
+    listeners: RwLock<Vec<Arc<dyn VmEventListener>>>,
+}
+
+impl EventBus {
+    /// Create a new event bus with no listeners.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a listener that will receive all subsequent events.
+    pub(crate) fn subscribe(&self, listener: Arc<dyn VmEventListener>) {
+        let mut listeners = self
+            .listeners
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        listeners.push(listener);
+    }
+
+    /// Returns true if there are any listeners; this allows callers to avoid building events that
+    /// would not be delivered.
+    pub(crate) fn has_listeners(&self) -> bool {
+        let listeners = self
+            .listeners
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        !listeners.is_empty()
+    }
+
+    /// Deliver an event to all listeners.  Delivery is best-effort; a listener that panicked while
+    /// the listeners were being modified does not prevent delivery to the others.
+    pub(crate) fn publish(&self, event: &VmEvent) {
+        let listeners = self
+            .listeners
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        for listener in listeners {
+            listener.on_event(event);
+        }
+    }
+}
+
+impl Debug for EventBus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let listeners = self
+            .listeners
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len();
+        f.debug_struct("EventBus")
+            .field("listeners", &listeners)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingListener {
+        events: Mutex<Vec<VmEvent>>,
+    }
+
+    impl VmEventListener for RecordingListener {
+        fn on_event(&self, event: &VmEvent) {
+            self.events.lock().expect("events").push(event.clone());
+        }
+    }
+
+    #[test]
+    fn test_publish() {
+        let event_bus = EventBus::new();
+        assert!(!event_bus.has_listeners());
+        let listener = Arc::new(RecordingListener::default());
+        event_bus.subscribe(listener.clone());
+        assert!(event_bus.has_listeners());
+
+        let event = VmEvent::ThreadStarted { thread_id: 1 };
+        event_bus.publish(&event);
+        assert_eq!(vec![event], *listener.events.lock().expect("events"));
+    }
+
+    #[test]
+    fn test_debug() {
+        let event_bus = EventBus::new();
+        assert_eq!("EventBus { listeners: 0 }", format!("{event_bus:?}"));
+    }
+}
@@ -0,0 +1,218 @@
+use crate::Error::PoisonedLock;
+use crate::JavaError::IOException;
+use crate::{HostFile, Result};
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs::ReadDir;
+use std::io::{Read, Write};
+use std::sync::Mutex;
+
+/// The first handle assigned to an opened file; handles 0, 1 and 2 are reserved for stdin, stdout
+/// and stderr.
+const FIRST_HANDLE: i64 = 3;
+
+/// Files opened by Java code (e.g. `FileOutputStream.open0`) through the host I/O of the VM,
+/// addressed by the handle stored in the `fd` field of the associated `java.io.FileDescriptor`.
+/// Directory streams (e.g. `UnixNativeDispatcher.opendir0`) share the same handle space.
+#[derive(Debug)]
+pub(crate) struct FileHandles {
+    inner: Mutex<Handles>,
+}
+
+#[derive(Debug)]
+struct Handles {
+    next_handle: i64,
+    files: HashMap<i64, Box<dyn HostFile>>,
+    directories: HashMap<i64, ReadDir>,
+}
+
+impl FileHandles {
+    /// Create a new file handle table with no open files.
+    pub(crate) fn new() -> Self {
+        Self {
+            inner: Mutex::new(Handles {
+                next_handle: FIRST_HANDLE,
+                files: HashMap::new(),
+                directories: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Add an opened file and return its handle.
+    ///
+    /// # Errors
+    /// if the lock is poisoned.
+    pub(crate) fn insert(&self, file: Box<dyn HostFile>) -> Result<i64> {
+        let mut files = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let handle = files.next_handle;
+        files.next_handle += 1;
+        files.files.insert(handle, file);
+        Ok(handle)
+    }
+
+    /// Read bytes from the file with the given handle into the buffer; returns the number of bytes
+    /// read or 0 at the end of the file.
+    ///
+    /// # Errors
+    /// if the file is not open, the bytes cannot be read or the lock is poisoned.
+    pub(crate) fn read(&self, handle: i64, buffer: &mut [u8]) -> Result<usize> {
+        let mut files = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let Some(file) = files.files.get_mut(&handle) else {
+            return Err(IOException("Stream Closed".to_string()).into());
+        };
+        let length = file
+            .read(buffer)
+            .map_err(|error| IOException(error.to_string()))?;
+        Ok(length)
+    }
+
+    /// Write all the bytes to the file with the given handle.
+    ///
+    /// # Errors
+    /// if the file is not open, the bytes cannot be written or the lock is poisoned.
+    pub(crate) fn write(&self, handle: i64, bytes: &[u8]) -> Result<()> {
+        let mut files = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let Some(file) = files.files.get_mut(&handle) else {
+            return Err(IOException("Stream Closed".to_string()).into());
+        };
+        file.write_all(bytes)
+            .map_err(|error| IOException(error.to_string()))?;
+        Ok(())
+    }
+
+    /// Flush and close the file with the given handle; closing a handle that is not open does
+    /// nothing.
+    ///
+    /// # Errors
+    /// if the file cannot be flushed or the lock is poisoned.
+    pub(crate) fn close(&self, handle: i64) -> Result<()> {
+        let mut files = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        if let Some(mut file) = files.files.remove(&handle) {
+            file.flush()
+                .map_err(|error| IOException(error.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Add an opened directory stream and return its handle.
+    ///
+    /// # Errors
+    /// if the lock is poisoned.
+    pub(crate) fn insert_directory(&self, directory: ReadDir) -> Result<i64> {
+        let mut files = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let handle = files.next_handle;
+        files.next_handle += 1;
+        files.directories.insert(handle, directory);
+        Ok(handle)
+    }
+
+    /// Read the file name of the next entry of the directory stream with the given handle; returns
+    /// `None` at the end of the stream.
+    ///
+    /// # Errors
+    /// if the directory stream is not open, the entry cannot be read or the lock is poisoned.
+    pub(crate) fn read_directory(&self, handle: i64) -> Result<Option<OsString>> {
+        let mut files = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let Some(directory) = files.directories.get_mut(&handle) else {
+            return Err(IOException("Directory Closed".to_string()).into());
+        };
+        match directory.next() {
+            Some(Ok(entry)) => Ok(Some(entry.file_name())),
+            Some(Err(error)) => Err(IOException(error.to_string()).into()),
+            None => Ok(None),
+        }
+    }
+
+    /// Close the directory stream with the given handle; closing a handle that is not open does
+    /// nothing.
+    ///
+    /// # Errors
+    /// if the lock is poisoned.
+    pub(crate) fn close_directory(&self, handle: i64) -> Result<()> {
+        let mut files = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        files.directories.remove(&handle);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::MemoryHostIo;
+    use crate::Error::JavaError;
+    use crate::{HostIo, OpenMode};
+
+    #[test]
+    fn test_write_and_close() {
+        let host_io = MemoryHostIo::default();
+        let file_handles = FileHandles::new();
+        let file = host_io.open("test.txt", OpenMode::Write).expect("file");
+        let handle = file_handles.insert(file).expect("insert");
+        assert_eq!(FIRST_HANDLE, handle);
+        file_handles.write(handle, b"foo").expect("write");
+        file_handles.close(handle).expect("close");
+        assert_eq!(Some(b"foo".to_vec()), host_io.file("test.txt"));
+
+        let result = file_handles.write(handle, b"bar");
+        assert!(matches!(result, Err(JavaError(IOException(_)))));
+        file_handles.close(handle).expect("close");
+    }
+
+    #[test]
+    fn test_read() {
+        let host_io = MemoryHostIo::default();
+        let mut file = host_io.open("test.txt", OpenMode::Write).expect("file");
+        file.write_all(b"foo").expect("write");
+        let file_handles = FileHandles::new();
+        let file = host_io.open("test.txt", OpenMode::Read).expect("file");
+        let handle = file_handles.insert(file).expect("insert");
+
+        let mut buffer = [0; 2];
+        assert_eq!(2, file_handles.read(handle, &mut buffer).expect("read"));
+        assert_eq!(b"fo", &buffer);
+        assert_eq!(1, file_handles.read(handle, &mut buffer).expect("read"));
+        assert_eq!(b'o', buffer[0]);
+        assert_eq!(0, file_handles.read(handle, &mut buffer).expect("read"));
+
+        file_handles.close(handle).expect("close");
+        let result = file_handles.read(handle, &mut buffer);
+        assert!(matches!(result, Err(JavaError(IOException(_)))));
+    }
+
+    #[test]
+    fn test_read_directory() {
+        let directory = tempfile::tempdir().expect("temp dir");
+        std::fs::write(directory.path().join("a.txt"), b"a").expect("write");
+        let file_handles = FileHandles::new();
+        let read_dir = std::fs::read_dir(directory.path()).expect("read dir");
+        let handle = file_handles.insert_directory(read_dir).expect("insert");
+
+        let file_name = file_handles.read_directory(handle).expect("read");
+        assert_eq!(Some(OsString::from("a.txt")), file_name);
+        assert_eq!(None, file_handles.read_directory(handle).expect("read"));
+        file_handles.close_directory(handle).expect("close");
+        let result = file_handles.read_directory(handle);
+        assert!(matches!(result, Err(JavaError(IOException(_)))));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::event::VmEvent;
 use crate::frame::ExecutionResult::{Continue, ContinueAtPosition, Return};
 use crate::instruction::{
     aaload, aastore, aconst_null, aload, aload_0, aload_1, aload_2, aload_3, aload_w, anewarray,
@@ -17,16 +18,20 @@ use crate::instruction::{
     istore_1, istore_2, istore_3, istore_w, isub, iushr, ixor, jsr, jsr_w, l2d, l2f, l2i, ladd,
     laload, land, lastore, lcmp, lconst_0, lconst_1, ldc, ldc2_w, ldc_w, ldiv, lload, lload_0,
     lload_1, lload_2, lload_3, lload_w, lmul, lneg, lookupswitch, lor, lrem, lreturn, lshl, lshr,
-    lstore, lstore_0, lstore_1, lstore_2, lstore_3, lstore_w, lsub, lushr, lxor, multianewarray,
-    new, newarray, pop, pop2, process_throwable, putfield, putstatic, r#return, ret, ret_w, saload,
-    sastore, sipush, swap, tableswitch,
+    lstore, lstore_0, lstore_1, lstore_2, lstore_3, lstore_w, lsub, lushr, lxor, monitorenter,
+    monitorexit, multianewarray, new, newarray, pop, pop2, process_throwable, putfield, putstatic,
+    r#return, ret, ret_w, saload, sastore, sipush, swap, tableswitch,
 };
-use crate::Error::{InternalError, InvalidOperand, InvalidProgramCounter};
-use crate::{LocalVariables, OperandStack, Result, Thread};
+use crate::Error::{InternalError, InvalidOperand, InvalidProgramCounter, Throwable};
+use crate::{LocalVariables, OperandStack, Result, Thread, VM};
 use async_recursion::async_recursion;
 use byte_unit::{Byte, UnitType};
+#[cfg(feature = "debug")]
+use ristretto_classfile::attributes::Attribute;
 use ristretto_classfile::attributes::Instruction;
-use ristretto_classloader::{Class, Method, Value};
+use ristretto_classloader::{Class, Method, Object, Value};
+#[cfg(feature = "debug")]
+use std::io::Cursor;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Weak};
 use tracing::{debug, event_enabled, Level};
@@ -61,7 +66,11 @@ impl Frame {
         method: &Arc<Method>,
         arguments: Vec<Value>,
     ) -> Result<Self> {
-        let max_locals = method.max_locals();
+        // Methods without code (e.g. native methods) do not declare max_locals; ensure there is
+        // room for the arguments, where long and double arguments occupy two slots.
+        let max_locals = method
+            .max_locals()
+            .max(usize::from(method.argument_slots()));
         let locals = LocalVariables::with_max_size(max_locals);
         for (index, argument) in arguments.into_iter().enumerate() {
             locals.set(index, argument)?;
@@ -120,6 +129,80 @@ impl Frame {
         self.program_counter.load(Ordering::Relaxed)
     }
 
+    /// Get the values of the local variables in this frame
+    ///
+    /// # Errors
+    /// if the local variables cannot be accessed.
+    #[cfg(feature = "debug")]
+    pub(crate) fn local_values(&self) -> Result<Vec<Value>> {
+        self.locals.to_vec()
+    }
+
+    /// Get the values on the operand stack in this frame, from the bottom to the top of the stack
+    ///
+    /// # Errors
+    /// if the operand stack cannot be accessed.
+    #[cfg(feature = "debug")]
+    pub(crate) fn stack_values(&self) -> Result<Vec<Value>> {
+        self.stack.to_vec()
+    }
+
+    /// Get the names and values of the local variables in scope at the current program counter,
+    /// ordered by slot.  Names come from the `LocalVariableTable` attribute, so no variables are
+    /// returned for classes compiled without debug information (e.g. without `javac -g`).
+    ///
+    /// # Errors
+    /// if the local variable table references an invalid constant or local variable.
+    #[cfg(feature = "debug")]
+    pub(crate) fn named_locals(&self) -> Result<Vec<(String, Value)>> {
+        let class_file = self.class.class_file();
+        let constant_pool = &class_file.constant_pool;
+        let Some(definition) = class_file.methods.iter().find(|definition| {
+            constant_pool
+                .try_get_utf8(definition.name_index)
+                .is_ok_and(|name| name == self.method.name())
+                && constant_pool
+                    .try_get_utf8(definition.descriptor_index)
+                    .is_ok_and(|descriptor| descriptor == self.method.descriptor())
+        }) else {
+            return Ok(Vec::new());
+        };
+
+        // The ranges of the local variable table are byte offsets, while the program counter is
+        // the index of the instruction
+        let mut code_bytes = Cursor::new(Vec::new());
+        for instruction in self.method.code().iter().take(self.program_counter()) {
+            instruction.to_bytes(&mut code_bytes)?;
+        }
+        let byte_offset = usize::try_from(code_bytes.position())?;
+        let mut named_locals = Vec::new();
+        for attribute in &definition.attributes {
+            let Attribute::Code { attributes, .. } = attribute else {
+                continue;
+            };
+            for attribute in attributes {
+                let Attribute::LocalVariableTable { variables, .. } = attribute else {
+                    continue;
+                };
+                for variable in variables {
+                    let start = usize::from(variable.start_pc);
+                    let end = start + usize::from(variable.length);
+                    if !(start..end).contains(&byte_offset) {
+                        continue;
+                    }
+                    let name = constant_pool.try_get_utf8(variable.name_index)?;
+                    let value = self.locals.get(usize::from(variable.index))?;
+                    named_locals.push((variable.index, name.clone(), Value::clone(&value)));
+                }
+            }
+        }
+        named_locals.sort_by_key(|(index, _, _)| *index);
+        Ok(named_locals
+            .into_iter()
+            .map(|(_, name, value)| (name, value))
+            .collect())
+    }
+
     /// Execute the method in this frame
     ///
     /// # Errors
@@ -138,6 +221,8 @@ impl Frame {
             if event_enabled!(Level::DEBUG) {
                 self.debug_execute(instruction)?;
             }
+            #[cfg(feature = "debug")]
+            self.stop_at(program_counter, instruction)?;
 
             let result = self.process(instruction).await;
             match result {
@@ -152,7 +237,13 @@ impl Frame {
                 Ok(Return(value)) => return Ok(value.clone()),
                 Err(error) => {
                     let vm = self.thread()?.vm()?;
-                    let throwable = convert_error_to_throwable(vm, error).await?;
+                    // Exceptions thrown by athrow are published by the instruction; a throwable
+                    // returned from an invoked method has already been published by its frame.
+                    let is_thrown_here = !matches!(error, Throwable(_));
+                    let throwable = convert_error_to_throwable(vm.clone(), error).await?;
+                    if is_thrown_here {
+                        self.publish_exception_thrown(&vm, &throwable);
+                    }
                     let handler_program_counter = process_throwable(self, throwable).await?;
                     self.program_counter
                         .store(handler_program_counter, Ordering::Relaxed);
@@ -161,6 +252,31 @@ impl Frame {
         }
     }
 
+    /// Pass control to the debugger of the VM if the thread stops before the instruction.
+    ///
+    /// # Errors
+    /// if the thread or VM cannot be accessed.
+    #[cfg(feature = "debug")]
+    fn stop_at(&self, program_counter: usize, instruction: &Instruction) -> Result<()> {
+        let thread = self.thread()?;
+        let vm = thread.vm()?;
+        vm.debug_state()
+            .stop_at(&thread, self, program_counter, instruction)
+    }
+
+    /// Publish an exception thrown event for the current instruction in this frame.
+    pub(crate) fn publish_exception_thrown(&self, vm: &VM, throwable: &Object) {
+        if !vm.has_event_listeners() {
+            return;
+        }
+        vm.publish(&VmEvent::ExceptionThrown {
+            exception_class_name: throwable.class().name().to_string(),
+            class_name: self.class.name().to_string(),
+            method_name: self.method.name().to_string(),
+            program_counter: self.program_counter(),
+        });
+    }
+
     /// Debug the execution of an instruction in this frame
     fn debug_execute(&self, instruction: &Instruction) -> Result<()> {
         let program_counter = self.program_counter();
@@ -356,7 +472,10 @@ impl Frame {
             Instruction::If_acmpeq(address) => if_acmpeq(&self.stack, *address),
             Instruction::If_acmpne(address) => if_acmpne(&self.stack, *address),
             Instruction::Goto(address) => goto(*address),
-            Instruction::Jsr(address) => jsr(&self.stack, *address),
+            Instruction::Jsr(address) => {
+                let program_counter = self.program_counter.load(Ordering::Relaxed);
+                jsr(&self.stack, program_counter, *address)
+            }
             Instruction::Ret(index) => ret(&self.locals, *index),
             Instruction::Tableswitch {
                 default,
@@ -389,20 +508,17 @@ impl Frame {
             }
             Instruction::Invokedynamic(index) => invokedynamic(self, *index).await,
             Instruction::New(index) => new(self, *index).await,
-            Instruction::Newarray(array_type) => newarray(&self.stack, array_type),
+            Instruction::Newarray(array_type) => {
+                let max_array_length = self.thread()?.vm()?.configuration().max_array_length();
+                newarray(&self.stack, array_type, max_array_length)
+            }
             Instruction::Anewarray(index) => anewarray(self, *index).await,
             Instruction::Arraylength => arraylength(&self.stack),
             Instruction::Athrow => athrow(self).await,
             Instruction::Checkcast(class_index) => checkcast(self, *class_index).await,
             Instruction::Instanceof(class_index) => instanceof(self, *class_index).await,
-            Instruction::Monitorenter | Instruction::Monitorexit => {
-                // The monitorenter and monitorexit instructions are not currently used by this
-                // implementation.
-                // See: https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.monitorenter
-                // https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.monitorexit
-                let _ = self.stack.pop_object()?;
-                Ok(Continue)
-            }
+            Instruction::Monitorenter => monitorenter(&self.stack),
+            Instruction::Monitorexit => monitorexit(&self.stack),
             Instruction::Wide => {
                 // The wide instruction is not directly used by this implementation.  The wide
                 // versions of instructions are specifically enumerated in the instruction set.
@@ -418,7 +534,10 @@ impl Frame {
             Instruction::Ifnull(address) => ifnull(&self.stack, *address),
             Instruction::Ifnonnull(address) => ifnonnull(&self.stack, *address),
             Instruction::Goto_w(address) => goto_w(*address),
-            Instruction::Jsr_w(address) => jsr_w(&self.stack, *address),
+            Instruction::Jsr_w(address) => {
+                let program_counter = self.program_counter.load(Ordering::Relaxed);
+                jsr_w(&self.stack, program_counter, *address)
+            }
             Instruction::Breakpoint | Instruction::Impdep1 | Instruction::Impdep2 => {
                 // Breakpoint, Impdep1 and Impdep2 instructions are reserved for debugging and implementation
                 // dependent operations.
@@ -447,8 +566,14 @@ mod tests {
     use super::*;
     use crate::configuration::ConfigurationBuilder;
     use crate::thread::Thread;
+    use crate::Error::Throwable;
     use crate::VM;
-    use ristretto_classloader::ClassPath;
+    #[cfg(feature = "debug")]
+    use ristretto_classfile::attributes::LocalVariableTable;
+    use ristretto_classfile::MethodAccessFlags;
+    #[cfg(feature = "debug")]
+    use ristretto_classfile::{ClassFile, ConstantPool};
+    use ristretto_classloader::{ClassPath, Reference};
     use std::path::PathBuf;
 
     async fn get_class(class_name: &str) -> Result<(Arc<Thread>, Arc<Class>)> {
@@ -475,6 +600,110 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "debug")]
+    #[tokio::test]
+    async fn test_named_locals() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut constant_pool = ConstantPool::default();
+        let this_class = constant_pool.add_class("Debug")?;
+        let code_index = constant_pool.add_utf8("Code")?;
+        let table_index = constant_pool.add_utf8("LocalVariableTable")?;
+        let name_index = constant_pool.add_utf8("test")?;
+        let descriptor_index = constant_pool.add_utf8("(I)V")?;
+        let count_index = constant_pool.add_utf8("count")?;
+        let total_index = constant_pool.add_utf8("total")?;
+        let int_index = constant_pool.add_utf8("I")?;
+        let variable = |name_index, start_pc, length, index| LocalVariableTable {
+            start_pc,
+            length,
+            name_index,
+            descriptor_index: int_index,
+            index,
+        };
+        let code = Attribute::Code {
+            name_index: code_index,
+            max_stack: 2,
+            max_locals: 2,
+            code: vec![
+                Instruction::Iload_0,
+                Instruction::Bipush(1),
+                Instruction::Iadd,
+                Instruction::Istore_1,
+                Instruction::Return,
+            ],
+            exception_table: Vec::new(),
+            // The ranges are byte offsets; bipush is two bytes, so return is at byte offset 5
+            attributes: vec![Attribute::LocalVariableTable {
+                name_index: table_index,
+                variables: vec![
+                    variable(count_index, 0, 6, 0),
+                    variable(total_index, 5, 1, 1),
+                ],
+            }],
+        };
+        let method = ristretto_classfile::Method {
+            access_flags: MethodAccessFlags::STATIC,
+            name_index,
+            descriptor_index,
+            attributes: vec![code],
+        };
+        let class_file = ClassFile {
+            constant_pool,
+            this_class,
+            methods: vec![method],
+            ..Default::default()
+        };
+        let class = Arc::new(Class::from(class_file)?);
+        let method = class.try_get_method("test", "(I)V")?;
+        let frame = Frame::new(
+            &Arc::downgrade(&thread),
+            &class,
+            &method,
+            vec![Value::Int(42)],
+        )?;
+        assert_eq!(
+            vec![("count".to_string(), Value::Int(42))],
+            frame.named_locals()?
+        );
+
+        // Execute up to the return instruction, where total is in scope
+        for program_counter in 0..4 {
+            assert_eq!(
+                Continue,
+                frame.process(&method.code()[program_counter]).await?
+            );
+            frame
+                .program_counter
+                .store(program_counter + 1, Ordering::Relaxed);
+            if program_counter == 0 {
+                assert_eq!(vec![Value::Int(42)], frame.stack_values()?);
+            }
+        }
+        assert_eq!(4, frame.program_counter());
+        assert_eq!(vec![Value::Int(42), Value::Int(43)], frame.local_values()?);
+        assert!(frame.stack_values()?.is_empty());
+        assert_eq!(
+            vec![
+                ("count".to_string(), Value::Int(42)),
+                ("total".to_string(), Value::Int(43))
+            ],
+            frame.named_locals()?
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "debug")]
+    #[tokio::test]
+    async fn test_named_locals_without_debug_information() -> Result<()> {
+        let (thread, class) = get_class("Expressions").await?;
+        let method = class.method("add", "(II)I").expect("method not found");
+        let arguments = vec![Value::Int(1), Value::Int(2)];
+        let frame = Frame::new(&Arc::downgrade(&thread), &class, &method, arguments)?;
+        assert!(frame.named_locals()?.is_empty());
+        assert_eq!(vec![Value::Int(1), Value::Int(2)], frame.local_values()?);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_initial_frame() -> Result<()> {
         let (_vm, _thread, frame) = crate::test::frame().await?;
@@ -496,7 +725,7 @@ mod tests {
     #[tokio::test]
     async fn test_process_monitorenter() -> Result<()> {
         let (_vm, _thread, frame) = crate::test::frame().await?;
-        frame.stack.push_object(None)?;
+        frame.stack.push_object(Some(Reference::from(vec![42i8])))?;
         let process_result = frame.process(&Instruction::Monitorenter).await?;
         assert_eq!(Continue, process_result);
         Ok(())
@@ -505,12 +734,39 @@ mod tests {
     #[tokio::test]
     async fn test_process_monitorexit() -> Result<()> {
         let (_vm, _thread, frame) = crate::test::frame().await?;
-        frame.stack.push_object(None)?;
+        frame.stack.push_object(Some(Reference::from(vec![42i8])))?;
         let process_result = frame.process(&Instruction::Monitorexit).await?;
         assert_eq!(Continue, process_result);
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_execute_monitorenter_null() -> Result<()> {
+        let (_vm, thread, class) = crate::test::class().await?;
+        let method = Method::new(
+            MethodAccessFlags::STATIC,
+            "test",
+            "()V",
+            1,
+            0,
+            vec![Instruction::Aconst_null, Instruction::Monitorenter],
+            Vec::new(),
+            Vec::new(),
+        )?;
+        let frame = Frame::new(
+            &Arc::downgrade(&thread),
+            &class,
+            &Arc::new(method),
+            Vec::new(),
+        )?;
+        let result = frame.execute().await;
+        let Err(Throwable(throwable)) = result else {
+            panic!("expected throwable");
+        };
+        assert_eq!("java/lang/NullPointerException", throwable.class().name());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_process_wide() -> Result<()> {
         let (_vm, _thread, frame) = crate::test::frame().await?;
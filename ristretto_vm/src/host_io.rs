@@ -0,0 +1,121 @@
+use std::fmt::Debug;
+use std::fs::OpenOptions;
+use std::io::{self, Read, Write};
+
+/// The mode used to open a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpenMode {
+    /// Open an existing file for reading.
+    Read,
+    /// Create or truncate a file for writing.
+    Write,
+    /// Create a file or open an existing file for writing at the end of the file.
+    Append,
+}
+
+/// A file opened by a [`HostIo`] implementation.
+pub trait HostFile: Read + Write + Debug + Send {}
+
+impl<T: Read + Write + Debug + Send> HostFile for T {}
+
+/// The I/O of the host the VM is running on.  File streams (e.g. `java.io.FileInputStream` and
+/// `java.io.FileOutputStream`), the standard streams and the print natives of the VM are routed
+/// through this trait, so that an embedder can run Java code without access to the real file
+/// system or process streams by supplying an in-memory or virtualized implementation.
+pub trait HostIo: Debug + Send + Sync {
+    /// Open the file at the given path.
+    ///
+    /// # Errors
+    /// if the file cannot be opened.
+    fn open(&self, path: &str, mode: OpenMode) -> io::Result<Box<dyn HostFile>>;
+
+    /// Read bytes from the standard input into the buffer; returns the number of bytes read or 0
+    /// at the end of the input.
+    ///
+    /// # Errors
+    /// if the standard input cannot be read.
+    fn read_stdin(&self, buffer: &mut [u8]) -> io::Result<usize>;
+
+    /// Write all the bytes to the standard output.
+    ///
+    /// # Errors
+    /// if the standard output cannot be written.
+    fn write_stdout(&self, bytes: &[u8]) -> io::Result<()>;
+
+    /// Write all the bytes to the standard error.
+    ///
+    /// # Errors
+    /// if the standard error cannot be written.
+    fn write_stderr(&self, bytes: &[u8]) -> io::Result<()>;
+}
+
+/// Host I/O backed by the real file system and the standard streams of the process.
+#[derive(Debug, Default)]
+pub struct StdHostIo;
+
+impl HostIo for StdHostIo {
+    fn open(&self, path: &str, mode: OpenMode) -> io::Result<Box<dyn HostFile>> {
+        let mut options = OpenOptions::new();
+        match mode {
+            OpenMode::Read => options.read(true),
+            OpenMode::Write => options.write(true).create(true).truncate(true),
+            OpenMode::Append => options.append(true).create(true),
+        };
+        let file = options.open(path)?;
+        Ok(Box::new(file))
+    }
+
+    fn read_stdin(&self, buffer: &mut [u8]) -> io::Result<usize> {
+        io::stdin().lock().read(buffer)
+    }
+
+    fn write_stdout(&self, bytes: &[u8]) -> io::Result<()> {
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(bytes)?;
+        stdout.flush()
+    }
+
+    fn write_stderr(&self, bytes: &[u8]) -> io::Result<()> {
+        let mut stderr = io::stderr().lock();
+        stderr.write_all(bytes)?;
+        stderr.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_std_host_io() -> io::Result<()> {
+        let directory = tempfile::tempdir()?;
+        let path = directory.path().join("test.txt");
+        let path = path.to_string_lossy();
+        let host_io = StdHostIo;
+
+        let mut file = host_io.open(&path, OpenMode::Write)?;
+        file.write_all(b"foo")?;
+        drop(file);
+        let mut file = host_io.open(&path, OpenMode::Append)?;
+        file.write_all(b"bar")?;
+        drop(file);
+
+        let mut file = host_io.open(&path, OpenMode::Read)?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+        assert_eq!("foobar", contents);
+
+        let mut file = host_io.open(&path, OpenMode::Write)?;
+        file.write_all(b"baz")?;
+        drop(file);
+        assert_eq!(b"baz".to_vec(), std::fs::read(path.as_ref())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_std_host_io_open_missing_file() {
+        let host_io = StdHostIo;
+        let result = host_io.open("missing/test.txt", OpenMode::Read);
+        assert!(result.is_err());
+    }
+}
@@ -2,26 +2,121 @@ use crate::frame::ExecutionResult::Continue;
 use crate::frame::{ExecutionResult, Frame};
 use crate::operand_stack::OperandStack;
 use crate::Error::InvalidStackValue;
-use crate::JavaError::NullPointerException;
+use crate::JavaError::{
+    ArrayIndexOutOfBoundsException, NegativeArraySizeException, NullPointerException,
+    OutOfMemoryError,
+};
 use crate::Result;
 use ristretto_classfile::attributes::ArrayType;
 use ristretto_classfile::BaseType;
 use ristretto_classloader::{ConcurrentVec, Reference};
+use std::fmt::Debug;
+
+/// Verify that an array of `count` elements, each `element_size` bytes, can be allocated.
+///
+/// # Errors
+/// `OutOfMemoryError` if the array length exceeds the maximum array length or if the size of the
+/// array exceeds the maximum size of an allocation.
+fn check_array_size(count: usize, element_size: usize, max_array_length: usize) -> Result<()> {
+    if count > max_array_length {
+        return Err(OutOfMemoryError("Requested array size exceeds VM limit".to_string()).into());
+    }
+    let can_allocate = count
+        .checked_mul(element_size)
+        .is_some_and(|size| isize::try_from(size).is_ok());
+    if !can_allocate {
+        return Err(OutOfMemoryError("Java heap space".to_string()).into());
+    }
+    Ok(())
+}
+
+/// Convert an array length popped from the operand stack to a `usize`.
+///
+/// # Errors
+/// `NegativeArraySizeException` if the length is negative.
+fn array_length(count: i32) -> Result<usize> {
+    usize::try_from(count).map_err(|_| NegativeArraySizeException(count.to_string()).into())
+}
+
+/// Get the element of an array at an index popped from the operand stack.
+///
+/// # Errors
+/// `ArrayIndexOutOfBoundsException` if the index is negative or not less than the length of the
+/// array.
+pub(crate) fn get_array_element<T: Clone + Debug + PartialEq>(
+    array: &ConcurrentVec<T>,
+    index: i32,
+) -> Result<T> {
+    let element = match usize::try_from(index) {
+        Ok(array_index) => array.get(array_index)?,
+        Err(_) => None,
+    };
+    let Some(element) = element else {
+        let length = array.len()?;
+        return Err(ArrayIndexOutOfBoundsException { index, length }.into());
+    };
+    Ok(element)
+}
+
+/// Set the element of an array at an index popped from the operand stack.
+///
+/// # Errors
+/// `ArrayIndexOutOfBoundsException` if the index is negative or not less than the length of the
+/// array.
+pub(crate) fn set_array_element<T: Clone + Debug + PartialEq>(
+    array: &ConcurrentVec<T>,
+    index: i32,
+    value: T,
+) -> Result<()> {
+    let previous = match usize::try_from(index) {
+        Ok(array_index) => array.set(array_index, value)?,
+        Err(_) => None,
+    };
+    if previous.is_none() {
+        let length = array.len()?;
+        return Err(ArrayIndexOutOfBoundsException { index, length }.into());
+    }
+    Ok(())
+}
+
+/// Get the size in bytes of an element of a primitive array.
+pub(crate) fn base_type_size(base_type: &BaseType) -> usize {
+    match base_type {
+        BaseType::Char => size_of::<u16>(),
+        BaseType::Float => size_of::<f32>(),
+        BaseType::Double => size_of::<f64>(),
+        BaseType::Boolean | BaseType::Byte => size_of::<i8>(),
+        BaseType::Short => size_of::<i16>(),
+        BaseType::Int => size_of::<i32>(),
+        BaseType::Long => size_of::<i64>(),
+    }
+}
+
+/// Create a primitive array of `count` elements initialized to the default value of the type;
+/// boolean arrays are stored as byte arrays, where `false` is 0.
+fn primitive_array(base_type: &BaseType, count: usize) -> Reference {
+    match base_type {
+        BaseType::Char => Reference::from(vec![0 as char; count]),
+        BaseType::Float => Reference::from(vec![0.0f32; count]),
+        BaseType::Double => Reference::from(vec![0.0f64; count]),
+        BaseType::Boolean | BaseType::Byte => Reference::from(vec![0i8; count]),
+        BaseType::Short => Reference::from(vec![0i16; count]),
+        BaseType::Int => Reference::from(vec![0i32; count]),
+        BaseType::Long => Reference::from(vec![0i64; count]),
+    }
+}
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.newarray>
 #[inline]
-pub(crate) fn newarray(stack: &OperandStack, array_type: &ArrayType) -> Result<ExecutionResult> {
-    let count = stack.pop_int()?;
-    let count = usize::try_from(count)?;
-    let array = match array_type {
-        ArrayType::Char => Reference::from(vec![0 as char; count]),
-        ArrayType::Float => Reference::from(vec![0.0f32; count]),
-        ArrayType::Double => Reference::from(vec![0.0f64; count]),
-        ArrayType::Boolean | ArrayType::Byte => Reference::from(vec![0i8; count]),
-        ArrayType::Short => Reference::from(vec![0i16; count]),
-        ArrayType::Int => Reference::from(vec![0i32; count]),
-        ArrayType::Long => Reference::from(vec![0i64; count]),
-    };
+pub(crate) fn newarray(
+    stack: &OperandStack,
+    array_type: &ArrayType,
+    max_array_length: usize,
+) -> Result<ExecutionResult> {
+    let count = array_length(stack.pop_int()?)?;
+    let base_type = array_type.base_type();
+    check_array_size(count, base_type_size(&base_type), max_array_length)?;
+    let array = primitive_array(&base_type, count);
     stack.push_object(Some(array))?;
     Ok(Continue)
 }
@@ -35,8 +130,9 @@ pub(crate) async fn anewarray(frame: &Frame, index: u16) -> Result<ExecutionResu
     let array_class_name = format!("[L{class_name};");
     let class = thread.class(array_class_name.as_str()).await?;
     let stack = frame.stack();
-    let count = stack.pop_int()?;
-    let count = usize::try_from(count)?;
+    let count = array_length(stack.pop_int()?)?;
+    let max_array_length = thread.vm()?.configuration().max_array_length();
+    check_array_size(count, size_of::<Option<Reference>>(), max_array_length)?;
     let array = Reference::Array(class, ConcurrentVec::from(vec![None; count]));
     stack.push_object(Some(array))?;
     Ok(Continue)
@@ -79,32 +175,26 @@ pub(crate) async fn multianewarray(
     let class_name = constant_pool.try_get_class(index)?;
     let class = vm.class(class_name.as_str()).await?;
     let stack = frame.stack();
-    let count = stack.pop_int()?;
-    let count = usize::try_from(count)?;
+    let count = array_length(stack.pop_int()?)?;
+    let max_array_length = vm.configuration().max_array_length();
 
     let mut type_class_name = class.array_component_type().to_string();
     let mut array = if type_class_name.len() == 1 {
         let base_type = BaseType::parse(type_class_name.chars().next().unwrap_or_default())?;
-        let array = match base_type {
-            BaseType::Char => Reference::from(vec![0 as char; count]),
-            BaseType::Float => Reference::from(vec![0.0f32; count]),
-            BaseType::Double => Reference::from(vec![0.0f64; count]),
-            BaseType::Boolean | BaseType::Byte => Reference::from(vec![0i8; count]),
-            BaseType::Short => Reference::from(vec![0i16; count]),
-            BaseType::Int => Reference::from(vec![0i32; count]),
-            BaseType::Long => Reference::from(vec![0i64; count]),
-        };
+        check_array_size(count, base_type_size(&base_type), max_array_length)?;
+        let array = primitive_array(&base_type, count);
         type_class_name = array.class_name();
         array
     } else {
         type_class_name = format!("[L{type_class_name};");
         let type_class = thread.class(type_class_name.as_str()).await?;
+        check_array_size(count, size_of::<Option<Reference>>(), max_array_length)?;
         Reference::Array(type_class, ConcurrentVec::from(vec![None; count]))
     };
 
     for _ in 1..dimensions {
-        let count = stack.pop_int()?;
-        let count = usize::try_from(count)?;
+        let count = array_length(stack.pop_int()?)?;
+        check_array_size(count, size_of::<Option<Reference>>(), max_array_length)?;
         type_class_name = format!("[{type_class_name}");
         let type_class = vm.class(type_class_name.as_str()).await?;
         let mut array_values = Vec::new();
@@ -128,13 +218,16 @@ mod tests {
     use ristretto_classfile::attributes::ArrayType;
     use ristretto_classfile::MethodAccessFlags;
     use ristretto_classloader::{Method, Value};
+    use std::io::Cursor;
     use std::sync::Arc;
 
+    const MAX_ARRAY_LENGTH: usize = 1_024;
+
     #[test]
     fn test_newarray_boolean() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(0)?;
-        let result = newarray(stack, &ArrayType::Boolean)?;
+        let result = newarray(stack, &ArrayType::Boolean, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let object = stack.pop()?;
         assert!(matches!(
@@ -148,7 +241,7 @@ mod tests {
     fn test_newarray_byte() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(0)?;
-        let result = newarray(stack, &ArrayType::Byte)?;
+        let result = newarray(stack, &ArrayType::Byte, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let object = stack.pop()?;
         assert!(matches!(
@@ -162,7 +255,7 @@ mod tests {
     fn test_newarray_char() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(0)?;
-        let result = newarray(stack, &ArrayType::Char)?;
+        let result = newarray(stack, &ArrayType::Char, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let object = stack.pop()?;
         assert!(matches!(
@@ -176,7 +269,7 @@ mod tests {
     fn test_newarray_double() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(0)?;
-        let result = newarray(stack, &ArrayType::Double)?;
+        let result = newarray(stack, &ArrayType::Double, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let object = stack.pop()?;
         assert!(matches!(
@@ -190,7 +283,7 @@ mod tests {
     fn test_newarray_float() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(0)?;
-        let result = newarray(stack, &ArrayType::Float)?;
+        let result = newarray(stack, &ArrayType::Float, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let object = stack.pop()?;
         assert!(matches!(
@@ -204,7 +297,7 @@ mod tests {
     fn test_newarray_int() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(0)?;
-        let result = newarray(stack, &ArrayType::Int)?;
+        let result = newarray(stack, &ArrayType::Int, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let object = stack.pop()?;
         assert!(matches!(
@@ -218,7 +311,7 @@ mod tests {
     fn test_newarray_long() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(0)?;
-        let result = newarray(stack, &ArrayType::Long)?;
+        let result = newarray(stack, &ArrayType::Long, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let object = stack.pop()?;
         assert!(matches!(
@@ -232,7 +325,7 @@ mod tests {
     fn test_newarray_short() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(0)?;
-        let result = newarray(stack, &ArrayType::Short)?;
+        let result = newarray(stack, &ArrayType::Short, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let object = stack.pop()?;
         assert!(matches!(
@@ -242,6 +335,84 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_newarray_types() -> Result<()> {
+        // Boolean arrays are stored as byte arrays
+        let array_types = [
+            (4, "[B"),
+            (5, "[C"),
+            (6, "[F"),
+            (7, "[D"),
+            (8, "[B"),
+            (9, "[S"),
+            (10, "[I"),
+            (11, "[J"),
+        ];
+        for (code, class_name) in array_types {
+            let array_type = ArrayType::from_bytes(&mut Cursor::new(vec![code]))?;
+            let stack = &mut OperandStack::with_max_size(1);
+            stack.push_int(3)?;
+            let result = newarray(stack, &array_type, MAX_ARRAY_LENGTH)?;
+            assert_eq!(Continue, result);
+            let array = stack.pop_object()?.expect("array");
+            assert_eq!(class_name, array.class_name());
+
+            stack.push_object(Some(array.clone()))?;
+            arraylength(stack)?;
+            assert_eq!(3, stack.pop_int()?);
+            match array_type.base_type() {
+                BaseType::Boolean | BaseType::Byte => assert_eq!(vec![0; 3], array.to_byte_vec()?),
+                BaseType::Char => assert_eq!(vec![0; 3], array.to_char_vec()?),
+                BaseType::Float => assert_eq!(vec![0.0; 3], array.to_float_vec()?),
+                BaseType::Double => assert_eq!(vec![0.0; 3], array.to_double_vec()?),
+                BaseType::Short => assert_eq!(vec![0; 3], array.to_short_vec()?),
+                BaseType::Int => assert_eq!(vec![0; 3], array.to_int_vec()?),
+                BaseType::Long => assert_eq!(vec![0; 3], array.to_long_vec()?),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_newarray_negative_size() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(1);
+        stack.push_int(-1)?;
+        let result = newarray(stack, &ArrayType::Int, MAX_ARRAY_LENGTH);
+        assert!(matches!(
+            result,
+            Err(JavaError(NegativeArraySizeException(message))) if message == "-1"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_newarray_exceeds_max_array_length() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(1);
+        stack.push_int(i32::MAX)?;
+        let result = newarray(stack, &ArrayType::Byte, MAX_ARRAY_LENGTH);
+        assert!(matches!(
+            result,
+            Err(JavaError(OutOfMemoryError(message)))
+                if message == "Requested array size exceeds VM limit"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_array_size() -> Result<()> {
+        check_array_size(MAX_ARRAY_LENGTH, 8, MAX_ARRAY_LENGTH)?;
+        assert!(matches!(
+            check_array_size(MAX_ARRAY_LENGTH + 1, 1, MAX_ARRAY_LENGTH),
+            Err(JavaError(OutOfMemoryError(message)))
+                if message == "Requested array size exceeds VM limit"
+        ));
+        assert!(matches!(
+            check_array_size(usize::MAX / 2, 8, usize::MAX),
+            Err(JavaError(OutOfMemoryError(message))) if message == "Java heap space"
+        ));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_anewarray() -> Result<()> {
         let (_vm, thread, mut class) = crate::test::class().await?;
@@ -279,11 +450,44 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_anewarray_exceeds_max_array_length() -> Result<()> {
+        let (_vm, thread, mut class) = crate::test::class().await?;
+        let constant_pool = Arc::get_mut(&mut class).expect("class").constant_pool_mut();
+        let class_index = constant_pool.add_class("java/lang/Object")?;
+        let method = Method::new(
+            MethodAccessFlags::STATIC,
+            "test",
+            "()V",
+            10,
+            10,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )?;
+        let arguments = Vec::new();
+        let frame = Frame::new(
+            &Arc::downgrade(&thread),
+            &class,
+            &Arc::new(method),
+            arguments,
+        )?;
+        let stack = frame.stack();
+        stack.push_int(i32::MAX)?;
+        let result = anewarray(&frame, class_index).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(OutOfMemoryError(message)))
+                if message == "Requested array size exceeds VM limit"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_arraylength_boolean() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(3)?;
-        let result = newarray(stack, &ArrayType::Boolean)?;
+        let result = newarray(stack, &ArrayType::Boolean, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let result = arraylength(stack)?;
         assert_eq!(Continue, result);
@@ -295,7 +499,7 @@ mod tests {
     fn test_arraylength_byte() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(3)?;
-        let result = newarray(stack, &ArrayType::Byte)?;
+        let result = newarray(stack, &ArrayType::Byte, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let result = arraylength(stack)?;
         assert_eq!(Continue, result);
@@ -307,7 +511,7 @@ mod tests {
     fn test_arraylength_char() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(3)?;
-        let result = newarray(stack, &ArrayType::Char)?;
+        let result = newarray(stack, &ArrayType::Char, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let result = arraylength(stack)?;
         assert_eq!(Continue, result);
@@ -319,7 +523,7 @@ mod tests {
     fn test_arraylength_double() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(3)?;
-        let result = newarray(stack, &ArrayType::Double)?;
+        let result = newarray(stack, &ArrayType::Double, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let result = arraylength(stack)?;
         assert_eq!(Continue, result);
@@ -331,7 +535,7 @@ mod tests {
     fn test_arraylength_float() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(3)?;
-        let result = newarray(stack, &ArrayType::Float)?;
+        let result = newarray(stack, &ArrayType::Float, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let result = arraylength(stack)?;
         assert_eq!(Continue, result);
@@ -343,7 +547,7 @@ mod tests {
     fn test_arraylength_int() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(3)?;
-        let result = newarray(stack, &ArrayType::Int)?;
+        let result = newarray(stack, &ArrayType::Int, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let result = arraylength(stack)?;
         assert_eq!(Continue, result);
@@ -355,7 +559,7 @@ mod tests {
     fn test_arraylength_long() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(3)?;
-        let result = newarray(stack, &ArrayType::Long)?;
+        let result = newarray(stack, &ArrayType::Long, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let result = arraylength(stack)?;
         assert_eq!(Continue, result);
@@ -367,7 +571,7 @@ mod tests {
     fn test_arraylength_short() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
         stack.push_int(3)?;
-        let result = newarray(stack, &ArrayType::Short)?;
+        let result = newarray(stack, &ArrayType::Short, MAX_ARRAY_LENGTH)?;
         assert_eq!(Continue, result);
         let result = arraylength(stack)?;
         assert_eq!(Continue, result);
@@ -4,6 +4,7 @@ use crate::local_variables::LocalVariables;
 use crate::operand_stack::OperandStack;
 use crate::Result;
 use indexmap::IndexMap;
+use ristretto_classloader::{Reference, Value};
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.if_cond>
 #[inline]
@@ -125,12 +126,22 @@ pub(crate) fn if_icmple(stack: &OperandStack, address: u16) -> Result<ExecutionR
     Ok(Continue)
 }
 
+/// Returns true if both references are null or refer to the same array or object; references
+/// are compared by identity, not by their contents.
+fn is_same_reference(reference: Option<&Reference>, other: Option<&Reference>) -> bool {
+    match (reference, other) {
+        (Some(reference), Some(other)) => reference.ptr_eq(other),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.if_acmp_cond>
 #[inline]
 pub(crate) fn if_acmpeq(stack: &OperandStack, address: u16) -> Result<ExecutionResult> {
     let value2 = stack.pop_object()?;
     let value1 = stack.pop_object()?;
-    if value1 == value2 {
+    if is_same_reference(value1.as_ref(), value2.as_ref()) {
         return Ok(ContinueAtPosition(usize::from(address)));
     }
     Ok(Continue)
@@ -141,7 +152,7 @@ pub(crate) fn if_acmpeq(stack: &OperandStack, address: u16) -> Result<ExecutionR
 pub(crate) fn if_acmpne(stack: &OperandStack, address: u16) -> Result<ExecutionResult> {
     let value2 = stack.pop_object()?;
     let value1 = stack.pop_object()?;
-    if value1 != value2 {
+    if !is_same_reference(value1.as_ref(), value2.as_ref()) {
         return Ok(ContinueAtPosition(usize::from(address)));
     }
     Ok(Continue)
@@ -163,33 +174,44 @@ pub(crate) fn goto_w(address: i32) -> Result<ExecutionResult> {
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.jsr>
 #[inline]
-pub(crate) fn jsr(stack: &OperandStack, address: u16) -> Result<ExecutionResult> {
-    let address = i32::from(address);
-    stack.push_int(address)?;
-    Ok(ContinueAtPosition(usize::try_from(address)?))
+pub(crate) fn jsr(
+    stack: &OperandStack,
+    program_counter: usize,
+    address: u16,
+) -> Result<ExecutionResult> {
+    // The return address is the instruction immediately following the jsr instruction.
+    let return_address = u16::try_from(program_counter + 1)?;
+    stack.push(Value::ReturnAddress(return_address))?;
+    Ok(ContinueAtPosition(usize::from(address)))
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.jsr_w>
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.wide>
 #[inline]
-pub(crate) fn jsr_w(stack: &OperandStack, address: i32) -> Result<ExecutionResult> {
-    stack.push_int(address)?;
+pub(crate) fn jsr_w(
+    stack: &OperandStack,
+    program_counter: usize,
+    address: i32,
+) -> Result<ExecutionResult> {
+    // The return address is the instruction immediately following the jsr_w instruction.
+    let return_address = u16::try_from(program_counter + 1)?;
+    stack.push(Value::ReturnAddress(return_address))?;
     Ok(ContinueAtPosition(usize::try_from(address)?))
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.ret>
 #[inline]
 pub(crate) fn ret(locals: &LocalVariables, index: u8) -> Result<ExecutionResult> {
-    let address = locals.get_int(usize::from(index))?;
-    Ok(ContinueAtPosition(usize::try_from(address)?))
+    let address = locals.get_return_address(usize::from(index))?;
+    Ok(ContinueAtPosition(usize::from(address)))
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.ret_w>
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.wide>
 #[inline]
 pub(crate) fn ret_w(locals: &LocalVariables, index: u16) -> Result<ExecutionResult> {
-    let address = locals.get_int(usize::from(index))?;
-    Ok(ContinueAtPosition(usize::try_from(address)?))
+    let address = locals.get_return_address(usize::from(index))?;
+    Ok(ContinueAtPosition(usize::from(address)))
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.tableswitch>
@@ -613,6 +635,22 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_if_acmp_distinct_equal_objects() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(2);
+        let object = Reference::ByteArray(ConcurrentVec::from(vec![42]));
+        let equal_object = Reference::ByteArray(ConcurrentVec::from(vec![42]));
+        stack.push_object(Some(object.clone()))?;
+        stack.push_object(Some(equal_object.clone()))?;
+        let result = if_acmpeq(stack, 3)?;
+        assert_eq!(Continue, result);
+        stack.push_object(Some(object))?;
+        stack.push_object(Some(equal_object))?;
+        let result = if_acmpne(stack, 3)?;
+        assert_eq!(ContinueAtPosition(3), result);
+        Ok(())
+    }
+
     #[test]
     fn test_goto() -> Result<()> {
         let result = goto(3)?;
@@ -630,25 +668,37 @@ mod test {
     #[test]
     fn test_jsr() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
-        let result = jsr(stack, 3)?;
+        let result = jsr(stack, 1, 3)?;
         assert_eq!(ContinueAtPosition(3), result);
-        assert_eq!(3, stack.pop_int()?);
+        assert_eq!(Value::ReturnAddress(2), stack.pop()?);
         Ok(())
     }
 
     #[test]
     fn test_jsr_w() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
-        let result = jsr_w(stack, 3)?;
+        let result = jsr_w(stack, 1, 3)?;
         assert_eq!(ContinueAtPosition(3), result);
-        assert_eq!(3, stack.pop_int()?);
+        assert_eq!(Value::ReturnAddress(2), stack.pop()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsr_ret() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(1);
+        let locals = &LocalVariables::with_max_size(1);
+        let result = jsr(stack, 4, 10)?;
+        assert_eq!(ContinueAtPosition(10), result);
+        locals.set(0, stack.pop()?)?;
+        let result = ret(locals, 0)?;
+        assert_eq!(ContinueAtPosition(5), result);
         Ok(())
     }
 
     #[test]
     fn test_ret() -> Result<()> {
         let locals = &LocalVariables::with_max_size(1);
-        locals.set_int(0, 3)?;
+        locals.set(0, Value::ReturnAddress(3))?;
         let result = ret(locals, 0)?;
         assert_eq!(ContinueAtPosition(3), result);
         Ok(())
@@ -657,7 +707,7 @@ mod test {
     #[test]
     fn test_ret_w() -> Result<()> {
         let locals = &LocalVariables::with_max_size(1);
-        locals.set_int(0, 3)?;
+        locals.set(0, Value::ReturnAddress(3))?;
         let result = ret_w(locals, 0)?;
         assert_eq!(ContinueAtPosition(3), result);
         Ok(())
@@ -1,8 +1,9 @@
 use crate::frame::ExecutionResult;
 use crate::frame::ExecutionResult::Continue;
+use crate::instruction::{get_array_element, set_array_element};
 use crate::operand_stack::OperandStack;
 use crate::Error::InvalidStackValue;
-use crate::JavaError::{ArrayIndexOutOfBoundsException, NullPointerException};
+use crate::JavaError::NullPointerException;
 use crate::Result;
 use ristretto_classloader::Reference;
 
@@ -13,11 +14,7 @@ pub(crate) fn caload(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::CharArray(array)) => {
-            let index = usize::try_from(index)?;
-            let Some(value) = array.get(index)? else {
-                let length = array.len()?;
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
+            let value = get_array_element(&array, index)?;
             stack.push_int(i32::from(value))?;
             Ok(Continue)
         }
@@ -36,12 +33,7 @@ pub(crate) fn castore(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::CharArray(ref mut array)) => {
-            let index = usize::try_from(index)?;
-            let length = array.capacity()?;
-            if index >= length {
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
-            array.set(index, u16::try_from(value)?)?;
+            set_array_element(array, index, u16::try_from(value)?)?;
             Ok(Continue)
         }
         Some(object) => Err(InvalidStackValue {
@@ -55,6 +47,7 @@ pub(crate) fn castore(stack: &OperandStack) -> Result<ExecutionResult> {
 mod test {
     use super::*;
     use crate::Error::JavaError;
+    use crate::JavaError::ArrayIndexOutOfBoundsException;
 
     #[test]
     fn test_caload() -> Result<()> {
@@ -3,6 +3,47 @@ use crate::frame::ExecutionResult::Continue;
 use crate::operand_stack::OperandStack;
 use crate::Result;
 
+/// 2<sup>31</sup>, the smallest float value greater than `i32::MAX`
+const INT_BOUND: f64 = 2_147_483_648.0;
+/// 2<sup>63</sup>, the smallest float value greater than `i64::MAX`
+const LONG_BOUND: f64 = 9_223_372_036_854_775_808.0;
+
+/// Convert a floating point value to an int following the rules of `d2i` and `f2i`: `NaN`
+/// converts to 0, values too large or too small saturate to `i32::MAX` or `i32::MIN`, and all
+/// other values are rounded towards zero.
+#[inline]
+fn to_int(value: f64) -> i32 {
+    if value.is_nan() {
+        0
+    } else if value >= INT_BOUND {
+        i32::MAX
+    } else if value <= -INT_BOUND {
+        i32::MIN
+    } else {
+        #[expect(clippy::cast_possible_truncation)]
+        let value = value.trunc() as i32;
+        value
+    }
+}
+
+/// Convert a floating point value to a long following the rules of `d2l` and `f2l`: `NaN`
+/// converts to 0, values too large or too small saturate to `i64::MAX` or `i64::MIN`, and all
+/// other values are rounded towards zero.
+#[inline]
+fn to_long(value: f64) -> i64 {
+    if value.is_nan() {
+        0
+    } else if value >= LONG_BOUND {
+        i64::MAX
+    } else if value <= -LONG_BOUND {
+        i64::MIN
+    } else {
+        #[expect(clippy::cast_possible_truncation)]
+        let value = value.trunc() as i64;
+        value
+    }
+}
+
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.i2l>
 #[inline]
 pub(crate) fn i2l(stack: &OperandStack) -> Result<ExecutionResult> {
@@ -32,6 +73,7 @@ pub(crate) fn i2d(stack: &OperandStack) -> Result<ExecutionResult> {
 #[inline]
 pub(crate) fn l2i(stack: &OperandStack) -> Result<ExecutionResult> {
     let value = stack.pop_long()?;
+    // Narrowing discards all but the low order 32 bits
     #[expect(clippy::cast_possible_truncation)]
     stack.push_int(value as i32)?;
     Ok(Continue)
@@ -59,8 +101,7 @@ pub(crate) fn l2d(stack: &OperandStack) -> Result<ExecutionResult> {
 #[inline]
 pub(crate) fn f2i(stack: &OperandStack) -> Result<ExecutionResult> {
     let value = stack.pop_float()?;
-    #[expect(clippy::cast_possible_truncation)]
-    stack.push_int(value as i32)?;
+    stack.push_int(to_int(f64::from(value)))?;
     Ok(Continue)
 }
 
@@ -68,8 +109,7 @@ pub(crate) fn f2i(stack: &OperandStack) -> Result<ExecutionResult> {
 #[inline]
 pub(crate) fn f2l(stack: &OperandStack) -> Result<ExecutionResult> {
     let value = stack.pop_float()?;
-    #[expect(clippy::cast_possible_truncation)]
-    stack.push_long(value as i64)?;
+    stack.push_long(to_long(f64::from(value)))?;
     Ok(Continue)
 }
 
@@ -85,8 +125,7 @@ pub(crate) fn f2d(stack: &OperandStack) -> Result<ExecutionResult> {
 #[inline]
 pub(crate) fn d2i(stack: &OperandStack) -> Result<ExecutionResult> {
     let value = stack.pop_double()?;
-    #[expect(clippy::cast_possible_truncation)]
-    stack.push_int(value as i32)?;
+    stack.push_int(to_int(value))?;
     Ok(Continue)
 }
 
@@ -94,8 +133,7 @@ pub(crate) fn d2i(stack: &OperandStack) -> Result<ExecutionResult> {
 #[inline]
 pub(crate) fn d2l(stack: &OperandStack) -> Result<ExecutionResult> {
     let value = stack.pop_double()?;
-    #[expect(clippy::cast_possible_truncation)]
-    stack.push_long(value as i64)?;
+    stack.push_long(to_long(value))?;
     Ok(Continue)
 }
 
@@ -258,6 +296,121 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_l2i_narrowing() -> Result<()> {
+        let cases = [
+            (i64::from(i32::MAX) + 1, i32::MIN),
+            (i64::from(i32::MIN) - 1, i32::MAX),
+            (0x1_0000_0000, 0),
+            (0x1_0000_002A, 42),
+            (i64::MAX, -1),
+            (i64::MIN, 0),
+            (-1, -1),
+        ];
+        let stack = &mut OperandStack::with_max_size(1);
+        for (value, expected) in cases {
+            stack.push_long(value)?;
+            l2i(stack)?;
+            assert_eq!(expected, stack.pop_int()?, "l2i({value})");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_f2i_edge_cases() -> Result<()> {
+        let cases = [
+            (f32::NAN, 0),
+            (f32::INFINITY, i32::MAX),
+            (f32::NEG_INFINITY, i32::MIN),
+            (2_147_483_648.0, i32::MAX),
+            (-2_147_483_904.0, i32::MIN),
+            (f32::MAX, i32::MAX),
+            (f32::MIN, i32::MIN),
+            (-0.0, 0),
+            (-42.9, -42),
+            (42.9, 42),
+        ];
+        let stack = &mut OperandStack::with_max_size(1);
+        for (value, expected) in cases {
+            stack.push_float(value)?;
+            f2i(stack)?;
+            assert_eq!(expected, stack.pop_int()?, "f2i({value})");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_f2l_edge_cases() -> Result<()> {
+        let cases = [
+            (f32::NAN, 0),
+            (f32::INFINITY, i64::MAX),
+            (f32::NEG_INFINITY, i64::MIN),
+            (9_223_372_036_854_775_808.0, i64::MAX),
+            (-9_223_373_136_366_403_584.0, i64::MIN),
+            (f32::MAX, i64::MAX),
+            (f32::MIN, i64::MIN),
+            (-42.9, -42),
+            (42.9, 42),
+        ];
+        let stack = &mut OperandStack::with_max_size(1);
+        for (value, expected) in cases {
+            stack.push_float(value)?;
+            f2l(stack)?;
+            assert_eq!(expected, stack.pop_long()?, "f2l({value})");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_d2i_edge_cases() -> Result<()> {
+        let cases = [
+            (f64::NAN, 0),
+            (f64::INFINITY, i32::MAX),
+            (f64::NEG_INFINITY, i32::MIN),
+            (2_147_483_647.9, i32::MAX),
+            (2_147_483_648.0, i32::MAX),
+            (-2_147_483_648.9, i32::MIN),
+            (-2_147_483_649.0, i32::MIN),
+            (f64::MAX, i32::MAX),
+            (f64::MIN, i32::MIN),
+            (-0.0, 0),
+            (-42.9, -42),
+            (42.9, 42),
+        ];
+        let stack = &mut OperandStack::with_max_size(1);
+        for (value, expected) in cases {
+            stack.push_double(value)?;
+            d2i(stack)?;
+            assert_eq!(expected, stack.pop_int()?, "d2i({value})");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_d2l_edge_cases() -> Result<()> {
+        let cases = [
+            (f64::NAN, 0),
+            (f64::INFINITY, i64::MAX),
+            (f64::NEG_INFINITY, i64::MIN),
+            (9_223_372_036_854_775_808.0, i64::MAX),
+            (9_223_372_036_854_774_784.0, 9_223_372_036_854_774_784),
+            (-9_223_372_036_854_777_856.0, i64::MIN),
+            (-9_223_372_036_854_775_808.0, i64::MIN),
+            (f64::MAX, i64::MAX),
+            (f64::MIN, i64::MIN),
+            (-0.0, 0),
+            (-42.9, -42),
+            (42.9, 42),
+        ];
+        let stack = &mut OperandStack::with_max_size(1);
+        for (value, expected) in cases {
+            stack.push_double(value)?;
+            d2l(stack)?;
+            assert_eq!(expected, stack.pop_long()?, "d2l({value})");
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_d2f() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
@@ -1,10 +1,11 @@
 use crate::frame::ExecutionResult::Return;
 use crate::frame::{ExecutionResult, ExecutionResult::Continue};
+use crate::instruction::{get_array_element, set_array_element};
 use crate::java_error::JavaError::ArithmeticException;
 use crate::local_variables::LocalVariables;
 use crate::operand_stack::OperandStack;
 use crate::Error::InvalidStackValue;
-use crate::JavaError::{ArrayIndexOutOfBoundsException, NullPointerException};
+use crate::JavaError::NullPointerException;
 use crate::{Result, Value};
 use ristretto_classloader::Reference;
 
@@ -143,11 +144,7 @@ pub(crate) fn daload(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::DoubleArray(array)) => {
-            let index = usize::try_from(index)?;
-            let Some(value) = array.get(index)? else {
-                let length = array.len()?;
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
+            let value = get_array_element(&array, index)?;
             stack.push_double(value)?;
             Ok(Continue)
         }
@@ -166,12 +163,7 @@ pub(crate) fn dastore(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::DoubleArray(ref mut array)) => {
-            let index = usize::try_from(index)?;
-            let length = array.capacity()?;
-            if index >= length {
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
-            array.set(index, value)?;
+            set_array_element(array, index, value)?;
             Ok(Continue)
         }
         Some(object) => Err(InvalidStackValue {
@@ -289,6 +281,7 @@ pub(crate) fn dreturn(stack: &OperandStack) -> Result<ExecutionResult> {
 mod tests {
     use super::*;
     use crate::Error::{InvalidOperand, JavaError};
+    use crate::JavaError::ArrayIndexOutOfBoundsException;
 
     #[test]
     fn test_dconst_0() -> Result<()> {
@@ -1,6 +1,8 @@
 use crate::frame::{ExecutionResult, Frame};
-use crate::Error::{InternalError, JavaError, Throwable};
+use crate::Error::{ClassLoaderError, InternalError, JavaError, Throwable};
+use crate::JavaError::{ClassFormatError, NullPointerException};
 use crate::{Error, Result, VM};
+use ristretto_classloader::Error::MalformedDescriptor;
 use ristretto_classloader::{Object, Reference};
 use std::sync::Arc;
 
@@ -8,9 +10,13 @@ use std::sync::Arc;
 #[inline]
 pub(crate) async fn athrow(frame: &Frame) -> Result<ExecutionResult> {
     let stack = frame.stack();
-    let Some(Reference::Object(throwable)) = stack.pop_object()? else {
-        return Err(InternalError("Expected object on top of stack".to_string()));
+    let throwable = match stack.pop_object()? {
+        Some(Reference::Object(throwable)) => throwable,
+        None => return Err(NullPointerException("throwable cannot be null".to_string()).into()),
+        Some(_) => return Err(InternalError("Expected object on top of stack".to_string())),
     };
+    let vm = frame.thread()?.vm()?;
+    frame.publish_exception_thrown(&vm, &throwable);
     // Return the exception to the caller and let the frame error handler deal with it
     Err(Throwable(throwable))
 }
@@ -45,12 +51,15 @@ pub(crate) async fn process_throwable(frame: &Frame, throwable: Object) -> Resul
             let exception_class_name =
                 constant_pool.try_get_class(exception_table_entry.catch_type)?;
             let exception_class = vm.class(exception_class_name).await?;
-            throwable_class.is_assignable_from(&exception_class)?
+            exception_class.is_assignable_from(throwable_class)?
         };
 
         if matching_exception_handler {
+            // The operand stack is cleared before the throwable is pushed so that values left by
+            // the instruction that threw do not leak into the handler.
             let stack = frame.stack();
             let handler_program_counter = usize::from(exception_table_entry.handler_pc);
+            stack.clear()?;
             stack.push_object(Some(Reference::from(throwable)))?;
             return Ok(handler_program_counter);
         }
@@ -71,6 +80,11 @@ pub(crate) async fn convert_error_to_throwable(vm: Arc<VM>, error: Error) -> Res
             let message = java_error.message();
             (class_name, message)
         }
+        // A malformed descriptor can only come from a corrupt class file
+        ClassLoaderError(error @ MalformedDescriptor { .. }) => {
+            let java_error = ClassFormatError(error.to_string());
+            (java_error.class_name().to_string(), java_error.message())
+        }
         Throwable(throwable) => return Ok(throwable),
         _ => ("java/lang/InternalError".to_string(), format!("{error}")),
     };
@@ -86,7 +100,11 @@ pub(crate) async fn convert_error_to_throwable(vm: Arc<VM>, error: Error) -> Res
 mod test {
     use super::*;
     use crate::java_object::JavaObject;
+    use crate::JavaError::ArithmeticException;
     use crate::VM;
+    use ristretto_classfile::attributes::{ExceptionTableEntry, Instruction};
+    use ristretto_classfile::MethodAccessFlags;
+    use ristretto_classloader::{Method, Value};
 
     #[tokio::test]
     async fn test_process_throwable() -> Result<()> {
@@ -103,4 +121,200 @@ mod test {
         assert!(result.is_err());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_finally_propagates_exception() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        let result = vm
+            .invoke("Finally", "propagate", "()I", Vec::<Value>::new())
+            .await?;
+        assert_eq!(Some(Value::Int(11_111)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_finally_propagates_exception_in_frame() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        let result = vm
+            .invoke("Finally", "local", "()I", Vec::<Value>::new())
+            .await?;
+        assert_eq!(Some(Value::Int(1_111)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_finally_throws() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        let result = vm
+            .invoke("Finally", "finallyThrows", "()I", Vec::<Value>::new())
+            .await?;
+        assert_eq!(Some(Value::Int(111)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_finally_subroutine() -> Result<()> {
+        let (_vm, thread, mut class) = crate::test::class().await?;
+        let constant_pool = Arc::get_mut(&mut class).expect("class").constant_pool_mut();
+        let arithmetic_exception = constant_pool.add_class("java/lang/ArithmeticException")?;
+        // A pre-Java 6 finally block implemented as a subroutine with jsr/ret:
+        //
+        // int value = 0;
+        // try {
+        //     try {
+        //         value = 5 + (1 / 0);
+        //     } finally {
+        //         value += 10;
+        //     }
+        // } catch (ArithmeticException e) {
+        //     value += 100;
+        // }
+        // return value;
+        let code = vec![
+            Instruction::Iconst_0,
+            Instruction::Istore_0,
+            Instruction::Iconst_5,
+            Instruction::Iconst_1,
+            Instruction::Iconst_0,
+            Instruction::Idiv,
+            Instruction::Iadd,
+            Instruction::Istore_0,
+            Instruction::Jsr(14),
+            Instruction::Goto(21),
+            // finally handler: run the subroutine and rethrow the exception
+            Instruction::Astore_1,
+            Instruction::Jsr(14),
+            Instruction::Aload_1,
+            Instruction::Athrow,
+            // finally subroutine
+            Instruction::Astore_2,
+            Instruction::Iinc(0, 10),
+            Instruction::Ret(2),
+            // catch handler
+            Instruction::Astore_1,
+            Instruction::Iinc(0, 100),
+            Instruction::Iload_0,
+            Instruction::Ireturn,
+            Instruction::Iload_0,
+            Instruction::Ireturn,
+        ];
+        let exception_table = vec![
+            ExceptionTableEntry {
+                range_pc: 2..10,
+                handler_pc: 10,
+                catch_type: 0,
+            },
+            ExceptionTableEntry {
+                range_pc: 2..14,
+                handler_pc: 17,
+                catch_type: arithmetic_exception,
+            },
+        ];
+        let method = Method::new(
+            MethodAccessFlags::STATIC,
+            "test",
+            "()I",
+            3,
+            3,
+            code,
+            Vec::new(),
+            exception_table,
+        )?;
+        let frame = Frame::new(
+            &Arc::downgrade(&thread),
+            &class,
+            &Arc::new(method),
+            Vec::new(),
+        )?;
+        let result = frame.execute().await?;
+        assert_eq!(Some(Value::Int(110)), result);
+        assert!(frame.stack().is_empty()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_process_throwable_clears_stack() -> Result<()> {
+        let (vm, thread, class) = crate::test::class().await?;
+        let code = vec![
+            Instruction::Iconst_1,
+            Instruction::Iconst_2,
+            Instruction::Iconst_0,
+            Instruction::Idiv,
+            Instruction::Ireturn,
+            // catch handler
+            Instruction::Areturn,
+        ];
+        let exception_table = vec![ExceptionTableEntry {
+            range_pc: 0..5,
+            handler_pc: 5,
+            catch_type: 0,
+        }];
+        let method = Method::new(
+            MethodAccessFlags::STATIC,
+            "test",
+            "()I",
+            3,
+            0,
+            code,
+            Vec::new(),
+            exception_table,
+        )?;
+        let frame = Frame::new(
+            &Arc::downgrade(&thread),
+            &class,
+            &Arc::new(method),
+            Vec::new(),
+        )?;
+        let stack = frame.stack();
+        stack.push_int(1)?;
+        stack.push_int(2)?;
+        let throwable =
+            convert_error_to_throwable(vm, JavaError(ArithmeticException("/ by zero".to_string())))
+                .await?;
+
+        let handler_program_counter = process_throwable(&frame, throwable.clone()).await?;
+        assert_eq!(5, handler_program_counter);
+        assert_eq!(1, stack.len()?);
+        let Some(Reference::Object(handled)) = stack.pop_object()? else {
+            panic!("Expected throwable on top of stack");
+        };
+        assert!(handled.ptr_eq(&throwable));
+        assert!(stack.is_empty()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_athrow_null() -> Result<()> {
+        let (_vm, _thread, frame) = crate::test::frame().await?;
+        frame.stack().push_object(None)?;
+        let result = athrow(&frame).await;
+        assert!(matches!(result, Err(JavaError(NullPointerException(_)))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_convert_malformed_descriptor_to_class_format_error() -> Result<()> {
+        let vm = VM::default().await?;
+        let error = Method::new(
+            MethodAccessFlags::PUBLIC,
+            "test",
+            "(Q)V",
+            0,
+            0,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )
+        .expect_err("malformed descriptor");
+        assert!(matches!(
+            &error,
+            MalformedDescriptor { descriptor } if descriptor == "(Q)V"
+        ));
+
+        let throwable = convert_error_to_throwable(vm, ClassLoaderError(error)).await?;
+        assert_eq!("java/lang/ClassFormatError", throwable.class().name());
+        let message: String = throwable.value("detailMessage")?.try_into()?;
+        assert_eq!("Malformed descriptor: (Q)V", message);
+        Ok(())
+    }
 }
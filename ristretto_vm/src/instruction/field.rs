@@ -2,8 +2,10 @@ use crate::frame::ExecutionResult;
 use crate::frame::ExecutionResult::Continue;
 use crate::operand_stack::OperandStack;
 use crate::Error::InvalidStackValue;
+use crate::JavaError::NoSuchFieldError;
 use crate::Result;
-use ristretto_classloader::{Class, Reference, Value};
+use ristretto_classfile::FieldAccessFlags;
+use ristretto_classloader::{Class, Field, Object, Reference, Value};
 use std::sync::Arc;
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.getfield>
@@ -16,12 +18,8 @@ pub(crate) fn getfield(
     let value = stack.pop()?;
     match value {
         Value::Object(Some(Reference::Object(object))) => {
-            let constant_pool = class.constant_pool();
-            let (_class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
-            let (name_index, _descriptor_index) =
-                constant_pool.try_get_name_and_type(*name_and_type_index)?;
-            let field_name = constant_pool.try_get_utf8(*name_index)?;
-            let value = object.value(field_name)?;
+            let field = resolve_field(class, &object, index)?;
+            let value = object.value(field.name())?;
             stack.push(value)?;
             Ok(Continue)
         }
@@ -43,12 +41,8 @@ pub(crate) fn putfield(
     let mut object_value = stack.pop()?;
     match object_value {
         Value::Object(Some(Reference::Object(ref mut object))) => {
-            let constant_pool = class.constant_pool();
-            let (_class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
-            let (name_index, _descriptor_index) =
-                constant_pool.try_get_name_and_type(*name_and_type_index)?;
-            let field_name = constant_pool.try_get_utf8(*name_index)?;
-            object.set_value(field_name, value)?;
+            let field = resolve_field(class, object, index)?;
+            object.set_value(field.name(), value)?;
             Ok(Continue)
         }
         _ => Err(InvalidStackValue {
@@ -58,12 +52,61 @@ pub(crate) fn putfield(
     }
 }
 
+/// Resolve the instance field referenced at the given constant pool index of the class; the
+/// resolved field is cached on the class so that the field reference is only resolved once.  The
+/// field is looked up by name and descriptor in the referenced class and its superclasses; a
+/// `NoSuchFieldError` is returned if the referenced class is not in the hierarchy of the object or
+/// no matching field is found.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.4.3.2>
+fn resolve_field(class: &Arc<Class>, object: &Object, index: u16) -> Result<Arc<Field>> {
+    if let Some((_field_class, field)) = class.resolved_field(index)? {
+        return Ok(field);
+    }
+
+    let constant_pool = class.constant_pool();
+    let (class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
+    let class_name = constant_pool.try_get_class(*class_index)?;
+    let (name_index, descriptor_index) =
+        constant_pool.try_get_name_and_type(*name_and_type_index)?;
+    let field_name = constant_pool.try_get_utf8(*name_index)?;
+    let field_descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
+
+    let mut classes = Vec::new();
+    let mut current_class = Some(object.class().clone());
+    while let Some(hierarchy_class) = current_class {
+        current_class = hierarchy_class.parent()?;
+        classes.push(hierarchy_class);
+    }
+    let Some(start) = classes
+        .iter()
+        .position(|hierarchy_class| hierarchy_class.name() == class_name)
+    else {
+        return Err(NoSuchFieldError(format!("{class_name}.{field_name}")).into());
+    };
+
+    for field_class in classes.iter().skip(start) {
+        let field = field_class.fields().into_iter().find(|field| {
+            field.name() == field_name
+                && field.field_type().descriptor() == *field_descriptor
+                && !field.access_flags().contains(FieldAccessFlags::STATIC)
+        });
+        if let Some(field) = field {
+            class.set_resolved_field(index, field_class, field.clone())?;
+            return Ok(field);
+        }
+    }
+
+    Err(NoSuchFieldError(format!("{class_name}.{field_name}")).into())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::frame::Frame;
     use crate::instruction::{dup, new};
     use crate::thread::Thread;
+    use crate::Error::JavaError;
     use crate::VM;
     use ristretto_classfile::MethodAccessFlags;
     use ristretto_classloader::{Method, Value};
@@ -128,6 +171,29 @@ mod test {
         test_put_and_get_field().await
     }
 
+    #[tokio::test]
+    async fn test_getfield_resolved_once() -> Result<()> {
+        let (_vm, _thread, frame, class_index, field_index) =
+            test_class_field("Child", "zero", "I").await?;
+        let class = frame.class();
+        let stack = frame.stack();
+        let result = new(&frame, class_index).await?;
+        assert_eq!(Continue, result);
+        let resolution_count = class.resolution_count();
+
+        for _ in 0..3 {
+            dup(stack)?;
+            getfield(stack, class, field_index)?;
+            assert_eq!(Value::Int(0), stack.pop()?);
+        }
+        assert_eq!(resolution_count + 1, class.resolution_count());
+
+        let (field_class, field) = class.resolved_field(field_index)?.expect("resolved field");
+        assert_eq!("GrandParent", field_class.name());
+        assert_eq!("zero", field.name());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_getfield_field_not_found() -> Result<()> {
         let (_vm, _thread, frame, class_index, field_index) =
@@ -137,7 +203,58 @@ mod test {
         let class = frame.class();
         let stack = frame.stack();
         let result = getfield(stack, class, field_index);
-        assert!(result.is_err());
+        assert!(matches!(
+            result,
+            Err(JavaError(NoSuchFieldError(message))) if message == "Child.foo"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_getfield_descriptor_mismatch() -> Result<()> {
+        let (_vm, _thread, frame, class_index, field_index) =
+            test_class_field("Child", "zero", "J").await?;
+        let result = new(&frame, class_index).await?;
+        assert_eq!(Continue, result);
+        let class = frame.class();
+        let stack = frame.stack();
+        let result = getfield(stack, class, field_index);
+        assert!(matches!(result, Err(JavaError(NoSuchFieldError(_)))));
+        assert!(class.resolved_field(field_index)?.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_getfield_class_not_in_hierarchy() -> Result<()> {
+        let (_vm, thread, mut class) = crate::test::class().await?;
+        let constant_pool = Arc::get_mut(&mut class).expect("class").constant_pool_mut();
+        let class_index = constant_pool.add_class("Child")?;
+        let string_class_index = constant_pool.add_class("java/lang/String")?;
+        let field_index = constant_pool.add_field_ref(string_class_index, "zero", "I")?;
+        let method = Method::new(
+            MethodAccessFlags::STATIC,
+            "test",
+            "()V",
+            10,
+            10,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )?;
+        let frame = Frame::new(
+            &Arc::downgrade(&thread),
+            &class,
+            &Arc::new(method),
+            Vec::new(),
+        )?;
+        let result = new(&frame, class_index).await?;
+        assert_eq!(Continue, result);
+        let stack = frame.stack();
+        let result = getfield(stack, &class, field_index);
+        assert!(matches!(
+            result,
+            Err(JavaError(NoSuchFieldError(message))) if message == "java/lang/String.zero"
+        ));
         Ok(())
     }
 
@@ -173,7 +290,7 @@ mod test {
         assert_eq!(Continue, result);
         stack.push_int(42)?;
         let result = putfield(stack, class, field_index);
-        assert!(result.is_err());
+        assert!(matches!(result, Err(JavaError(NoSuchFieldError(_)))));
         Ok(())
     }
 
@@ -1,10 +1,11 @@
 use crate::frame::ExecutionResult::Return;
 use crate::frame::{ExecutionResult, ExecutionResult::Continue};
+use crate::instruction::{get_array_element, set_array_element};
 use crate::java_error::JavaError::ArithmeticException;
 use crate::local_variables::LocalVariables;
 use crate::operand_stack::OperandStack;
 use crate::Error::InvalidStackValue;
-use crate::JavaError::{ArrayIndexOutOfBoundsException, NullPointerException};
+use crate::JavaError::NullPointerException;
 use crate::{Result, Value};
 use ristretto_classloader::Reference;
 
@@ -150,11 +151,7 @@ pub(crate) fn faload(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::FloatArray(array)) => {
-            let index = usize::try_from(index)?;
-            let Some(value) = array.get(index)? else {
-                let length = array.len()?;
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
+            let value = get_array_element(&array, index)?;
             stack.push_float(value)?;
             Ok(Continue)
         }
@@ -173,12 +170,7 @@ pub(crate) fn fastore(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::FloatArray(ref mut array)) => {
-            let index = usize::try_from(index)?;
-            let length = array.capacity()?;
-            if index >= length {
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
-            array.set(index, value)?;
+            set_array_element(array, index, value)?;
             Ok(Continue)
         }
         Some(object) => Err(InvalidStackValue {
@@ -297,6 +289,7 @@ mod tests {
     use super::*;
     use crate::java_error::JavaError::ArithmeticException;
     use crate::Error::{InvalidOperand, JavaError};
+    use crate::JavaError::ArrayIndexOutOfBoundsException;
 
     #[test]
     fn test_fconst_0() -> Result<()> {
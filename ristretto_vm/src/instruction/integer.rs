@@ -1,10 +1,11 @@
 use crate::frame::ExecutionResult::Return;
 use crate::frame::{ExecutionResult, ExecutionResult::Continue};
+use crate::instruction::{get_array_element, set_array_element};
 use crate::java_error::JavaError::ArithmeticException;
 use crate::local_variables::LocalVariables;
 use crate::operand_stack::OperandStack;
 use crate::Error::InvalidStackValue;
-use crate::JavaError::{ArrayIndexOutOfBoundsException, NullPointerException};
+use crate::JavaError::NullPointerException;
 use crate::{Result, Value};
 use ristretto_classloader::Reference;
 
@@ -178,11 +179,7 @@ pub(crate) fn iaload(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::IntArray(array)) => {
-            let index = usize::try_from(index)?;
-            let Some(value) = array.get(index)? else {
-                let length = array.len()?;
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
+            let value = get_array_element(&array, index)?;
             stack.push_int(value)?;
             Ok(Continue)
         }
@@ -201,12 +198,7 @@ pub(crate) fn iastore(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::IntArray(ref mut array)) => {
-            let index = usize::try_from(index)?;
-            let length = array.capacity()?;
-            if index >= length {
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
-            array.set(index, value)?;
+            set_array_element(array, index, value)?;
             Ok(Continue)
         }
         Some(object) => Err(InvalidStackValue {
@@ -248,11 +240,11 @@ pub(crate) fn imul(stack: &OperandStack) -> Result<ExecutionResult> {
 pub(crate) fn idiv(stack: &OperandStack) -> Result<ExecutionResult> {
     let value2 = stack.pop_int()?;
     let value1 = stack.pop_int()?;
-    stack.push_int(
-        value1
-            .checked_div(value2)
-            .ok_or(ArithmeticException("/ by zero".to_string()))?,
-    )?;
+    if value2 == 0 {
+        return Err(ArithmeticException("/ by zero".to_string()).into());
+    }
+    // MIN_VALUE / -1 overflows; the JVMS specifies that the result wraps to MIN_VALUE
+    stack.push_int(value1.wrapping_div(value2))?;
     Ok(Continue)
 }
 
@@ -261,11 +253,11 @@ pub(crate) fn idiv(stack: &OperandStack) -> Result<ExecutionResult> {
 pub(crate) fn irem(stack: &OperandStack) -> Result<ExecutionResult> {
     let value2 = stack.pop_int()?;
     let value1 = stack.pop_int()?;
-    stack.push_int(
-        value1
-            .checked_rem(value2)
-            .ok_or(ArithmeticException("/ by zero".to_string()))?,
-    )?;
+    if value2 == 0 {
+        return Err(ArithmeticException("/ by zero".to_string()).into());
+    }
+    // MIN_VALUE % -1 overflows; the JVMS specifies that the result is 0
+    stack.push_int(value1.wrapping_rem(value2))?;
     Ok(Continue)
 }
 
@@ -376,6 +368,7 @@ mod tests {
     use super::*;
     use crate::java_error::JavaError::ArithmeticException;
     use crate::Error::{InvalidOperand, JavaError};
+    use crate::JavaError::ArrayIndexOutOfBoundsException;
 
     #[test]
     fn test_iconst_m1() -> Result<()> {
@@ -616,6 +609,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_iaload_negative_index() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(2);
+        let array = Reference::from(vec![42i32]);
+        stack.push_object(Some(array))?;
+        stack.push_int(-1)?;
+        let result = iaload(stack);
+        assert!(matches!(
+            result,
+            Err(JavaError(ArrayIndexOutOfBoundsException { index, length }))
+            if index == -1 && length == 1
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_iaload_null_pointer() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(2);
@@ -672,6 +680,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_iastore_negative_index() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(3);
+        let array = Reference::from(vec![3i32]);
+        stack.push_object(Some(array))?;
+        stack.push_int(-1)?;
+        stack.push_int(42)?;
+        let result = iastore(stack);
+        assert!(matches!(
+            result,
+            Err(JavaError(ArrayIndexOutOfBoundsException { index, length }))
+            if index == -1 && length == 1
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_iastore_null_pointer() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(3);
@@ -759,6 +783,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_idiv_overflow() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(2);
+        stack.push_int(i32::MIN)?;
+        stack.push_int(-1)?;
+        let result = idiv(stack)?;
+        assert_eq!(Continue, result);
+        assert_eq!(i32::MIN, stack.pop_int()?);
+        Ok(())
+    }
+
     #[test]
     fn test_irem() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(2);
@@ -780,6 +815,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_irem_overflow() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(2);
+        stack.push_int(i32::MIN)?;
+        stack.push_int(-1)?;
+        let result = irem(stack)?;
+        assert_eq!(Continue, result);
+        assert_eq!(0, stack.pop_int()?);
+        Ok(())
+    }
+
     #[test]
     fn test_ineg() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
@@ -3,8 +3,8 @@ use crate::frame::{ExecutionResult, Frame};
 use crate::thread::Thread;
 use crate::Error::InternalError;
 use crate::{Error, Result};
-use ristretto_classfile::Constant;
 use ristretto_classfile::Error::InvalidConstantPoolIndexType;
+use ristretto_classfile::{ClassAccessFlags, Constant};
 use ristretto_classloader::Error::MethodNotFound;
 use ristretto_classloader::{Class, Method, Reference, Value};
 use std::sync::Arc;
@@ -21,6 +21,11 @@ enum InvocationType {
 #[inline]
 pub(crate) async fn invokevirtual(frame: &Frame, method_index: u16) -> Result<ExecutionResult> {
     let thread = frame.thread()?;
+    // The symbolic method reference is cached on the class of the call site; the method to
+    // execute is still selected from the class of the receiver on every invocation.
+    if let Some((class, method)) = frame.class().resolved_method(method_index)? {
+        return invoke_method(&thread, frame, class, method, &InvocationType::Virtual).await;
+    }
     let constant_pool = frame.class().constant_pool();
     let (class_index, name_and_type_index) = constant_pool.try_get_method_ref(method_index)?;
     let class_name = constant_pool.try_get_class(*class_index)?;
@@ -29,25 +34,30 @@ pub(crate) async fn invokevirtual(frame: &Frame, method_index: u16) -> Result<Ex
         constant_pool.try_get_name_and_type(*name_and_type_index)?;
     let method_name = constant_pool.try_get_utf8(*name_index)?;
     let method_descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
-    let method = try_get_virtual_method(&class, method_name, method_descriptor)?;
+    let (class, method) = try_get_virtual_method(&class, method_name, method_descriptor)?;
+    frame
+        .class()
+        .set_resolved_method(method_index, &class, method.clone())?;
 
     invoke_method(&thread, frame, class, method, &InvocationType::Virtual).await
 }
 
-/// Get a virtual method by name and descriptor.
+/// Get a virtual method by name and descriptor, along with the class that declares it.  Methods
+/// of `java/lang/Object` are found for interfaces as well, since the parent of an interface is
+/// `java/lang/Object`.
 ///
 /// # Errors
 /// if the method is not found.
-fn try_get_virtual_method<S: AsRef<str>>(
+pub(crate) fn try_get_virtual_method<S: AsRef<str>>(
     class: &Arc<Class>,
     name: S,
     descriptor: S,
-) -> Result<Arc<Method>> {
+) -> Result<(Arc<Class>, Arc<Method>)> {
     let name = name.as_ref();
     let descriptor = descriptor.as_ref();
 
     if let Some(method) = class.method(name, descriptor) {
-        return Ok(method);
+        return Ok((class.clone(), method));
     }
 
     for interface in class.interfaces()? {
@@ -74,10 +84,34 @@ fn try_get_virtual_method<S: AsRef<str>>(
     Ok(method)
 }
 
+/// Determine if the resolved method cannot be overridden; private and final methods, and methods
+/// declared by a final class, are always the selected method regardless of the class of the
+/// receiver.
+fn is_non_overridable(class: &Class, method: &Method) -> bool {
+    method.is_private()
+        || method.is_final()
+        || class
+            .class_file()
+            .access_flags
+            .contains(ClassAccessFlags::FINAL)
+}
+
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.invokespecial>
 #[inline]
 pub(crate) async fn invokespecial(frame: &Frame, method_index: u16) -> Result<ExecutionResult> {
     let thread = frame.thread()?;
+    // Special invocations always resolve to the same method, so the resolved method is cached on
+    // the class of the call site; resolution errors are not cached and surface on every use.
+    if let Some((method_class, method)) = frame.class().resolved_method(method_index)? {
+        return invoke_method(
+            &thread,
+            frame,
+            method_class,
+            method,
+            &InvocationType::Special,
+        )
+        .await;
+    }
     let constant_pool = frame.class().constant_pool();
     let (class_index, name_and_type_index) = constant_pool.try_get_method_ref(method_index)?;
     let class_name = constant_pool.try_get_class(*class_index)?;
@@ -87,6 +121,9 @@ pub(crate) async fn invokespecial(frame: &Frame, method_index: u16) -> Result<Ex
     let method_name = constant_pool.try_get_utf8(*name_index)?;
     let method_descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
     let (method_class, method) = try_get_special_method(&class, method_name, method_descriptor)?;
+    frame
+        .class()
+        .set_resolved_method(method_index, &method_class, method.clone())?;
 
     invoke_method(
         &thread,
@@ -136,6 +173,11 @@ fn try_get_special_method<S: AsRef<str>>(
 #[inline]
 pub(crate) async fn invokestatic(frame: &Frame, method_index: u16) -> Result<ExecutionResult> {
     let thread = frame.thread()?;
+    // Static invocations always resolve to the same method, so the resolved method is cached on
+    // the class of the call site; the class is initialized when the method is first resolved.
+    if let Some((class, method)) = frame.class().resolved_method(method_index)? {
+        return invoke_method(&thread, frame, class, method, &InvocationType::Static).await;
+    }
     let constant_pool = frame.class().constant_pool();
     let constant = constant_pool.try_get(method_index)?;
     let (Constant::MethodRef {
@@ -147,7 +189,12 @@ pub(crate) async fn invokestatic(frame: &Frame, method_index: u16) -> Result<Exe
         name_and_type_index,
     }) = constant
     else {
-        return Err(InvalidConstantPoolIndexType(method_index).into());
+        return Err(InvalidConstantPoolIndexType {
+            index: method_index,
+            expected: "CONSTANT_Methodref|CONSTANT_InterfaceMethodref".to_string(),
+            actual: constant.to_string(),
+        }
+        .into());
     };
     let class_name = constant_pool.try_get_class(*class_index)?;
     let class = thread.class(class_name).await?;
@@ -156,6 +203,9 @@ pub(crate) async fn invokestatic(frame: &Frame, method_index: u16) -> Result<Exe
     let method_name = constant_pool.try_get_utf8(*name_index)?;
     let method_descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
     let method = class.try_get_method(method_name, method_descriptor)?;
+    frame
+        .class()
+        .set_resolved_method(method_index, &class, method.clone())?;
 
     invoke_method(&thread, frame, class, method, &InvocationType::Static).await
 }
@@ -168,6 +218,11 @@ pub(crate) async fn invokeinterface(
     _count: u8,
 ) -> Result<ExecutionResult> {
     let thread = frame.thread()?;
+    // The symbolic method reference is cached on the class of the call site; the method to
+    // execute is still selected from the class of the receiver on every invocation.
+    if let Some((class, method)) = frame.class().resolved_method(method_index)? {
+        return invoke_method(&thread, frame, class, method, &InvocationType::Interface).await;
+    }
     let constant_pool = frame.class().constant_pool();
     let (class_index, name_and_type_index) =
         constant_pool.try_get_interface_method_ref(method_index)?;
@@ -177,7 +232,10 @@ pub(crate) async fn invokeinterface(
         constant_pool.try_get_name_and_type(*name_and_type_index)?;
     let method_name = constant_pool.try_get_utf8(*name_index)?;
     let method_descriptor = constant_pool.try_get_utf8(*descriptor_index)?;
-    let method = try_get_virtual_method(&class, method_name, method_descriptor)?;
+    let (class, method) = try_get_virtual_method(&class, method_name, method_descriptor)?;
+    frame
+        .class()
+        .set_resolved_method(method_index, &class, method.clone())?;
 
     invoke_method(&thread, frame, class, method, &InvocationType::Interface).await
 }
@@ -219,7 +277,9 @@ async fn invoke_method(
 
     // TODO: evaluate refactoring this
     match invocation_type {
-        InvocationType::Interface | InvocationType::Virtual => {
+        InvocationType::Interface | InvocationType::Virtual
+            if !is_non_overridable(&class, &method) =>
+        {
             let Some(Value::Object(Some(reference))) = arguments.first() else {
                 return Err(InternalError("No reference found".to_string()));
             };
@@ -238,11 +298,13 @@ async fn invoke_method(
 
             // Find the method in the class hierarchy; the Method.try_get_virtual_method() cannot
             // currently be used here because the class constant pool associated with the method is
-            // required for execution.
+            // required for execution.  Private methods do not override the resolved method.
             loop {
                 if let Some(class_method) = class.method(method_name, method_descriptor) {
-                    method = class_method;
-                    break;
+                    if !class_method.is_private() {
+                        method = class_method;
+                        break;
+                    }
                 }
                 let Some(parent_class) = class.parent()? else {
                     return Err(InternalError(
@@ -266,8 +328,217 @@ async fn invoke_method(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::java_object::JavaObject;
     use crate::Error::ClassLoaderError;
     use crate::VM;
+    use ristretto_classfile::MethodAccessFlags;
+
+    /// Create a frame whose class has a method reference to the given method.
+    async fn test_method_ref(
+        class_name: &str,
+        method_name: &str,
+        method_descriptor: &str,
+    ) -> Result<(Arc<VM>, Arc<Thread>, Frame, u16)> {
+        let (vm, thread, mut class) = crate::test::class().await?;
+        let constant_pool = Arc::get_mut(&mut class).expect("class").constant_pool_mut();
+        let class_index = constant_pool.add_class(class_name)?;
+        let method_index =
+            constant_pool.add_method_ref(class_index, method_name, method_descriptor)?;
+        let method = Method::new(
+            MethodAccessFlags::STATIC,
+            "test",
+            "()V",
+            10,
+            10,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )?;
+        let frame = Frame::new(
+            &Arc::downgrade(&thread),
+            &class,
+            &Arc::new(method),
+            Vec::new(),
+        )?;
+        Ok((vm, thread, frame, method_index))
+    }
+
+    #[tokio::test]
+    async fn test_invokestatic() -> Result<()> {
+        let (_vm, _thread, frame, method_index) =
+            test_method_ref("java/lang/Math", "abs", "(I)I").await?;
+        assert!(frame.class().resolved_method(method_index)?.is_none());
+
+        // The second invocation uses the cached method
+        for _ in 0..2 {
+            frame.stack().push_int(-3)?;
+            let result = invokestatic(&frame, method_index).await?;
+            assert_eq!(Continue, result);
+            assert_eq!(3, frame.stack().pop_int()?);
+            let (class, method) = frame
+                .class()
+                .resolved_method(method_index)?
+                .expect("resolved method");
+            assert_eq!("java/lang/Math", class.name());
+            assert_eq!("abs", method.name());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invokestatic_loop() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        let result = vm.invoke("StaticCall", "loop", "(I)I", vec![10]).await?;
+        assert_eq!(Some(Value::Int(45)), result);
+        Ok(())
+    }
+
+    #[expect(clippy::cast_sign_loss)]
+    #[tokio::test]
+    async fn test_arithmetic_loop() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        let result = vm
+            .invoke("StaticCall", "arithmetic", "(I)I", vec![100])
+            .await?;
+        let mut expected = 0i32;
+        for i in 0..100 {
+            expected = expected.wrapping_mul(31).wrapping_add(i);
+            expected ^= i32::try_from(expected as u32 >> 7)?;
+        }
+        assert_eq!(Some(Value::Int(expected)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invokevirtual_final_method() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        let result = vm
+            .invoke("VirtualCall", "finalMethod", "()I", Vec::<Value>::new())
+            .await?;
+        assert_eq!(Some(Value::Int(1)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invokevirtual_private_method() -> Result<()> {
+        let (vm, _thread, frame, method_index) =
+            test_method_ref("VirtualCall$Base", "secret", "()I").await?;
+
+        // The public method of the subclass does not override the private method
+        let derived = vm
+            .object("VirtualCall$Derived", "", Vec::<Value>::new())
+            .await?;
+        frame.stack().push(derived)?;
+        let result = invokevirtual(&frame, method_index).await?;
+        assert_eq!(Continue, result);
+        assert_eq!(10, frame.stack().pop_int()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invokeinterface_overridden_method() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        let result = vm
+            .invoke(
+                "VirtualCall",
+                "virtualMethod",
+                "()Ljava/lang/String;",
+                Vec::<Value>::new(),
+            )
+            .await?;
+        let value: String = result.expect("value").try_into()?;
+        assert_eq!("derived", value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invokevirtual_object_method_on_interface() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        let result = vm
+            .invoke(
+                "VirtualCall",
+                "interfaceHashCode",
+                "()Z",
+                Vec::<Value>::new(),
+            )
+            .await?;
+        assert_eq!(Some(Value::Int(1)), result);
+        let result = vm
+            .invoke(
+                "VirtualCall",
+                "interfaceToString",
+                "()Ljava/lang/String;",
+                Vec::<Value>::new(),
+            )
+            .await?;
+        let value: String = result.expect("value").try_into()?;
+        assert_eq!("Base:derived", value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invokevirtual_dispatch_loop() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        let result = vm
+            .invoke("VirtualCall", "dispatch", "(I)I", vec![5])
+            .await?;
+        assert_eq!(Some(Value::Int(7)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invokestatic_method_not_found() -> Result<()> {
+        let (_vm, _thread, frame, method_index) =
+            test_method_ref("java/lang/Math", "foo", "()V").await?;
+        // Resolution errors are not cached
+        for _ in 0..2 {
+            let result = invokestatic(&frame, method_index).await;
+            assert!(matches!(
+                result,
+                Err(ClassLoaderError(MethodNotFound { method_name, .. })) if method_name == "foo"
+            ));
+            assert!(frame.class().resolved_method(method_index)?.is_none());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invokespecial() -> Result<()> {
+        let (vm, _thread, frame, method_index) =
+            test_method_ref("java/lang/String", "length", "()I").await?;
+
+        // The second invocation uses the cached method
+        for _ in 0..2 {
+            let string = "foo".to_object(&vm).await?;
+            frame.stack().push(string)?;
+            let result = invokespecial(&frame, method_index).await?;
+            assert_eq!(Continue, result);
+            assert_eq!(3, frame.stack().pop_int()?);
+            let (class, method) = frame
+                .class()
+                .resolved_method(method_index)?
+                .expect("resolved method");
+            assert_eq!("java/lang/String", class.name());
+            assert_eq!("length", method.name());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invokespecial_method_not_found() -> Result<()> {
+        let (_vm, _thread, frame, method_index) =
+            test_method_ref("java/lang/String", "foo", "()V").await?;
+        // Resolution errors are not cached
+        for _ in 0..2 {
+            let result = invokespecial(&frame, method_index).await;
+            assert!(matches!(
+                result,
+                Err(ClassLoaderError(MethodNotFound { method_name, .. })) if method_name == "foo"
+            ));
+            assert!(frame.class().resolved_method(method_index)?.is_none());
+        }
+        Ok(())
+    }
 
     #[tokio::test]
     async fn test_try_get_virtual_method_hierarchy() -> Result<()> {
@@ -287,6 +558,16 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_try_get_virtual_method_object_method_on_interface() -> Result<()> {
+        let vm = VM::default().await?;
+        let class = vm.class("java/lang/Runnable").await?;
+        let (method_class, method) = try_get_virtual_method(&class, "hashCode", "()I")?;
+        assert_eq!("java/lang/Object", method_class.name());
+        assert_eq!("hashCode", method.name());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_try_get_virtual_method_not_found() -> Result<()> {
         let vm = VM::default().await?;
@@ -22,10 +22,14 @@ pub(crate) async fn ldc_w(frame: &Frame, index: u16) -> Result<ExecutionResult>
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.ldc2_w>
 #[inline]
 pub(crate) fn ldc2_w(frame: &Frame, index: u16) -> Result<ExecutionResult> {
-    let constant_pool = frame.class().constant_pool();
+    let class = frame.class();
+    let constant_pool = class.constant_pool();
     let constant = constant_pool
         .get(index)
-        .ok_or_else(|| InvalidConstantIndex(index))?;
+        .ok_or_else(|| InvalidConstantIndex {
+            index,
+            class_name: Some(class.name().to_string()),
+        })?;
 
     let value = match constant {
         Constant::Long(value) => Value::Long(*value),
@@ -34,6 +38,7 @@ pub(crate) fn ldc2_w(frame: &Frame, index: u16) -> Result<ExecutionResult> {
             return Err(InvalidConstant {
                 expected: "long|double".to_string(),
                 actual: format!("{constant:?}"),
+                class_name: Some(class.name().to_string()),
             })
         }
     };
@@ -46,19 +51,29 @@ pub(crate) fn ldc2_w(frame: &Frame, index: u16) -> Result<ExecutionResult> {
 /// # Errors
 /// if the constant is not an integer, float, string or class
 async fn load_constant(frame: &Frame, index: u16) -> Result<ExecutionResult> {
-    let constant_pool = frame.class().constant_pool();
+    let class = frame.class();
+    let constant_pool = class.constant_pool();
     let constant = constant_pool
         .get(index)
-        .ok_or_else(|| InvalidConstantIndex(index))?;
+        .ok_or_else(|| InvalidConstantIndex {
+            index,
+            class_name: Some(class.name().to_string()),
+        })?;
 
     let value = match constant {
         Constant::Integer(value) => Value::Int(*value),
         Constant::Float(value) => Value::Float(*value),
         Constant::String(utf8_index) => {
+            // String literals with the same value are the same object
             let utf8_value = constant_pool.try_get_utf8(*utf8_index)?;
             let thread = frame.thread()?;
             let vm = thread.vm()?;
-            utf8_value.to_object(&vm).await?
+            if let Some(string) = vm.interned_string(utf8_value) {
+                string
+            } else {
+                let string = utf8_value.to_object(&vm).await?;
+                vm.intern_string(utf8_value.clone(), string)
+            }
         }
         Constant::Class(class_index) => {
             let class_name = constant_pool.try_get_utf8(*class_index)?;
@@ -71,6 +86,7 @@ async fn load_constant(frame: &Frame, index: u16) -> Result<ExecutionResult> {
             return Err(InvalidConstant {
                 expected: "integer|float|string|class".to_string(),
                 actual: format!("{constant:?}"),
+                class_name: Some(class.name().to_string()),
             })
         }
     };
@@ -146,6 +162,20 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_load_constant_string_interned() -> Result<()> {
+        let (_vm, _thread, mut frame) = crate::test::frame().await?;
+        let class = frame.class_mut();
+        let constant_pool = Arc::get_mut(class).expect("class").constant_pool_mut();
+        let index = constant_pool.add_string("interned literal")?;
+        load_constant(&frame, index).await?;
+        load_constant(&frame, index).await?;
+        let object1 = frame.stack().pop_object()?.expect("object");
+        let object2 = frame.stack().pop_object()?.expect("object");
+        assert!(object1.ptr_eq(&object2));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_load_constant_class() -> Result<()> {
         let (_vm, _thread, mut frame) = crate::test::frame().await?;
@@ -155,7 +185,7 @@ mod test {
         let process_result = load_constant(&frame, index).await?;
         assert_eq!(process_result, Continue);
         let object = frame.stack().pop_object()?.expect("object");
-        assert_eq!("Class(java/lang/Object)", format!("{object}"));
+        assert_eq!("Class(java.lang.Object)", format!("{object}"));
         Ok(())
     }
 
@@ -163,7 +193,24 @@ mod test {
     async fn test_load_constant_invalid_index() -> Result<()> {
         let (_vm, _thread, frame) = crate::test::frame().await?;
         let result = load_constant(&frame, 42).await;
-        assert!(matches!(result, Err(InvalidConstantIndex(42))));
+        assert!(matches!(
+            result,
+            Err(InvalidConstantIndex { index: 42, .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_load_constant_invalid_index_message() -> Result<()> {
+        let (_vm, _thread, frame) = crate::test::frame().await?;
+        let class_name = frame.class().name().to_string();
+        let Err(error) = load_constant(&frame, 42).await else {
+            panic!("expected error");
+        };
+        assert_eq!(
+            format!("Invalid constant index 42 in class {class_name}"),
+            error.to_string()
+        );
         Ok(())
     }
 
@@ -178,7 +225,8 @@ mod test {
             result,
             Err(InvalidConstant {
                 expected,
-                actual
+                actual,
+                ..
             }) if expected == "integer|float|string|class" && actual == "Long(42)"
         ));
         Ok(())
@@ -213,7 +261,10 @@ mod test {
     async fn test_ldc2_w_invalid_index() -> Result<()> {
         let (_vm, _thread, frame) = crate::test::frame().await?;
         let result = ldc2_w(&frame, 42);
-        assert!(matches!(result, Err(InvalidConstantIndex(42))));
+        assert!(matches!(
+            result,
+            Err(InvalidConstantIndex { index: 42, .. })
+        ));
         Ok(())
     }
 
@@ -228,7 +279,8 @@ mod test {
             result,
             Err(InvalidConstant {
                 expected,
-                actual
+                actual,
+                ..
             }) if expected == "long|double" && actual == "Integer(42)"
         ));
 
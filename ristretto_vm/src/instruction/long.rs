@@ -1,10 +1,11 @@
 use crate::frame::ExecutionResult::Return;
 use crate::frame::{ExecutionResult, ExecutionResult::Continue};
+use crate::instruction::{get_array_element, set_array_element};
 use crate::java_error::JavaError::ArithmeticException;
 use crate::local_variables::LocalVariables;
 use crate::operand_stack::OperandStack;
 use crate::Error::InvalidStackValue;
-use crate::JavaError::{ArrayIndexOutOfBoundsException, NullPointerException};
+use crate::JavaError::NullPointerException;
 use crate::{Result, Value};
 use ristretto_classloader::Reference;
 use std::cmp::Ordering;
@@ -144,11 +145,7 @@ pub(crate) fn laload(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::LongArray(array)) => {
-            let index = usize::try_from(index)?;
-            let Some(value) = array.get(index)? else {
-                let length = array.len()?;
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
+            let value = get_array_element(&array, index)?;
             stack.push_long(value)?;
             Ok(Continue)
         }
@@ -167,12 +164,7 @@ pub(crate) fn lastore(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::LongArray(ref mut array)) => {
-            let index = usize::try_from(index)?;
-            let length = array.capacity()?;
-            if index >= length {
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
-            array.set(index, value)?;
+            set_array_element(array, index, value)?;
             Ok(Continue)
         }
         Some(object) => Err(InvalidStackValue {
@@ -214,11 +206,11 @@ pub(crate) fn lmul(stack: &OperandStack) -> Result<ExecutionResult> {
 pub(crate) fn ldiv(stack: &OperandStack) -> Result<ExecutionResult> {
     let value2 = stack.pop_long()?;
     let value1 = stack.pop_long()?;
-    stack.push_long(
-        value1
-            .checked_div(value2)
-            .ok_or(ArithmeticException("/ by zero".to_string()))?,
-    )?;
+    if value2 == 0 {
+        return Err(ArithmeticException("/ by zero".to_string()).into());
+    }
+    // MIN_VALUE / -1 overflows; the JVMS specifies that the result wraps to MIN_VALUE
+    stack.push_long(value1.wrapping_div(value2))?;
     Ok(Continue)
 }
 
@@ -227,11 +219,11 @@ pub(crate) fn ldiv(stack: &OperandStack) -> Result<ExecutionResult> {
 pub(crate) fn lrem(stack: &OperandStack) -> Result<ExecutionResult> {
     let value2 = stack.pop_long()?;
     let value1 = stack.pop_long()?;
-    stack.push_long(
-        value1
-            .checked_rem(value2)
-            .ok_or(ArithmeticException("/ by zero".to_string()))?,
-    )?;
+    if value2 == 0 {
+        return Err(ArithmeticException("/ by zero".to_string()).into());
+    }
+    // MIN_VALUE % -1 overflows; the JVMS specifies that the result is 0
+    stack.push_long(value1.wrapping_rem(value2))?;
     Ok(Continue)
 }
 
@@ -333,6 +325,7 @@ mod tests {
     use super::*;
     use crate::java_error::JavaError::ArithmeticException;
     use crate::Error::{InvalidOperand, JavaError};
+    use crate::JavaError::ArrayIndexOutOfBoundsException;
 
     #[test]
     fn test_lconst_0() -> Result<()> {
@@ -671,6 +664,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_ldiv_overflow() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(2);
+        stack.push_long(i64::MIN)?;
+        stack.push_long(-1)?;
+        let result = ldiv(stack)?;
+        assert_eq!(Continue, result);
+        assert_eq!(i64::MIN, stack.pop_long()?);
+        Ok(())
+    }
+
     #[test]
     fn test_lrem() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(2);
@@ -692,6 +696,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_lrem_overflow() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(2);
+        stack.push_long(i64::MIN)?;
+        stack.push_long(-1)?;
+        let result = lrem(stack)?;
+        assert_eq!(Continue, result);
+        assert_eq!(0, stack.pop_long()?);
+        Ok(())
+    }
+
     #[test]
     fn test_lneg() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(1);
@@ -1,10 +1,13 @@
 use crate::frame::ExecutionResult::Return;
 use crate::frame::{ExecutionResult, ExecutionResult::Continue, Frame};
+use crate::instruction::{get_array_element, set_array_element};
 use crate::local_variables::LocalVariables;
 use crate::operand_stack::OperandStack;
-use crate::Error::{InternalError, InvalidStackValue};
-use crate::JavaError::{ArrayIndexOutOfBoundsException, ClassCastException, NullPointerException};
+use crate::thread::Thread;
+use crate::Error::{InternalError, InvalidOperand, InvalidStackValue};
+use crate::JavaError::{ClassCastException, NullPointerException};
 use crate::{Result, Value};
+use async_recursion::async_recursion;
 use ristretto_classloader::{Class, Object, Reference};
 use std::sync::Arc;
 
@@ -15,6 +18,20 @@ pub(crate) fn aconst_null(stack: &OperandStack) -> Result<ExecutionResult> {
     Ok(Continue)
 }
 
+/// Load a reference from the local variable at the given index and push it onto the operand
+/// stack. Unlike `astore`, the `aload` instructions cannot load a return address; this asymmetry
+/// is intentional in the JVM specification so that return addresses can only be used by `ret`.
+#[inline]
+fn load_reference(
+    locals: &LocalVariables,
+    stack: &OperandStack,
+    index: usize,
+) -> Result<ExecutionResult> {
+    let object = locals.get_object(index)?;
+    stack.push_object(object)?;
+    Ok(Continue)
+}
+
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.aload>
 #[inline]
 pub(crate) fn aload(
@@ -22,9 +39,7 @@ pub(crate) fn aload(
     stack: &OperandStack,
     index: u8,
 ) -> Result<ExecutionResult> {
-    let object = locals.get_object(usize::from(index))?;
-    stack.push_object(object)?;
-    Ok(Continue)
+    load_reference(locals, stack, usize::from(index))
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.aload>
@@ -35,40 +50,52 @@ pub(crate) fn aload_w(
     stack: &OperandStack,
     index: u16,
 ) -> Result<ExecutionResult> {
-    let object = locals.get_object(usize::from(index))?;
-    stack.push_object(object)?;
-    Ok(Continue)
+    load_reference(locals, stack, usize::from(index))
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.aload_n>
 #[inline]
 pub(crate) fn aload_0(locals: &LocalVariables, stack: &OperandStack) -> Result<ExecutionResult> {
-    let object = locals.get_object(0)?;
-    stack.push_object(object)?;
-    Ok(Continue)
+    load_reference(locals, stack, 0)
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.aload_n>
 #[inline]
 pub(crate) fn aload_1(locals: &LocalVariables, stack: &OperandStack) -> Result<ExecutionResult> {
-    let object = locals.get_object(1)?;
-    stack.push_object(object)?;
-    Ok(Continue)
+    load_reference(locals, stack, 1)
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.aload_n>
 #[inline]
 pub(crate) fn aload_2(locals: &LocalVariables, stack: &OperandStack) -> Result<ExecutionResult> {
-    let object = locals.get_object(2)?;
-    stack.push_object(object)?;
-    Ok(Continue)
+    load_reference(locals, stack, 2)
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.aload_n>
 #[inline]
 pub(crate) fn aload_3(locals: &LocalVariables, stack: &OperandStack) -> Result<ExecutionResult> {
-    let object = locals.get_object(3)?;
-    stack.push_object(object)?;
+    load_reference(locals, stack, 3)
+}
+
+/// Pop a reference or a return address from the operand stack and store it in the local
+/// variable at the given index. Return addresses are pushed by the `jsr` and `jsr_w` instructions
+/// and are stored with `astore` so that `ret` can later jump back to the caller.
+#[inline]
+fn store_reference(
+    locals: &LocalVariables,
+    stack: &OperandStack,
+    index: usize,
+) -> Result<ExecutionResult> {
+    let value = stack.pop()?;
+    match value {
+        Value::Object(_) | Value::ReturnAddress(_) => locals.set(index, value)?,
+        value => {
+            return Err(InvalidOperand {
+                expected: "object or returnAddress".to_string(),
+                actual: value.to_string(),
+            })
+        }
+    }
     Ok(Continue)
 }
 
@@ -79,9 +106,7 @@ pub(crate) fn astore(
     stack: &OperandStack,
     index: u8,
 ) -> Result<ExecutionResult> {
-    let value = stack.pop_object()?;
-    locals.set_object(usize::from(index), value)?;
-    Ok(Continue)
+    store_reference(locals, stack, usize::from(index))
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.astore>
@@ -92,41 +117,31 @@ pub(crate) fn astore_w(
     stack: &OperandStack,
     index: u16,
 ) -> Result<ExecutionResult> {
-    let value = stack.pop_object()?;
-    locals.set_object(usize::from(index), value)?;
-    Ok(Continue)
+    store_reference(locals, stack, usize::from(index))
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.astore_n>
 #[inline]
 pub(crate) fn astore_0(locals: &LocalVariables, stack: &OperandStack) -> Result<ExecutionResult> {
-    let value = stack.pop_object()?;
-    locals.set_object(0, value)?;
-    Ok(Continue)
+    store_reference(locals, stack, 0)
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.astore_n>
 #[inline]
 pub(crate) fn astore_1(locals: &LocalVariables, stack: &OperandStack) -> Result<ExecutionResult> {
-    let value = stack.pop_object()?;
-    locals.set_object(1, value)?;
-    Ok(Continue)
+    store_reference(locals, stack, 1)
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.astore_n>
 #[inline]
 pub(crate) fn astore_2(locals: &LocalVariables, stack: &OperandStack) -> Result<ExecutionResult> {
-    let value = stack.pop_object()?;
-    locals.set_object(2, value)?;
-    Ok(Continue)
+    store_reference(locals, stack, 2)
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.astore_n>
 #[inline]
 pub(crate) fn astore_3(locals: &LocalVariables, stack: &OperandStack) -> Result<ExecutionResult> {
-    let value = stack.pop_object()?;
-    locals.set_object(3, value)?;
-    Ok(Continue)
+    store_reference(locals, stack, 3)
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.aaload>
@@ -136,11 +151,7 @@ pub(crate) fn aaload(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::Array(_class, array)) => {
-            let index = usize::try_from(index)?;
-            let Some(value) = array.get(index)? else {
-                let length = array.len()?;
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
+            let value = get_array_element(&array, index)?;
             stack.push_object(value)?;
             Ok(Continue)
         }
@@ -159,14 +170,9 @@ pub(crate) fn aastore(stack: &OperandStack) -> Result<ExecutionResult> {
     match stack.pop_object()? {
         None => Err(NullPointerException("array cannot be null".to_string()).into()),
         Some(Reference::Array(_class, ref mut array)) => {
-            let index = usize::try_from(index)?;
-            let length = array.capacity()?;
-            if index >= length {
-                return Err(ArrayIndexOutOfBoundsException { index, length }.into());
-            };
             // TODO: validate object type is compatible with array type
             // See: https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.aastore
-            array.set(index, value)?;
+            set_array_element(array, index, value)?;
             Ok(Continue)
         }
         Some(object) => Err(InvalidStackValue {
@@ -186,10 +192,16 @@ pub(crate) fn areturn(stack: &OperandStack) -> Result<ExecutionResult> {
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.new>
 #[inline]
 pub(crate) async fn new(frame: &Frame, index: u16) -> Result<ExecutionResult> {
-    let thread = frame.thread()?;
-    let constant_pool = frame.class().constant_pool();
-    let class_name = constant_pool.try_get_class(index)?;
-    let class = thread.class(class_name).await?;
+    let class = if let Some(class) = frame.class().resolved_class(index)? {
+        class
+    } else {
+        let thread = frame.thread()?;
+        let constant_pool = frame.class().constant_pool();
+        let class_name = constant_pool.try_get_class(index)?;
+        let class = thread.class(class_name).await?;
+        frame.class().set_resolved_class(index, &class)?;
+        class
+    };
     let object = Object::new(class)?;
     let reference = Reference::from(object);
     let stack = frame.stack();
@@ -213,7 +225,7 @@ pub(crate) async fn checkcast(frame: &Frame, class_index: u16) -> Result<Executi
     let class_name = constant_pool.try_get_class(class_index)?;
     let thread = frame.thread()?;
     let class = thread.class(class_name).await?;
-    if !is_instance_of(&object, &class)? {
+    if !is_instance_of(&thread, &object, &class).await? {
         let source_class_name = object.class_name().replace('/', ".");
         let target_class_name = class_name.replace('/', ".");
         return Err(ClassCastException {
@@ -240,7 +252,7 @@ pub(crate) async fn instanceof(frame: &Frame, class_index: u16) -> Result<Execut
     let class_name = constant_pool.try_get_class(class_index)?;
     let thread = frame.thread()?;
     let class = thread.class(class_name).await?;
-    if is_instance_of(&object, &class)? {
+    if is_instance_of(&thread, &object, &class).await? {
         stack.push_int(1)?;
     } else {
         stack.push_int(0)?;
@@ -248,29 +260,103 @@ pub(crate) async fn instanceof(frame: &Frame, class_index: u16) -> Result<Execut
     Ok(Continue)
 }
 
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.monitorenter>
+#[inline]
+pub(crate) fn monitorenter(stack: &OperandStack) -> Result<ExecutionResult> {
+    if stack.pop_object()?.is_none() {
+        return Err(NullPointerException(
+            "Cannot enter synchronized block because object is null".to_string(),
+        )
+        .into());
+    }
+    // Monitors are not currently implemented; this implementation does not acquire a lock.
+    Ok(Continue)
+}
+
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.monitorexit>
+#[inline]
+pub(crate) fn monitorexit(stack: &OperandStack) -> Result<ExecutionResult> {
+    if stack.pop_object()?.is_none() {
+        return Err(NullPointerException(
+            "Cannot exit synchronized block because object is null".to_string(),
+        )
+        .into());
+    }
+    // Monitors are not currently implemented; this implementation does not release a lock.
+    Ok(Continue)
+}
+
+/// Determine if the object is an instance of the class.
 #[inline]
-fn is_instance_of(object: &Reference, class: &Arc<Class>) -> Result<bool> {
+pub(crate) async fn is_instance_of(
+    thread: &Thread,
+    object: &Reference,
+    class: &Arc<Class>,
+) -> Result<bool> {
     match object {
-        Reference::ByteArray(_)
-        | Reference::CharArray(_)
-        | Reference::ShortArray(_)
-        | Reference::IntArray(_)
-        | Reference::LongArray(_)
-        | Reference::FloatArray(_)
-        | Reference::DoubleArray(_) => {
-            let reference_class_name = object.class_name();
-            Ok(reference_class_name == class.name())
-        }
-        Reference::Array(array_class, _) => Ok(class.is_assignable_from(array_class)?),
         Reference::Object(object) => Ok(object.instance_of(class)?),
+        Reference::Array(array_class, _) => is_assignable(thread, class, array_class).await,
+        _ => {
+            let array_class = thread.class(object.class_name()).await?;
+            is_assignable(thread, class, &array_class).await
+        }
     }
 }
 
+/// Determine if a value of the `source` class can be assigned to the `target` class.  Array
+/// component classes are resolved so that array covariance is handled; i.e. `SC[]` can be
+/// assigned to `TC[]` if `SC` can be assigned to `TC`, and every array can be assigned to
+/// `java.lang.Object`, `java.lang.Cloneable` and `java.io.Serializable`.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.checkcast>
+#[async_recursion(?Send)]
+pub(crate) async fn is_assignable(
+    thread: &Thread,
+    target: &Arc<Class>,
+    source: &Arc<Class>,
+) -> Result<bool> {
+    if !source.is_array() {
+        return Ok(target.is_assignable_from(source)?);
+    }
+    if matches!(
+        target.name(),
+        "java/lang/Object" | "java/lang/Cloneable" | "java/io/Serializable"
+    ) {
+        return Ok(true);
+    }
+    if !target.is_array() {
+        return Ok(false);
+    }
+
+    let source_component = &source.name()[1..];
+    let target_component = &target.name()[1..];
+    let is_reference = |component: &str| component.starts_with(['L', '[']);
+    if !is_reference(source_component) || !is_reference(target_component) {
+        // Arrays of primitives are only assignable to arrays of the same primitive type
+        return Ok(source_component == target_component);
+    }
+
+    let source_component = thread.class(component_name(source_component)).await?;
+    let target_component = thread.class(component_name(target_component)).await?;
+    is_assignable(thread, &target_component, &source_component).await
+}
+
+/// Get the class name of an array component descriptor (e.g. `Ljava/lang/String;` is
+/// `java/lang/String` and `[I` is unchanged).
+fn component_name(component: &str) -> &str {
+    component
+        .strip_prefix('L')
+        .and_then(|component| component.strip_suffix(';'))
+        .unwrap_or(component)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::frame::ExecutionResult::ContinueAtPosition;
     use crate::java_object::JavaObject;
-    use crate::Error::{InvalidOperand, JavaError};
+    use crate::Error::{InvalidLocalVariable, InvalidOperand, JavaError};
+    use crate::JavaError::ArrayIndexOutOfBoundsException;
     use crate::JavaError::NullPointerException;
     use ristretto_classloader::ConcurrentVec;
     use std::sync::Arc;
@@ -361,6 +447,96 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_astore_return_address() -> Result<()> {
+        let locals = &LocalVariables::with_max_size(1);
+        let stack = &mut OperandStack::with_max_size(1);
+        stack.push(Value::ReturnAddress(3))?;
+        let result = astore(locals, stack, 0)?;
+        assert_eq!(Continue, result);
+        assert_eq!(3, locals.get_return_address(0)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_astore_invalid_value() -> Result<()> {
+        let locals = &LocalVariables::with_max_size(1);
+        let stack = &mut OperandStack::with_max_size(1);
+        stack.push_int(42)?;
+        let result = astore(locals, stack, 0);
+        assert!(matches!(
+            result,
+            Err(InvalidOperand {
+                expected,
+                actual
+            }) if expected == "object or returnAddress" && actual == "int(42)"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsr_astore_ret() -> Result<()> {
+        let locals = &LocalVariables::with_max_size(1);
+        let stack = &mut OperandStack::with_max_size(1);
+        let result = crate::instruction::jsr(stack, 4, 10)?;
+        assert_eq!(ContinueAtPosition(10), result);
+        assert_eq!(Value::ReturnAddress(5), stack.peek()?);
+        astore_0(locals, stack)?;
+        assert!(stack.is_empty()?);
+        let result = crate::instruction::ret(locals, 0)?;
+        assert_eq!(ContinueAtPosition(5), result);
+        Ok(())
+    }
+
+    #[test]
+    fn test_aload_return_address() -> Result<()> {
+        let locals = LocalVariables::with_max_size(1);
+        locals.set(0, Value::ReturnAddress(5))?;
+        let stack = &mut OperandStack::with_max_size(1);
+        let result = aload_0(&locals, stack);
+        assert!(matches!(
+            result,
+            Err(InvalidLocalVariable { expected, actual })
+                if expected == "object" && actual == "returnAddress(5)"
+        ));
+        assert!(stack.is_empty()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_astore_reference_and_return_address_same_slot() -> Result<()> {
+        let locals = &LocalVariables::with_max_size(1);
+        let stack = &mut OperandStack::with_max_size(1);
+        let object = Reference::from(vec![42i32]);
+
+        // A reference is stored and loaded through the slot
+        stack.push_object(Some(object.clone()))?;
+        astore_0(locals, stack)?;
+        aload_0(locals, stack)?;
+        let Some(loaded) = stack.pop_object()? else {
+            panic!("expected reference");
+        };
+        assert!(loaded.ptr_eq(&object));
+
+        // The same slot is then reused for the return address of a subroutine
+        crate::instruction::jsr(stack, 7, 12)?;
+        astore_0(locals, stack)?;
+        assert_eq!(Value::ReturnAddress(8), *locals.get(0)?);
+        let result = crate::instruction::ret(locals, 0)?;
+        assert_eq!(ContinueAtPosition(8), result);
+
+        // And for a reference again
+        stack.push_object(None)?;
+        astore_0(locals, stack)?;
+        aload_0(locals, stack)?;
+        assert_eq!(None, stack.pop_object()?);
+        assert!(matches!(
+            crate::instruction::ret(locals, 0),
+            Err(InvalidLocalVariable { .. })
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_astore_w() -> Result<()> {
         let locals = &LocalVariables::with_max_size(1);
@@ -648,6 +824,134 @@ mod tests {
         Ok(())
     }
 
+    async fn test_instanceof_reference(reference: Reference, class_name: &str) -> Result<i32> {
+        let (_vm, _thread, mut frame) = crate::test::frame().await?;
+        frame.stack().push_object(Some(reference))?;
+        let class_index = get_class_index(&mut frame, class_name)?;
+        let result = instanceof(&frame, class_index).await?;
+        assert_eq!(Continue, result);
+        frame.stack().pop_int()
+    }
+
+    #[tokio::test]
+    async fn test_instanceof_string_array_to_object_array() -> Result<()> {
+        let (_vm, thread, _frame) = crate::test::frame().await?;
+        let string_class = thread.class("[Ljava/lang/String;").await?;
+        let string_array = Reference::Array(string_class, ConcurrentVec::default());
+        let result = test_instanceof_reference(string_array, "[Ljava/lang/Object;").await?;
+        assert_eq!(1, result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_instanceof_object_array_to_string_array() -> Result<()> {
+        let (_vm, thread, _frame) = crate::test::frame().await?;
+        let object_class = thread.class("[Ljava/lang/Object;").await?;
+        let object_array = Reference::Array(object_class, ConcurrentVec::default());
+        let result = test_instanceof_reference(object_array, "[Ljava/lang/String;").await?;
+        assert_eq!(0, result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_instanceof_multi_dimensional_string_array_to_object_array() -> Result<()> {
+        let (_vm, thread, _frame) = crate::test::frame().await?;
+        let string_class = thread.class("[[Ljava/lang/String;").await?;
+        let string_array = Reference::Array(string_class, ConcurrentVec::default());
+        assert_eq!(
+            1,
+            test_instanceof_reference(string_array.clone(), "[Ljava/lang/Object;").await?
+        );
+        assert_eq!(
+            1,
+            test_instanceof_reference(string_array.clone(), "[Ljava/lang/Cloneable;").await?
+        );
+        assert_eq!(
+            0,
+            test_instanceof_reference(string_array, "[Ljava/lang/String;").await?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_instanceof_int_array() -> Result<()> {
+        let int_array = Reference::from(vec![0i32]);
+        assert_eq!(
+            1,
+            test_instanceof_reference(int_array.clone(), "java/lang/Cloneable").await?
+        );
+        assert_eq!(
+            1,
+            test_instanceof_reference(int_array.clone(), "java/io/Serializable").await?
+        );
+        assert_eq!(
+            1,
+            test_instanceof_reference(int_array.clone(), "java/lang/Object").await?
+        );
+        assert_eq!(1, test_instanceof_reference(int_array.clone(), "[I").await?);
+        assert_eq!(0, test_instanceof_reference(int_array.clone(), "[J").await?);
+        assert_eq!(
+            0,
+            test_instanceof_reference(int_array, "[Ljava/lang/Object;").await?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_checkcast_object_array_to_string_array() -> Result<()> {
+        let (_vm, thread, mut frame) = crate::test::frame().await?;
+        let object_class = thread.class("[Ljava/lang/Object;").await?;
+        let object_array = Reference::Array(object_class, ConcurrentVec::default());
+        frame.stack().push_object(Some(object_array))?;
+        let class_index = get_class_index(&mut frame, "[Ljava/lang/String;")?;
+        let result = checkcast(&frame, class_index).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(ClassCastException { source_class_name, target_class_name}))
+            if source_class_name == "[Ljava.lang.Object;"
+                && target_class_name == "[Ljava.lang.String;"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_monitorenter() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(1);
+        stack.push_object(Some(Reference::from(vec![42i8])))?;
+        let result = monitorenter(stack)?;
+        assert_eq!(Continue, result);
+        assert!(stack.is_empty()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_monitorenter_null() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(1);
+        stack.push_object(None)?;
+        let result = monitorenter(stack);
+        assert!(matches!(result, Err(JavaError(NullPointerException(_)))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_monitorexit() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(1);
+        stack.push_object(Some(Reference::from(vec![42i8])))?;
+        let result = monitorexit(stack)?;
+        assert_eq!(Continue, result);
+        assert!(stack.is_empty()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_monitorexit_null() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(1);
+        stack.push_object(None)?;
+        let result = monitorexit(stack);
+        assert!(matches!(result, Err(JavaError(NullPointerException(_)))));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_instanceof_null() -> Result<()> {
         let (_vm, _thread, mut frame) = crate::test::frame().await?;
@@ -1,7 +1,26 @@
 use crate::frame::ExecutionResult;
 use crate::frame::ExecutionResult::Continue;
 use crate::operand_stack::OperandStack;
+use crate::Error::InvalidStackValue;
 use crate::Result;
+use ristretto_classloader::Value;
+
+/// Pop a value that must be a category 1 computational type (i.e. not a `long` or `double`); the
+/// forms of the `pop2` and `dup2` instructions that operate on two values require the second value
+/// to be category 1.
+///
+/// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-2.html#jvms-2.11.1>
+#[inline]
+fn pop_category_1(stack: &OperandStack) -> Result<Value> {
+    let value = stack.pop()?;
+    if !value.is_category_1() {
+        return Err(InvalidStackValue {
+            expected: "category 1 value".to_string(),
+            actual: value.to_string(),
+        });
+    }
+    Ok(value)
+}
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.pop>
 #[inline]
@@ -15,8 +34,10 @@ pub(crate) fn pop(stack: &OperandStack) -> Result<ExecutionResult> {
 pub(crate) fn pop2(stack: &OperandStack) -> Result<ExecutionResult> {
     let value = stack.pop()?;
     if value.is_category_1() {
-        let _ = stack.pop()?;
+        // Form 1: two category 1 values
+        let _ = pop_category_1(stack)?;
     }
+    // Form 2: one category 2 value
     Ok(Continue)
 }
 
@@ -64,12 +85,14 @@ pub(crate) fn dup_x2(stack: &OperandStack) -> Result<ExecutionResult> {
 pub(crate) fn dup2(stack: &OperandStack) -> Result<ExecutionResult> {
     let value1 = stack.pop()?;
     if value1.is_category_1() {
-        let value2 = stack.pop()?;
+        // Form 1: ..., value2, value1 → ..., value2, value1, value2, value1
+        let value2 = pop_category_1(stack)?;
         stack.push(value2.clone())?;
         stack.push(value1.clone())?;
         stack.push(value2)?;
         stack.push(value1)?;
     } else {
+        // Form 2: ..., value1 → ..., value1, value1
         stack.push(value1.clone())?;
         stack.push(value1)?;
     }
@@ -144,7 +167,6 @@ pub(crate) fn swap(stack: &OperandStack) -> Result<ExecutionResult> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use ristretto_classloader::Value;
 
     #[test]
     fn test_pop() -> Result<()> {
@@ -177,6 +199,20 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_pop2_category_2_second_value() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(2);
+        stack.push_long(2)?;
+        stack.push_int(1)?;
+        let result = pop2(stack);
+        assert!(matches!(
+            result,
+            Err(InvalidStackValue { expected, actual })
+                if expected == "category 1 value" && actual == "long(2)"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_dup() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(2);
@@ -254,6 +290,38 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_dup2_forms() -> Result<()> {
+        // Two ints are both duplicated, while a single long is duplicated once
+        let ints = &mut OperandStack::with_max_size(4);
+        ints.push_int(2)?;
+        ints.push_int(1)?;
+        dup2(ints)?;
+        assert_eq!(4, ints.len()?);
+
+        let long = &mut OperandStack::with_max_size(2);
+        long.push_long(1)?;
+        dup2(long)?;
+        assert_eq!(2, long.len()?);
+        assert_eq!(Value::Long(1), long.pop()?);
+        assert_eq!(Value::Long(1), long.pop()?);
+        assert!(long.is_empty()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dup2_category_2_second_value() -> Result<()> {
+        let stack = &mut OperandStack::with_max_size(4);
+        stack.push_double(2.0)?;
+        stack.push_int(1)?;
+        let result = dup2(stack);
+        assert!(matches!(
+            result,
+            Err(InvalidStackValue { expected, .. }) if expected == "category 1 value"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_dup2_x1_form_1() -> Result<()> {
         let stack = &mut OperandStack::with_max_size(5);
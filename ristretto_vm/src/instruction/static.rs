@@ -2,34 +2,37 @@ use crate::frame::ExecutionResult::Continue;
 use crate::frame::{ExecutionResult, Frame};
 use crate::Result;
 use ristretto_classfile::FieldType;
+use ristretto_classloader::Field;
+use std::sync::Arc;
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.getstatic>
 #[inline]
 pub(crate) async fn getstatic(frame: &Frame, index: u16) -> Result<ExecutionResult> {
-    let thread = frame.thread()?;
-    let constant_pool = frame.class().constant_pool();
-    let (class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
-    let (name_index, _descriptor_index) =
-        constant_pool.try_get_name_and_type(*name_and_type_index)?;
-    let class_name = constant_pool.try_get_class(*class_index)?;
-    let class = thread.class(class_name).await?;
-    let field_name = constant_pool.try_get_utf8(*name_index)?;
-    let field = class.static_field(field_name)?;
+    let field = resolve_static_field(frame, index).await?;
     let value = field.value()?;
     let stack = frame.stack();
     stack.push(value)?;
-
-    if let FieldType::Object(class_name) = field.field_type() {
-        // Load the class of the field value if it is an object.
-        // https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.4.3
-        thread.class(class_name).await?;
-    }
     Ok(Continue)
 }
 
 /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-6.html#jvms-6.5.putstatic>
 #[inline]
 pub(crate) async fn putstatic(frame: &Frame, index: u16) -> Result<ExecutionResult> {
+    let field = resolve_static_field(frame, index).await?;
+    let stack = frame.stack();
+    let value = stack.pop()?;
+    field.set_value(value)?;
+    Ok(Continue)
+}
+
+/// Resolve the static field referenced at the given constant pool index of the frame class,
+/// initializing the class that declares it; the resolved field is cached on the frame class so
+/// that the field reference is only resolved once.
+async fn resolve_static_field(frame: &Frame, index: u16) -> Result<Arc<Field>> {
+    if let Some((_class, field)) = frame.class().resolved_field(index)? {
+        return Ok(field);
+    }
+
     let thread = frame.thread()?;
     let constant_pool = frame.class().constant_pool();
     let (class_index, name_and_type_index) = constant_pool.try_get_field_ref(index)?;
@@ -39,16 +42,17 @@ pub(crate) async fn putstatic(frame: &Frame, index: u16) -> Result<ExecutionResu
     let class = thread.class(class_name).await?;
     let field_name = constant_pool.try_get_utf8(*name_index)?;
     let field = class.static_field(field_name)?;
-    let stack = frame.stack();
-    let value = stack.pop()?;
-    field.set_value(value)?;
 
     if let FieldType::Object(class_name) = field.field_type() {
         // Load the class of the field value if it is an object.
         // https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.4.3
         thread.class(class_name).await?;
     }
-    Ok(Continue)
+
+    frame
+        .class()
+        .set_resolved_field(index, &class, field.clone())?;
+    Ok(field)
 }
 
 #[cfg(test)]
@@ -0,0 +1,176 @@
+use crate::thread::Thread;
+use crate::JavaError::InterruptedException;
+use crate::Result;
+use ristretto_classloader::{Reference, Value};
+use std::future::pending;
+use std::pin::pin;
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// The reason an [`InterruptibleWait`] completed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum WaitOutcome {
+    /// The wait was woken by its notifier (e.g. `Object.notify` or `Unsafe.unpark`).
+    Woken,
+    /// The timeout of the wait elapsed.
+    TimedOut,
+    /// The thread was interrupted before or during the wait.
+    Interrupted,
+}
+
+/// A wait of a thread that completes when the thread is woken by a notifier, the timeout elapses
+/// or the thread is interrupted; without a notifier or timeout, only an interrupt completes the
+/// wait.  The interrupt status of the thread is not changed by the wait.
+///
+/// This is shared by the `Thread.sleep`, `Unsafe.park` and `Object.wait` natives.
+#[derive(Debug)]
+pub(crate) struct InterruptibleWait<'a> {
+    thread: &'a Thread,
+    notify: Option<&'a Notify>,
+    timeout: Option<Duration>,
+}
+
+impl<'a> InterruptibleWait<'a> {
+    /// Create a new wait for the thread.
+    pub(crate) fn new(thread: &'a Thread) -> Self {
+        Self {
+            thread,
+            notify: None,
+            timeout: None,
+        }
+    }
+
+    /// Complete the wait when the notifier is signaled.
+    pub(crate) fn notify(mut self, notify: &'a Notify) -> Self {
+        self.notify = Some(notify);
+        self
+    }
+
+    /// Complete the wait when the timeout elapses.
+    pub(crate) fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Wait until the thread is woken, the timeout elapses or the thread is interrupted.
+    pub(crate) async fn wait(self) -> WaitOutcome {
+        // Register for the interrupt signal before checking the interrupt status, so that an
+        // interrupt between the check and the wait is not lost.
+        let mut interrupted = pin!(self.thread.interrupt_notify().notified());
+        interrupted.as_mut().enable();
+        if self.thread.is_interrupted() {
+            return WaitOutcome::Interrupted;
+        }
+
+        let woken = async {
+            match self.notify {
+                Some(notify) => notify.notified().await,
+                None => pending().await,
+            }
+        };
+        let timed_out = async {
+            match self.timeout {
+                Some(timeout) => sleep(timeout).await,
+                None => pending().await,
+            }
+        };
+        tokio::select! {
+            biased;
+            () = interrupted => WaitOutcome::Interrupted,
+            () = woken => WaitOutcome::Woken,
+            () = timed_out => WaitOutcome::TimedOut,
+        }
+    }
+}
+
+/// Sleep for the duration.
+async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    std::thread::sleep(duration);
+}
+
+/// Clear the interrupt status of the thread and return an `InterruptedException`; natives call
+/// this when an [`InterruptibleWait`] is interrupted.  The `interrupted` field of the
+/// `java.lang.Thread` object, present on newer Java versions, is cleared as well.
+///
+/// # Errors
+/// always; an `InterruptedException` or an error if the thread object cannot be updated.
+pub(crate) async fn interrupted_exception(thread: &Thread, message: &str) -> Result<Option<Value>> {
+    thread.clear_interrupt();
+    if let Value::Object(Some(Reference::Object(java_thread))) = thread.java_object().await {
+        if java_thread.value("interrupted").is_ok() {
+            java_thread.set_value("interrupted", Value::Int(0))?;
+        }
+    }
+    Err(InterruptedException(message.to_string()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error::JavaError;
+
+    #[tokio::test]
+    async fn test_interrupted_before_wait() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let notify = Notify::new();
+        thread.interrupt();
+        let outcome = InterruptibleWait::new(&thread).notify(&notify).wait().await;
+        assert_eq!(WaitOutcome::Interrupted, outcome);
+        assert!(thread.is_interrupted());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_during_wait() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let wait = InterruptibleWait::new(&thread).wait();
+        let interrupt = async {
+            tokio::task::yield_now().await;
+            thread.interrupt();
+        };
+        let (outcome, ()) = tokio::join!(wait, interrupt);
+        assert_eq!(WaitOutcome::Interrupted, outcome);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_woken() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        thread.unpark();
+        let outcome = InterruptibleWait::new(&thread)
+            .notify(thread.park_notify())
+            .wait()
+            .await;
+        assert_eq!(WaitOutcome::Woken, outcome);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_timed_out() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let notify = Notify::new();
+        let outcome = InterruptibleWait::new(&thread)
+            .notify(&notify)
+            .timeout(Duration::from_millis(1))
+            .wait()
+            .await;
+        assert_eq!(WaitOutcome::TimedOut, outcome);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_exception() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        thread.interrupt();
+        let result = interrupted_exception(&thread, "sleep interrupted").await;
+        assert!(matches!(
+            result,
+            Err(JavaError(InterruptedException(message))) if message == "sleep interrupted"
+        ));
+        assert!(!thread.is_interrupted());
+        Ok(())
+    }
+}
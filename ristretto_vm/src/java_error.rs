@@ -8,7 +8,7 @@ pub enum JavaError {
     /// `ArrayIndexOutOfBoundsException`
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/ArrayIndexOutOfBoundsException.html>
     #[error("Index {index} out of bounds for length {length}")]
-    ArrayIndexOutOfBoundsException { index: usize, length: usize },
+    ArrayIndexOutOfBoundsException { index: i32, length: usize },
     /// `ClassCastException`
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/ClassCastException.html>
     #[error("class {source_class_name} cannot be cast to class {target_class_name}")]
@@ -20,18 +20,58 @@ pub enum JavaError {
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/ClassFormatError.html>
     #[error("{0}")]
     ClassFormatError(String),
+    /// `CloneNotSupportedException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/CloneNotSupportedException.html>
+    #[error("{0}")]
+    CloneNotSupportedException(String),
+    /// `FileNotFoundException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/io/FileNotFoundException.html>
+    #[error("{0}")]
+    FileNotFoundException(String),
+    /// `IOException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/io/IOException.html>
+    #[error("{0}")]
+    IOException(String),
+    /// `IllegalArgumentException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/IllegalArgumentException.html>
+    #[error("{0}")]
+    IllegalArgumentException(String),
     /// `IndexOutOfBoundsException`
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/IndexOutOfBoundsException.html>
     #[error("Index: {index}, Size {size}")]
     IndexOutOfBoundsException { index: i32, size: i32 },
+    /// `InstantiationException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/InstantiationException.html>
+    #[error("{0}")]
+    InstantiationException(String),
+    /// `InterruptedException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/InterruptedException.html>
+    #[error("{0}")]
+    InterruptedException(String),
+    /// `NegativeArraySizeException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/NegativeArraySizeException.html>
+    #[error("{0}")]
+    NegativeArraySizeException(String),
     /// `NoClassDefFoundError`
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/NoClassDefFoundError.html>
     #[error("{0}")]
     NoClassDefFoundError(String),
+    /// `NoSuchFieldError`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/NoSuchFieldError.html>
+    #[error("{0}")]
+    NoSuchFieldError(String),
     /// `NullPointerException`
     /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/NullPointerException.html>
     #[error("{0}")]
     NullPointerException(String),
+    /// `OutOfMemoryError`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/OutOfMemoryError.html>
+    #[error("{0}")]
+    OutOfMemoryError(String),
+    /// `UnsupportedOperationException`
+    /// See: <https://docs.oracle.com/en/java/javase/23/docs/api/java.base/java/lang/UnsupportedOperationException.html>
+    #[error("{0}")]
+    UnsupportedOperationException(String),
 }
 
 impl JavaError {
@@ -45,9 +85,21 @@ impl JavaError {
             JavaError::ArithmeticException(_) => "java/lang/ArithmeticException",
             JavaError::ClassCastException { .. } => "java/lang/ClassCastException",
             JavaError::ClassFormatError(_) => "java/lang/ClassFormatError",
+            JavaError::CloneNotSupportedException(_) => "java/lang/CloneNotSupportedException",
+            JavaError::FileNotFoundException(_) => "java/io/FileNotFoundException",
+            JavaError::IOException(_) => "java/io/IOException",
+            JavaError::IllegalArgumentException(_) => "java/lang/IllegalArgumentException",
             JavaError::IndexOutOfBoundsException { .. } => "java/lang/IndexOutOfBoundsException",
+            JavaError::InstantiationException(_) => "java/lang/InstantiationException",
+            JavaError::InterruptedException(_) => "java/lang/InterruptedException",
+            JavaError::NegativeArraySizeException(_) => "java/lang/NegativeArraySizeException",
             JavaError::NoClassDefFoundError(_) => "java/lang/NoClassDefFoundError",
+            JavaError::NoSuchFieldError(_) => "java/lang/NoSuchFieldError",
             JavaError::NullPointerException(_) => "java/lang/NullPointerException",
+            JavaError::OutOfMemoryError(_) => "java/lang/OutOfMemoryError",
+            JavaError::UnsupportedOperationException(_) => {
+                "java/lang/UnsupportedOperationException"
+            }
         }
     }
 
@@ -102,6 +154,34 @@ mod tests {
         assert_eq!(error.message(), "invalid class format");
     }
 
+    #[test]
+    fn test_clone_not_supported_exception() {
+        let error = JavaError::CloneNotSupportedException("java.lang.Object".to_string());
+        assert_eq!(error.class_name(), "java/lang/CloneNotSupportedException");
+        assert_eq!(error.message(), "java.lang.Object");
+    }
+
+    #[test]
+    fn test_file_not_found_exception() {
+        let error = JavaError::FileNotFoundException("foo.txt".to_string());
+        assert_eq!(error.class_name(), "java/io/FileNotFoundException");
+        assert_eq!(error.message(), "foo.txt");
+    }
+
+    #[test]
+    fn test_io_exception() {
+        let error = JavaError::IOException("Stream Closed".to_string());
+        assert_eq!(error.class_name(), "java/io/IOException");
+        assert_eq!(error.message(), "Stream Closed");
+    }
+
+    #[test]
+    fn test_illegal_argument_exception() {
+        let error = JavaError::IllegalArgumentException("timeout value is negative".to_string());
+        assert_eq!(error.class_name(), "java/lang/IllegalArgumentException");
+        assert_eq!(error.message(), "timeout value is negative");
+    }
+
     #[test]
     fn test_index_out_of_bounds_exception() {
         let error = JavaError::IndexOutOfBoundsException { index: 5, size: 3 };
@@ -109,6 +189,27 @@ mod tests {
         assert_eq!(error.message(), "Index: 5, Size 3");
     }
 
+    #[test]
+    fn test_instantiation_exception() {
+        let error = JavaError::InstantiationException("java.util.AbstractList".to_string());
+        assert_eq!(error.class_name(), "java/lang/InstantiationException");
+        assert_eq!(error.message(), "java.util.AbstractList");
+    }
+
+    #[test]
+    fn test_interrupted_exception() {
+        let error = JavaError::InterruptedException("sleep interrupted".to_string());
+        assert_eq!(error.class_name(), "java/lang/InterruptedException");
+        assert_eq!(error.message(), "sleep interrupted");
+    }
+
+    #[test]
+    fn test_negative_array_size_exception() {
+        let error = JavaError::NegativeArraySizeException("-1".to_string());
+        assert_eq!(error.class_name(), "java/lang/NegativeArraySizeException");
+        assert_eq!(error.message(), "-1");
+    }
+
     #[test]
     fn test_no_class_def_found_error() {
         let error = JavaError::NoClassDefFoundError("java/lang/String".to_string());
@@ -116,10 +217,37 @@ mod tests {
         assert_eq!(error.message(), "java/lang/String");
     }
 
+    #[test]
+    fn test_no_such_field_error() {
+        let error = JavaError::NoSuchFieldError("java/lang/String.foo".to_string());
+        assert_eq!(error.class_name(), "java/lang/NoSuchFieldError");
+        assert_eq!(error.message(), "java/lang/String.foo");
+    }
+
     #[test]
     fn test_null_pointer_exception() {
         let error = JavaError::NullPointerException("null".to_string());
         assert_eq!(error.class_name(), "java/lang/NullPointerException");
         assert_eq!(error.message(), "null");
     }
+
+    #[test]
+    fn test_out_of_memory_error() {
+        let error =
+            JavaError::OutOfMemoryError("Requested array size exceeds VM limit".to_string());
+        assert_eq!(error.class_name(), "java/lang/OutOfMemoryError");
+        assert_eq!(error.message(), "Requested array size exceeds VM limit");
+    }
+
+    #[test]
+    fn test_unsupported_operation_exception() {
+        let error = JavaError::UnsupportedOperationException(
+            "class redefinition not supported".to_string(),
+        );
+        assert_eq!(
+            error.class_name(),
+            "java/lang/UnsupportedOperationException"
+        );
+        assert_eq!(error.message(), "class redefinition not supported");
+    }
 }
@@ -1,12 +1,17 @@
 use crate::Error::InternalError;
 use crate::{Result, VM};
-use ristretto_classfile::{mutf8, Version};
+use ristretto_classfile::{BaseType, FieldType, Version};
 use ristretto_classloader::{Class, ConcurrentVec, Object, Reference, Value};
 use std::sync::Arc;
 
 const JAVA_8: Version = Version::Java8 { minor: 0 };
 const JAVA_17: Version = Version::Java17 { minor: 0 };
 
+/// The `java.lang.String` coder for strings with one byte per character
+const LATIN1: i32 = 0;
+/// The `java.lang.String` coder for strings with two bytes per character
+const UTF16: i32 = 1;
+
 /// Trait for converting a Rust value to a Java object.  Converts to objects of the primitive
 /// wrapper, classes, and strings.
 pub trait JavaObject {
@@ -195,9 +200,20 @@ impl JavaObject for &str {
             if java_class_file_version >= &JAVA_17 {
                 object.set_value("hashIsZero", Value::Int(0))?;
             }
-            object.set_value("coder", Value::Int(0))?; // LATIN1
 
-            let bytes = mutf8::to_bytes(self)?;
+            // Compact strings store LATIN1 strings with one byte per character and all other
+            // strings as big endian UTF-16 (see StringUTF16.isBigEndian)
+            let latin1_bytes: Option<Vec<u8>> = self
+                .chars()
+                .map(|character| u8::try_from(character).ok())
+                .collect();
+            let (coder, bytes) = if let Some(bytes) = latin1_bytes {
+                (LATIN1, bytes)
+            } else {
+                let bytes: Vec<u8> = self.encode_utf16().flat_map(u16::to_be_bytes).collect();
+                (UTF16, bytes)
+            };
+            object.set_value("coder", Value::Int(coder))?;
             Reference::from(bytes)
         };
 
@@ -216,10 +232,22 @@ impl JavaObject for String {
     }
 }
 
+/// Get the internal name (e.g. `java/lang/String`) of the class represented by a `java.lang.Class`
+/// object.
+///
+/// # Errors
+/// if the `name` field of the class object is not a string.
+pub(crate) fn class_object_name(class_object: &Object) -> Result<String> {
+    let class_name: String = class_object.value("name")?.try_into()?;
+    Ok(class_name.replace('.', "/"))
+}
+
 async fn to_class_object(vm: &VM, class: &Arc<Class>) -> Result<Value> {
     let java_lang_class = vm.class("java/lang/Class").await?;
     let object = Object::new(java_lang_class)?;
-    let class_name = class.name();
+    // The binary name (e.g. `java.lang.String`, `[Ljava.lang.String;` or `int`) is computed once
+    // and cached in the `name` field, which `Class.getName()` returns without calling into the VM.
+    let class_name = class.name().replace('/', ".");
     let name = class_name.to_object(vm).await?;
     object.set_value("name", name)?;
     // TODO: a "null" class loader indicates a system class loader; this should be re-evaluated
@@ -232,6 +260,9 @@ async fn to_class_object(vm: &VM, class: &Arc<Class>) -> Result<Value> {
 
 impl JavaObject for Arc<Class> {
     async fn to_object(&self, vm: &VM) -> Result<Value> {
+        if let Some(class_object) = vm.class_object(self) {
+            return Ok(class_object);
+        }
         let class_object = to_class_object(vm, self).await?;
         let Value::Object(Some(Reference::Object(ref object))) = class_object else {
             return Err(InternalError("Expected class object".to_string()));
@@ -244,11 +275,164 @@ impl JavaObject for Arc<Class> {
                 ));
             };
             let component_type_class = vm.class(component_type).await?;
-            let component_type_object = to_class_object(vm, &component_type_class).await?;
+            let component_type_object = Box::pin(component_type_class.to_object(vm)).await?;
             object.set_value("componentType", component_type_object)?;
         }
 
-        Ok(class_object)
+        Ok(vm.cache_class_object(self, class_object))
+    }
+}
+
+/// Constructors for the primitive wrapper objects (e.g. `java.lang.Integer`) that box a value of a
+/// primitive type, for use where a `Value` is stored in a field or an array of objects.
+pub trait BoxedValue: Sized {
+    async fn boxed_boolean(vm: &VM, value: bool) -> Result<Self>;
+    async fn boxed_byte(vm: &VM, value: i8) -> Result<Self>;
+    async fn boxed_char(vm: &VM, value: char) -> Result<Self>;
+    async fn boxed_short(vm: &VM, value: i16) -> Result<Self>;
+    async fn boxed_int(vm: &VM, value: i32) -> Result<Self>;
+    async fn boxed_long(vm: &VM, value: i64) -> Result<Self>;
+    async fn boxed_float(vm: &VM, value: f32) -> Result<Self>;
+    async fn boxed_double(vm: &VM, value: f64) -> Result<Self>;
+
+    /// Box a value of the given primitive type as it is held on the operand stack; `boolean`,
+    /// `byte`, `char` and `short` values are held as an `int` and are narrowed to the type.
+    async fn boxed(vm: &VM, base_type: BaseType, value: &Value) -> Result<Self>;
+
+    /// Box a primitive value that is passed where a reference of the given type is expected (e.g.
+    /// an `int` passed as a `java.lang.Object`).  The value is boxed in the wrapper class named by
+    /// the type (e.g. `java.lang.Long`), widening it if needed, or in the wrapper class of the
+    /// value for other types (e.g. `java.lang.Integer` for an `int`).  References, values passed
+    /// where a primitive is expected and values that cannot be widened are returned unchanged.
+    async fn boxed_as(vm: &VM, field_type: &FieldType, value: Value) -> Result<Self>;
+
+    /// Unbox a primitive wrapper object (e.g. `java.lang.Integer`) into its primitive type and its
+    /// value as it is held on the operand stack; `None` if the value is not a wrapper object.
+    ///
+    /// # Errors
+    /// if the value of the wrapper object cannot be read.
+    fn unboxed(&self) -> Result<Option<(BaseType, Value)>>;
+}
+
+/// Get the primitive type boxed by a wrapper class (e.g. `int` for `java/lang/Integer`).
+fn wrapped_type(class_name: &str) -> Option<BaseType> {
+    let base_type = match class_name {
+        "java/lang/Boolean" => BaseType::Boolean,
+        "java/lang/Byte" => BaseType::Byte,
+        "java/lang/Character" => BaseType::Char,
+        "java/lang/Short" => BaseType::Short,
+        "java/lang/Integer" => BaseType::Int,
+        "java/lang/Long" => BaseType::Long,
+        "java/lang/Float" => BaseType::Float,
+        "java/lang/Double" => BaseType::Double,
+        _ => return None,
+    };
+    Some(base_type)
+}
+
+impl BoxedValue for Value {
+    async fn boxed_boolean(vm: &VM, value: bool) -> Result<Self> {
+        value.to_object(vm).await
+    }
+
+    async fn boxed_byte(vm: &VM, value: i8) -> Result<Self> {
+        value.to_object(vm).await
+    }
+
+    async fn boxed_char(vm: &VM, value: char) -> Result<Self> {
+        value.to_object(vm).await
+    }
+
+    async fn boxed_short(vm: &VM, value: i16) -> Result<Self> {
+        value.to_object(vm).await
+    }
+
+    async fn boxed_int(vm: &VM, value: i32) -> Result<Self> {
+        value.to_object(vm).await
+    }
+
+    async fn boxed_long(vm: &VM, value: i64) -> Result<Self> {
+        value.to_object(vm).await
+    }
+
+    async fn boxed_float(vm: &VM, value: f32) -> Result<Self> {
+        value.to_object(vm).await
+    }
+
+    async fn boxed_double(vm: &VM, value: f64) -> Result<Self> {
+        value.to_object(vm).await
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    #[expect(clippy::cast_sign_loss)]
+    async fn boxed(vm: &VM, base_type: BaseType, value: &Value) -> Result<Self> {
+        match base_type {
+            BaseType::Boolean => Self::boxed_boolean(vm, value.to_int()? & 1 != 0).await,
+            BaseType::Byte => Self::boxed_byte(vm, value.to_int()? as i8).await,
+            // A char is a UTF-16 code unit, which may be a surrogate that is not a valid Rust char
+            BaseType::Char => {
+                let value = Value::Int(i32::from(value.to_int()? as u16));
+                vm.try_invoke(
+                    "java/lang/Character",
+                    "valueOf",
+                    "(C)Ljava/lang/Character;",
+                    vec![value],
+                )
+                .await
+            }
+            BaseType::Short => Self::boxed_short(vm, value.to_int()? as i16).await,
+            BaseType::Int => Self::boxed_int(vm, value.to_int()?).await,
+            BaseType::Long => Self::boxed_long(vm, value.to_long()?).await,
+            BaseType::Float => Self::boxed_float(vm, value.to_float()?).await,
+            BaseType::Double => Self::boxed_double(vm, value.to_double()?).await,
+        }
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    async fn boxed_as(vm: &VM, field_type: &FieldType, value: Value) -> Result<Self> {
+        let FieldType::Object(class_name) = field_type else {
+            return Ok(value);
+        };
+        let base_type = match (wrapped_type(class_name), &value) {
+            (Some(base_type), _) => base_type,
+            (None, Value::Int(_)) => BaseType::Int,
+            (None, Value::Long(_)) => BaseType::Long,
+            (None, Value::Float(_)) => BaseType::Float,
+            (None, Value::Double(_)) => BaseType::Double,
+            (None, _) => return Ok(value),
+        };
+        let value = match (&base_type, value) {
+            (
+                BaseType::Boolean
+                | BaseType::Byte
+                | BaseType::Char
+                | BaseType::Short
+                | BaseType::Int,
+                Value::Int(value),
+            ) => Value::Int(value),
+            (BaseType::Long, Value::Int(value)) => Value::Long(i64::from(value)),
+            (BaseType::Long, Value::Long(value)) => Value::Long(value),
+            (BaseType::Float, Value::Int(value)) => Value::Float(value as f32),
+            (BaseType::Float, Value::Long(value)) => Value::Float(value as f32),
+            (BaseType::Float, Value::Float(value)) => Value::Float(value),
+            (BaseType::Double, Value::Int(value)) => Value::Double(f64::from(value)),
+            (BaseType::Double, Value::Long(value)) => Value::Double(value as f64),
+            (BaseType::Double, Value::Float(value)) => Value::Double(f64::from(value)),
+            (BaseType::Double, Value::Double(value)) => Value::Double(value),
+            (_, value) => return Ok(value),
+        };
+        Self::boxed(vm, base_type, &value).await
+    }
+
+    fn unboxed(&self) -> Result<Option<(BaseType, Value)>> {
+        let Value::Object(Some(Reference::Object(object))) = self else {
+            return Ok(None);
+        };
+        let Some(base_type) = wrapped_type(object.class().name()) else {
+            return Ok(None);
+        };
+        let value = object.value("value")?;
+        Ok(Some((base_type, value)))
     }
 }
 
@@ -408,6 +592,16 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_str_to_object_utf16() -> Result<()> {
+        let vm = VM::default().await?;
+        let original_value = "héllo 世界";
+        let value: Value = original_value.to_object(&vm).await?;
+        let value: String = value.try_into()?;
+        assert_eq!(original_value, value);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_string_to_object() -> Result<()> {
         let vm = VM::default().await?;
@@ -427,4 +621,120 @@ mod tests {
         assert_eq!("java/lang/Class", value.name());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_class_to_object_cached_per_class() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let class1 = Arc::new(Class::new_named("[J")?);
+        let class2 = Arc::new(Class::new_named("[J")?);
+        let object1: Reference = class1.to_object(&vm).await?.try_into()?;
+        let object2: Reference = class1.to_object(&vm).await?.try_into()?;
+        let object3: Reference = class2.to_object(&vm).await?.try_into()?;
+        assert!(object1.ptr_eq(&object2));
+        assert!(!object1.ptr_eq(&object3));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_boxed_values() -> Result<()> {
+        let vm = VM::default().await?;
+        let value: bool = Value::boxed_boolean(&vm, true).await?.try_into()?;
+        assert!(value);
+        let value: i8 = Value::boxed_byte(&vm, -42).await?.try_into()?;
+        assert_eq!(-42, value);
+        let value: char = Value::boxed_char(&vm, '*').await?.try_into()?;
+        assert_eq!('*', value);
+        let value: i16 = Value::boxed_short(&vm, -42).await?.try_into()?;
+        assert_eq!(-42, value);
+        let value: i32 = Value::boxed_int(&vm, 42).await?.try_into()?;
+        assert_eq!(42, value);
+        let value: i64 = Value::boxed_long(&vm, 42).await?.try_into()?;
+        assert_eq!(42, value);
+        let value: f32 = Value::boxed_float(&vm, 42.1).await?.try_into()?;
+        assert!((value - 42.1f32).abs() < 0.1f32);
+        let value: f64 = Value::boxed_double(&vm, 42.1).await?.try_into()?;
+        assert!((value - 42.1f64).abs() < 0.1f64);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_boxed() -> Result<()> {
+        let vm = VM::default().await?;
+        let value = Value::boxed(&vm, BaseType::Boolean, &Value::Int(1)).await?;
+        assert_eq!(
+            "java/lang/Boolean",
+            value.to_reference()?.expect("object").class_name()
+        );
+        let value: bool = value.try_into()?;
+        assert!(value);
+        let value: i8 = Value::boxed(&vm, BaseType::Byte, &Value::Int(0x1FF))
+            .await?
+            .try_into()?;
+        assert_eq!(-1, value);
+        let value: char = Value::boxed(&vm, BaseType::Char, &Value::Int(42))
+            .await?
+            .try_into()?;
+        assert_eq!('*', value);
+        let value: i16 = Value::boxed(&vm, BaseType::Short, &Value::Int(-42))
+            .await?
+            .try_into()?;
+        assert_eq!(-42, value);
+        let value: i32 = Value::boxed(&vm, BaseType::Int, &Value::Int(42))
+            .await?
+            .try_into()?;
+        assert_eq!(42, value);
+        let value: i64 = Value::boxed(&vm, BaseType::Long, &Value::Long(42))
+            .await?
+            .try_into()?;
+        assert_eq!(42, value);
+        let value: f32 = Value::boxed(&vm, BaseType::Float, &Value::Float(42.1))
+            .await?
+            .try_into()?;
+        assert!((value - 42.1f32).abs() < 0.1f32);
+        let value: f64 = Value::boxed(&vm, BaseType::Double, &Value::Double(42.1))
+            .await?
+            .try_into()?;
+        assert!((value - 42.1f64).abs() < 0.1f64);
+
+        let result = Value::boxed(&vm, BaseType::Int, &Value::Long(42)).await;
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_boxed_as() -> Result<()> {
+        let vm = VM::default().await?;
+        let object_type = FieldType::Object("java/lang/Object".to_string());
+        let value = Value::boxed_as(&vm, &object_type, Value::Int(42)).await?;
+        assert_eq!(
+            "java/lang/Integer",
+            value.to_reference()?.expect("object").class_name()
+        );
+        let long_type = FieldType::Object("java/lang/Long".to_string());
+        let value = Value::boxed_as(&vm, &long_type, Value::Int(42)).await?;
+        assert_eq!(
+            "java/lang/Long",
+            value.to_reference()?.expect("object").class_name()
+        );
+        let value: i64 = value.try_into()?;
+        assert_eq!(42, value);
+        let value = Value::boxed_as(&vm, &FieldType::Base(BaseType::Int), Value::Int(42)).await?;
+        assert_eq!(Value::Int(42), value);
+        let integer_type = FieldType::Object("java/lang/Integer".to_string());
+        let value = Value::boxed_as(&vm, &integer_type, Value::Long(42)).await?;
+        assert_eq!(Value::Long(42), value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unboxed() -> Result<()> {
+        let vm = VM::default().await?;
+        let value = Value::boxed_long(&vm, 42).await?;
+        assert_eq!(Some((BaseType::Long, Value::Long(42))), value.unboxed()?);
+        assert_eq!(None, Value::Int(42).unboxed()?);
+        assert_eq!(None, Value::Object(None).unboxed()?);
+        let value = "foo".to_object(&vm).await?;
+        assert_eq!(None, value.unboxed()?);
+        Ok(())
+    }
 }
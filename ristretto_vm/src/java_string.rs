@@ -0,0 +1,228 @@
+use crate::instruction::try_get_virtual_method;
+use crate::VM;
+use ristretto_classloader::{Reference, Value};
+use std::cell::RefCell;
+use std::fmt;
+
+thread_local! {
+    /// The objects whose `toString()` is being invoked on this thread; an object found here is
+    /// rendered in its identity form rather than invoking `toString()` again.  Objects are
+    /// compared by identity rather than by identity hash code, which may collide.
+    static IN_PROGRESS: RefCell<Vec<Reference>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Marks an object as being rendered on this thread; the mark is removed when the guard is
+/// dropped, including when the future rendering the object is dropped before it completes.
+struct InProgressGuard {
+    reference: Reference,
+}
+
+impl InProgressGuard {
+    /// Mark the object as being rendered; `None` is returned if it is already being rendered.
+    fn enter(reference: &Reference) -> Option<Self> {
+        IN_PROGRESS.with(|in_progress| {
+            let mut in_progress = in_progress.borrow_mut();
+            if in_progress.iter().any(|other| other.ptr_eq(reference)) {
+                return None;
+            }
+            in_progress.push(reference.clone());
+            Some(Self {
+                reference: reference.clone(),
+            })
+        })
+    }
+}
+
+impl Drop for InProgressGuard {
+    fn drop(&mut self) {
+        IN_PROGRESS.with(|in_progress| {
+            let mut in_progress = in_progress.borrow_mut();
+            if let Some(index) = in_progress
+                .iter()
+                .rposition(|other| other.ptr_eq(&self.reference))
+            {
+                in_progress.remove(index);
+            }
+        });
+    }
+}
+
+/// Trait for rendering a value the way Java's `String.valueOf` would, for use in logging and
+/// tracing.  Unlike `Display`, objects are rendered by invoking their `toString()` method.
+///
+/// Methods are invoked on the VM, whose futures are not `Send`, so the future is not `Send`.
+#[expect(async_fn_in_trait)]
+pub trait JavaString {
+    /// Render the value as Java would.  Objects whose `toString()` throws, returns `null` or is
+    /// re-entered while rendering the same object are rendered in their identity form (e.g.
+    /// `java.lang.Object@1b6d3586`).
+    async fn to_java_string(&self, vm: &VM) -> String;
+}
+
+impl JavaString for Value {
+    async fn to_java_string(&self, vm: &VM) -> String {
+        match self {
+            Value::Int(value) => value.to_string(),
+            Value::Long(value) => value.to_string(),
+            Value::Float(value) => java_float_string(f64::from(*value), value),
+            Value::Double(value) => java_float_string(*value, value),
+            Value::Object(None) => "null".to_string(),
+            Value::Object(Some(reference)) => reference.to_java_string(vm).await,
+            Value::ReturnAddress(address) => format!("returnAddress({address})"),
+            Value::Unused => "unused".to_string(),
+        }
+    }
+}
+
+impl JavaString for Reference {
+    async fn to_java_string(&self, vm: &VM) -> String {
+        let Reference::Object(object) = self else {
+            // Arrays do not override Object.toString()
+            return identity_string(self);
+        };
+        if object.class().name() == "java/lang/String" {
+            if let Ok(value) = self.clone().try_into() {
+                return value;
+            }
+        }
+
+        let Some(_guard) = InProgressGuard::enter(self) else {
+            return identity_string(self);
+        };
+        let result = invoke_to_string(vm, self).await;
+        result.unwrap_or_else(|| identity_string(self))
+    }
+}
+
+/// Invoke `toString()` on the object; `None` is returned if the method cannot be found, throws or
+/// returns `null`.
+async fn invoke_to_string(vm: &VM, reference: &Reference) -> Option<String> {
+    let Reference::Object(object) = reference else {
+        return None;
+    };
+    let (class, method) =
+        try_get_virtual_method(object.class(), "toString", "()Ljava/lang/String;").ok()?;
+    let value = Value::Object(Some(reference.clone()));
+    let result = Box::pin(vm.invoke(
+        class.name(),
+        method.name(),
+        method.descriptor(),
+        vec![value],
+    ))
+    .await
+    .ok()??;
+    result.try_into().ok()
+}
+
+/// Render a reference as `Object.toString()` does, using the identity hash code of the object.
+fn identity_string(reference: &Reference) -> String {
+    let class_name = reference.class_name().replace('/', ".");
+    let hash_code = reference.identity_hash_code();
+    format!("{class_name}@{hash_code:x}")
+}
+
+/// Render a floating point value as `Double.toString` does; magnitudes in the range `[10^-3, 10^7)`
+/// are rendered in decimal notation and all others in computerized scientific notation.  The
+/// digits are those of `digits`, so that a `float` is rendered with no more digits than it holds.
+fn java_float_string<T: fmt::Debug + fmt::LowerExp>(value: f64, digits: &T) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+    if value.is_infinite() {
+        return if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    }
+    let magnitude = value.abs();
+    if magnitude == 0.0 || (1e-3..1e7).contains(&magnitude) {
+        return format!("{digits:?}");
+    }
+    let scientific = format!("{digits:e}");
+    let Some((mantissa, exponent)) = scientific.split_once('e') else {
+        return scientific;
+    };
+    if mantissa.contains('.') {
+        format!("{mantissa}E{exponent}")
+    } else {
+        format!("{mantissa}.0E{exponent}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+    use crate::Result;
+    use ristretto_classloader::Object;
+
+    #[tokio::test]
+    async fn test_primitives_to_java_string() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        assert_eq!("42", Value::Int(42).to_java_string(&vm).await);
+        assert_eq!("-42", Value::Long(-42).to_java_string(&vm).await);
+        assert_eq!("1.0", Value::Float(1.0).to_java_string(&vm).await);
+        assert_eq!("0.1", Value::Float(0.1).to_java_string(&vm).await);
+        assert_eq!("0.5", Value::Double(0.5).to_java_string(&vm).await);
+        assert_eq!("1.0E10", Value::Double(1e10).to_java_string(&vm).await);
+        assert_eq!("1.5E-5", Value::Double(1.5e-5).to_java_string(&vm).await);
+        assert_eq!("NaN", Value::Double(f64::NAN).to_java_string(&vm).await);
+        assert_eq!(
+            "-Infinity",
+            Value::Float(f32::NEG_INFINITY).to_java_string(&vm).await
+        );
+        assert_eq!("null", Value::Object(None).to_java_string(&vm).await);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_boxed_integer_to_java_string() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let value = 42i32.to_object(&vm).await?;
+        assert_eq!("42", value.to_java_string(&vm).await);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_string_to_java_string() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let value = "foo".to_object(&vm).await?;
+        assert_eq!("foo", value.to_java_string(&vm).await);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_object_to_java_string() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let class = vm.class("java/lang/Object").await?;
+        let reference = Reference::from(Object::new(class)?);
+        let hash_code = reference.identity_hash_code();
+        let value = Value::Object(Some(reference));
+        assert_eq!(
+            format!("java.lang.Object@{hash_code:x}"),
+            value.to_java_string(&vm).await
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_array_to_java_string() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let reference = Reference::from(vec![1i32, 2, 3]);
+        let hash_code = reference.identity_hash_code();
+        let value = Value::Object(Some(reference));
+        assert_eq!(format!("[I@{hash_code:x}"), value.to_java_string(&vm).await);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_to_java_string_re_entrant() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let value = 42i32.to_object(&vm).await?;
+        let reference: Reference = value.clone().try_into()?;
+        let hash_code = reference.identity_hash_code();
+        let guard = InProgressGuard::enter(&reference).expect("guard");
+        let result = value.to_java_string(&vm).await;
+        assert_eq!(format!("java.lang.Integer@{hash_code:x}"), result);
+        drop(guard);
+        assert_eq!("42", value.to_java_string(&vm).await);
+        Ok(())
+    }
+}
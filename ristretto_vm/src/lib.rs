@@ -14,6 +14,15 @@
 //! the requested version of Corretto into and create a class loader that can be used to load Java
 //! classes.
 //!
+//! ## Feature flags
+//!
+//! The following features are available:
+//!
+//! | Name      | Description                                             | Default? |
+//! |-----------|---------------------------------------------------------|----------|
+//! | `debug`   | Enables bytecode breakpoints and single-step execution  | No       |
+//! | `url`     | Enables url class path entries                          | No       |
+//!
 //! ## Safety
 //!
 //! This crate uses `#![forbid(unsafe_code)]` to ensure everything is implemented in 100% safe Rust.
@@ -28,26 +37,40 @@
 mod arguments;
 #[expect(clippy::module_name_repetitions)]
 mod configuration;
+#[cfg(feature = "debug")]
+mod debugger;
 mod error;
+mod event;
+mod file_handles;
 mod frame;
+mod host_io;
 mod instruction;
+mod interruptible_wait;
 mod java_error;
 mod java_object;
+mod java_string;
 mod local_variables;
+mod native_memory;
 mod native_methods;
 mod operand_stack;
 mod rust_value;
+mod signals;
 #[cfg(test)]
 pub(crate) mod test;
 mod thread;
 mod vm;
 
 pub use configuration::{Configuration, ConfigurationBuilder};
+#[cfg(feature = "debug")]
+pub use debugger::{Breakpoint, DebugAction, DebugFrame, Debugger};
 pub use error::{Error, Result};
+pub use event::{VmEvent, VmEventListener};
 pub(crate) use frame::Frame;
+pub use host_io::{HostFile, HostIo, OpenMode, StdHostIo};
 pub use java_error::JavaError;
+pub use java_string::JavaString;
 pub(crate) use local_variables::LocalVariables;
 pub(crate) use operand_stack::OperandStack;
 pub use ristretto_classloader::{Class, ClassPath, Reference, Value};
-pub(crate) use thread::Thread;
+pub use thread::Thread;
 pub use vm::VM;
@@ -106,6 +106,22 @@ impl LocalVariables {
         }
     }
 
+    /// Get a return address from the local variables.
+    ///
+    /// # Errors
+    /// if the local variable at the given index was not found or if the value is not a return
+    /// address.
+    pub fn get_return_address(&self, index: usize) -> Result<u16> {
+        let value = self.get(index)?;
+        match &*value {
+            Value::ReturnAddress(address) => Ok(*address),
+            value => Err(InvalidLocalVariable {
+                expected: "returnAddress".to_string(),
+                actual: value.to_string(),
+            }),
+        }
+    }
+
     /// Set a value in the local variables.
     ///
     /// # Errors
@@ -174,6 +190,16 @@ impl LocalVariables {
         Ok(length)
     }
 
+    /// Get the values of all local variables; slots that have not been set, and the second slot of
+    /// long and double values, are [`Value::Unused`].
+    ///
+    /// # Errors
+    /// if the local variables cannot be accessed.
+    pub fn to_vec(&self) -> Result<Vec<Value>> {
+        let locals = self.locals.to_vec()?;
+        Ok(locals.iter().map(|value| Value::clone(value)).collect())
+    }
+
     /// Check if the local variables are empty.
     pub fn is_empty(&self) -> Result<bool> {
         Ok(self.len()? == 0)
@@ -370,6 +396,37 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_get_return_address() -> Result<()> {
+        let locals = LocalVariables::with_max_size(1);
+        locals.set(0, Value::ReturnAddress(42))?;
+        assert_eq!(locals.get_return_address(0)?, 42);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_return_address_invalid_index() {
+        let locals = LocalVariables::with_max_size(0);
+        assert!(matches!(
+            locals.get_return_address(0),
+            Err(InvalidLocalVariableIndex(0))
+        ));
+    }
+
+    #[test]
+    fn test_get_return_address_invalid_type() -> Result<()> {
+        let locals = LocalVariables::with_max_size(1);
+        locals.set_int(0, 42)?;
+        assert!(matches!(
+            locals.get_return_address(0),
+            Err(InvalidLocalVariable {
+                expected,
+                actual
+            }) if expected == "returnAddress" && actual == "int(42)"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_set() -> Result<()> {
         let locals = LocalVariables::with_max_size(1);
@@ -495,6 +552,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_to_vec() -> Result<()> {
+        let local_variables = LocalVariables::with_max_size(3);
+        local_variables.set(1, Value::Int(42))?;
+        assert_eq!(
+            vec![Value::Unused, Value::Int(42), Value::Unused],
+            local_variables.to_vec()?
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_display() -> Result<()> {
         let local_variables = LocalVariables::with_max_size(6);
@@ -0,0 +1,259 @@
+use crate::Error::{InternalError, PoisonedLock};
+use crate::Result;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// The address of the first allocation; non-zero so that allocations are never confused with a
+/// null address.
+const BASE_ADDRESS: i64 = 0x1000;
+/// The alignment of allocated addresses.
+const ALIGNMENT: i64 = 8;
+
+/// Off-heap memory allocated with `Unsafe.allocateMemory`.  Allocations are addressed by opaque
+/// addresses rather than host pointers; an address can refer to any byte within an allocation.
+#[derive(Debug)]
+pub(crate) struct NativeMemory {
+    inner: Mutex<Allocations>,
+}
+
+#[derive(Debug)]
+struct Allocations {
+    next_address: i64,
+    blocks: BTreeMap<i64, Vec<u8>>,
+}
+
+impl NativeMemory {
+    /// Create a new native memory with no allocations.
+    pub(crate) fn new() -> Self {
+        Self {
+            inner: Mutex::new(Allocations {
+                next_address: BASE_ADDRESS,
+                blocks: BTreeMap::new(),
+            }),
+        }
+    }
+
+    /// Allocate a zeroed block of `size` bytes and return its address; a size of zero returns the
+    /// null address.
+    ///
+    /// # Errors
+    /// if the size is negative or the lock is poisoned.
+    pub(crate) fn allocate(&self, size: i64) -> Result<i64> {
+        if size == 0 {
+            return Ok(0);
+        }
+        let length = usize::try_from(size)?;
+        let mut allocations = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let address = allocations.next_address;
+        let aligned_size = size.saturating_add(ALIGNMENT - 1) / ALIGNMENT * ALIGNMENT;
+        allocations.next_address = address.saturating_add(aligned_size);
+        allocations.blocks.insert(address, vec![0; length]);
+        Ok(address)
+    }
+
+    /// Free the block at the given address; freeing the null address does nothing.
+    ///
+    /// # Errors
+    /// if the address is not the start of an allocated block or the lock is poisoned.
+    pub(crate) fn free(&self, address: i64) -> Result<()> {
+        if address == 0 {
+            return Ok(());
+        }
+        let mut allocations = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        if allocations.blocks.remove(&address).is_none() {
+            return Err(InternalError(format!(
+                "Invalid native memory address: {address:#x}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Set `length` bytes starting at the given address to `value`.
+    ///
+    /// # Errors
+    /// if the range is not within a single allocated block or the lock is poisoned.
+    pub(crate) fn fill(&self, address: i64, length: usize, value: u8) -> Result<()> {
+        self.with_range(address, length, |bytes| bytes.fill(value))
+    }
+
+    /// Read `length` bytes starting at the given address.
+    ///
+    /// # Errors
+    /// if the range is not within a single allocated block or the lock is poisoned.
+    pub(crate) fn read(&self, address: i64, length: usize) -> Result<Vec<u8>> {
+        self.with_range(address, length, |bytes| bytes.to_vec())
+    }
+
+    /// Write the bytes starting at the given address.
+    ///
+    /// # Errors
+    /// if the range is not within a single allocated block or the lock is poisoned.
+    pub(crate) fn write(&self, address: i64, bytes: &[u8]) -> Result<()> {
+        self.with_range(address, bytes.len(), |range| range.copy_from_slice(bytes))
+    }
+
+    /// Read the bytes of the NUL-terminated string starting at the given address, excluding the
+    /// terminating NUL.
+    ///
+    /// # Errors
+    /// if the string is not terminated within a single allocated block or the lock is poisoned.
+    pub(crate) fn read_c_string(&self, address: i64) -> Result<Vec<u8>> {
+        let allocations = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let Some((&block_address, block)) = allocations.blocks.range(..=address).next_back() else {
+            return Err(InternalError(format!(
+                "Invalid native memory address: {address:#x}"
+            )));
+        };
+        let start = usize::try_from(address - block_address)?;
+        let Some(bytes) = block.get(start..) else {
+            return Err(InternalError(format!(
+                "Invalid native memory address: {address:#x}"
+            )));
+        };
+        let Some(length) = bytes.iter().position(|byte| *byte == 0) else {
+            return Err(InternalError(format!(
+                "Unterminated native string: {address:#x}"
+            )));
+        };
+        Ok(bytes[..length].to_vec())
+    }
+
+    /// Call the function with the `length` bytes starting at the given address.
+    fn with_range<T>(
+        &self,
+        address: i64,
+        length: usize,
+        function: impl FnOnce(&mut [u8]) -> T,
+    ) -> Result<T> {
+        let mut allocations = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let Some((&block_address, block)) = allocations.blocks.range_mut(..=address).next_back()
+        else {
+            return Err(InternalError(format!(
+                "Invalid native memory address: {address:#x}"
+            )));
+        };
+        let start = usize::try_from(address - block_address)?;
+        let Some(bytes) = start
+            .checked_add(length)
+            .and_then(|end| block.get_mut(start..end))
+        else {
+            return Err(InternalError(format!(
+                "Invalid native memory range: {address:#x} + {length}"
+            )));
+        };
+        Ok(function(bytes))
+    }
+
+    /// Get the byte at the given address; returns `None` if the address is not within an
+    /// allocated block.
+    ///
+    /// # Errors
+    /// if the lock is poisoned.
+    pub(crate) fn get_byte(&self, address: i64) -> Result<Option<i8>> {
+        let allocations = self
+            .inner
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        let Some((&block_address, block)) = allocations.blocks.range(..=address).next_back() else {
+            return Ok(None);
+        };
+        let index = usize::try_from(address - block_address)?;
+        #[expect(clippy::cast_possible_wrap)]
+        let byte = block.get(index).map(|byte| *byte as i8);
+        Ok(byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate() -> Result<()> {
+        let native_memory = NativeMemory::new();
+        let address = native_memory.allocate(3)?;
+        assert_eq!(BASE_ADDRESS, address);
+        assert_eq!(BASE_ADDRESS + ALIGNMENT, native_memory.allocate(1)?);
+        assert_eq!(0, native_memory.allocate(0)?);
+        for offset in 0..3 {
+            assert_eq!(Some(0), native_memory.get_byte(address + offset)?);
+        }
+        assert_eq!(None, native_memory.get_byte(address + 3)?);
+        assert_eq!(None, native_memory.get_byte(0)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_allocate_negative_size() {
+        let native_memory = NativeMemory::new();
+        assert!(native_memory.allocate(-1).is_err());
+    }
+
+    #[test]
+    fn test_fill() -> Result<()> {
+        let native_memory = NativeMemory::new();
+        let address = native_memory.allocate(4)?;
+        native_memory.fill(address + 1, 2, 0xFF)?;
+        assert_eq!(Some(0), native_memory.get_byte(address)?);
+        assert_eq!(Some(-1), native_memory.get_byte(address + 1)?);
+        assert_eq!(Some(-1), native_memory.get_byte(address + 2)?);
+        assert_eq!(Some(0), native_memory.get_byte(address + 3)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_out_of_range() -> Result<()> {
+        let native_memory = NativeMemory::new();
+        let address = native_memory.allocate(4)?;
+        assert!(native_memory.fill(address + 2, 3, 0).is_err());
+        assert!(native_memory.fill(0, 1, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_c_string() -> Result<()> {
+        let native_memory = NativeMemory::new();
+        let address = native_memory.allocate(6)?;
+        native_memory.write(address, b"/tmp\0x")?;
+        assert_eq!(b"/tmp".to_vec(), native_memory.read_c_string(address)?);
+        assert_eq!(b"mp".to_vec(), native_memory.read_c_string(address + 2)?);
+        assert!(native_memory.read_c_string(address + 5).is_err());
+        assert!(native_memory.read_c_string(0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_write() -> Result<()> {
+        let native_memory = NativeMemory::new();
+        let address = native_memory.allocate(4)?;
+        native_memory.write(address + 1, &[1, 2])?;
+        assert_eq!(vec![0, 1, 2, 0], native_memory.read(address, 4)?);
+        assert_eq!(vec![1, 2], native_memory.read(address + 1, 2)?);
+        assert!(native_memory.read(address + 2, 3).is_err());
+        assert!(native_memory.write(address + 3, &[1, 2]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_free() -> Result<()> {
+        let native_memory = NativeMemory::new();
+        let address = native_memory.allocate(4)?;
+        native_memory.free(address)?;
+        assert_eq!(None, native_memory.get_byte(address)?);
+        assert!(native_memory.free(address).is_err());
+        native_memory.free(0)?;
+        Ok(())
+    }
+}
@@ -1,10 +1,11 @@
 use crate::arguments::Arguments;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
-use crate::Result;
+use crate::Error::InternalError;
+use crate::{Result, VM};
 use async_recursion::async_recursion;
 use ristretto_classfile::Version;
-use ristretto_classloader::Value;
+use ristretto_classloader::{Object, Reference, Value};
 use std::sync::Arc;
 
 const JAVA_11: Version = Version::Java11 { minor: 0 };
@@ -31,9 +32,52 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     registry.register(class_name, "sync", "()V", sync);
 }
 
+/// Get the `java.io.FileDescriptor` of a file stream object (e.g. `java.io.FileInputStream`).
+pub(crate) fn from_stream(stream: &Object) -> Result<Object> {
+    let Value::Object(Some(Reference::Object(file_descriptor))) = stream.value("fd")? else {
+        return Err(InternalError(
+            "Invalid file stream; expected fd object".to_string(),
+        ));
+    };
+    Ok(file_descriptor)
+}
+
+/// Get the handle of the file referenced by the `java.io.FileDescriptor` object; -1 if the file
+/// descriptor is not valid.
+pub(crate) fn handle(file_descriptor: &Object) -> Result<i64> {
+    let Value::Int(handle) = file_descriptor.value("fd")? else {
+        return Err(InternalError(
+            "Invalid file descriptor; expected int fd".to_string(),
+        ));
+    };
+    Ok(i64::from(handle))
+}
+
+/// Set the handle of the file referenced by the `java.io.FileDescriptor` object.
+pub(crate) fn set_handle(file_descriptor: &Object, handle: i64) -> Result<()> {
+    file_descriptor.set_value("fd", Value::Int(i32::try_from(handle)?))?;
+    file_descriptor.set_value("handle", Value::Long(handle))?;
+    Ok(())
+}
+
+/// Close the file referenced by the `java.io.FileDescriptor` object and invalidate the file
+/// descriptor.  The standard streams are written through the host I/O without buffering, so they
+/// remain open for the process.
+pub(crate) fn close(vm: &VM, file_descriptor: &Object) -> Result<()> {
+    match handle(file_descriptor)? {
+        -1 => return Ok(()),
+        0..=2 => {}
+        handle => vm.file_handles().close(handle)?,
+    }
+    set_handle(file_descriptor, -1)
+}
+
 #[async_recursion(?Send)]
-async fn close_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.io.FileDescriptor.close0()V")
+async fn close_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let file_descriptor = arguments.pop_object()?;
+    let vm = thread.vm()?;
+    close(&vm, &file_descriptor)?;
+    Ok(None)
 }
 
 #[expect(clippy::match_same_arms)]
@@ -1,10 +1,14 @@
 use crate::arguments::Arguments;
+use crate::native_methods::java::io::filedescriptor;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
-use crate::Result;
+use crate::JavaError::{
+    FileNotFoundException, IOException, IndexOutOfBoundsException, NullPointerException,
+};
+use crate::{OpenMode, Result, VM};
 use async_recursion::async_recursion;
 use ristretto_classfile::Version;
-use ristretto_classloader::Value;
+use ristretto_classloader::{Object, Reference, Value};
 use std::sync::Arc;
 
 const JAVA_8: Version = Version::Java8 { minor: 0 };
@@ -30,14 +34,34 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     registry.register(class_name, "skip0", "(J)J", skip_0);
 }
 
+/// Read bytes from the file referenced by the file input stream into the buffer; handle 0 is the
+/// stdin of the host I/O.  Returns the number of bytes read or 0 at the end of the file.
+fn read_from_file(vm: &VM, file_input_stream: &Object, buffer: &mut [u8]) -> Result<usize> {
+    let file_descriptor = filedescriptor::from_stream(file_input_stream)?;
+    let length = match filedescriptor::handle(&file_descriptor)? {
+        0 => vm
+            .configuration()
+            .host_io()
+            .read_stdin(buffer)
+            .map_err(|error| IOException(error.to_string()))?,
+        -1 | 1 | 2 => return Err(IOException("Stream Closed".to_string()).into()),
+        handle => vm.file_handles().read(handle, buffer)?,
+    };
+    Ok(length)
+}
+
 #[async_recursion(?Send)]
 async fn available_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
     todo!("java.io.FileInputStream.available0()I")
 }
 
 #[async_recursion(?Send)]
-async fn close_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.close0()V")
+async fn close_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let file_input_stream = arguments.pop_object()?;
+    let file_descriptor = filedescriptor::from_stream(&file_input_stream)?;
+    let vm = thread.vm()?;
+    filedescriptor::close(&vm, &file_descriptor)?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -51,8 +75,23 @@ async fn length_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<
 }
 
 #[async_recursion(?Send)]
-async fn open_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.open0(Ljava/lang/String;)V")
+async fn open_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let Some(Reference::Object(path)) = arguments.pop_reference()? else {
+        return Err(NullPointerException("path cannot be null".to_string()).into());
+    };
+    let path: String = path.try_into()?;
+    let file_input_stream = arguments.pop_object()?;
+
+    let vm = thread.vm()?;
+    let file = vm
+        .configuration()
+        .host_io()
+        .open(&path, OpenMode::Read)
+        .map_err(|error| FileNotFoundException(format!("{path} ({error})")))?;
+    let handle = vm.file_handles().insert(file)?;
+    let file_descriptor = filedescriptor::from_stream(&file_input_stream)?;
+    filedescriptor::set_handle(&file_descriptor, handle)?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -61,16 +100,192 @@ async fn position_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Optio
 }
 
 #[async_recursion(?Send)]
-async fn read_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.read0()I")
+async fn read_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let file_input_stream = arguments.pop_object()?;
+    let vm = thread.vm()?;
+    let mut buffer = [0; 1];
+    let byte = if read_from_file(&vm, &file_input_stream, &mut buffer)? == 0 {
+        -1
+    } else {
+        i32::from(buffer[0])
+    };
+    Ok(Some(Value::Int(byte)))
 }
 
 #[async_recursion(?Send)]
-async fn read_bytes(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.io.FileInputStream.readBytes([BII)I")
+async fn read_bytes(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let length = arguments.pop_int()?;
+    let offset = arguments.pop_int()?;
+    let Some(Reference::ByteArray(bytes)) = arguments.pop_reference()? else {
+        return Err(NullPointerException("bytes cannot be null".to_string()).into());
+    };
+    let file_input_stream = arguments.pop_object()?;
+
+    let size = bytes.len()?;
+    let range = usize::try_from(offset)
+        .ok()
+        .zip(usize::try_from(length).ok())
+        .and_then(|(start, length)| Some(start..start.checked_add(length)?))
+        .filter(|range| range.end <= size);
+    let Some(range) = range else {
+        return Err(IndexOutOfBoundsException {
+            index: offset.saturating_add(length),
+            size: i32::try_from(size)?,
+        }
+        .into());
+    };
+    if range.is_empty() {
+        return Ok(Some(Value::Int(0)));
+    }
+
+    let vm = thread.vm()?;
+    let mut buffer = vec![0; range.len()];
+    let read = read_from_file(&vm, &file_input_stream, &mut buffer)?;
+    if read == 0 {
+        return Ok(Some(Value::Int(-1)));
+    }
+    for (index, byte) in range.zip(&buffer[..read]) {
+        #[expect(clippy::cast_possible_wrap)]
+        bytes.set(index, *byte as i8)?;
+    }
+    Ok(Some(Value::Int(i32::try_from(read)?)))
 }
 
 #[async_recursion(?Send)]
 async fn skip_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
     todo!("java.io.FileInputStream.skip0(J)J")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+    use crate::test::MemoryHostIo;
+    use crate::Error::JavaError;
+    use crate::{HostIo, OpenMode};
+    use std::io::Write;
+
+    /// Create a `java.io.FileInputStream` with a new `java.io.FileDescriptor` without invoking
+    /// the constructor, so that only the native methods are exercised.
+    async fn new_file_input_stream(thread: &Thread) -> Result<Object> {
+        let vm = thread.vm()?;
+        let file_descriptor = vm
+            .object("java/io/FileDescriptor", "", Vec::<Value>::new())
+            .await?;
+        let class = thread.class("java/io/FileInputStream").await?;
+        let file_input_stream = Object::new(class)?;
+        file_input_stream.set_value("fd", file_descriptor)?;
+        Ok(file_input_stream)
+    }
+
+    async fn open(thread: &Arc<Thread>, file_input_stream: &Object, path: &str) -> Result<()> {
+        let vm = thread.vm()?;
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(file_input_stream.clone()));
+        arguments.push(path.to_object(&vm).await?);
+        open_0(thread.clone(), arguments).await?;
+        Ok(())
+    }
+
+    async fn read(thread: &Arc<Thread>, file_input_stream: &Object) -> Result<i32> {
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(file_input_stream.clone()));
+        let value = read_0(thread.clone(), arguments).await?.expect("value");
+        Ok(value.to_int()?)
+    }
+
+    async fn read_bytes_into(
+        thread: &Arc<Thread>,
+        file_input_stream: &Object,
+        bytes: &Reference,
+        offset: i32,
+        length: i32,
+    ) -> Result<i32> {
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(file_input_stream.clone()));
+        arguments.push_reference(Some(bytes.clone()));
+        arguments.push_int(offset);
+        arguments.push_int(length);
+        let value = read_bytes(thread.clone(), arguments).await?.expect("value");
+        Ok(value.to_int()?)
+    }
+
+    #[tokio::test]
+    async fn test_read_file() -> Result<()> {
+        let host_io = Arc::new(MemoryHostIo::default());
+        let mut file = host_io.open("test.txt", OpenMode::Write).expect("file");
+        file.write_all(b"foobar").expect("write");
+        let (_vm, thread, _class) = crate::test::class_with_host_io(host_io).await?;
+
+        let file_input_stream = new_file_input_stream(&thread).await?;
+        open(&thread, &file_input_stream, "test.txt").await?;
+        assert_eq!(i32::from(b'f'), read(&thread, &file_input_stream).await?);
+        let bytes = Reference::from(vec![0i8; 8]);
+        assert_eq!(
+            4,
+            read_bytes_into(&thread, &file_input_stream, &bytes, 2, 4).await?
+        );
+        assert_eq!(Reference::from(vec![0i8, 0, 111, 111, 98, 97, 0, 0]), bytes);
+        assert_eq!(
+            1,
+            read_bytes_into(&thread, &file_input_stream, &bytes, 0, 8).await?
+        );
+        assert_eq!(
+            -1,
+            read_bytes_into(&thread, &file_input_stream, &bytes, 0, 8).await?
+        );
+        assert_eq!(-1, read(&thread, &file_input_stream).await?);
+        assert_eq!(
+            0,
+            read_bytes_into(&thread, &file_input_stream, &bytes, 0, 0).await?
+        );
+
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(file_input_stream.clone()));
+        close_0(thread.clone(), arguments).await?;
+        let result = read(&thread, &file_input_stream).await;
+        assert!(matches!(result, Err(JavaError(IOException(_)))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_bytes_out_of_bounds() -> Result<()> {
+        let host_io = Arc::new(MemoryHostIo::default());
+        host_io.open("test.txt", OpenMode::Write).expect("file");
+        let (_vm, thread, _class) = crate::test::class_with_host_io(host_io).await?;
+
+        let file_input_stream = new_file_input_stream(&thread).await?;
+        open(&thread, &file_input_stream, "test.txt").await?;
+        let bytes = Reference::from(vec![0i8; 3]);
+        for (offset, length) in [(2, 2), (-1, 1), (0, -1)] {
+            let result = read_bytes_into(&thread, &file_input_stream, &bytes, offset, length).await;
+            assert!(matches!(
+                result,
+                Err(JavaError(IndexOutOfBoundsException { .. }))
+            ));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_open_file_not_found() -> Result<()> {
+        let host_io = Arc::new(MemoryHostIo::default());
+        let (_vm, thread, _class) = crate::test::class_with_host_io(host_io).await?;
+        let file_input_stream = new_file_input_stream(&thread).await?;
+        let result = open(&thread, &file_input_stream, "missing.txt").await;
+        assert!(matches!(result, Err(JavaError(FileNotFoundException(_)))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_stdin() -> Result<()> {
+        let host_io = Arc::new(MemoryHostIo::new(b"a"));
+        let (_vm, thread, _class) = crate::test::class_with_host_io(host_io).await?;
+        let file_input_stream = new_file_input_stream(&thread).await?;
+        let file_descriptor = filedescriptor::from_stream(&file_input_stream)?;
+        filedescriptor::set_handle(&file_descriptor, 0)?;
+        assert_eq!(i32::from(b'a'), read(&thread, &file_input_stream).await?);
+        assert_eq!(-1, read(&thread, &file_input_stream).await?);
+        Ok(())
+    }
+}
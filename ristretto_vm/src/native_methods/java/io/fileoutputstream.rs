@@ -1,12 +1,14 @@
 use crate::arguments::Arguments;
+use crate::native_methods::java::io::filedescriptor;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
-use crate::Error::InternalError;
-use crate::Result;
+use crate::JavaError::{
+    FileNotFoundException, IOException, IndexOutOfBoundsException, NullPointerException,
+};
+use crate::{OpenMode, Result, VM};
 use async_recursion::async_recursion;
 use ristretto_classfile::Version;
-use ristretto_classloader::{Reference, Value};
-use std::io::Write;
+use ristretto_classloader::{Object, Reference, Value};
 use std::sync::Arc;
 
 const JAVA_8: Version = Version::Java8 { minor: 0 };
@@ -26,9 +28,31 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     registry.register(class_name, "writeBytes", "([BIIZ)V", write_bytes);
 }
 
+/// Write the bytes to the file referenced by the file output stream; handles 1 and 2 are the
+/// stdout and stderr of the host I/O.
+fn write_to_file(vm: &VM, file_output_stream: &Object, bytes: &[u8]) -> Result<()> {
+    let file_descriptor = filedescriptor::from_stream(file_output_stream)?;
+    let host_io = vm.configuration().host_io();
+    match filedescriptor::handle(&file_descriptor)? {
+        1 => host_io
+            .write_stdout(bytes)
+            .map_err(|error| IOException(error.to_string()))?,
+        2 => host_io
+            .write_stderr(bytes)
+            .map_err(|error| IOException(error.to_string()))?,
+        -1 | 0 => return Err(IOException("Stream Closed".to_string()).into()),
+        handle => vm.file_handles().write(handle, bytes)?,
+    }
+    Ok(())
+}
+
 #[async_recursion(?Send)]
-async fn close_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.io.FileOutputStream.close0()V")
+async fn close_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let file_output_stream = arguments.pop_object()?;
+    let file_descriptor = filedescriptor::from_stream(&file_output_stream)?;
+    let vm = thread.vm()?;
+    filedescriptor::close(&vm, &file_descriptor)?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -37,68 +61,250 @@ async fn init_ids(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<
 }
 
 #[async_recursion(?Send)]
-async fn open_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.io.FileOutputStream.open0(Ljava/lang/String;Z)V")
+async fn open_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let append = arguments.pop_int()? != 0;
+    let Some(Reference::Object(path)) = arguments.pop_reference()? else {
+        return Err(NullPointerException("path cannot be null".to_string()).into());
+    };
+    let path: String = path.try_into()?;
+    let file_output_stream = arguments.pop_object()?;
+
+    let mode = if append {
+        OpenMode::Append
+    } else {
+        OpenMode::Write
+    };
+    let vm = thread.vm()?;
+    let file = vm
+        .configuration()
+        .host_io()
+        .open(&path, mode)
+        .map_err(|error| FileNotFoundException(format!("{path} ({error})")))?;
+    let handle = vm.file_handles().insert(file)?;
+    let file_descriptor = filedescriptor::from_stream(&file_output_stream)?;
+    filedescriptor::set_handle(&file_descriptor, handle)?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
-async fn write(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.io.FileOutputStream.write(IZ)V")
+async fn write(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    // The append mode of the file is set when it is opened by open0
+    let _append = arguments.pop_int()? != 0;
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let byte = arguments.pop_int()? as u8;
+    let file_output_stream = arguments.pop_object()?;
+    let vm = thread.vm()?;
+    write_to_file(&vm, &file_output_stream, &[byte])?;
+    Ok(None)
 }
 
 #[expect(clippy::cast_sign_loss)]
 #[async_recursion(?Send)]
-async fn write_bytes(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+async fn write_bytes(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    // The append mode of the file is set when it is opened by open0
     let _append = arguments.pop_int()? != 0;
-    let length = usize::try_from(arguments.pop_int()?)?;
-    let offset = usize::try_from(arguments.pop_int()?)?;
+    let length = arguments.pop_int()?;
+    let offset = arguments.pop_int()?;
     let Some(Reference::ByteArray(bytes)) = arguments.pop_reference()? else {
-        return Err(InternalError(
-            "Invalid argument type; expected byte[]".to_string(),
-        ));
+        return Err(NullPointerException("bytes cannot be null".to_string()).into());
     };
     let bytes: Vec<u8> = bytes.to_vec()?.iter().map(|&x| x as u8).collect();
-    let Some(Reference::Object(file_output_stream)) = arguments.pop_reference()? else {
-        return Err(InternalError(
-            "Invalid argument type; expected object".to_string(),
-        ));
-    };
-    let Value::Object(Some(Reference::Object(file_descriptor))) = file_output_stream.value("fd")?
-    else {
-        return Err(InternalError(
-            "Invalid argument type; expected object".to_string(),
-        ));
-    };
-    let Value::Long(handle) = file_descriptor.value("handle")? else {
-        return Err(InternalError(
-            "Invalid argument type; expected long".to_string(),
-        ));
-    };
+    let file_output_stream = arguments.pop_object()?;
 
-    match handle {
-        1 => {
-            let stdout = std::io::stdout();
-            let mut stdout = stdout.lock();
-            stdout
-                .write_all(&bytes[offset..offset + length])
-                .map_err(|error| InternalError(error.to_string()))?;
-            stdout
-                .flush()
-                .map_err(|error| InternalError(error.to_string()))?;
-        }
-        2 => {
-            let stderr = std::io::stderr();
-            let mut stderr = stderr.lock();
-            stderr
-                .write_all(&bytes[offset..offset + length])
-                .map_err(|error| InternalError(error.to_string()))?;
-            stderr
-                .flush()
-                .map_err(|error| InternalError(error.to_string()))?;
+    let range = usize::try_from(offset)
+        .ok()
+        .zip(usize::try_from(length).ok())
+        .and_then(|(start, length)| Some(start..start.checked_add(length)?));
+    let Some(bytes) = range.and_then(|range| bytes.get(range)) else {
+        return Err(IndexOutOfBoundsException {
+            index: offset.saturating_add(length),
+            size: i32::try_from(bytes.len())?,
         }
-        _ => {
-            return Err(InternalError(format!("Invalid file handle: {handle}")));
+        .into());
+    };
+    let vm = thread.vm()?;
+    write_to_file(&vm, &file_output_stream, bytes)?;
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+    use crate::test::MemoryHostIo;
+    use crate::Error::JavaError;
+
+    /// Create a `java.io.FileOutputStream` with a new `java.io.FileDescriptor` without invoking
+    /// the constructor, so that only the native methods are exercised.
+    async fn new_file_output_stream(thread: &Thread) -> Result<Object> {
+        let vm = thread.vm()?;
+        let file_descriptor = vm
+            .object("java/io/FileDescriptor", "", Vec::<Value>::new())
+            .await?;
+        let class = thread.class("java/io/FileOutputStream").await?;
+        let file_output_stream = Object::new(class)?;
+        file_output_stream.set_value("fd", file_descriptor)?;
+        Ok(file_output_stream)
+    }
+
+    async fn open(
+        thread: &Arc<Thread>,
+        file_output_stream: &Object,
+        path: &str,
+        append: bool,
+    ) -> Result<()> {
+        let vm = thread.vm()?;
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(file_output_stream.clone()));
+        arguments.push(path.to_object(&vm).await?);
+        arguments.push_int(i32::from(append));
+        open_0(thread.clone(), arguments).await?;
+        Ok(())
+    }
+
+    async fn write_bytes_to(
+        thread: &Arc<Thread>,
+        file_output_stream: &Object,
+        bytes: Vec<i8>,
+        offset: i32,
+        length: i32,
+    ) -> Result<Option<Value>> {
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(file_output_stream.clone()));
+        arguments.push_reference(Some(Reference::from(bytes)));
+        arguments.push_int(offset);
+        arguments.push_int(length);
+        arguments.push_int(0);
+        write_bytes(thread.clone(), arguments).await
+    }
+
+    async fn close(thread: &Arc<Thread>, file_output_stream: &Object) -> Result<()> {
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(file_output_stream.clone()));
+        close_0(thread.clone(), arguments).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_file() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let directory = tempfile::tempdir().expect("temp dir");
+        let path = directory.path().join("test.txt");
+        let path_name = path.to_string_lossy().to_string();
+
+        let file_output_stream = new_file_output_stream(&thread).await?;
+        open(&thread, &file_output_stream, &path_name, false).await?;
+        write_bytes_to(
+            &thread,
+            &file_output_stream,
+            vec![0, 102, 111, 111, 0],
+            1,
+            3,
+        )
+        .await?;
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(file_output_stream.clone()));
+        arguments.push_int(i32::from(b'!'));
+        arguments.push_int(0);
+        write(thread.clone(), arguments).await?;
+        close(&thread, &file_output_stream).await?;
+        assert_eq!(b"foo!".to_vec(), std::fs::read(&path).expect("read"));
+
+        // Writing to a closed stream fails
+        let result = write_bytes_to(&thread, &file_output_stream, vec![102], 0, 1).await;
+        assert!(matches!(result, Err(JavaError(IOException(_)))));
+
+        // Opening without append truncates the file
+        let file_output_stream = new_file_output_stream(&thread).await?;
+        open(&thread, &file_output_stream, &path_name, false).await?;
+        write_bytes_to(&thread, &file_output_stream, vec![98, 97, 114], 0, 3).await?;
+        close(&thread, &file_output_stream).await?;
+        assert_eq!(b"bar".to_vec(), std::fs::read(&path).expect("read"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_file_append() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let directory = tempfile::tempdir().expect("temp dir");
+        let path = directory.path().join("test.txt");
+        std::fs::write(&path, b"foo").expect("write");
+
+        let file_output_stream = new_file_output_stream(&thread).await?;
+        open(&thread, &file_output_stream, &path.to_string_lossy(), true).await?;
+        write_bytes_to(&thread, &file_output_stream, vec![98, 97, 114], 0, 3).await?;
+        close(&thread, &file_output_stream).await?;
+        assert_eq!(b"foobar".to_vec(), std::fs::read(&path).expect("read"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_bytes_out_of_bounds() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let directory = tempfile::tempdir().expect("temp dir");
+        let path = directory.path().join("test.txt");
+
+        let file_output_stream = new_file_output_stream(&thread).await?;
+        open(&thread, &file_output_stream, &path.to_string_lossy(), false).await?;
+        for (offset, length) in [(2, 2), (-1, 1), (0, -1)] {
+            let result =
+                write_bytes_to(&thread, &file_output_stream, vec![1, 2, 3], offset, length).await;
+            assert!(matches!(
+                result,
+                Err(JavaError(IndexOutOfBoundsException { .. }))
+            ));
         }
+        close(&thread, &file_output_stream).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_open_file_not_found() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let directory = tempfile::tempdir().expect("temp dir");
+        let path = directory.path().join("missing").join("test.txt");
+
+        let file_output_stream = new_file_output_stream(&thread).await?;
+        let result = open(&thread, &file_output_stream, &path.to_string_lossy(), false).await;
+        assert!(matches!(result, Err(JavaError(FileNotFoundException(_)))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_host_io_file() -> Result<()> {
+        let host_io = Arc::new(MemoryHostIo::default());
+        let (_vm, thread, _class) = crate::test::class_with_host_io(host_io.clone()).await?;
+
+        let file_output_stream = new_file_output_stream(&thread).await?;
+        open(&thread, &file_output_stream, "test.txt", false).await?;
+        write_bytes_to(&thread, &file_output_stream, vec![102, 111, 111], 0, 3).await?;
+        close(&thread, &file_output_stream).await?;
+
+        let file_output_stream = new_file_output_stream(&thread).await?;
+        open(&thread, &file_output_stream, "test.txt", true).await?;
+        write_bytes_to(&thread, &file_output_stream, vec![98, 97, 114], 0, 3).await?;
+        close(&thread, &file_output_stream).await?;
+        assert_eq!(Some(b"foobar".to_vec()), host_io.file("test.txt"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_standard_streams() -> Result<()> {
+        let host_io = Arc::new(MemoryHostIo::default());
+        let (vm, thread, _class) = crate::test::class_with_host_io(host_io.clone()).await?;
+        let stdout = new_file_output_stream(&thread).await?;
+        let stdout_descriptor = filedescriptor::from_stream(&stdout)?;
+        filedescriptor::set_handle(&stdout_descriptor, 1)?;
+        let stderr = new_file_output_stream(&thread).await?;
+        filedescriptor::set_handle(&filedescriptor::from_stream(&stderr)?, 2)?;
+
+        write_to_file(&vm, &stdout, b"foo")?;
+        write_to_file(&vm, &stderr, b"bar")?;
+        assert_eq!(b"foo".to_vec(), host_io.stdout());
+        assert_eq!(b"bar".to_vec(), host_io.stderr());
+
+        close(&thread, &stdout).await?;
+        assert_eq!(-1, filedescriptor::handle(&stdout_descriptor)?);
+        Ok(())
     }
-    Ok(None)
 }
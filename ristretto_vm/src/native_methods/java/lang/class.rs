@@ -1,5 +1,6 @@
 use crate::arguments::Arguments;
-use crate::java_object::JavaObject;
+use crate::instruction::{is_assignable, is_instance_of};
+use crate::java_object::{class_object_name, JavaObject};
 use crate::native_methods::registry::MethodRegistry;
 use crate::rust_value::RustValue;
 use crate::thread::Thread;
@@ -7,7 +8,8 @@ use crate::Error::InternalError;
 use crate::JavaError::NullPointerException;
 use crate::Result;
 use async_recursion::async_recursion;
-use ristretto_classfile::{ClassAccessFlags, FieldAccessFlags, Version};
+use ristretto_classfile::attributes::{Attribute, NestedClassAccessFlags};
+use ristretto_classfile::{FieldAccessFlags, MethodAccessFlags, MethodDescriptor, Version};
 use ristretto_classloader::{Class, Object, Reference, Value};
 use std::sync::Arc;
 
@@ -250,13 +252,24 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 async fn get_class(thread: &Thread, object: &Object) -> Result<Arc<Class>> {
     let class = object.class();
     if class.name() == "java/lang/Class" {
-        let class_name: String = object.value("name")?.try_into()?;
+        let class_name = class_object_name(object)?;
         let class = thread.class(class_name.as_str()).await?;
         return Ok(class);
     }
     Ok(Arc::clone(class))
 }
 
+/// Get the binary name (e.g. `java.lang.String`) of a class object.  The name is computed once,
+/// when the class object is created, and cached in the `name` field; reading the field avoids
+/// formatting a new string for each call.
+fn cached_name(object: &Object) -> Result<Value> {
+    let name = object.value("name")?;
+    if matches!(name, Value::Object(None)) {
+        return Err(InternalError("class object has no name".to_string()));
+    }
+    Ok(name)
+}
+
 #[async_recursion(?Send)]
 async fn desired_assertion_status_0(
     _thread: Arc<Thread>,
@@ -270,15 +283,19 @@ async fn for_name_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Opt
     // TODO: Add support for unused arguments
     let _caller = arguments.pop_reference()?;
     let _class_loader = arguments.pop_reference()?;
-    let _initialize = arguments.pop_int()? != 0;
+    let initialize = arguments.pop_int()? != 0;
     let Some(Reference::Object(class_name)) = arguments.pop_reference()? else {
         return Err(InternalError(
             "forName0: no class_name argument".to_string(),
         ));
     };
     let class_name: String = class_name.try_into()?;
+    let class_name = class_name.replace('.', "/");
     let vm = thread.vm()?;
-    let class = thread.class(class_name).await?;
+    let class = thread.load_class(class_name).await?;
+    if initialize {
+        thread.initialize_class(&class).await?;
+    }
     let class_object = class.to_object(&vm).await?;
 
     Ok(Some(class_object))
@@ -326,7 +343,7 @@ async fn get_component_type(
         return Ok(Some(Value::Object(None)));
     }
 
-    let class_name: String = object.value("name")?.try_into()?;
+    let class_name = class_object_name(&object)?;
     let class = thread.class(class_name).await?;
     let vm = thread.vm()?;
     let class_object = class.to_object(&vm).await?;
@@ -341,10 +358,40 @@ async fn get_constant_pool(_thread: Arc<Thread>, _arguments: Arguments) -> Resul
 
 #[async_recursion(?Send)]
 async fn get_declared_classes_0(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getDeclaredClasses0()[Ljava/lang/Class;")
+    let object = arguments.pop_object()?;
+    let class = get_class(&thread, &object).await?;
+    let class_file = class.class_file();
+    let constant_pool = &class_file.constant_pool;
+    let vm = thread.vm()?;
+
+    // Member classes are the InnerClasses entries whose outer class is this class; anonymous and
+    // local classes have no outer class and are excluded.
+    let mut declared_classes = Vec::new();
+    for attribute in &class_file.attributes {
+        let Attribute::InnerClasses { classes, .. } = attribute else {
+            continue;
+        };
+        for inner_class in classes {
+            if inner_class.outer_class_info_index == 0 {
+                continue;
+            }
+            let outer_class_name =
+                constant_pool.try_get_class(inner_class.outer_class_info_index)?;
+            let inner_class_name = constant_pool.try_get_class(inner_class.class_info_index)?;
+            if outer_class_name != class.name() || inner_class_name == class.name() {
+                continue;
+            }
+            let inner_class = thread.load_class(inner_class_name).await?;
+            declared_classes.push(inner_class.to_object(&vm).await?);
+        }
+    }
+
+    let class_array = thread.load_class("[Ljava/lang/Class;").await?;
+    let declared_classes = Reference::try_from((class_array, declared_classes))?;
+    Ok(Some(Value::from(declared_classes)))
 }
 
 #[async_recursion(?Send)]
@@ -427,10 +474,95 @@ async fn get_declared_fields_0(
 
 #[async_recursion(?Send)]
 async fn get_declared_methods_0(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getDeclaredMethods0(Z)[Ljava/lang/reflect/Method;")
+    let public_only = arguments.pop_int()? != 0;
+    let object = arguments.pop_object()?;
+    let vm = thread.vm()?;
+    let class = get_class(&thread, &object).await?;
+    let class_object = class.to_object(&vm).await?;
+    let class_file = class.class_file();
+    let constant_pool = &class_file.constant_pool;
+    let class_array = thread.load_class("[Ljava/lang/Class;").await?;
+
+    let mut methods = Vec::new();
+    // The slot of a method is its index in the class file, which is stable for the class
+    for (slot, definition) in class_file.methods.iter().enumerate() {
+        let access_flags = definition.access_flags;
+        if public_only && !access_flags.contains(MethodAccessFlags::PUBLIC) {
+            continue;
+        }
+        let method_name = constant_pool.try_get_utf8(definition.name_index)?;
+        // Constructors and class initializers are not methods
+        if method_name == "<init>" || method_name == "<clinit>" {
+            continue;
+        }
+        let method_descriptor = constant_pool.try_get_utf8(definition.descriptor_index)?;
+        let MethodDescriptor {
+            parameters,
+            return_type,
+        } = MethodDescriptor::parse(method_descriptor)?;
+
+        let mut parameter_types = Vec::with_capacity(parameters.len());
+        for parameter in &parameters {
+            let parameter_class = thread.load_class(parameter.class_name()).await?;
+            parameter_types.push(parameter_class.to_object(&vm).await?);
+        }
+        let parameter_types = Value::try_from((class_array.clone(), parameter_types))?;
+        let return_type_class_name =
+            return_type.map_or("void".to_string(), |return_type| return_type.class_name());
+        let return_type = thread.load_class(return_type_class_name).await?;
+        let return_type = return_type.to_object(&vm).await?;
+
+        let mut exception_types = Vec::new();
+        for attribute in &definition.attributes {
+            let Attribute::Exceptions {
+                exception_indexes, ..
+            } = attribute
+            else {
+                continue;
+            };
+            for exception_index in exception_indexes {
+                let exception_class_name = constant_pool.try_get_class(*exception_index)?;
+                let exception_class = thread.load_class(exception_class_name).await?;
+                exception_types.push(exception_class.to_object(&vm).await?);
+            }
+        }
+        let exception_types = Value::try_from((class_array.clone(), exception_types))?;
+
+        let modifiers = Value::Int(i32::from(access_flags.bits()));
+        let slot = Value::Int(i32::try_from(slot)?);
+        // TODO: Add support for generic signature
+        let signature = Value::Object(None);
+        // TODO: Add support for annotations
+        let annotations = Value::Object(None);
+        let parameter_annotations = Value::Object(None);
+        let annotation_default = Value::Object(None);
+        let method = thread
+            .object(
+                "java/lang/reflect/Method",
+                "Ljava/lang/Class;Ljava/lang/String;[Ljava/lang/Class;Ljava/lang/Class;[Ljava/lang/Class;IILjava/lang/String;[B[B[B",
+                vec![
+                    class_object.clone(),
+                    method_name.to_value(),
+                    parameter_types,
+                    return_type,
+                    exception_types,
+                    modifiers,
+                    slot,
+                    signature,
+                    annotations,
+                    parameter_annotations,
+                    annotation_default,
+                ],
+            )
+            .await?;
+        methods.push(method);
+    }
+    let methods_array_class = thread.load_class("[Ljava/lang/reflect/Method;").await?;
+    let methods = Value::try_from((methods_array_class, methods))?;
+    Ok(Some(methods))
 }
 
 #[async_recursion(?Send)]
@@ -474,8 +606,35 @@ async fn get_generic_signature_0(
 }
 
 #[async_recursion(?Send)]
-async fn get_interfaces_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.Class.getInterfaces0()[Ljava/lang/Class;")
+async fn get_interfaces_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let object = arguments.pop_object()?;
+    let class = get_class(&thread, &object).await?;
+    let vm = thread.vm()?;
+
+    // Arrays implement Cloneable and Serializable; the interfaces of other classes are those
+    // declared by the class, in the order they are declared, and not those of its superclasses.
+    let interface_names = if class.is_array() {
+        vec![
+            "java/lang/Cloneable".to_string(),
+            "java/io/Serializable".to_string(),
+        ]
+    } else {
+        let constant_pool = class.constant_pool();
+        let mut interface_names = Vec::new();
+        for interface_index in &class.class_file().interfaces {
+            interface_names.push(constant_pool.try_get_class(*interface_index)?.clone());
+        }
+        interface_names
+    };
+    let mut interfaces = Vec::with_capacity(interface_names.len());
+    for interface_name in interface_names {
+        let interface = thread.load_class(interface_name).await?;
+        interfaces.push(interface.to_object(&vm).await?);
+    }
+
+    let class_array = thread.load_class("[Ljava/lang/Class;").await?;
+    let interfaces = Reference::try_from((class_array, interfaces))?;
+    Ok(Some(Value::from(interfaces)))
 }
 
 #[async_recursion(?Send)]
@@ -483,27 +642,43 @@ async fn get_modifiers(thread: Arc<Thread>, mut arguments: Arguments) -> Result<
     let object = arguments.pop_object()?;
     let class = get_class(&thread, &object).await?;
     let class_file = class.class_file();
-    let access_flags = &class_file.access_flags.bits();
-    let excluded_flags = (ClassAccessFlags::ANNOTATION
-        | ClassAccessFlags::ENUM
-        | ClassAccessFlags::MODULE
-        | ClassAccessFlags::SUPER
-        | ClassAccessFlags::SYNTHETIC)
-        .bits();
-    let excluded_flags_mask = !excluded_flags;
-    let modifiers = i32::from(access_flags & excluded_flags_mask);
+    let mut access_flags = class_file.access_flags.bits();
+
+    // The source level modifiers of member classes (e.g. private, protected and static) are only
+    // recorded in the InnerClasses attribute; these take precedence over the class access flags.
+    for attribute in &class_file.attributes {
+        let Attribute::InnerClasses { classes, .. } = attribute else {
+            continue;
+        };
+        for inner_class in classes {
+            let inner_class_name = class_file
+                .constant_pool
+                .try_get_class(inner_class.class_info_index)?;
+            if inner_class_name == class.name() {
+                access_flags = inner_class.access_flags.bits();
+            }
+        }
+    }
 
-    // TODO: correct the modifier values
+    // Only the modifiers defined by the Java Language Specification are reported; internal flags
+    // such as ACC_SUPER, ACC_SYNTHETIC, ACC_ANNOTATION and ACC_ENUM are masked off.
+    let modifiers_mask = (NestedClassAccessFlags::PUBLIC
+        | NestedClassAccessFlags::PRIVATE
+        | NestedClassAccessFlags::PROTECTED
+        | NestedClassAccessFlags::STATIC
+        | NestedClassAccessFlags::FINAL
+        | NestedClassAccessFlags::INTERFACE
+        | NestedClassAccessFlags::ABSTRACT)
+        .bits();
+    let modifiers = i32::from(access_flags & modifiers_mask);
     Ok(Some(Value::Int(modifiers)))
 }
 
 #[async_recursion(?Send)]
-async fn get_name_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+async fn get_name_0(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
     let object = arguments.pop_object()?;
-    let class = get_class(&thread, &object).await?;
-    let class_name = class.name().replace('/', ".");
-    let value = class_name.to_value();
-    Ok(Some(value))
+    let name = cached_name(&object)?;
+    Ok(Some(name))
 }
 
 #[async_recursion(?Send)]
@@ -601,7 +776,11 @@ async fn get_simple_binary_name_0(
 #[async_recursion(?Send)]
 async fn get_superclass(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
     let object = arguments.pop_object()?;
-    let class = object.class();
+    let class = get_class(&thread, &object).await?;
+    // Interfaces and primitives are linked to java.lang.Object, but have no superclass
+    if class.is_interface() || class.is_primitive() {
+        return Ok(Some(Value::Object(None)));
+    }
     match class.parent()? {
         Some(parent) => {
             let class_name = parent.name();
@@ -610,18 +789,16 @@ async fn get_superclass(thread: Arc<Thread>, mut arguments: Arguments) -> Result
             let class = class.to_object(&vm).await?;
             Ok(Some(class))
         }
-        None => Ok(None),
+        None => Ok(Some(Value::Object(None))),
     }
 }
 
 #[async_recursion(?Send)]
-async fn init_class_name(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+async fn init_class_name(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
     // TODO: implement support for hidden classes
     let object = arguments.pop_object()?;
-    let class = get_class(&thread, &object).await?;
-    let class_name = class.name().replace('/', ".");
-    let value = class_name.to_value();
-    Ok(Some(value))
+    let name = cached_name(&object)?;
+    Ok(Some(name))
 }
 
 #[async_recursion(?Send)]
@@ -650,11 +827,12 @@ async fn is_assignable_from(
         return Err(InternalError("isAssignableFrom: no instance".to_string()));
     };
     let class = get_class(&thread, &object).await?;
-    if class.is_assignable_from(&class_argument)? {
-        Ok(Some(Value::from(true)))
-    } else {
-        Ok(Some(Value::from(false)))
+    // Primitive types are only assignable from themselves
+    if class.is_primitive() || class_argument.is_primitive() {
+        return Ok(Some(Value::from(class.name() == class_argument.name())));
     }
+    let is_assignable = is_assignable(&thread, &class, &class_argument).await?;
+    Ok(Some(Value::from(is_assignable)))
 }
 
 #[async_recursion(?Send)]
@@ -664,8 +842,17 @@ async fn is_hidden(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option
 }
 
 #[async_recursion(?Send)]
-async fn is_instance(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.Class.isInstance(Ljava/lang/Object;)Z")
+async fn is_instance(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let Some(object_argument) = arguments.pop_reference()? else {
+        return Ok(Some(Value::from(false)));
+    };
+    let object = arguments.pop_object()?;
+    let class = get_class(&thread, &object).await?;
+    if class.is_primitive() {
+        return Ok(Some(Value::from(false)));
+    }
+    let is_instance = is_instance_of(&thread, &object_argument, &class).await?;
+    Ok(Some(Value::from(is_instance)))
 }
 
 #[async_recursion(?Send)]
@@ -705,3 +892,360 @@ async fn set_signers(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Opti
     // TODO: Implement set_signers
     Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StdHostIo;
+    use ristretto_classfile::ClassAccessFlags;
+    use ristretto_classloader::InitializationState;
+
+    async fn modifiers(class_name: &str) -> Result<i32> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class(class_name).await?;
+        let class_object = class.to_object(&vm).await?;
+        let mut arguments = Arguments::default();
+        arguments.push(class_object);
+        let result = get_modifiers(thread, arguments).await?;
+        let Some(Value::Int(modifiers)) = result else {
+            panic!("expected int");
+        };
+        Ok(modifiers)
+    }
+
+    async fn superclass(class_name: &str) -> Result<Option<String>> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class(class_name).await?;
+        let class_object = class.to_object(&vm).await?;
+        let mut arguments = Arguments::default();
+        arguments.push(class_object);
+        let result = get_superclass(thread, arguments).await?;
+        let Some(Value::Object(superclass)) = result else {
+            panic!("expected object");
+        };
+        match superclass {
+            Some(Reference::Object(superclass)) => Ok(Some(class_object_name(&superclass)?)),
+            Some(_) => panic!("expected class object"),
+            None => Ok(None),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_superclass() -> Result<()> {
+        assert_eq!(None, superclass("java/lang/Object").await?);
+        assert_eq!(None, superclass("java/lang/Runnable").await?);
+        assert_eq!(None, superclass("int").await?);
+        assert_eq!(None, superclass("void").await?);
+        assert_eq!(
+            Some("java/lang/Object".to_string()),
+            superclass("java/lang/String").await?
+        );
+        assert_eq!(
+            Some("java/lang/Number".to_string()),
+            superclass("java/lang/Integer").await?
+        );
+        assert_eq!(
+            Some("java/lang/Object".to_string()),
+            superclass("[I").await?
+        );
+        Ok(())
+    }
+
+    async fn assignable_from(class_name: &str, other_class_name: &str) -> Result<bool> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class(class_name).await?.to_object(&vm).await?;
+        let other_class = thread.class(other_class_name).await?.to_object(&vm).await?;
+        let arguments = Arguments::new(vec![class, other_class]);
+        let result = is_assignable_from(thread, arguments).await?;
+        let Some(Value::Int(is_assignable)) = result else {
+            panic!("expected int");
+        };
+        Ok(is_assignable != 0)
+    }
+
+    #[tokio::test]
+    async fn test_is_assignable_from() -> Result<()> {
+        assert!(assignable_from("java/lang/Number", "java/lang/Integer").await?);
+        assert!(!assignable_from("java/lang/Integer", "java/lang/Number").await?);
+        assert!(assignable_from("java/lang/Object", "java/lang/String").await?);
+        assert!(assignable_from("java/lang/CharSequence", "java/lang/String").await?);
+        assert!(assignable_from("java/lang/Object", "[I").await?);
+        assert!(assignable_from("[Ljava/lang/Object;", "[Ljava/lang/String;").await?);
+        assert!(!assignable_from("[Ljava/lang/String;", "[Ljava/lang/Object;").await?);
+        assert!(assignable_from("int", "int").await?);
+        assert!(!assignable_from("java/lang/Object", "int").await?);
+        assert!(!assignable_from("long", "int").await?);
+        Ok(())
+    }
+
+    async fn instance(class_name: &str, object: Value) -> Result<bool> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class(class_name).await?.to_object(&vm).await?;
+        let arguments = Arguments::new(vec![class, object]);
+        let result = is_instance(thread, arguments).await?;
+        let Some(Value::Int(is_instance)) = result else {
+            panic!("expected int");
+        };
+        Ok(is_instance != 0)
+    }
+
+    #[tokio::test]
+    async fn test_is_instance() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let string = "foo".to_object(&vm).await?;
+        assert!(instance("java/lang/String", string.clone()).await?);
+        assert!(instance("java/lang/Object", string.clone()).await?);
+        assert!(instance("java/lang/CharSequence", string.clone()).await?);
+        assert!(!instance("java/lang/Integer", string).await?);
+        assert!(instance("[I", Value::from(vec![1i32, 2, 3])).await?);
+        assert!(instance("java/io/Serializable", Value::from(vec![1i32])).await?);
+        assert!(!instance("java/lang/Object", Value::Object(None)).await?);
+        Ok(())
+    }
+
+    async fn interfaces(class_name: &str) -> Result<Vec<String>> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class(class_name).await?.to_object(&vm).await?;
+        let result = get_interfaces_0(thread, Arguments::new(vec![class])).await?;
+        let Some(Value::Object(Some(Reference::Array(_, interfaces)))) = result else {
+            panic!("expected array");
+        };
+        let mut interface_names = Vec::new();
+        for interface in interfaces.to_vec()? {
+            let Some(Reference::Object(interface)) = interface else {
+                panic!("expected class object");
+            };
+            interface_names.push(class_object_name(&interface)?);
+        }
+        Ok(interface_names)
+    }
+
+    #[tokio::test]
+    async fn test_get_interfaces_0() -> Result<()> {
+        assert!(interfaces("java/lang/Object").await?.is_empty());
+        assert_eq!(
+            vec!["java/util/Map".to_string()],
+            interfaces("java/util/SortedMap").await?
+        );
+        // Interfaces of the superclass (e.g. Number implements Serializable) are not included
+        let integer_interfaces = interfaces("java/lang/Integer").await?;
+        assert!(integer_interfaces.contains(&"java/lang/Comparable".to_string()));
+        assert!(!integer_interfaces.contains(&"java/io/Serializable".to_string()));
+        assert_eq!(
+            vec![
+                "java/lang/Cloneable".to_string(),
+                "java/io/Serializable".to_string()
+            ],
+            interfaces("[I").await?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_modifiers() -> Result<()> {
+        let modifiers = modifiers("java/lang/String").await?;
+        let expected = (ClassAccessFlags::PUBLIC | ClassAccessFlags::FINAL).bits();
+        assert_eq!(i32::from(expected), modifiers);
+        assert_eq!(0, modifiers & i32::from(ClassAccessFlags::SUPER.bits()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_modifiers_inner_class() -> Result<()> {
+        let modifiers = modifiers("java/util/Map$Entry").await?;
+        let expected = (NestedClassAccessFlags::PUBLIC
+            | NestedClassAccessFlags::STATIC
+            | NestedClassAccessFlags::INTERFACE
+            | NestedClassAccessFlags::ABSTRACT)
+            .bits();
+        assert_eq!(i32::from(expected), modifiers);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_declared_classes_0() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class("java/util/AbstractMap").await?;
+        let mut arguments = Arguments::default();
+        arguments.push(class.to_object(&vm).await?);
+        let result = get_declared_classes_0(thread, arguments).await?;
+        let Some(Value::Object(Some(Reference::Array(_, declared_classes)))) = result else {
+            panic!("expected class array");
+        };
+        let mut names = Vec::new();
+        for declared_class in declared_classes.to_vec()? {
+            let Some(Reference::Object(declared_class)) = declared_class else {
+                panic!("expected class object");
+            };
+            names.push(class_object_name(&declared_class)?);
+        }
+        names.sort();
+        assert_eq!(
+            vec![
+                "java/util/AbstractMap$SimpleEntry",
+                "java/util/AbstractMap$SimpleImmutableEntry",
+            ],
+            names
+        );
+        Ok(())
+    }
+
+    /// Get the names of the reflection objects (e.g. `java.lang.reflect.Field`) in the array.
+    fn member_names(members: Option<Value>) -> Result<Vec<String>> {
+        let Some(Value::Object(Some(Reference::Array(_, members)))) = members else {
+            panic!("expected member array");
+        };
+        let mut names = Vec::new();
+        for member in members.to_vec()? {
+            let Some(Reference::Object(member)) = member else {
+                panic!("expected member object");
+            };
+            let name: String = member.value("name")?.try_into()?;
+            names.push(name);
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    #[tokio::test]
+    async fn test_get_declared_fields_0() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class("java/util/AbstractMap$SimpleEntry").await?;
+        let mut arguments = Arguments::default();
+        arguments.push(class.to_object(&vm).await?);
+        arguments.push_int(0);
+        let result = get_declared_fields_0(thread.clone(), arguments).await?;
+        assert_eq!(
+            vec!["key", "serialVersionUID", "value"],
+            member_names(result)?
+        );
+
+        let mut arguments = Arguments::default();
+        arguments.push(class.to_object(&vm).await?);
+        arguments.push_int(1);
+        let result = get_declared_fields_0(thread, arguments).await?;
+        assert!(member_names(result)?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_declared_methods_0() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class("java/util/AbstractMap$SimpleEntry").await?;
+        let mut arguments = Arguments::default();
+        arguments.push(class.to_object(&vm).await?);
+        arguments.push_int(1);
+        let result = get_declared_methods_0(thread.clone(), arguments).await?;
+        assert_eq!(
+            vec!["equals", "getKey", "getValue", "hashCode", "setValue", "toString"],
+            member_names(result)?
+        );
+
+        let class = thread.class("java/lang/Object").await?;
+        let mut arguments = Arguments::default();
+        arguments.push(class.to_object(&vm).await?);
+        arguments.push_int(0);
+        let result = get_declared_methods_0(thread, arguments).await?;
+        let names = member_names(result)?;
+        assert!(names.contains(&"clone".to_string()));
+        assert!(!names.contains(&"<init>".to_string()));
+        Ok(())
+    }
+
+    async fn get_name(class_name: &str) -> Result<(Reference, Reference)> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class(class_name).await?;
+        let class_object = class.to_object(&vm).await?;
+        let mut names = Vec::new();
+        for _ in 0..2 {
+            let name = vm
+                .try_invoke(
+                    "java/lang/Class",
+                    "getName",
+                    "()Ljava/lang/String;",
+                    vec![class_object.clone()],
+                )
+                .await?;
+            let Value::Object(Some(name)) = name else {
+                panic!("expected name");
+            };
+            names.push(name);
+        }
+        let second = names.pop().expect("second name");
+        let first = names.pop().expect("first name");
+        Ok((first, second))
+    }
+
+    #[tokio::test]
+    async fn test_get_name() -> Result<()> {
+        let (first, second) = get_name("java/lang/String").await?;
+        // The second call reads the cached name instead of creating a new string
+        assert!(first.ptr_eq(&second));
+        let name: String = Value::Object(Some(first)).try_into()?;
+        assert_eq!("java.lang.String", name);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_name_array_and_primitive() -> Result<()> {
+        for (class_name, expected) in [
+            ("[Ljava/lang/String;", "[Ljava.lang.String;"),
+            ("[[I", "[[I"),
+            ("int", "int"),
+        ] {
+            let (first, second) = get_name(class_name).await?;
+            assert!(first.ptr_eq(&second));
+            let name: String = Value::Object(Some(first)).try_into()?;
+            assert_eq!(expected, name);
+        }
+        Ok(())
+    }
+
+    async fn for_name(class_name: &str, initialize: bool) -> Result<Arc<Class>> {
+        // Use an isolated VM so that the initialization state is not changed by other tests
+        let vm = crate::test::isolated_vm(Arc::new(StdHostIo)).await?;
+        let thread = vm.new_thread()?;
+        let mut arguments = Arguments::default();
+        arguments.push(class_name.to_object(&vm).await?);
+        arguments.push_int(i32::from(initialize));
+        arguments.push_reference(None);
+        arguments.push_reference(None);
+        let result = for_name_0(thread.clone(), arguments).await?;
+        let Some(Value::Object(Some(Reference::Object(class_object)))) = result else {
+            panic!("expected class");
+        };
+        // Load the class without initializing it so that the initialization state is unchanged
+        let class_name = class_object_name(&class_object)?;
+        thread.load_class(class_name).await
+    }
+
+    #[tokio::test]
+    async fn test_for_name_0() -> Result<()> {
+        let class = for_name("StaticInitializer", true).await?;
+        assert_eq!("StaticInitializer", class.name());
+        assert_eq!(
+            InitializationState::Initialized,
+            class.initialization_state()?
+        );
+        assert_eq!(Value::Int(21), class.static_field("value")?.value()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_for_name_0_binary_name() -> Result<()> {
+        let class = for_name("java.lang.String", true).await?;
+        assert_eq!("java/lang/String", class.name());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_for_name_0_no_initialize() -> Result<()> {
+        let class = for_name("StaticInitializer", false).await?;
+        assert_eq!(
+            InitializationState::NotInitialized,
+            class.initialization_state()?
+        );
+        assert_eq!(Value::Int(0), class.static_field("value")?.value()?);
+        Ok(())
+    }
+}
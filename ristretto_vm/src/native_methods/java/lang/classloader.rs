@@ -6,6 +6,7 @@ use crate::JavaError::{ClassFormatError, IndexOutOfBoundsException, NoClassDefFo
 use crate::{Result, VM};
 use async_recursion::async_recursion;
 use ristretto_classfile::{ClassFile, Version};
+use ristretto_classloader::Error::MalformedDescriptor;
 use ristretto_classloader::{Class, Object, Reference, Value};
 use std::io::Cursor;
 use std::sync::Arc;
@@ -111,7 +112,13 @@ async fn class_object_from_bytes(
         // TODO: implement setting the source file
     }
 
-    let class = Arc::new(Class::from(class_file)?);
+    let class = match Class::from(class_file) {
+        Ok(class) => Arc::new(class),
+        Err(error @ MalformedDescriptor { .. }) => {
+            return Err(ClassFormatError(error.to_string()).into());
+        }
+        Err(error) => return Err(error.into()),
+    };
     let class = class.to_object(vm).await?;
     let class: Object = class.try_into()?;
     Ok(class)
@@ -40,3 +40,60 @@ async fn long_bits_to_double(
     let double = f64::from_bits(bits);
     Ok(Some(Value::Double(double)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn to_bits(value: f64) -> Result<i64> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push(Value::Double(value));
+        let result = double_to_raw_long_bits(thread, arguments).await?;
+        let Some(Value::Long(bits)) = result else {
+            panic!("expected long");
+        };
+        Ok(bits)
+    }
+
+    async fn from_bits(bits: i64) -> Result<f64> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push(Value::Long(bits));
+        let result = long_bits_to_double(thread, arguments).await?;
+        let Some(Value::Double(value)) = result else {
+            panic!("expected double");
+        };
+        Ok(value)
+    }
+
+    #[tokio::test]
+    async fn test_double_to_raw_long_bits_zero() -> Result<()> {
+        assert_eq!(0, to_bits(0.0).await?);
+        assert_eq!(i64::MIN, to_bits(-0.0).await?);
+        assert!(from_bits(i64::MIN).await?.is_sign_negative());
+        assert!(from_bits(0).await?.is_sign_positive());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_double_to_raw_long_bits_infinity() -> Result<()> {
+        let bits = to_bits(f64::INFINITY).await?;
+        assert_eq!(0x7ff0_0000_0000_0000, bits);
+        let value = from_bits(bits).await?;
+        assert!(value.is_infinite() && value.is_sign_positive());
+        let bits = to_bits(f64::NEG_INFINITY).await?;
+        let value = from_bits(bits).await?;
+        assert!(value.is_infinite() && value.is_sign_negative());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_double_to_raw_long_bits_signaling_nan() -> Result<()> {
+        let signaling_nan_bits = 0x7ff0_0000_0000_0001;
+        let signaling_nan = from_bits(signaling_nan_bits).await?;
+        assert!(signaling_nan.is_nan());
+        assert_eq!(signaling_nan_bits, to_bits(signaling_nan).await?);
+        Ok(())
+    }
+}
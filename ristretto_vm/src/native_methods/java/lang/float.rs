@@ -39,3 +39,60 @@ async fn int_bits_to_float(
     let float = f32::from_bits(integer as u32);
     Ok(Some(Value::Float(float)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn to_bits(value: f32) -> Result<i32> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push(Value::Float(value));
+        let result = float_to_raw_int_bits(thread, arguments).await?;
+        let Some(Value::Int(bits)) = result else {
+            panic!("expected int");
+        };
+        Ok(bits)
+    }
+
+    async fn from_bits(bits: i32) -> Result<f32> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push(Value::Int(bits));
+        let result = int_bits_to_float(thread, arguments).await?;
+        let Some(Value::Float(value)) = result else {
+            panic!("expected float");
+        };
+        Ok(value)
+    }
+
+    #[tokio::test]
+    async fn test_float_to_raw_int_bits_zero() -> Result<()> {
+        assert_eq!(0, to_bits(0.0).await?);
+        assert_eq!(i32::MIN, to_bits(-0.0).await?);
+        assert!(from_bits(i32::MIN).await?.is_sign_negative());
+        assert!(from_bits(0).await?.is_sign_positive());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_float_to_raw_int_bits_infinity() -> Result<()> {
+        let bits = to_bits(f32::INFINITY).await?;
+        assert_eq!(0x7f80_0000, bits);
+        let value = from_bits(bits).await?;
+        assert!(value.is_infinite() && value.is_sign_positive());
+        let bits = to_bits(f32::NEG_INFINITY).await?;
+        let value = from_bits(bits).await?;
+        assert!(value.is_infinite() && value.is_sign_negative());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_float_to_raw_int_bits_signaling_nan() -> Result<()> {
+        let signaling_nan_bits = 0x7f80_0001;
+        let signaling_nan = from_bits(signaling_nan_bits).await?;
+        assert!(signaling_nan.is_nan());
+        assert_eq!(signaling_nan_bits, to_bits(signaling_nan).await?);
+        Ok(())
+    }
+}
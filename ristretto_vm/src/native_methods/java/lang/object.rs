@@ -1,14 +1,16 @@
 use crate::arguments::Arguments;
+use crate::interruptible_wait::{interrupted_exception, InterruptibleWait, WaitOutcome};
 use crate::java_object::JavaObject;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
 use crate::Error::InternalError;
+use crate::JavaError::{CloneNotSupportedException, IllegalArgumentException};
 use crate::Result;
 use async_recursion::async_recursion;
 use ristretto_classfile::Version;
-use ristretto_classloader::{Reference, Value};
-use std::hash::{DefaultHasher, Hash, Hasher};
+use ristretto_classloader::{ConcurrentVec, Object, Reference, Value};
 use std::sync::Arc;
+use std::time::Duration;
 
 const JAVA_11: Version = Version::Java11 { minor: 0 };
 const JAVA_18: Version = Version::Java18 { minor: 0 };
@@ -41,11 +43,43 @@ async fn init(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Valu
     Ok(None)
 }
 
+/// Create a shallow copy of an object or array; the copy has the same class and its fields or
+/// elements are the values of the original, which are not themselves copied.
 #[async_recursion(?Send)]
-async fn clone(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
-    let object = arguments.pop_reference()?;
-    let cloned_object = object.clone();
-    Ok(Some(Value::Object(cloned_object)))
+async fn clone(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let Some(reference) = arguments.pop_reference()? else {
+        return Err(InternalError("no object reference defined".to_string()));
+    };
+    let cloned_reference = match reference {
+        Reference::ByteArray(array) => Reference::ByteArray(ConcurrentVec::from(array.to_vec()?)),
+        Reference::CharArray(array) => Reference::CharArray(ConcurrentVec::from(array.to_vec()?)),
+        Reference::ShortArray(array) => Reference::ShortArray(ConcurrentVec::from(array.to_vec()?)),
+        Reference::IntArray(array) => Reference::IntArray(ConcurrentVec::from(array.to_vec()?)),
+        Reference::LongArray(array) => Reference::LongArray(ConcurrentVec::from(array.to_vec()?)),
+        Reference::FloatArray(array) => Reference::FloatArray(ConcurrentVec::from(array.to_vec()?)),
+        Reference::DoubleArray(array) => {
+            Reference::DoubleArray(ConcurrentVec::from(array.to_vec()?))
+        }
+        Reference::Array(class, array) => {
+            Reference::Array(class, ConcurrentVec::from(array.to_vec()?))
+        }
+        Reference::Object(object) => {
+            let class = object.class();
+            let cloneable = thread.class("java/lang/Cloneable").await?;
+            if !cloneable.is_assignable_from(class)? {
+                let class_name = class.name().replace('/', ".");
+                return Err(CloneNotSupportedException(class_name).into());
+            }
+            let cloned_object = Object::new(class.clone())?;
+            for field in object.fields() {
+                cloned_object
+                    .field(field.name())?
+                    .unsafe_set_value(field.value()?)?;
+            }
+            Reference::from(cloned_object)
+        }
+    };
+    Ok(Some(Value::from(cloned_reference)))
 }
 
 #[async_recursion(?Send)]
@@ -66,27 +100,21 @@ async fn hash_code(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Opt
     let Some(object) = arguments.pop_reference()? else {
         return Err(InternalError("no object reference defined".to_string()));
     };
-    let hash_code = object_hash_code(&object);
+    let hash_code = object.identity_hash_code();
     Ok(Some(Value::Int(hash_code)))
 }
 
-pub(crate) fn object_hash_code(object: &Reference) -> i32 {
-    let value = format!("{object}");
-    let mut hasher = DefaultHasher::new();
-    value.hash(&mut hasher);
-    let hash_code = hasher.finish();
-    #[expect(clippy::cast_possible_truncation)]
-    let hash_code = hash_code as i32;
-    hash_code
-}
-
 #[async_recursion(?Send)]
-async fn notify(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.Object.notify()V")
+async fn notify(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    // Object monitors are not implemented, so a single waiting thread cannot be selected; waking
+    // all waiting threads is a permitted spurious wakeup for the others.
+    notify_all(thread, arguments).await
 }
 
 #[async_recursion(?Send)]
-async fn notify_all(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
+async fn notify_all(thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
+    let vm = thread.vm()?;
+    vm.monitor_notify().notify_waiters();
     Ok(None)
 }
 
@@ -96,11 +124,152 @@ async fn register_natives(_thread: Arc<Thread>, _arguments: Arguments) -> Result
 }
 
 #[async_recursion(?Send)]
-async fn wait(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.Object.wait(J)V")
+async fn wait(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let timeout = arguments.pop_long()?;
+    if arguments.pop_reference()?.is_none() {
+        return Err(InternalError("no object reference defined".to_string()));
+    }
+    let Ok(timeout) = u64::try_from(timeout) else {
+        return Err(IllegalArgumentException("timeout value is negative".to_string()).into());
+    };
+
+    let vm = thread.vm()?;
+    let mut wait = InterruptibleWait::new(&thread).notify(vm.monitor_notify());
+    if timeout > 0 {
+        wait = wait.timeout(Duration::from_millis(timeout));
+    }
+    if wait.wait().await == WaitOutcome::Interrupted {
+        return interrupted_exception(&thread, "wait interrupted").await;
+    }
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
-async fn wait_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.Object.wait0(J)V")
+async fn wait_0(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    wait(thread, arguments).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error::JavaError;
+    use crate::JavaError::InterruptedException;
+
+    async fn clone_reference(thread: &Arc<Thread>, reference: Reference) -> Result<Reference> {
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(reference));
+        let Some(Value::Object(Some(reference))) = clone(thread.clone(), arguments).await? else {
+            panic!("expected reference");
+        };
+        Ok(reference)
+    }
+
+    #[tokio::test]
+    async fn test_clone_object() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class("java/util/ArrayList").await?;
+        let object = Object::new(class)?;
+        let element_data = Reference::from(vec![1i32, 2, 3]);
+        object.set_value("elementData", Value::Object(Some(element_data.clone())))?;
+        object.set_value("size", Value::Int(3))?;
+
+        let Reference::Object(cloned_object) =
+            clone_reference(&thread, Reference::from(object.clone())).await?
+        else {
+            panic!("expected object");
+        };
+        assert!(!cloned_object.ptr_eq(&object));
+        assert_eq!("java/util/ArrayList", cloned_object.class().name());
+        assert_eq!(Value::Int(3), cloned_object.value("size")?);
+        // The copy is shallow; the fields of the clone refer to the same objects
+        let Value::Object(Some(cloned_element_data)) = cloned_object.value("elementData")? else {
+            panic!("expected elementData");
+        };
+        assert_eq!(
+            element_data.identity_hash_code(),
+            cloned_element_data.identity_hash_code()
+        );
+
+        cloned_object.set_value("size", Value::Int(0))?;
+        assert_eq!(Value::Int(3), object.value("size")?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_clone_not_cloneable() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class("java/lang/Object").await?;
+        let object = Reference::from(Object::new(class)?);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(object));
+        let result = clone(thread, arguments).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(CloneNotSupportedException(class_name))) if class_name == "java.lang.Object"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_clone_array() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let array = Reference::from(vec![1i32, 2, 3]);
+        let cloned_array = clone_reference(&thread, array.clone()).await?;
+        assert_eq!(vec![1i32, 2, 3], cloned_array.to_int_vec()?);
+        assert_ne!(
+            array.identity_hash_code(),
+            cloned_array.identity_hash_code()
+        );
+        Ok(())
+    }
+
+    fn wait_arguments(timeout: i64) -> Arguments {
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(Reference::from(vec![0i32])));
+        arguments.push_long(timeout);
+        arguments
+    }
+
+    #[tokio::test]
+    async fn test_wait_timeout() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let result = wait_0(thread, wait_arguments(1)).await?;
+        assert_eq!(None, result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_negative_timeout() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let result = wait_0(thread, wait_arguments(-1)).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(IllegalArgumentException(_)))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_interrupted() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        thread.interrupt();
+        let result = wait_0(thread.clone(), wait_arguments(0)).await;
+        assert!(matches!(result, Err(JavaError(InterruptedException(_)))));
+        assert!(!thread.is_interrupted());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_notify() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let wait = wait_0(thread.clone(), wait_arguments(0));
+        let notify = async {
+            tokio::task::yield_now().await;
+            notify_all(thread.clone(), Arguments::default()).await
+        };
+        let (wait, notify) = tokio::join!(wait, notify);
+        assert_eq!(None, wait?);
+        assert_eq!(None, notify?);
+        Ok(())
+    }
 }
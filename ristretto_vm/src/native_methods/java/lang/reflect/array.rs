@@ -1,8 +1,14 @@
 use crate::arguments::Arguments;
+use crate::java_object::{class_object_name, BoxedValue};
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
+use crate::JavaError::{
+    ArrayIndexOutOfBoundsException, IllegalArgumentException, NegativeArraySizeException,
+    NullPointerException,
+};
 use crate::Result;
 use async_recursion::async_recursion;
+use ristretto_classfile::BaseType;
 use ristretto_classloader::{ConcurrentVec, Object, Reference, Value};
 use std::sync::Arc;
 
@@ -74,58 +80,262 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 fn get_class_name(value: Value) -> Result<String> {
     let component_type: Object = value.try_into()?;
-    let class_name: String = component_type.value("name")?.try_into()?;
-    Ok(class_name)
+    class_object_name(&component_type)
+}
+
+/// Get the array argument of a reflective array operation.
+///
+/// # Errors
+/// `NullPointerException` if the array is null or `IllegalArgumentException` if the argument is
+/// not an array.
+fn get_array(value: Option<Reference>) -> Result<Reference> {
+    match value {
+        None => Err(NullPointerException("array cannot be null".to_string()).into()),
+        Some(Reference::Object(_)) => {
+            Err(IllegalArgumentException("Argument is not an array".to_string()).into())
+        }
+        Some(array) => Ok(array),
+    }
+}
+
+/// Get the element type of a primitive array, or `None` for an array of references.  Boolean
+/// arrays are stored as byte arrays, so the element type of a byte array is reported as `byte`.
+fn element_type(array: &Reference) -> Option<BaseType> {
+    match array {
+        Reference::ByteArray(_) => Some(BaseType::Byte),
+        Reference::CharArray(_) => Some(BaseType::Char),
+        Reference::ShortArray(_) => Some(BaseType::Short),
+        Reference::IntArray(_) => Some(BaseType::Int),
+        Reference::LongArray(_) => Some(BaseType::Long),
+        Reference::FloatArray(_) => Some(BaseType::Float),
+        Reference::DoubleArray(_) => Some(BaseType::Double),
+        Reference::Array(..) | Reference::Object(_) => None,
+    }
+}
+
+/// Get the number of elements in the array.
+fn array_length(array: &Reference) -> Result<usize> {
+    let length = match array {
+        Reference::ByteArray(array) => array.len()?,
+        Reference::CharArray(array) => array.len()?,
+        Reference::ShortArray(array) => array.len()?,
+        Reference::IntArray(array) => array.len()?,
+        Reference::LongArray(array) => array.len()?,
+        Reference::FloatArray(array) => array.len()?,
+        Reference::DoubleArray(array) => array.len()?,
+        Reference::Array(_, array) => array.len()?,
+        Reference::Object(_) => {
+            return Err(IllegalArgumentException("Argument is not an array".to_string()).into())
+        }
+    };
+    Ok(length)
+}
+
+/// Check that the index is within the bounds of the array.
+///
+/// # Errors
+/// `ArrayIndexOutOfBoundsException` if the index is not within the bounds of the array.
+fn check_index(array: &Reference, index: i32) -> Result<usize> {
+    let length = array_length(array)?;
+    if index < 0 || usize::try_from(index)? >= length {
+        return Err(ArrayIndexOutOfBoundsException { index, length }.into());
+    }
+    Ok(usize::try_from(index)?)
+}
+
+/// Get an element of the array as it would be held on the operand stack.
+fn get_element(array: &Reference, index: i32) -> Result<Value> {
+    let index = check_index(array, index)?;
+    let value = match array {
+        Reference::ByteArray(array) => array.get(index)?.map(|value| Value::Int(value.into())),
+        Reference::CharArray(array) => array.get(index)?.map(|value| Value::Int(value.into())),
+        Reference::ShortArray(array) => array.get(index)?.map(|value| Value::Int(value.into())),
+        Reference::IntArray(array) => array.get(index)?.map(Value::Int),
+        Reference::LongArray(array) => array.get(index)?.map(Value::Long),
+        Reference::FloatArray(array) => array.get(index)?.map(Value::Float),
+        Reference::DoubleArray(array) => array.get(index)?.map(Value::Double),
+        Reference::Array(_, array) => array.get(index)?.map(Value::Object),
+        Reference::Object(_) => None,
+    };
+    let Some(value) = value else {
+        let length = array_length(array)?;
+        return Err(ArrayIndexOutOfBoundsException {
+            index: i32::try_from(index)?,
+            length,
+        }
+        .into());
+    };
+    Ok(value)
+}
+
+/// Set an element of a primitive array to a value that has already been converted to the element
+/// type of the array.
+#[expect(clippy::cast_possible_truncation)]
+#[expect(clippy::cast_sign_loss)]
+fn set_primitive_element(array: &Reference, index: i32, value: &Value) -> Result<()> {
+    let index = check_index(array, index)?;
+    match array {
+        Reference::ByteArray(array) => {
+            array.set(index, value.to_int()? as i8)?;
+        }
+        Reference::CharArray(array) => {
+            array.set(index, value.to_int()? as u16)?;
+        }
+        Reference::ShortArray(array) => {
+            array.set(index, value.to_int()? as i16)?;
+        }
+        Reference::IntArray(array) => {
+            array.set(index, value.to_int()?)?;
+        }
+        Reference::LongArray(array) => {
+            array.set(index, value.to_long()?)?;
+        }
+        Reference::FloatArray(array) => {
+            array.set(index, value.to_float()?)?;
+        }
+        Reference::DoubleArray(array) => {
+            array.set(index, value.to_double()?)?;
+        }
+        Reference::Array(..) | Reference::Object(_) => {
+            return Err(IllegalArgumentException("argument type mismatch".to_string()).into());
+        }
+    }
+    Ok(())
+}
+
+/// Convert a primitive value from one type to another using an identity or widening primitive
+/// conversion, as the reflective array operations do.  Boolean arrays are stored as byte arrays,
+/// so conversions between `boolean` and `byte` are also permitted.
+///
+/// See: <https://docs.oracle.com/javase/specs/jls/se23/html/jls-5.html#jls-5.1.2>
+///
+/// # Errors
+/// `IllegalArgumentException` if the value cannot be converted to the type.
+#[expect(clippy::cast_precision_loss)]
+fn widen(value: &Value, from: &BaseType, to: &BaseType) -> Result<Value> {
+    let value = match (from, to) {
+        _ if from == to => value.clone(),
+        (BaseType::Boolean, BaseType::Byte)
+        | (BaseType::Byte, BaseType::Short)
+        | (BaseType::Byte | BaseType::Short | BaseType::Char, BaseType::Int) => value.clone(),
+        (BaseType::Byte, BaseType::Boolean) => Value::Int(i32::from(value.to_int()? != 0)),
+        (BaseType::Byte | BaseType::Short | BaseType::Char | BaseType::Int, BaseType::Long) => {
+            Value::Long(i64::from(value.to_int()?))
+        }
+        (BaseType::Byte | BaseType::Short | BaseType::Char | BaseType::Int, BaseType::Float) => {
+            Value::Float(value.to_int()? as f32)
+        }
+        (BaseType::Long, BaseType::Float) => Value::Float(value.to_long()? as f32),
+        (BaseType::Byte | BaseType::Short | BaseType::Char | BaseType::Int, BaseType::Double) => {
+            Value::Double(f64::from(value.to_int()?))
+        }
+        (BaseType::Long, BaseType::Double) => Value::Double(value.to_long()? as f64),
+        (BaseType::Float, BaseType::Double) => Value::Double(f64::from(value.to_float()?)),
+        _ => return Err(IllegalArgumentException("argument type mismatch".to_string()).into()),
+    };
+    Ok(value)
+}
+
+/// Unbox a primitive wrapper object (e.g. `java.lang.Integer`) into its primitive type and value.
+///
+/// # Errors
+/// `IllegalArgumentException` if the value is null or not a primitive wrapper object.
+fn unbox(value: Option<Reference>) -> Result<(BaseType, Value)> {
+    let Some(unboxed) = Value::Object(value).unboxed()? else {
+        return Err(IllegalArgumentException("argument type mismatch".to_string()).into());
+    };
+    Ok(unboxed)
+}
+
+/// Get an element of a primitive array converted to the given type.
+fn get_primitive(mut arguments: Arguments, base_type: &BaseType) -> Result<Option<Value>> {
+    let index = arguments.pop_int()?;
+    let array = get_array(arguments.pop_reference()?)?;
+    let Some(element_type) = element_type(&array) else {
+        return Err(IllegalArgumentException(
+            "Argument is not an array of primitive type".to_string(),
+        )
+        .into());
+    };
+    let value = get_element(&array, index)?;
+    let value = widen(&value, &element_type, base_type)?;
+    Ok(Some(value))
+}
+
+/// Set an element of a primitive array to a value of the given type.
+fn set_primitive(
+    array: Option<Reference>,
+    index: i32,
+    base_type: &BaseType,
+    value: &Value,
+) -> Result<Option<Value>> {
+    let array = get_array(array)?;
+    let Some(element_type) = element_type(&array) else {
+        return Err(IllegalArgumentException("argument type mismatch".to_string()).into());
+    };
+    let value = widen(value, base_type, &element_type)?;
+    set_primitive_element(&array, index, &value)?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
-async fn get(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.get(Ljava/lang/Object;I)Ljava/lang/Object;")
+async fn get(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let index = arguments.pop_int()?;
+    let array = get_array(arguments.pop_reference()?)?;
+    let value = get_element(&array, index)?;
+    let Some(element_type) = element_type(&array) else {
+        return Ok(Some(value));
+    };
+    let vm = thread.vm()?;
+    let value = Value::boxed(&vm, element_type, &value).await?;
+    Ok(Some(value))
 }
 
 #[async_recursion(?Send)]
-async fn get_boolean(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getBoolean(Ljava/lang/Object;I)Z")
+async fn get_boolean(_thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    get_primitive(arguments, &BaseType::Boolean)
 }
 
 #[async_recursion(?Send)]
-async fn get_byte(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getByte(Ljava/lang/Object;I)B")
+async fn get_byte(_thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    get_primitive(arguments, &BaseType::Byte)
 }
 
 #[async_recursion(?Send)]
-async fn get_char(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getChar(Ljava/lang/Object;I)C")
+async fn get_char(_thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    get_primitive(arguments, &BaseType::Char)
 }
 
 #[async_recursion(?Send)]
-async fn get_double(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getDouble(Ljava/lang/Object;I)D")
+async fn get_double(_thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    get_primitive(arguments, &BaseType::Double)
 }
 
 #[async_recursion(?Send)]
-async fn get_float(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getFloat(Ljava/lang/Object;I)F")
+async fn get_float(_thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    get_primitive(arguments, &BaseType::Float)
 }
 
 #[async_recursion(?Send)]
-async fn get_int(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getInt(Ljava/lang/Object;I)I")
+async fn get_int(_thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    get_primitive(arguments, &BaseType::Int)
 }
 
 #[async_recursion(?Send)]
-async fn get_length(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getLength(Ljava/lang/Object;)I")
+async fn get_length(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let array = get_array(arguments.pop_reference()?)?;
+    let length = i32::try_from(array_length(&array)?)?;
+    Ok(Some(Value::Int(length)))
 }
 
 #[async_recursion(?Send)]
-async fn get_long(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getLong(Ljava/lang/Object;I)J")
+async fn get_long(_thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    get_primitive(arguments, &BaseType::Long)
 }
 
 #[async_recursion(?Send)]
-async fn get_short(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.getShort(Ljava/lang/Object;I)S")
+async fn get_short(_thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    get_primitive(arguments, &BaseType::Short)
 }
 
 #[async_recursion(?Send)]
@@ -135,8 +345,11 @@ async fn multi_new_array(_thread: Arc<Thread>, _arguments: Arguments) -> Result<
 
 #[async_recursion(?Send)]
 async fn new_array(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
-    let length = usize::try_from(arguments.pop_int()?)?;
+    let length = arguments.pop_int()?;
     let class_name = get_class_name(arguments.pop()?)?;
+    let Ok(length) = usize::try_from(length) else {
+        return Err(NegativeArraySizeException(length.to_string()).into());
+    };
 
     let array = match class_name.as_str() {
         "boolean" | "byte" => Reference::from(vec![0i8; length]),
@@ -146,10 +359,15 @@ async fn new_array(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Opti
         "int" => Reference::from(vec![0i32; length]),
         "long" => Reference::from(vec![0i64; length]),
         "short" => Reference::from(vec![0i16; length]),
+        "void" => return Err(IllegalArgumentException(class_name).into()),
         _ => {
-            let class_name = format!("[L{class_name};");
+            let class_name = if class_name.starts_with('[') {
+                format!("[{class_name}")
+            } else {
+                format!("[L{class_name};")
+            };
             let class = thread.class(&class_name).await?;
-            Reference::Array(class, ConcurrentVec::with_capacity(length))
+            Reference::Array(class, ConcurrentVec::from(vec![None; length]))
         }
     };
 
@@ -158,46 +376,259 @@ async fn new_array(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Opti
 }
 
 #[async_recursion(?Send)]
-async fn set(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.set(Ljava/lang/Object;ILjava/lang/Object;)V")
+async fn set(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let value = arguments.pop_reference()?;
+    let index = arguments.pop_int()?;
+    let array = get_array(arguments.pop_reference()?)?;
+    let Reference::Array(ref class, ref elements) = array else {
+        let (base_type, value) = unbox(value)?;
+        return set_primitive(Some(array), index, &base_type, &value);
+    };
+
+    let index = check_index(&array, index)?;
+    if let Some(ref reference) = value {
+        let component_name = class.name().strip_prefix('[').unwrap_or_default();
+        let component_name = component_name
+            .strip_prefix('L')
+            .and_then(|name| name.strip_suffix(';'))
+            .unwrap_or(component_name);
+        let component_class = thread.load_class(component_name).await?;
+        if !component_class.is_assignable_from(&reference.class()?)? {
+            return Err(IllegalArgumentException("array element type mismatch".to_string()).into());
+        }
+    }
+    elements.set(index, value)?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
-async fn set_boolean(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setBoolean(Ljava/lang/Object;IZ)V")
+async fn set_boolean(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let value = arguments.pop()?;
+    let index = arguments.pop_int()?;
+    set_primitive(
+        arguments.pop_reference()?,
+        index,
+        &BaseType::Boolean,
+        &value,
+    )
 }
 
 #[async_recursion(?Send)]
-async fn set_byte(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setByte(Ljava/lang/Object;IB)V")
+async fn set_byte(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let value = arguments.pop()?;
+    let index = arguments.pop_int()?;
+    set_primitive(arguments.pop_reference()?, index, &BaseType::Byte, &value)
 }
 
 #[async_recursion(?Send)]
-async fn set_char(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setChar(Ljava/lang/Object;IC)V")
+async fn set_char(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let value = arguments.pop()?;
+    let index = arguments.pop_int()?;
+    set_primitive(arguments.pop_reference()?, index, &BaseType::Char, &value)
 }
 
 #[async_recursion(?Send)]
-async fn set_double(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setDouble(Ljava/lang/Object;ID)V")
+async fn set_double(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let value = arguments.pop()?;
+    let index = arguments.pop_int()?;
+    set_primitive(arguments.pop_reference()?, index, &BaseType::Double, &value)
 }
 
 #[async_recursion(?Send)]
-async fn set_float(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setFloat(Ljava/lang/Object;IF)V")
+async fn set_float(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let value = arguments.pop()?;
+    let index = arguments.pop_int()?;
+    set_primitive(arguments.pop_reference()?, index, &BaseType::Float, &value)
 }
 
 #[async_recursion(?Send)]
-async fn set_int(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setInt(Ljava/lang/Object;II)V")
+async fn set_int(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let value = arguments.pop()?;
+    let index = arguments.pop_int()?;
+    set_primitive(arguments.pop_reference()?, index, &BaseType::Int, &value)
 }
 
 #[async_recursion(?Send)]
-async fn set_long(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setLong(Ljava/lang/Object;IJ)V")
+async fn set_long(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let value = arguments.pop()?;
+    let index = arguments.pop_int()?;
+    set_primitive(arguments.pop_reference()?, index, &BaseType::Long, &value)
 }
 
 #[async_recursion(?Send)]
-async fn set_short(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.reflect.Array.setShort(Ljava/lang/Object;IS)V")
+async fn set_short(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let value = arguments.pop()?;
+    let index = arguments.pop_int()?;
+    set_primitive(arguments.pop_reference()?, index, &BaseType::Short, &value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+    use crate::Error::JavaError;
+
+    async fn object_array(thread: &Arc<Thread>, length: i32) -> Result<Reference> {
+        let class = thread.class("java/lang/Object").await?;
+        let vm = thread.vm()?;
+        let mut arguments = Arguments::default();
+        arguments.push(class.to_object(&vm).await?);
+        arguments.push_int(length);
+        let Some(Value::Object(Some(array))) = new_array(thread.clone(), arguments).await? else {
+            panic!("expected array");
+        };
+        Ok(array)
+    }
+
+    #[tokio::test]
+    async fn test_new_array() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let array = object_array(&thread, 3).await?;
+        assert_eq!("[Ljava/lang/Object;", array.class_name());
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array));
+        let length = get_length(thread, arguments).await?;
+        assert_eq!(Some(Value::Int(3)), length);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_get_object_array() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        let class = vm.class("java/lang/Object").await?;
+        let class_object = class.to_object(&vm).await?;
+        let array = vm
+            .try_invoke(
+                "java.lang.reflect.Array",
+                "newInstance",
+                "(Ljava/lang/Class;I)Ljava/lang/Object;",
+                vec![class_object, Value::Int(2)],
+            )
+            .await?;
+        let value = "foo".to_object(&vm).await?;
+        vm.invoke(
+            "java.lang.reflect.Array",
+            "set",
+            "(Ljava/lang/Object;ILjava/lang/Object;)V",
+            vec![array.clone(), Value::Int(1), value.clone()],
+        )
+        .await?;
+        let result = vm
+            .try_invoke(
+                "java.lang.reflect.Array",
+                "get",
+                "(Ljava/lang/Object;I)Ljava/lang/Object;",
+                vec![array.clone(), Value::Int(1)],
+            )
+            .await?;
+        assert_eq!(value, result);
+        let result = vm
+            .try_invoke(
+                "java.lang.reflect.Array",
+                "get",
+                "(Ljava/lang/Object;I)Ljava/lang/Object;",
+                vec![array, Value::Int(0)],
+            )
+            .await?;
+        assert_eq!(Value::Object(None), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_index_out_of_bounds() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let array = object_array(&thread, 1).await?;
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array));
+        arguments.push_int(1);
+        let result = get(thread, arguments).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(ArrayIndexOutOfBoundsException {
+                index: 1,
+                length: 1
+            }))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_negative_index() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let array = object_array(&thread, 1).await?;
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array));
+        arguments.push_int(-1);
+        let result = get(thread, arguments).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(ArrayIndexOutOfBoundsException {
+                index: -1,
+                length: 1
+            }))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_get_boxed_primitive() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let array = Reference::from(vec![0i64; 2]);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array.clone()));
+        arguments.push_int(1);
+        arguments.push(42i32.to_object(&vm).await?);
+        set(thread.clone(), arguments).await?;
+        assert_eq!(vec![0i64, 42], array.to_long_vec()?);
+
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array.clone()));
+        arguments.push_int(1);
+        let Some(value) = get(thread.clone(), arguments).await? else {
+            panic!("expected value");
+        };
+        let value: Object = value.try_into()?;
+        assert_eq!("java/lang/Long", value.class().name());
+        assert_eq!(Value::Long(42), value.value("value")?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_primitive_type_mismatch() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let array = Reference::from(vec![0i32; 1]);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array));
+        arguments.push_int(0);
+        arguments.push(42i64.to_object(&vm).await?);
+        let result = set(thread, arguments).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(IllegalArgumentException(_)))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_int_widening() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let array = Reference::from(vec![1i16, 2]);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array.clone()));
+        arguments.push_int(1);
+        assert_eq!(
+            Some(Value::Int(2)),
+            get_int(thread.clone(), arguments).await?
+        );
+
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array));
+        arguments.push_int(1);
+        let result = get_byte(thread, arguments).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(IllegalArgumentException(_)))
+        ));
+        Ok(())
+    }
 }
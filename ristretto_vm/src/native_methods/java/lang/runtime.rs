@@ -4,9 +4,10 @@ use crate::thread::Thread;
 use crate::Result;
 use async_recursion::async_recursion;
 use ristretto_classloader::Value;
-use std::cmp::min;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
-use sysinfo::System;
+use std::thread::available_parallelism;
+use sysinfo::{get_current_pid, Process, ProcessRefreshKind, ProcessesToUpdate, System};
 
 /// Register all native methods for `java.lang.Runtime`.
 pub(crate) fn register(registry: &mut MethodRegistry) {
@@ -28,46 +29,88 @@ async fn available_processors(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let sys = System::new_all();
-    let cpus = sys.physical_core_count().unwrap_or(1);
-    let cpus = i32::try_from(cpus)?;
+    let cpus = available_parallelism().map_or(1, NonZeroUsize::get);
+    let cpus = i32::try_from(cpus).unwrap_or(i32::MAX).max(1);
     Ok(Some(Value::Int(cpus)))
 }
 
-#[async_recursion(?Send)]
-async fn free_memory(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    let sys = System::new_all();
-    let free_memory = sys.total_memory() - sys.used_memory();
-    let free_memory = if free_memory > u64::try_from(i64::MAX)? {
-        i64::MAX
-    } else {
-        i64::try_from(free_memory)?
+/// Get the memory used by the VM process; the VM does not manage its own heap, so the memory of
+/// the process is used as an approximation of the used heap.
+fn used_memory() -> u64 {
+    let Ok(pid) = get_current_pid() else {
+        return 0;
     };
-    Ok(Some(Value::Long(free_memory)))
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing().with_memory(),
+    );
+    system.process(pid).map_or(0, Process::memory)
+}
+
+/// Convert a number of bytes to a Java long, saturating at `Long.MAX_VALUE`.
+fn to_long(bytes: u64) -> i64 {
+    i64::try_from(bytes).unwrap_or(i64::MAX)
 }
 
+/// The heap is treated as fully committed, so the total memory is the maximum memory.
 #[async_recursion(?Send)]
-async fn total_memory(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    // TODO: This is not the correct implementation; should be the total memory of the JVM
-    let sys = System::new_all();
-    let used_memory = sys.used_memory();
-    let free_memory = if used_memory > u64::try_from(i64::MAX)? {
-        i64::MAX
-    } else {
-        i64::try_from(used_memory)?
-    };
-    Ok(Some(Value::Long(free_memory)))
+async fn total_memory(thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
+    let max_memory = thread.vm()?.configuration().max_memory();
+    Ok(Some(Value::Long(to_long(max_memory))))
 }
 
 #[async_recursion(?Send)]
-async fn max_memory(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    let sys = System::new_all();
-    let total_memory = min(sys.total_memory(), u64::try_from(i64::MAX)?);
-    let total_memory = i64::try_from(total_memory)?;
-    Ok(Some(Value::Long(total_memory)))
+async fn free_memory(thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
+    let max_memory = thread.vm()?.configuration().max_memory();
+    let free_memory = max_memory.saturating_sub(used_memory());
+    Ok(Some(Value::Long(to_long(free_memory))))
+}
+
+#[async_recursion(?Send)]
+async fn max_memory(thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
+    let max_memory = thread.vm()?.configuration().max_memory();
+    Ok(Some(Value::Long(to_long(max_memory))))
 }
 
 #[async_recursion(?Send)]
 async fn gc(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
     Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConfigurationBuilder, VM};
+
+    #[tokio::test]
+    async fn test_available_processors() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let result = available_processors(thread, Arguments::default()).await?;
+        let Some(Value::Int(processors)) = result else {
+            panic!("expected int");
+        };
+        assert!(processors > 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_memory() -> Result<()> {
+        let max = 64 * 1024 * 1024 * 1024;
+        let configuration = ConfigurationBuilder::new().max_memory(max).build()?;
+        let vm = VM::new(configuration).await?;
+        let thread = vm.new_thread()?;
+
+        let result = max_memory(thread.clone(), Arguments::default()).await?;
+        assert_eq!(Some(Value::Long(to_long(max))), result);
+        let result = total_memory(thread.clone(), Arguments::default()).await?;
+        assert_eq!(Some(Value::Long(to_long(max))), result);
+        let Some(Value::Long(free)) = free_memory(thread, Arguments::default()).await? else {
+            panic!("expected long");
+        };
+        assert!(free > 0);
+        assert!(free < to_long(max));
+        Ok(())
+    }
+}
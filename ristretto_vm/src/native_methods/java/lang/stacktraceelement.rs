@@ -1,15 +1,19 @@
 use crate::arguments::Arguments;
+use crate::java_object::JavaObject;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
 use crate::Error::InternalError;
-use crate::Result;
+use crate::{Frame, Result};
 use async_recursion::async_recursion;
 use ristretto_classfile::Version;
-use ristretto_classloader::{Reference, Value};
+use ristretto_classloader::{ConcurrentVec, Object, Reference, Value};
 use std::sync::Arc;
 
 const JAVA_18: Version = Version::Java18 { minor: 0 };
 
+/// The line number used by `java.lang.StackTraceElement` to indicate a native method.
+pub(crate) const NATIVE_METHOD_LINE_NUMBER: i32 = -2;
+
 /// Register all native methods for `java.lang.StackTraceElement`.
 pub(crate) fn register(registry: &mut MethodRegistry) {
     let class_name = "java/lang/StackTraceElement";
@@ -39,6 +43,49 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     );
 }
 
+/// Create a `[Ljava/lang/StackTraceElement;` for the frames of a call stack; the frames are
+/// ordered from the bottom of the call stack to the top, and the elements are ordered from the top
+/// of the call stack (the most recent call) to the bottom.
+pub(crate) async fn stack_trace(thread: &Thread, frames: &[Arc<Frame>]) -> Result<Value> {
+    let vm = thread.vm()?;
+    let stack_element_class = thread.class("java/lang/StackTraceElement").await?;
+    let stack_elements = ConcurrentVec::with_capacity(frames.len());
+    for frame in frames.iter().rev() {
+        let class = frame.class();
+        let stack_element_object = Object::new(stack_element_class.clone())?;
+        let class_name = class.name().to_object(&vm).await?;
+        stack_element_object.set_value("declaringClass", class_name)?;
+
+        if let Some(source_file) = class.source_file() {
+            let source_file = source_file.to_object(&vm).await?;
+            stack_element_object.set_value("fileName", source_file)?;
+        }
+
+        let method = frame.method();
+        let method_name = method.name().to_object(&vm).await?;
+        stack_element_object.set_value("methodName", method_name)?;
+
+        let line_number = if method.is_native() {
+            NATIVE_METHOD_LINE_NUMBER
+        } else {
+            let program_counter = frame.program_counter();
+            i32::try_from(method.line_number(program_counter))?
+        };
+        stack_element_object.set_value("lineNumber", Value::Int(line_number))?;
+
+        stack_elements.push(Some(Reference::Object(stack_element_object)))?;
+    }
+
+    let stack_element_array_class = thread
+        .class(format!("[L{stack_element_class};").as_str())
+        .await?;
+    let stack_trace = Value::Object(Some(Reference::Array(
+        stack_element_array_class,
+        stack_elements,
+    )));
+    Ok(stack_trace)
+}
+
 #[async_recursion(?Send)]
 async fn init_stack_trace_element(
     _thread: Arc<Thread>,
@@ -13,8 +13,35 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 }
 
 #[async_recursion(?Send)]
-async fn intern(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
-    let value = arguments.pop()?;
-    // TODO: implement proper string interning
-    Ok(Some(value))
+async fn intern(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let string = arguments.pop()?;
+    let value: String = string.clone().try_into()?;
+    let vm = thread.vm()?;
+    Ok(Some(vm.intern_string(value, string)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+    use ristretto_classloader::Reference;
+
+    #[tokio::test]
+    async fn test_intern() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let string1 = "intern test".to_object(&vm).await?;
+        let string2 = "intern test".to_object(&vm).await?;
+        let interned1 = intern(thread.clone(), Arguments::new(vec![string1.clone()])).await?;
+        let interned2 = intern(thread, Arguments::new(vec![string2])).await?;
+        let Some(Value::Object(Some(interned1))) = interned1 else {
+            panic!("expected string");
+        };
+        let Some(Value::Object(Some(interned2))) = interned2 else {
+            panic!("expected string");
+        };
+        let string1: Reference = string1.try_into()?;
+        assert!(interned1.ptr_eq(&string1));
+        assert!(interned2.ptr_eq(&string1));
+        Ok(())
+    }
 }
@@ -1,18 +1,286 @@
 use crate::arguments::Arguments;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
+use crate::Error::InternalError;
+use crate::JavaError::ArrayIndexOutOfBoundsException;
 use crate::Result;
 use async_recursion::async_recursion;
-use ristretto_classloader::Value;
+use ristretto_classloader::{ConcurrentVec, Reference, Value};
 use std::sync::Arc;
 
 /// Register all native methods for `java.lang.StringUTF16`.
 pub(crate) fn register(registry: &mut MethodRegistry) {
     let class_name = "java/lang/StringUTF16";
+    registry.register(class_name, "compress", "([BI[BII)I", compress_bytes);
+    registry.register(class_name, "compress", "([CI[BII)I", compress_chars);
+    registry.register(class_name, "getChar", "([BI)C", get_char);
     registry.register(class_name, "isBigEndian", "()Z", is_big_endian);
+    registry.register(class_name, "putChar", "([BII)V", put_char);
+    registry.register(class_name, "toBytes", "([CII)[B", to_bytes);
+}
+
+/// Pop a `byte[]` argument.
+///
+/// # Errors
+/// if the argument is not a `byte[]`.
+fn pop_bytes(arguments: &mut Arguments) -> Result<ConcurrentVec<i8>> {
+    let Some(Reference::ByteArray(bytes)) = arguments.pop_reference()? else {
+        return Err(InternalError("expected a byte[] argument".to_string()));
+    };
+    Ok(bytes)
+}
+
+/// Pop a `char[]` argument.
+///
+/// # Errors
+/// if the argument is not a `char[]`.
+fn pop_chars(arguments: &mut Arguments) -> Result<ConcurrentVec<u16>> {
+    let Some(Reference::CharArray(chars)) = arguments.pop_reference()? else {
+        return Err(InternalError("expected a char[] argument".to_string()));
+    };
+    Ok(chars)
+}
+
+/// Check that the range of `length` elements starting at `offset` is within an array with
+/// `array_length` elements.
+///
+/// # Errors
+/// if the range is not within the array.
+fn check_range(offset: usize, length: usize, array_length: usize) -> Result<()> {
+    let end = offset.saturating_add(length);
+    if end > array_length {
+        return Err(ArrayIndexOutOfBoundsException {
+            index: i32::try_from(end - 1)?,
+            length: array_length,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Read the UTF-16 char at the char index of the byte array; chars are stored big-endian, as
+/// reported by `isBigEndian`.
+///
+/// # Errors
+/// if the char is not within the array.
+fn read_char(bytes: &ConcurrentVec<i8>, index: usize) -> Result<u16> {
+    let offset = index.saturating_mul(2);
+    check_range(offset, 2, bytes.len()?)?;
+    let high = bytes.get(offset)?.unwrap_or_default();
+    let low = bytes.get(offset + 1)?.unwrap_or_default();
+    let [high] = high.to_be_bytes();
+    let [low] = low.to_be_bytes();
+    Ok(u16::from_be_bytes([high, low]))
+}
+
+/// Narrow the chars to Latin-1 until the first char that cannot be represented in Latin-1;
+/// returns the number of chars that were compressed.
+///
+/// # Errors
+/// if the destination range is not within the array.
+fn compress(
+    chars: impl Iterator<Item = Result<u16>>,
+    destination: &ConcurrentVec<i8>,
+    destination_offset: usize,
+    length: usize,
+) -> Result<i32> {
+    check_range(destination_offset, length, destination.len()?)?;
+    let mut compressed = 0;
+    for (index, char) in chars.take(length).enumerate() {
+        let Ok(latin1) = u8::try_from(char?) else {
+            break;
+        };
+        destination.set(destination_offset + index, i8::from_be_bytes([latin1]))?;
+        compressed += 1;
+    }
+    Ok(compressed)
+}
+
+#[async_recursion(?Send)]
+async fn compress_bytes(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let length = usize::try_from(arguments.pop_int()?)?;
+    let destination_offset = usize::try_from(arguments.pop_int()?)?;
+    let destination = pop_bytes(&mut arguments)?;
+    let source_offset = usize::try_from(arguments.pop_int()?)?;
+    let source = pop_bytes(&mut arguments)?;
+    check_range(source_offset.saturating_mul(2), length * 2, source.len()?)?;
+    let chars = (source_offset..source_offset + length).map(|index| read_char(&source, index));
+    let compressed = compress(chars, &destination, destination_offset, length)?;
+    Ok(Some(Value::Int(compressed)))
+}
+
+#[async_recursion(?Send)]
+async fn compress_chars(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let length = usize::try_from(arguments.pop_int()?)?;
+    let destination_offset = usize::try_from(arguments.pop_int()?)?;
+    let destination = pop_bytes(&mut arguments)?;
+    let source_offset = usize::try_from(arguments.pop_int()?)?;
+    let source = pop_chars(&mut arguments)?;
+    let source = source.to_vec()?;
+    check_range(source_offset, length, source.len())?;
+    let chars = source[source_offset..source_offset + length]
+        .iter()
+        .map(|char| Ok(*char));
+    let compressed = compress(chars, &destination, destination_offset, length)?;
+    Ok(Some(Value::Int(compressed)))
+}
+
+#[async_recursion(?Send)]
+async fn get_char(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let index = usize::try_from(arguments.pop_int()?)?;
+    let bytes = pop_bytes(&mut arguments)?;
+    let char = read_char(&bytes, index)?;
+    Ok(Some(Value::Int(i32::from(char))))
 }
 
 #[async_recursion(?Send)]
 async fn is_big_endian(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
     Ok(Some(Value::from(true)))
 }
+
+#[async_recursion(?Send)]
+async fn put_char(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let char = arguments.pop_int()?;
+    let index = usize::try_from(arguments.pop_int()?)?;
+    let bytes = pop_bytes(&mut arguments)?;
+    let offset = index.saturating_mul(2);
+    check_range(offset, 2, bytes.len()?)?;
+    // Only the low 16 bits of the int are stored, as the char cast in Java does
+    let [_, _, high, low] = char.to_be_bytes();
+    bytes.set(offset, i8::from_be_bytes([high]))?;
+    bytes.set(offset + 1, i8::from_be_bytes([low]))?;
+    Ok(None)
+}
+
+#[async_recursion(?Send)]
+async fn to_bytes(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let length = usize::try_from(arguments.pop_int()?)?;
+    let offset = usize::try_from(arguments.pop_int()?)?;
+    let chars = pop_chars(&mut arguments)?.to_vec()?;
+    check_range(offset, length, chars.len())?;
+    let bytes: Vec<i8> = chars[offset..offset + length]
+        .iter()
+        .flat_map(|char| char.to_be_bytes())
+        .map(|byte| i8::from_be_bytes([byte]))
+        .collect();
+    Ok(Some(Value::from(bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error::JavaError;
+
+    fn bytes_value(bytes: &ConcurrentVec<i8>) -> Value {
+        Value::Object(Some(Reference::ByteArray(bytes.clone())))
+    }
+
+    fn chars_value(string: &str) -> Value {
+        let chars: Vec<u16> = string.encode_utf16().collect();
+        Value::Object(Some(Reference::CharArray(ConcurrentVec::from(chars))))
+    }
+
+    #[tokio::test]
+    async fn test_get_char_put_char() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let bytes = ConcurrentVec::from(vec![0i8; 6]);
+
+        for (index, char) in [(0, 'a'), (1, 'é'), (2, '€')] {
+            let mut arguments = Arguments::default();
+            arguments.push(bytes_value(&bytes));
+            arguments.push_int(index);
+            arguments.push_int(i32::try_from(u32::from(char))?);
+            assert_eq!(None, put_char(thread.clone(), arguments).await?);
+        }
+        // Chars are stored big-endian
+        assert_eq!(vec![0, 0x61, 0, -23, 0x20, -84], bytes.to_vec()?);
+
+        for (index, char) in [(0, 'a'), (1, 'é'), (2, '€')] {
+            let mut arguments = Arguments::default();
+            arguments.push(bytes_value(&bytes));
+            arguments.push_int(index);
+            let result = get_char(thread.clone(), arguments).await?;
+            assert_eq!(Some(Value::Int(i32::try_from(u32::from(char))?)), result);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_char_out_of_bounds() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push(bytes_value(&ConcurrentVec::from(vec![0i8; 3])));
+        arguments.push_int(1);
+        let result = get_char(thread, arguments).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(ArrayIndexOutOfBoundsException {
+                index: 3,
+                length: 3
+            }))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_compress_chars() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let destination = ConcurrentVec::from(vec![0i8; 7]);
+        let mut arguments = Arguments::default();
+        arguments.push(chars_value("xHello!"));
+        arguments.push_int(1);
+        arguments.push(bytes_value(&destination));
+        arguments.push_int(2);
+        arguments.push_int(5);
+        let result = compress_chars(thread, arguments).await?;
+        assert_eq!(Some(Value::Int(5)), result);
+        assert_eq!(vec![0, 0, 72, 101, 108, 108, 111], destination.to_vec()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_compress_chars_non_latin1() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let destination = ConcurrentVec::from(vec![0i8; 4]);
+        let mut arguments = Arguments::default();
+        arguments.push(chars_value("ab€c"));
+        arguments.push_int(0);
+        arguments.push(bytes_value(&destination));
+        arguments.push_int(0);
+        arguments.push_int(4);
+        let result = compress_chars(thread, arguments).await?;
+        assert_eq!(Some(Value::Int(2)), result);
+        assert_eq!(vec![97, 98, 0, 0], destination.to_vec()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_compress_bytes() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let source: Vec<i8> = vec![0, 0x41, 0, 0x42, 0, 0x43];
+        let destination = ConcurrentVec::from(vec![0i8; 2]);
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(source));
+        arguments.push_int(1);
+        arguments.push(bytes_value(&destination));
+        arguments.push_int(0);
+        arguments.push_int(2);
+        let result = compress_bytes(thread, arguments).await?;
+        assert_eq!(Some(Value::Int(2)), result);
+        assert_eq!(vec![0x42, 0x43], destination.to_vec()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_to_bytes() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push(chars_value("xa€"));
+        arguments.push_int(1);
+        arguments.push_int(2);
+        let result = to_bytes(thread, arguments).await?;
+        let expected: Vec<i8> = vec![0, 0x61, 0x20, -84];
+        assert_eq!(Some(Value::from(expected)), result);
+        Ok(())
+    }
+}
@@ -1,16 +1,15 @@
 use crate::arguments::Arguments;
 use crate::java_object::JavaObject;
-use crate::native_methods::java::lang::object::object_hash_code;
 use crate::native_methods::properties;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
 use crate::Error::InternalError;
+use crate::JavaError::ArrayIndexOutOfBoundsException;
 use crate::Result;
 use async_recursion::async_recursion;
 use ristretto_classfile::attributes::Instruction;
 use ristretto_classfile::{ClassFile, MethodAccessFlags, Version};
 use ristretto_classloader::{Class, ConcurrentVec, Method, Object, Reference, Value};
-use std::cmp::min;
 use std::env::consts::OS;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -75,23 +74,37 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     );
 }
 
+/// Copy the range of the source array to the destination array; the arrays may be the same array,
+/// in which case the ranges may overlap.
+///
+/// # Errors
+/// if either range is not within its array.
 fn arraycopy_vec<T: Clone + Debug + PartialEq>(
     source: &ConcurrentVec<T>,
     source_position: usize,
     destination: &ConcurrentVec<T>,
-    mut destination_position: usize,
+    destination_position: usize,
     length: usize,
 ) -> Result<()> {
-    // TODO: optimize this logic to avoid the need for looping
-    let max_length = min(source_position + length, source.len()?);
-    for i in source_position..max_length {
-        let Some(value) = source.get(i)? else {
-            return Err(InternalError("invalid source array index".to_string()));
-        };
-        destination.set(destination_position, value)?;
-        destination_position += 1;
+    let source_end = source_position.saturating_add(length);
+    if destination.copy_from(source, source_position..source_end, destination_position)? {
+        return Ok(());
+    }
+
+    let source_length = source.len()?;
+    let (index, length) = if source_end > source_length {
+        (source_end, source_length)
+    } else {
+        (
+            destination_position.saturating_add(length),
+            destination.len()?,
+        )
+    };
+    Err(ArrayIndexOutOfBoundsException {
+        index: i32::try_from(index)?,
+        length,
     }
-    Ok(())
+    .into())
 }
 
 #[async_recursion(?Send)]
@@ -236,7 +249,7 @@ async fn identity_hash_code(
     mut arguments: Arguments,
 ) -> Result<Option<Value>> {
     let hash_code = match arguments.pop_reference()? {
-        Some(object) => object_hash_code(&object),
+        Some(object) => object.identity_hash_code(),
         None => 0,
     };
     Ok(Some(Value::Int(hash_code)))
@@ -411,3 +424,104 @@ async fn set_security_manager(
         "SecurityManager is not supported".to_string(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error::JavaError;
+
+    async fn identity_hash_code_of(thread: &Arc<Thread>, object: Option<Reference>) -> Result<i32> {
+        let mut arguments = Arguments::default();
+        arguments.push_reference(object);
+        let result = identity_hash_code(thread.clone(), arguments).await?;
+        let Some(Value::Int(hash_code)) = result else {
+            panic!("expected int");
+        };
+        Ok(hash_code)
+    }
+
+    #[tokio::test]
+    async fn test_identity_hash_code() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class("java/lang/Object").await?;
+        let object = Some(Reference::from(Object::new(class.clone())?));
+        let other_object = Some(Reference::from(Object::new(class)?));
+
+        let hash_code = identity_hash_code_of(&thread, object.clone()).await?;
+        assert_eq!(
+            hash_code,
+            identity_hash_code_of(&thread, object.clone()).await?
+        );
+        assert_ne!(
+            hash_code,
+            identity_hash_code_of(&thread, other_object).await?
+        );
+        assert_eq!(0, identity_hash_code_of(&thread, None).await?);
+
+        // Object.hashCode() returns the identity hash code
+        let object_hash_code = vm
+            .invoke(
+                "java.lang.Object",
+                "hashCode",
+                "()I",
+                vec![Value::Object(object)],
+            )
+            .await?;
+        assert_eq!(Some(Value::Int(hash_code)), object_hash_code);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_arraycopy() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let source = Reference::from(vec![1i32, 2, 3, 4]);
+        let destination = Reference::from(vec![0i32; 4]);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(source));
+        arguments.push_int(1);
+        arguments.push_reference(Some(destination.clone()));
+        arguments.push_int(0);
+        arguments.push_int(3);
+        arraycopy(thread, arguments).await?;
+        assert_eq!(vec![2, 3, 4, 0], destination.to_int_vec()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_arraycopy_overlapping() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let array = Reference::from(vec![1i32, 2, 3, 4, 5]);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array.clone()));
+        arguments.push_int(0);
+        arguments.push_reference(Some(array.clone()));
+        arguments.push_int(1);
+        arguments.push_int(4);
+        arraycopy(thread, arguments).await?;
+        assert_eq!(vec![1, 1, 2, 3, 4], array.to_int_vec()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_arraycopy_out_of_bounds() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let source = Reference::from(vec![1i32, 2, 3]);
+        let destination = Reference::from(vec![0i32; 2]);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(source));
+        arguments.push_int(0);
+        arguments.push_reference(Some(destination.clone()));
+        arguments.push_int(0);
+        arguments.push_int(3);
+        let result = arraycopy(thread, arguments).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(ArrayIndexOutOfBoundsException {
+                index: 3,
+                length: 2
+            }))
+        ));
+        assert_eq!(vec![0, 0], destination.to_int_vec()?);
+        Ok(())
+    }
+}
@@ -1,11 +1,14 @@
 use crate::arguments::Arguments;
+use crate::interruptible_wait::{interrupted_exception, InterruptibleWait, WaitOutcome};
+use crate::native_methods::java::lang::stacktraceelement;
 use crate::native_methods::registry::MethodRegistry;
-use crate::thread::Thread;
+use crate::thread::{Thread, MIN_PRIORITY, NORM_PRIORITY};
+use crate::Error::InternalError;
 use crate::JavaError::NullPointerException;
 use crate::Result;
 use async_recursion::async_recursion;
 use ristretto_classfile::Version;
-use ristretto_classloader::{Object, Reference, Value};
+use ristretto_classloader::{ConcurrentVec, Object, Reference, Value};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -154,11 +157,40 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     registry.register(class_name, "suspend0", "()V", suspend_0);
 }
 
+/// Get the VM thread associated with the `java.lang.Thread` object; returns `None` if the thread
+/// has not been started or has terminated.
+pub(crate) async fn find_thread(
+    thread: &Thread,
+    java_thread: &Reference,
+) -> Result<Option<Arc<Thread>>> {
+    let vm = thread.vm()?;
+    for vm_thread in vm.threads() {
+        if let Value::Object(Some(thread_object)) = vm_thread.java_object().await {
+            if thread_object.ptr_eq(java_thread) {
+                return Ok(Some(vm_thread));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Get the stack trace of the VM thread associated with the `java.lang.Thread` object; threads
+/// without active frames have an empty stack trace.
+async fn thread_stack_trace(thread: &Thread, java_thread: &Reference) -> Result<Value> {
+    let frames = match find_thread(thread, java_thread).await? {
+        Some(vm_thread) => vm_thread.frames().await?,
+        None => Vec::new(),
+    };
+    stacktraceelement::stack_trace(thread, &frames).await
+}
+
 #[async_recursion(?Send)]
 async fn clear_interrupt_event(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
+    // Thread.interrupted() clears the interrupted field of the current thread before calling this
+    thread.clear_interrupt();
     Ok(None)
 }
 
@@ -185,8 +217,26 @@ async fn current_thread(thread: Arc<Thread>, _arguments: Arguments) -> Result<Op
 }
 
 #[async_recursion(?Send)]
-async fn dump_threads(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.dumpThreads([Ljava/lang/Thread;)[[Ljava/lang/StackTraceElement;")
+async fn dump_threads(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let Some(Reference::Array(_class, threads)) = arguments.pop_reference()? else {
+        return Err(NullPointerException("threads cannot be null".to_string()).into());
+    };
+
+    let threads = threads.to_vec()?;
+    let stack_traces = ConcurrentVec::with_capacity(threads.len());
+    for java_thread in threads {
+        let Some(java_thread) = java_thread else {
+            return Err(NullPointerException("thread cannot be null".to_string()).into());
+        };
+        let Value::Object(stack_trace) = thread_stack_trace(&thread, &java_thread).await? else {
+            return Err(InternalError("Invalid stack trace".to_string()));
+        };
+        stack_traces.push(stack_trace)?;
+    }
+
+    let stack_traces_class = thread.class("[[Ljava/lang/StackTraceElement;").await?;
+    let stack_traces = Value::Object(Some(Reference::Array(stack_traces_class, stack_traces)));
+    Ok(Some(stack_traces))
 }
 
 #[async_recursion(?Send)]
@@ -222,13 +272,36 @@ async fn get_next_thread_id_offset(
 }
 
 #[async_recursion(?Send)]
-async fn get_stack_trace_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.getStackTrace0()Ljava/lang/Object;")
+async fn get_stack_trace_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let Some(java_thread) = arguments.pop_reference()? else {
+        return Err(NullPointerException("thread cannot be null".to_string()).into());
+    };
+    // A thread that is not alive has no stack trace
+    if find_thread(&thread, &java_thread).await?.is_none() {
+        return Ok(Some(Value::Object(None)));
+    }
+    let stack_trace = thread_stack_trace(&thread, &java_thread).await?;
+    Ok(Some(stack_trace))
 }
 
 #[async_recursion(?Send)]
-async fn get_threads(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.getThreads()[Ljava/lang/Thread;")
+async fn get_threads(thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
+    let vm = thread.vm()?;
+    let mut threads = vm.threads();
+    threads.sort_by_key(|thread| thread.id());
+
+    let thread_objects = ConcurrentVec::with_capacity(threads.len());
+    for thread in threads {
+        // Skip threads that have not been associated with a Java object yet
+        let Value::Object(Some(thread_object)) = thread.java_object().await else {
+            continue;
+        };
+        thread_objects.push(Some(thread_object))?;
+    }
+
+    let thread_array_class = thread.class("[Ljava/lang/Thread;").await?;
+    let threads = Value::Object(Some(Reference::Array(thread_array_class, thread_objects)));
+    Ok(Some(threads))
 }
 
 #[async_recursion(?Send)]
@@ -237,8 +310,15 @@ async fn holds_lock(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Optio
 }
 
 #[async_recursion(?Send)]
-async fn interrupt_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.interrupt0()V")
+async fn interrupt_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let Some(java_thread) = arguments.pop_reference()? else {
+        return Err(NullPointerException("thread cannot be null".to_string()).into());
+    };
+    // A thread that has not been started has no VM thread to wake
+    if let Some(vm_thread) = find_thread(&thread, &java_thread).await? {
+        vm_thread.interrupt();
+    }
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -255,8 +335,20 @@ async fn is_alive_0(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<
 }
 
 #[async_recursion(?Send)]
-async fn is_interrupted(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("java.lang.Thread.isInterrupted(Z)Z")
+async fn is_interrupted(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let clear_interrupted = arguments.pop_int()? != 0;
+    let Some(java_thread) = arguments.pop_reference()? else {
+        return Err(NullPointerException("thread cannot be null".to_string()).into());
+    };
+    let Some(vm_thread) = find_thread(&thread, &java_thread).await? else {
+        return Ok(Some(Value::from(false)));
+    };
+    let interrupted = if clear_interrupted {
+        vm_thread.clear_interrupt()
+    } else {
+        vm_thread.is_interrupted()
+    };
+    Ok(Some(Value::from(interrupted)))
 }
 
 #[async_recursion(?Send)]
@@ -298,9 +390,16 @@ async fn set_native_name(thread: Arc<Thread>, mut arguments: Arguments) -> Resul
 }
 
 #[async_recursion(?Send)]
-async fn set_priority_0(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
-    let _new_priority = arguments.pop_int()?;
-    // TODO: implement priority if/when tokio supports it
+async fn set_priority_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let new_priority = arguments.pop_int()?;
+    let Some(java_thread) = arguments.pop_reference()? else {
+        return Err(NullPointerException("thread cannot be null".to_string()).into());
+    };
+    // A thread that has not been started has no VM thread; its priority is only held by the
+    // java.lang.Thread object
+    if let Some(vm_thread) = find_thread(&thread, &java_thread).await? {
+        vm_thread.set_priority(new_priority);
+    }
     Ok(None)
 }
 
@@ -313,15 +412,10 @@ async fn set_scoped_value_cache(
 }
 
 #[async_recursion(?Send)]
-async fn sleep(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+async fn sleep(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
     let millis = arguments.pop_long()?;
     let millis = u64::try_from(millis)?;
-    let duration = Duration::from_millis(millis);
-    #[cfg(not(target_arch = "wasm32"))]
-    tokio::time::sleep(duration).await;
-    #[cfg(target_arch = "wasm32")]
-    std::thread::sleep(duration);
-    Ok(None)
+    sleep_for(&thread, Duration::from_millis(millis)).await
 }
 
 #[async_recursion(?Send)]
@@ -330,14 +424,22 @@ async fn sleep_0(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Val
 }
 
 #[async_recursion(?Send)]
-async fn sleep_nanos_0(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+async fn sleep_nanos_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
     let nanos = arguments.pop_long()?;
     let nanos = u64::try_from(nanos)?;
-    let duration = Duration::from_nanos(nanos);
-    #[cfg(not(target_arch = "wasm32"))]
-    tokio::time::sleep(duration).await;
-    #[cfg(target_arch = "wasm32")]
-    std::thread::sleep(duration);
+    sleep_for(&thread, Duration::from_nanos(nanos)).await
+}
+
+/// Sleep the thread for the duration; throws an `InterruptedException` if the thread is
+/// interrupted before or while sleeping.
+async fn sleep_for(thread: &Thread, duration: Duration) -> Result<Option<Value>> {
+    let outcome = InterruptibleWait::new(thread)
+        .timeout(duration)
+        .wait()
+        .await;
+    if outcome == WaitOutcome::Interrupted {
+        return interrupted_exception(thread, "sleep interrupted").await;
+    }
     Ok(None)
 }
 
@@ -360,11 +462,18 @@ async fn suspend_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option
 }
 
 #[async_recursion(?Send)]
-async fn r#yield(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    #[cfg(not(target_arch = "wasm32"))]
-    tokio::task::yield_now().await;
-    #[cfg(target_arch = "wasm32")]
-    std::thread::yield_now();
+async fn r#yield(thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
+    // Tokio does not support task priorities; threads with a priority below normal yield
+    // additional times so that other tasks are more likely to be scheduled before them.  The
+    // priority is clamped so that a thread yields at most `NORM_PRIORITY` times.
+    let priority = thread.priority().clamp(MIN_PRIORITY, NORM_PRIORITY);
+    let yields = NORM_PRIORITY - priority + 1;
+    for _ in 0..yields {
+        #[cfg(not(target_arch = "wasm32"))]
+        tokio::task::yield_now().await;
+        #[cfg(target_arch = "wasm32")]
+        std::thread::yield_now();
+    }
     Ok(None)
 }
 
@@ -372,3 +481,218 @@ async fn r#yield(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<V
 async fn yield_0(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
     r#yield(thread, arguments).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::native_methods::java::lang::stacktraceelement::NATIVE_METHOD_LINE_NUMBER;
+    use crate::Frame;
+    use ristretto_classfile::MethodAccessFlags;
+    use ristretto_classloader::{Class, Method};
+
+    #[tokio::test]
+    async fn test_get_threads() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let primordial_thread = vm
+            .threads()
+            .into_iter()
+            .find(|thread| thread.id() == 1)
+            .expect("primordial thread");
+        let Value::Object(Some(primordial_thread)) = primordial_thread.java_object().await else {
+            panic!("expected primordial thread object");
+        };
+
+        let result = get_threads(thread, Arguments::default()).await?;
+        let Some(Value::Object(Some(Reference::Array(class, threads)))) = result else {
+            panic!("expected thread array");
+        };
+        assert_eq!("[Ljava/lang/Thread;", class.name());
+        assert_eq!(Some("java/lang/Thread"), class.component_type());
+        let threads = threads.to_vec()?;
+        assert!(threads.iter().any(|thread| thread
+            .as_ref()
+            .is_some_and(|thread| thread.ptr_eq(&primordial_thread))));
+        Ok(())
+    }
+
+    /// Create a new VM thread associated with a new `java.lang.Thread` object.
+    async fn new_thread(thread: &Thread) -> Result<(Arc<Thread>, Reference)> {
+        let vm = thread.vm()?;
+        let new_thread = vm.new_thread()?;
+        let thread_class = thread.class("java/lang/Thread").await?;
+        let java_thread = Reference::Object(Object::new(thread_class)?);
+        new_thread
+            .set_java_object(Value::Object(Some(java_thread.clone())))
+            .await;
+        Ok((new_thread, java_thread))
+    }
+
+    /// Add a frame for a new method to the call stack of the thread.
+    async fn push_frame(
+        thread: &Arc<Thread>,
+        class: &Arc<Class>,
+        access_flags: MethodAccessFlags,
+        name: &str,
+    ) -> Result<()> {
+        let method = Method::new(
+            access_flags,
+            name,
+            "()V",
+            1,
+            1,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )?;
+        let frame = Frame::new(
+            &Arc::downgrade(thread),
+            class,
+            &Arc::new(method),
+            Vec::new(),
+        )?;
+        thread.push_frame(Arc::new(frame)).await;
+        Ok(())
+    }
+
+    /// Get the method name and line number of the stack trace elements.
+    fn elements(stack_trace: Option<Reference>) -> Result<Vec<(String, i32)>> {
+        let Some(Reference::Array(class, elements)) = stack_trace else {
+            panic!("expected stack trace array");
+        };
+        assert_eq!("[Ljava/lang/StackTraceElement;", class.name());
+        let mut result = Vec::new();
+        for element in elements.to_vec()? {
+            let Some(Reference::Object(element)) = element else {
+                panic!("expected stack trace element");
+            };
+            let method_name: String = element.value("methodName")?.try_into()?;
+            let line_number = element.value("lineNumber")?.to_int()?;
+            result.push((method_name, line_number));
+        }
+        Ok(result)
+    }
+
+    #[tokio::test]
+    async fn test_dump_threads() -> Result<()> {
+        let (_vm, thread, class) = crate::test::class().await?;
+        let (_idle_thread, idle_java_thread) = new_thread(&thread).await?;
+        let (busy_thread, busy_java_thread) = new_thread(&thread).await?;
+        push_frame(&busy_thread, &class, MethodAccessFlags::STATIC, "caller").await?;
+        push_frame(
+            &busy_thread,
+            &class,
+            MethodAccessFlags::STATIC | MethodAccessFlags::NATIVE,
+            "callee",
+        )
+        .await?;
+
+        let thread_array_class = thread.class("[Ljava/lang/Thread;").await?;
+        let threads = ConcurrentVec::from(vec![Some(idle_java_thread), Some(busy_java_thread)]);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(Reference::Array(thread_array_class, threads)));
+        let result = dump_threads(thread, arguments).await?;
+
+        let Some(Value::Object(Some(Reference::Array(class, stack_traces)))) = result else {
+            panic!("expected stack traces array");
+        };
+        assert_eq!("[[Ljava/lang/StackTraceElement;", class.name());
+        let stack_traces = stack_traces.to_vec()?;
+        assert_eq!(2, stack_traces.len());
+        assert!(elements(stack_traces[0].clone())?.is_empty());
+        assert_eq!(
+            vec![
+                ("callee".to_string(), NATIVE_METHOD_LINE_NUMBER),
+                ("caller".to_string(), 0)
+            ],
+            elements(stack_traces[1].clone())?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_stack_trace_0() -> Result<()> {
+        let (_vm, thread, class) = crate::test::class().await?;
+        let (busy_thread, busy_java_thread) = new_thread(&thread).await?;
+        push_frame(&busy_thread, &class, MethodAccessFlags::STATIC, "caller").await?;
+
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(busy_java_thread));
+        let result = get_stack_trace_0(thread.clone(), arguments).await?;
+        let Some(Value::Object(stack_trace)) = result else {
+            panic!("expected stack trace");
+        };
+        assert_eq!(vec![("caller".to_string(), 0)], elements(stack_trace)?);
+
+        // A thread that has not been started has no stack trace
+        let thread_class = thread.class("java/lang/Thread").await?;
+        let unstarted_thread = Reference::Object(Object::new(thread_class)?);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(unstarted_thread));
+        let result = get_stack_trace_0(thread, arguments).await?;
+        assert_eq!(Some(Value::Object(None)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_interrupt_0() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let (new_thread, java_thread) = new_thread(&thread).await?;
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(java_thread.clone()));
+        interrupt_0(thread.clone(), arguments).await?;
+        assert!(new_thread.is_interrupted());
+
+        for (clear_interrupted, expected) in [(false, true), (true, true), (false, false)] {
+            let mut arguments = Arguments::default();
+            arguments.push_reference(Some(java_thread.clone()));
+            arguments.push_int(i32::from(clear_interrupted));
+            let result = is_interrupted(thread.clone(), arguments).await?;
+            assert_eq!(Some(Value::from(expected)), result);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sleep_interrupted() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        thread.interrupt();
+        let mut arguments = Arguments::default();
+        arguments.push_long(60_000);
+        let result = sleep_0(thread.clone(), arguments).await;
+        assert!(matches!(
+            result,
+            Err(crate::Error::JavaError(
+                crate::JavaError::InterruptedException(_)
+            ))
+        ));
+        assert!(!thread.is_interrupted());
+
+        let mut arguments = Arguments::default();
+        arguments.push_long(1);
+        sleep_0(thread, arguments).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_priority_0() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let (new_thread, java_thread) = new_thread(&thread).await?;
+        assert_eq!(NORM_PRIORITY, new_thread.priority());
+
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(java_thread));
+        arguments.push_int(8);
+        set_priority_0(thread.clone(), arguments).await?;
+        assert_eq!(8, new_thread.priority());
+
+        // Priorities outside of the range of Java priorities are clamped
+        new_thread.set_priority(i32::MAX);
+        assert_eq!(10, new_thread.priority());
+        new_thread.set_priority(i32::MIN);
+        assert_eq!(1, new_thread.priority());
+
+        // Yielding from a low priority thread cooperates without error
+        r#yield(new_thread, Arguments::default()).await?;
+        Ok(())
+    }
+}
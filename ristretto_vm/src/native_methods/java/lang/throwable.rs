@@ -1,9 +1,10 @@
 use crate::arguments::Arguments;
-use crate::java_object::JavaObject;
+use crate::native_methods::java::lang::stacktraceelement;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
 use crate::Error::InternalError;
-use crate::Result;
+use crate::JavaError::ArrayIndexOutOfBoundsException;
+use crate::{Frame, Result};
 use async_recursion::async_recursion;
 use ristretto_classfile::Version;
 use ristretto_classloader::{ConcurrentVec, Object, Reference, Value};
@@ -40,6 +41,41 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     );
 }
 
+/// Get the frames of the call stack at which the throwable was created.  The frames of
+/// `fillInStackTrace` and of the constructors of the throwable (i.e. the constructors of its
+/// class and superclasses) are skipped, so that the top frame is the method that created the
+/// throwable.
+async fn creation_frames(thread: &Thread, throwable: &Object) -> Result<Vec<Arc<Frame>>> {
+    let mut frames = thread.frames().await?;
+    while frames
+        .last()
+        .is_some_and(|frame| frame.method().name() == "fillInStackTrace")
+    {
+        frames.pop();
+    }
+    while let Some(frame) = frames.last() {
+        if frame.method().name() != "<init>"
+            || !frame.class().is_assignable_from(throwable.class())?
+        {
+            break;
+        }
+        frames.pop();
+    }
+    Ok(frames)
+}
+
+/// Get the stack trace stored in the backtrace of the throwable by `fillInStackTrace`.
+///
+/// # Errors
+/// if the throwable does not have a backtrace
+fn backtrace(throwable: &Object) -> Result<ConcurrentVec<Option<Reference>>> {
+    let Value::Object(Some(Reference::Array(_class, backtrace))) = throwable.value("backtrace")?
+    else {
+        return Err(InternalError("No back trace found".to_string()));
+    };
+    Ok(backtrace)
+}
+
 #[async_recursion(?Send)]
 async fn fill_in_stack_trace(
     thread: Arc<Thread>,
@@ -51,45 +87,12 @@ async fn fill_in_stack_trace(
         return Err(InternalError("No throwable object found".to_string()));
     };
 
-    let vm = thread.vm()?;
-    let stack_element_class = thread.class("java/lang/StackTraceElement").await?;
-    let stack_elements = ConcurrentVec::new();
-    for frame in thread.frames().await?.iter().rev() {
-        let class = frame.class();
-        let class_name = class.name();
-        if class_name == "java/lang/Throwable" {
-            continue;
-        }
-        let class_name = class_name.to_object(&vm).await?;
-        let stack_element_object = Object::new(stack_element_class.clone())?;
-        stack_element_object.set_value("declaringClass", class_name)?;
-
-        if let Some(source_file) = class.source_file() {
-            let source_file = source_file.to_object(&vm).await?;
-            stack_element_object.set_value("fileName", source_file)?;
-        }
-
-        let method = frame.method();
-        let method_name = method.name().to_object(&vm).await?;
-        stack_element_object.set_value("methodName", method_name)?;
-
-        let program_counter = frame.program_counter();
-        let line_number = method.line_number(program_counter);
-        stack_element_object.set_value("lineNumber", Value::Int(i32::try_from(line_number)?))?;
-
-        stack_elements.push(Some(Reference::Object(stack_element_object)))?;
-    }
-
-    let depth = i32::try_from(stack_elements.len()?)?;
-    let stack_element_array_class = thread
-        .class(format!("[L{stack_element_class};").as_str())
-        .await?;
-    let stack_trace = Value::Object(Some(Reference::Array(
-        stack_element_array_class,
-        stack_elements,
-    )));
+    let frames = creation_frames(&thread, throwable).await?;
+    let depth = i32::try_from(frames.len())?;
+    let stack_trace = stacktraceelement::stack_trace(&thread, &frames).await?;
     throwable.set_value("backtrace", stack_trace)?;
 
+    let vm = thread.vm()?;
     if vm.java_class_file_version() >= &JAVA_11 {
         throwable.set_value("depth", Value::Int(depth))?;
     }
@@ -100,15 +103,84 @@ async fn fill_in_stack_trace(
 #[async_recursion(?Send)]
 async fn get_stack_trace_depth(
     _thread: Arc<Thread>,
-    _arguments: Arguments,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Throwable.getStackTraceDepth()I")
+    let Some(Reference::Object(throwable)) = arguments.pop_reference()? else {
+        return Err(InternalError("No throwable object found".to_string()));
+    };
+    let depth = backtrace(&throwable)?.len()?;
+    Ok(Some(Value::Int(i32::try_from(depth)?)))
 }
 
 #[async_recursion(?Send)]
 async fn get_stack_trace_element(
     _thread: Arc<Thread>,
-    _arguments: Arguments,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("java.lang.Throwable.getStackTraceElement(I)Ljava/lang/StackTraceElement;")
+    let index = usize::try_from(arguments.pop_int()?)?;
+    let Some(Reference::Object(throwable)) = arguments.pop_reference()? else {
+        return Err(InternalError("No throwable object found".to_string()));
+    };
+    let backtrace = backtrace(&throwable)?;
+    let Some(element) = backtrace.get(index)? else {
+        let length = backtrace.len()?;
+        return Err(ArrayIndexOutOfBoundsException {
+            index: i32::try_from(index)?,
+            length,
+        }
+        .into());
+    };
+    Ok(Some(Value::Object(element)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::vm;
+    use crate::Result;
+    use ristretto_classloader::{Reference, Value};
+
+    /// Get the method names of the stack trace of the throwable returned by the method of the
+    /// `StackTraces` test class.
+    async fn stack_trace_method_names(method_name: &str) -> Result<Vec<String>> {
+        let vm = vm().await?;
+        let throwable = vm
+            .try_invoke(
+                "StackTraces",
+                method_name,
+                "()Ljava/lang/RuntimeException;",
+                Vec::<Value>::new(),
+            )
+            .await?;
+        let Value::Object(Some(Reference::Object(throwable))) = throwable else {
+            panic!("expected throwable");
+        };
+        let elements = backtrace(&throwable)?;
+
+        let mut method_names = Vec::new();
+        for element in elements.to_vec()?.into_iter().flatten() {
+            let Reference::Object(element) = element else {
+                panic!("expected stack trace element");
+            };
+            let method_name: String = element.value("methodName")?.try_into()?;
+            method_names.push(method_name);
+        }
+        Ok(method_names)
+    }
+
+    #[tokio::test]
+    async fn test_fill_in_stack_trace() -> Result<()> {
+        // The constructors of the exception and its superclasses are not part of the stack trace
+        let method_names = stack_trace_method_names("create").await?;
+        assert_eq!(vec!["level2", "level1", "create"], method_names);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fill_in_stack_trace_caught() -> Result<()> {
+        // The stack trace is where the exception was created, not where it was caught
+        let method_names = stack_trace_method_names("caught").await?;
+        assert_eq!(vec!["level2", "caught"], method_names);
+        Ok(())
+    }
 }
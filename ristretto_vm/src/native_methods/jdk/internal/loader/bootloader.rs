@@ -1,9 +1,14 @@
 use crate::arguments::Arguments;
+use crate::java_object::JavaObject;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
+use crate::Error::InternalError;
+use crate::JavaError::NullPointerException;
 use crate::Result;
 use async_recursion::async_recursion;
-use ristretto_classloader::Value;
+use ristretto_classloader::{ClassLoader, ClassPathEntry, ConcurrentVec, Reference, Value};
+use std::collections::BTreeSet;
+use std::path::Path;
 use std::sync::Arc;
 
 /// Register all native methods for `jdk.internal.loader.BootLoader`.
@@ -29,27 +34,168 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     );
 }
 
+/// Get the bootstrap class loader; the root of the class loader hierarchy of the VM.
+async fn bootstrap_class_loader(thread: &Thread) -> Result<ClassLoader> {
+    let vm = thread.vm()?;
+    let class_loader_lock = vm.class_loader();
+    let mut class_loader = class_loader_lock.read().await.clone();
+    while let Some(parent) = class_loader.parent() {
+        class_loader = parent.clone();
+    }
+    Ok(class_loader)
+}
+
+/// Get the names of the packages (e.g. `java/lang`) of the classes loaded by the bootstrap class
+/// loader, sorted and without duplicates.  Array and primitive classes do not belong to a package.
+async fn system_packages(class_loader: &ClassLoader) -> BTreeSet<String> {
+    class_loader
+        .classes()
+        .await
+        .iter()
+        .filter(|class| !class.is_array())
+        .filter_map(|class| {
+            let (package, _) = class.name().rsplit_once('/')?;
+            Some(package.to_string())
+        })
+        .collect()
+}
+
 #[async_recursion(?Send)]
 async fn get_system_package_location(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.loader.BootLoader.getSystemPackageLocation(Ljava/lang/String;)Ljava/lang/String;")
+    let Some(Reference::Object(name)) = arguments.pop_reference()? else {
+        return Err(NullPointerException("name cannot be null".to_string()).into());
+    };
+    let package: String = name.try_into()?;
+    let class_loader = bootstrap_class_loader(&thread).await?;
+    let Some(class) = class_loader.classes().await.into_iter().find(|class| {
+        !class.is_array()
+            && class
+                .name()
+                .rsplit_once('/')
+                .is_some_and(|(class_package, _)| class_package == package)
+    }) else {
+        return Ok(Some(Value::Object(None)));
+    };
+
+    // Packages of a module are located in the runtime image (e.g. `jrt:/java.base`); packages of
+    // any other class path entry are located at the path of the entry.
+    for class_path_entry in class_loader.class_path().iter() {
+        if class_path_entry.read_class(class.name()).await.is_err() {
+            continue;
+        }
+        let location = match class_path_entry {
+            ClassPathEntry::Jmod(jmod) => {
+                let module = Path::new(jmod.name())
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy();
+                format!("jrt:/{module}")
+            }
+            _ => class_path_entry.name().clone(),
+        };
+        let vm = thread.vm()?;
+        return Ok(Some(location.to_object(&vm).await?));
+    }
+    Ok(Some(Value::Object(None)))
 }
 
 #[async_recursion(?Send)]
 async fn get_system_package_names(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.loader.BootLoader.getSystemPackageNames()[Ljava/lang/String;")
+    let class_loader = bootstrap_class_loader(&thread).await?;
+    let vm = thread.vm()?;
+    let mut package_names = Vec::new();
+    for package in system_packages(&class_loader).await {
+        let Value::Object(package_name) = package.to_object(&vm).await? else {
+            return Err(InternalError(format!(
+                "Unable to convert package name to string: {package}"
+            )));
+        };
+        package_names.push(package_name);
+    }
+
+    let string_array_class = thread.class("[Ljava/lang/String;").await?;
+    let package_names = ConcurrentVec::from(package_names);
+    let result = Value::Object(Some(Reference::Array(string_array_class, package_names)));
+    Ok(Some(result))
 }
 
 #[async_recursion(?Send)]
 async fn set_boot_loader_unnamed_module_0(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let _object = arguments.pop_reference()?;
+    let Some(module) = arguments.pop_reference()? else {
+        return Err(NullPointerException("module cannot be null".to_string()).into());
+    };
+    let vm = thread.vm()?;
+    vm.set_boot_loader_unnamed_module(Value::Object(Some(module)))?;
     Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StdHostIo;
+    use ristretto_classloader::Object;
+
+    fn package_names(value: Option<Value>) -> Result<Vec<String>> {
+        let Some(Value::Object(Some(Reference::Array(_, package_names)))) = value else {
+            panic!("expected string array");
+        };
+        let mut names = Vec::new();
+        for package_name in package_names.to_vec()? {
+            names.push(Value::Object(package_name).try_into()?);
+        }
+        Ok(names)
+    }
+
+    #[tokio::test]
+    async fn test_get_system_package_names() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let result = get_system_package_names(thread, Arguments::default()).await?;
+        let package_names = package_names(result)?;
+        assert!(package_names.contains(&"java/lang".to_string()));
+        assert!(package_names.windows(2).all(|names| names[0] < names[1]));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_system_package_location() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let package = "java/lang".to_object(&vm).await?;
+        let result = get_system_package_location(thread, Arguments::new(vec![package])).await?;
+        let Some(location) = result else {
+            panic!("expected location");
+        };
+        let location: String = location.try_into()?;
+        assert_eq!("jrt:/java.base", location);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_system_package_location_not_found() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let package = "com/example".to_object(&vm).await?;
+        let result = get_system_package_location(thread, Arguments::new(vec![package])).await?;
+        assert_eq!(Some(Value::Object(None)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_boot_loader_unnamed_module_0() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class_with_host_io(Arc::new(StdHostIo)).await?;
+        let class = vm.class("java/lang/Object").await?;
+        let module = Value::Object(Some(Reference::from(Object::new(class)?)));
+        let arguments = Arguments::new(vec![module.clone()]);
+        let result = set_boot_loader_unnamed_module_0(thread, arguments).await?;
+        assert_eq!(None, result);
+        assert_eq!(Some(&module), vm.boot_loader_unnamed_module());
+        Ok(())
+    }
+}
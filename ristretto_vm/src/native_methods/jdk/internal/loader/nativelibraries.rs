@@ -5,24 +5,39 @@ use crate::thread::Thread;
 use crate::Error::InternalError;
 use crate::Result;
 use async_recursion::async_recursion;
+use ristretto_classfile::Version;
 use ristretto_classloader::{Reference, Value};
 use std::sync::Arc;
 
+const JAVA_17: Version = Version::Java17 { minor: 0 };
+
 /// Register all native methods for `jdk.internal.loader.NativeLibraries`.
 pub(crate) fn register(registry: &mut MethodRegistry) {
     let class_name = "jdk/internal/loader/NativeLibraries";
+    let java_version = registry.java_version();
+
+    if java_version <= &JAVA_17 {
+        registry.register(
+            class_name,
+            "load",
+            "(Ljdk/internal/loader/NativeLibraries$NativeLibraryImpl;Ljava/lang/String;ZZZ)Z",
+            load,
+        );
+    } else {
+        registry.register(
+            class_name,
+            "load",
+            "(Ljdk/internal/loader/NativeLibraries$NativeLibraryImpl;Ljava/lang/String;ZZ)Z",
+            load,
+        );
+    }
+
     registry.register(
         class_name,
         "findBuiltinLib",
         "(Ljava/lang/String;)Ljava/lang/String;",
         find_builtin_lib,
     );
-    registry.register(
-        class_name,
-        "load",
-        "(Ljdk/internal/loader/NativeLibraries$NativeLibraryImpl;Ljava/lang/String;ZZ)Z",
-        load,
-    );
     registry.register(class_name, "unload", "(Ljava/lang/String;ZJ)V", unload);
 }
 
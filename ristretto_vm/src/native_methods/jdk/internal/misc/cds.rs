@@ -5,12 +5,13 @@ use crate::Result;
 use async_recursion::async_recursion;
 use ristretto_classfile::Version;
 use ristretto_classloader::Value;
-use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
 
 const JAVA_22: Version = Version::Java22 { minor: 0 };
 
-/// Register all native methods for `jdk.internal.misc.CDS`.
+/// Register all native methods for `jdk.internal.misc.CDS`.  Class data sharing is not supported;
+/// the VM reports that it is neither dumping nor using a shared archive, so that the module system
+/// is booted without archived classes or modules.
 pub(crate) fn register(registry: &mut MethodRegistry) {
     let class_name = "jdk/internal/misc/CDS";
     let java_version = registry.java_version();
@@ -74,9 +75,11 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 #[async_recursion(?Send)]
 async fn define_archived_modules(
     _thread: Arc<Thread>,
-    _arguments: Arguments,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.CDS.defineArchivedModules(Ljava/lang/ClassLoader;Ljava/lang/ClassLoader;)V")
+    let _system_loader = arguments.pop_reference()?;
+    let _platform_loader = arguments.pop_reference()?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -100,17 +103,12 @@ async fn get_cds_config_status(
     Ok(Some(Value::Int(0)))
 }
 
-#[expect(clippy::cast_possible_wrap)]
 #[async_recursion(?Send)]
 async fn get_random_seed_for_dumping(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let version = env!("CARGO_PKG_VERSION");
-    let mut hasher = DefaultHasher::new();
-    version.hash(&mut hasher);
-    let hash = hasher.finish() as i64;
-    Ok(Some(Value::Long(hash)))
+    Ok(Some(Value::Long(0)))
 }
 
 #[async_recursion(?Send)]
@@ -153,3 +151,68 @@ async fn log_lambda_form_invoker(
 ) -> Result<Option<Value>> {
     todo!("jdk.internal.misc.CDS.logLambdaFormInvoker(Ljava/lang/String;)V")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+    use crate::{ConfigurationBuilder, VM};
+    use ristretto_classloader::Reference;
+
+    #[tokio::test]
+    async fn test_sharing_disabled() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let result = is_dumping_archive_0(thread.clone(), Arguments::default()).await?;
+        assert_eq!(Some(Value::from(false)), result);
+        let result = is_dumping_class_list_0(thread.clone(), Arguments::default()).await?;
+        assert_eq!(Some(Value::from(false)), result);
+        let result = is_sharing_enabled_0(thread.clone(), Arguments::default()).await?;
+        assert_eq!(Some(Value::from(false)), result);
+        let result = get_random_seed_for_dumping(thread, Arguments::default()).await?;
+        assert_eq!(Some(Value::Long(0)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_archive_not_used() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = vm.class("java/lang/Object").await?;
+        let class = class.to_object(&vm).await?;
+        let arguments = Arguments::new(vec![class]);
+        assert_eq!(
+            None,
+            initialize_from_archive(thread.clone(), arguments).await?
+        );
+        let arguments = Arguments::new(vec![Value::Object(None), Value::Object(None)]);
+        assert_eq!(None, define_archived_modules(thread, arguments).await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_java_21_initializes_without_sharing() -> Result<()> {
+        let configuration = ConfigurationBuilder::new().java_version("21").build()?;
+        let vm = VM::new(configuration).await?;
+        assert_eq!(&Version::Java21 { minor: 0 }, vm.java_class_file_version());
+        let result = vm
+            .invoke(
+                "jdk.internal.misc.CDS",
+                "isSharingEnabled",
+                "()Z",
+                Vec::<Value>::new(),
+            )
+            .await?;
+        assert_eq!(Some(Value::from(false)), result);
+        let Some(Value::Object(Some(Reference::Object(_)))) = vm
+            .invoke(
+                "java.lang.ModuleLayer",
+                "boot",
+                "()Ljava/lang/ModuleLayer;",
+                Vec::<Value>::new(),
+            )
+            .await?
+        else {
+            panic!("expected boot layer");
+        };
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 use crate::arguments::Arguments;
 use crate::native_methods::registry::MethodRegistry;
+use crate::signals::{DEFAULT_HANDLER, DISPATCH_HANDLER, IGNORE_HANDLER};
 use crate::thread::Thread;
-use crate::Error::{InternalError, InvalidOperand};
+use crate::Error::InvalidOperand;
 use crate::Result;
 use async_recursion::async_recursion;
 use ristretto_classloader::Value;
@@ -33,8 +34,16 @@ async fn find_signal_0(_thread: Arc<Thread>, mut arguments: Arguments) -> Result
         }
     };
 
-    // See: https://github.com/torvalds/linux/blob/master/arch/x86/include/uapi/asm/signal.h
-    let signal = match signal_name.as_str() {
+    let signal = signal_number(&signal_name).unwrap_or(-1);
+    Ok(Some(Value::Int(signal)))
+}
+
+/// Get the number of the signal with the given name (e.g. `INT`) on the platform.
+///
+/// See: <https://github.com/torvalds/linux/blob/master/arch/x86/include/uapi/asm/signal.h>
+#[cfg(not(target_os = "macos"))]
+fn signal_number(signal_name: &str) -> Option<i32> {
+    let signal = match signal_name {
         "HUP" => 1,
         "INT" => 2,
         "QUIT" => 3,
@@ -66,22 +75,104 @@ async fn find_signal_0(_thread: Arc<Thread>, mut arguments: Arguments) -> Result
         "IO" | "POLL" | "LOST" => 29,
         "PWR" => 30,
         "SYS" | "UNUSED" => 31,
-        _ => {
-            return Err(InternalError(format!("Unknown signal: {signal_name}")));
-        }
+        _ => return None,
     };
-    Ok(Some(Value::Int(signal)))
+    Some(signal)
+}
+
+/// Get the number of the signal with the given name (e.g. `INT`) on the platform.
+///
+/// See: <https://github.com/apple-oss-distributions/xnu/blob/main/bsd/sys/signal.h>
+#[cfg(target_os = "macos")]
+fn signal_number(signal_name: &str) -> Option<i32> {
+    let signal = match signal_name {
+        "HUP" => 1,
+        "INT" => 2,
+        "QUIT" => 3,
+        "ILL" => 4,
+        "TRAP" => 5,
+        "ABRT" | "IOT" => 6,
+        "EMT" => 7,
+        "FPE" => 8,
+        "KILL" => 9,
+        "BUS" => 10,
+        "SEGV" => 11,
+        "SYS" => 12,
+        "PIPE" => 13,
+        "ALRM" => 14,
+        "TERM" => 15,
+        "URG" => 16,
+        "STOP" => 17,
+        "TSTP" => 18,
+        "CONT" => 19,
+        "CHLD" => 20,
+        "TTIN" => 21,
+        "TTOU" => 22,
+        "IO" => 23,
+        "XCPU" => 24,
+        "XFSZ" => 25,
+        "VTALRM" => 26,
+        "PROF" => 27,
+        "WINCH" => 28,
+        "INFO" => 29,
+        "USR1" => 30,
+        "USR2" => 31,
+        _ => return None,
+    };
+    Some(signal)
 }
 
+/// Set the native handler of a signal and return the previous native handler, or -1 if the signal
+/// cannot be handled.  The native handler is one of `0` (default action), `1` (ignore) or `2`
+/// (dispatch to `Signal.dispatch`); received signals are dispatched by the signal dispatcher
+/// thread.
 #[async_recursion(?Send)]
-async fn handle_0(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
-    let _handler = arguments.pop_long()?;
-    let _signal = arguments.pop_int()?;
-    // TODO: implement signal handling
-    Ok(Some(Value::Long(0)))
+async fn handle_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let handler = arguments.pop_long()?;
+    let signal = arguments.pop_int()?;
+    if !matches!(handler, DEFAULT_HANDLER | IGNORE_HANDLER | DISPATCH_HANDLER) {
+        return Ok(Some(Value::Long(-1)));
+    }
+    let vm = thread.vm()?;
+    let previous_handler = vm.signals().set_handler(signal, handler)?.unwrap_or(-1);
+    Ok(Some(Value::Long(previous_handler)))
 }
 
 #[async_recursion(?Send)]
 async fn raise_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
     todo!("jdk.internal.misc.Signal.raise0(I)V")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+
+    async fn find_signal(thread: &Arc<Thread>, name: &str) -> Result<Option<Value>> {
+        let vm = thread.vm()?;
+        let mut arguments = Arguments::default();
+        arguments.push(name.to_object(&vm).await?);
+        find_signal_0(thread.clone(), arguments).await
+    }
+
+    #[tokio::test]
+    async fn test_find_signal_0() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        assert_eq!(Some(Value::Int(2)), find_signal(&thread, "INT").await?);
+        assert_eq!(Some(Value::Int(15)), find_signal(&thread, "TERM").await?);
+        assert_eq!(Some(Value::Int(1)), find_signal(&thread, "HUP").await?);
+        assert_eq!(Some(Value::Int(-1)), find_signal(&thread, "FOO").await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_0_invalid_handler() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push_int(2);
+        arguments.push_long(42);
+        let result = handle_0(thread, arguments).await?;
+        assert_eq!(Some(Value::Long(-1)), result);
+        Ok(())
+    }
+}
@@ -1,16 +1,33 @@
 use crate::arguments::Arguments;
+use crate::instruction::base_type_size;
+use crate::interruptible_wait::InterruptibleWait;
+use crate::java_object::class_object_name;
+use crate::native_methods::java::lang::thread::find_thread;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
 use crate::Error::{InternalError, InvalidOperand};
+use crate::JavaError::InstantiationException;
 use crate::Result;
 use async_recursion::async_recursion;
-use ristretto_classfile::{BaseType, Version};
-use ristretto_classloader::{Reference, Value};
+use ristretto_classfile::{BaseType, ClassAccessFlags, Version};
+use ristretto_classloader::{Field, Object, Reference, Value};
+use std::sync::atomic::{fence, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 const JAVA_11: Version = Version::Java11 { minor: 0 };
 const JAVA_17: Version = Version::Java17 { minor: 0 };
 
+/// The offset of the first element of an array.
+const ARRAY_BASE_OFFSET: i64 = 0;
+/// The index scale of arrays of object references; references are not addressable by byte, so the
+/// scale only needs to be consistent with the element index computed from an offset.
+const REFERENCE_INDEX_SCALE: i64 = 4;
+/// The offset added to the offsets of static fields, so that a static field accessed through the
+/// class object returned by `staticFieldBase0` is not confused with an instance field of
+/// `java.lang.Class`.
+const STATIC_FIELD_OFFSET: i64 = 1 << 32;
+
 /// Register all native methods for `jdk.internal.misc.Unsafe`.
 #[expect(clippy::too_many_lines)]
 pub(crate) fn register(registry: &mut MethodRegistry) {
@@ -426,18 +443,39 @@ pub(crate) async fn address_size_0(
 
 #[async_recursion(?Send)]
 pub(crate) async fn allocate_instance(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.allocateInstance(Ljava/lang/Class;)Ljava/lang/Object;")
+    let Some(Reference::Object(class_object)) = arguments.pop_reference()? else {
+        return Err(InternalError(
+            "allocateInstance: Invalid class reference".to_string(),
+        ));
+    };
+    let class_name = class_object_name(&class_object)?;
+    let class = thread.class(&class_name).await?;
+    if class.is_interface()
+        || class
+            .class_file()
+            .access_flags
+            .contains(ClassAccessFlags::ABSTRACT)
+    {
+        return Err(InstantiationException(class_name.replace('/', ".")).into());
+    }
+
+    // The object is allocated with default field values; no constructor is invoked.
+    let object = Object::new(class)?;
+    Ok(Some(Value::from(object)))
 }
 
 #[async_recursion(?Send)]
 pub(crate) async fn allocate_memory_0(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.allocateMemory0(J)J")
+    let bytes = arguments.pop_long()?;
+    let vm = thread.vm()?;
+    let address = vm.native_memory().allocate(bytes)?;
+    Ok(Some(Value::Long(address)))
 }
 
 #[async_recursion(?Send)]
@@ -445,15 +483,184 @@ pub(crate) async fn array_base_offset_0(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    Ok(Some(Value::Int(0)))
+    let offset = i32::try_from(ARRAY_BASE_OFFSET)?;
+    Ok(Some(Value::Int(offset)))
 }
 
 #[async_recursion(?Send)]
 pub(crate) async fn array_index_scale_0(
     _thread: Arc<Thread>,
-    _arguments: Arguments,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    Ok(Some(Value::Int(1)))
+    let Some(Reference::Object(class_object)) = arguments.pop_reference()? else {
+        return Err(InternalError(
+            "arrayIndexScale0: Invalid class reference".to_string(),
+        ));
+    };
+    let class_name = class_object_name(&class_object)?;
+    let scale = i32::try_from(array_index_scale(&class_name))?;
+    Ok(Some(Value::Int(scale)))
+}
+
+/// Returns the number of bytes between consecutive elements of an array with the given class
+/// name.
+fn array_index_scale(class_name: &str) -> i64 {
+    match class_name {
+        "[Z" | "[B" => 1,
+        "[C" | "[S" => 2,
+        "[I" | "[F" => 4,
+        "[J" | "[D" => 8,
+        _ => REFERENCE_INDEX_SCALE,
+    }
+}
+
+/// Returns the index of the element of an array of object references at the given offset.
+fn reference_array_index(offset: i64) -> Result<usize> {
+    let index = (offset - ARRAY_BASE_OFFSET) / REFERENCE_INDEX_SCALE;
+    Ok(usize::try_from(index)?)
+}
+
+/// Returns the bytes, in native byte order, of the element at the index of a primitive array; or
+/// `None` if the index is out of bounds.
+fn element_bytes(reference: &Reference, index: usize) -> Result<Option<Vec<u8>>> {
+    let bytes = match reference {
+        Reference::ByteArray(array) => array.get(index)?.map(|value| value.to_ne_bytes().to_vec()),
+        Reference::CharArray(array) => array.get(index)?.map(|value| value.to_ne_bytes().to_vec()),
+        Reference::ShortArray(array) => array.get(index)?.map(|value| value.to_ne_bytes().to_vec()),
+        Reference::IntArray(array) => array.get(index)?.map(|value| value.to_ne_bytes().to_vec()),
+        Reference::LongArray(array) => array.get(index)?.map(|value| value.to_ne_bytes().to_vec()),
+        Reference::FloatArray(array) => array.get(index)?.map(|value| value.to_ne_bytes().to_vec()),
+        Reference::DoubleArray(array) => {
+            array.get(index)?.map(|value| value.to_ne_bytes().to_vec())
+        }
+        Reference::Array(..) | Reference::Object(_) => {
+            return Err(InternalError(format!(
+                "Expected primitive array; found {}",
+                reference.class_name()
+            )));
+        }
+    };
+    Ok(bytes)
+}
+
+/// Sets the element at the index of a primitive array from its bytes in native byte order.
+fn set_element_bytes(reference: &Reference, index: usize, bytes: &[u8]) -> Result<()> {
+    let invalid_length = |_| InternalError(format!("Invalid element length: {}", bytes.len()));
+    match reference {
+        Reference::ByteArray(array) => {
+            array.set(
+                index,
+                i8::from_ne_bytes(bytes.try_into().map_err(invalid_length)?),
+            )?;
+        }
+        Reference::CharArray(array) => {
+            array.set(
+                index,
+                u16::from_ne_bytes(bytes.try_into().map_err(invalid_length)?),
+            )?;
+        }
+        Reference::ShortArray(array) => {
+            array.set(
+                index,
+                i16::from_ne_bytes(bytes.try_into().map_err(invalid_length)?),
+            )?;
+        }
+        Reference::IntArray(array) => {
+            array.set(
+                index,
+                i32::from_ne_bytes(bytes.try_into().map_err(invalid_length)?),
+            )?;
+        }
+        Reference::LongArray(array) => {
+            array.set(
+                index,
+                i64::from_ne_bytes(bytes.try_into().map_err(invalid_length)?),
+            )?;
+        }
+        Reference::FloatArray(array) => {
+            array.set(
+                index,
+                f32::from_ne_bytes(bytes.try_into().map_err(invalid_length)?),
+            )?;
+        }
+        Reference::DoubleArray(array) => {
+            array.set(
+                index,
+                f64::from_ne_bytes(bytes.try_into().map_err(invalid_length)?),
+            )?;
+        }
+        Reference::Array(..) | Reference::Object(_) => {
+            return Err(InternalError(format!(
+                "Expected primitive array; found {}",
+                reference.class_name()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Read the elements of a primitive array spanned by `length` bytes starting at the offset;
+/// returns the index of the first element, the position of the offset within that element and the
+/// bytes of the elements in native byte order.
+fn read_elements(
+    reference: &Reference,
+    offset: i64,
+    length: usize,
+) -> Result<(usize, usize, Vec<u8>)> {
+    let scale = usize::try_from(array_index_scale(&reference.class_name()))?;
+    let start = usize::try_from(offset - ARRAY_BASE_OFFSET)?;
+    let Some(end) = start.checked_add(length) else {
+        return Err(InternalError(format!(
+            "Invalid array range: {offset} + {length}"
+        )));
+    };
+    let first = start / scale;
+    let mut bytes = Vec::new();
+    for index in first..end.div_ceil(scale) {
+        let Some(element) = element_bytes(reference, index)? else {
+            return Err(InternalError(format!(
+                "Invalid array range: {offset} + {length}"
+            )));
+        };
+        bytes.extend(element);
+    }
+    Ok((first, start % scale, bytes))
+}
+
+/// Read `length` bytes, in native byte order, starting at the offset of a primitive array; the
+/// bytes may span several elements.
+///
+/// # Errors
+/// if the range is not within the array.
+fn read_array_bytes(reference: &Reference, offset: i64, length: usize) -> Result<Vec<u8>> {
+    let (_first, position, bytes) = read_elements(reference, offset, length)?;
+    let Some(range) = bytes.get(position..position + length) else {
+        return Err(InternalError(format!(
+            "Invalid array range: {offset} + {length}"
+        )));
+    };
+    Ok(range.to_vec())
+}
+
+/// Write the bytes, in native byte order, starting at the offset of a primitive array; the bytes
+/// may span several elements.
+///
+/// # Errors
+/// if the range is not within the array; the array is not modified.
+fn write_array_bytes(reference: &Reference, offset: i64, bytes: &[u8]) -> Result<()> {
+    let (first, position, mut elements) = read_elements(reference, offset, bytes.len())?;
+    let Some(range) = elements.get_mut(position..position + bytes.len()) else {
+        return Err(InternalError(format!(
+            "Invalid array range: {offset} + {}",
+            bytes.len()
+        )));
+    };
+    range.copy_from_slice(bytes);
+    let scale = usize::try_from(array_index_scale(&reference.class_name()))?;
+    for (index, element) in elements.chunks(scale).enumerate() {
+        set_element_bytes(reference, first + index, element)?;
+    }
+    Ok(())
 }
 
 #[async_recursion(?Send)]
@@ -490,7 +697,7 @@ pub(crate) async fn compare_and_exchange_reference(
 
 #[async_recursion(?Send)]
 pub(crate) async fn compare_and_set_int(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     mut arguments: Arguments,
 ) -> Result<Option<Value>> {
     let x = arguments.pop_int()?;
@@ -505,10 +712,7 @@ pub(crate) async fn compare_and_set_int(
 
     // TODO: the compare and set operation should be atomic
     let result = if let Some(Reference::Object(object)) = arguments.pop_reference()? {
-        let class = object.class();
-        let offset = usize::try_from(*offset)?;
-        let field_name = class.field_name(offset)?;
-        let field = object.field(&field_name)?;
+        let field = field_at(&thread, &object, *offset).await?;
         let value = field.value()?.to_int()?;
         if value == expected {
             field.set_value(Value::Int(x))?;
@@ -527,7 +731,7 @@ pub(crate) async fn compare_and_set_int(
 
 #[async_recursion(?Send)]
 pub(crate) async fn compare_and_set_long(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     mut arguments: Arguments,
 ) -> Result<Option<Value>> {
     let x = arguments.pop_long()?;
@@ -542,10 +746,7 @@ pub(crate) async fn compare_and_set_long(
 
     // TODO: the compare and set operation should be atomic
     let result = if let Some(Reference::Object(object)) = arguments.pop_reference()? {
-        let class = object.class();
-        let offset = usize::try_from(*offset)?;
-        let field_name = class.field_name(offset)?;
-        let field = object.field(&field_name)?;
+        let field = field_at(&thread, &object, *offset).await?;
         let value = field.value()?.to_long()?;
         if value == expected {
             field.set_value(Value::Long(x))?;
@@ -572,13 +773,12 @@ pub(crate) async fn compare_and_set_object(
 
 #[async_recursion(?Send)]
 pub(crate) async fn compare_and_set_reference(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     mut arguments: Arguments,
 ) -> Result<Option<Value>> {
     let x = arguments.pop()?;
     let expected = arguments.pop()?;
     let offset = arguments.pop_long()?;
-    let offset = usize::try_from(offset)?;
     let Some(object) = arguments.pop_reference()? else {
         return Err(InternalError(
             "compareAndSetReference: Invalid reference".to_string(),
@@ -588,7 +788,8 @@ pub(crate) async fn compare_and_set_reference(
     // TODO: the compare and set operation should be atomic
     let result = match object {
         Reference::Array(_class, array) => {
-            let Some(reference) = array.get(offset)? else {
+            let index = reference_array_index(offset)?;
+            let Some(reference) = array.get(index)? else {
                 return Err(InternalError(
                     "compareAndSetReference: Invalid reference index".to_string(),
                 ));
@@ -607,15 +808,14 @@ pub(crate) async fn compare_and_set_reference(
                         actual: x.to_string(),
                     });
                 };
-                array.set(offset, x_reference)?;
+                array.set(index, x_reference)?;
                 1
             } else {
                 0
             }
         }
         Reference::Object(object) => {
-            let field_name = object.class().field_name(offset)?;
-            let field = object.field(&field_name)?;
+            let field = field_at(&thread, &object, offset).await?;
             let value = field.value()?;
             if value == expected {
                 field.set_value(x)?;
@@ -635,21 +835,39 @@ pub(crate) async fn compare_and_set_reference(
 
 #[async_recursion(?Send)]
 pub(crate) async fn copy_memory_0(
-    _thread: Arc<Thread>,
+    thread: Arc<Thread>,
     mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let _bytes = usize::try_from(arguments.pop_long()?)?;
-    let _destination_offset = usize::try_from(arguments.pop_long()?)?;
-    let Value::Object(ref mut destination) = arguments.pop()? else {
-        return Err(InternalError(
-            "copyMemory0: Invalid destination".to_string(),
-        ));
-    };
-    let _source_offset = usize::try_from(arguments.pop_long()?)?;
-    let Value::Object(ref mut source) = arguments.pop()? else {
-        return Err(InternalError("copyMemory0: Invalid source".to_string()));
+    let bytes = usize::try_from(arguments.pop_long()?)?;
+    let destination_offset = arguments.pop_long()?;
+    let destination = arguments.pop_reference()?;
+    let source_offset = arguments.pop_long()?;
+    let source = arguments.pop_reference()?;
+    if bytes == 0 {
+        return Ok(None);
+    }
+
+    // The source is read in full before the destination is written so that overlapping ranges
+    // are copied correctly.
+    let vm = thread.vm()?;
+    let data = match source {
+        None => vm.native_memory().read(source_offset, bytes)?,
+        Some(Reference::Array(..) | Reference::Object(_)) => {
+            return Err(InternalError(
+                "copyMemory0: Cannot copy memory of object references or fields".to_string(),
+            ));
+        }
+        Some(reference) => read_array_bytes(&reference, source_offset, bytes)?,
     };
-    destination.clone_from(source);
+    match destination {
+        None => vm.native_memory().write(destination_offset, &data)?,
+        Some(Reference::Array(..) | Reference::Object(_)) => {
+            return Err(InternalError(
+                "copyMemory0: Cannot copy memory to object references or fields".to_string(),
+            ));
+        }
+        Some(reference) => write_array_bytes(&reference, destination_offset, &data)?,
+    }
     Ok(None)
 }
 
@@ -687,28 +905,56 @@ pub(crate) async fn ensure_class_initialized_0(
 
 #[async_recursion(?Send)]
 pub(crate) async fn free_memory_0(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
+    let address = arguments.pop_long()?;
+    let vm = thread.vm()?;
+    vm.native_memory().free(address)?;
     Ok(None)
 }
 
+/// Ensure that no load or store before the fence is reordered with a load or store after the
+/// fence; a sequentially consistent fence.
 #[async_recursion(?Send)]
 pub(crate) async fn full_fence(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
+    fence(Ordering::SeqCst);
     Ok(None)
 }
 
-#[expect(clippy::too_many_lines)]
-fn get_reference_type(
-    _thread: Arc<Thread>,
+/// Get the field of an object at an offset.  Static field offsets (see `staticFieldOffset0`) select
+/// a static field of the class represented by the `java.lang.Class` object that is the base of
+/// the static fields (see `staticFieldBase0`); other offsets select an instance field of the
+/// object.  The field shares its value with the field of the object or class.
+async fn field_at(thread: &Thread, object: &Object, offset: i64) -> Result<Field> {
+    if offset >= STATIC_FIELD_OFFSET && object.class().name() == "java/lang/Class" {
+        let class_name = class_object_name(object)?;
+        let class = thread.class(&class_name).await?;
+        let field_name = class.field_name(usize::try_from(offset - STATIC_FIELD_OFFSET)?)?;
+        let field = class.static_field(&field_name)?;
+        return Ok(Field::clone(&field));
+    }
+    let field_name = object.class().field_name(usize::try_from(offset)?)?;
+    Ok(object.field(&field_name)?.clone())
+}
+
+async fn get_reference_type(
+    thread: &Arc<Thread>,
     mut arguments: Arguments,
     base_type: Option<BaseType>,
 ) -> Result<Option<Value>> {
     let offset = arguments.pop_long()?;
     let Some(reference) = arguments.pop_reference()? else {
+        // A null object with an allocated address reads from native memory
+        if let Some(base_type) = &base_type {
+            let vm = thread.vm()?;
+            if let Ok(bytes) = vm.native_memory().read(offset, base_type_size(base_type)) {
+                return Ok(Some(value_from_bytes(base_type, &bytes)?));
+            }
+        }
         let Some(base_type) = base_type else {
             return Err(InternalError(
                 "getReferenceType: Invalid reference".to_string(),
@@ -730,94 +976,114 @@ fn get_reference_type(
         return Ok(Some(value));
     };
 
-    let offset = usize::try_from(offset)?;
     let value = match reference {
-        Reference::ByteArray(array) => {
-            let Some(byte) = array.get(offset)? else {
-                return Err(InternalError(
-                    "getReferenceType: Invalid byte reference index".to_string(),
-                ));
-            };
-            if matches!(base_type, Some(BaseType::Long)) {
-                Value::Long(i64::from(byte))
-            } else {
-                Value::Int(i32::from(byte))
-            }
-        }
-        Reference::CharArray(array) => {
-            let Some(char) = array.get(offset)? else {
-                return Err(InternalError(
-                    "getReferenceType: Invalid char reference index".to_string(),
-                ));
-            };
-            if matches!(base_type, Some(BaseType::Long)) {
-                Value::Long(i64::from(char))
-            } else {
-                Value::Int(i32::from(char))
-            }
-        }
-        Reference::ShortArray(array) => {
-            let Some(short) = array.get(offset)? else {
+        Reference::Array(_class, array) => {
+            let Some(reference) = array.get(reference_array_index(offset)?)? else {
                 return Err(InternalError(
-                    "getReferenceType: Invalid short reference index".to_string(),
+                    "getReferenceType: Invalid array reference index".to_string(),
                 ));
             };
-            if matches!(base_type, Some(BaseType::Long)) {
-                Value::Long(i64::from(short))
-            } else {
-                Value::Int(i32::from(short))
-            }
+            Value::Object(reference)
         }
-        Reference::IntArray(array) => {
-            let Some(int) = array.get(offset)? else {
+        Reference::Object(object) => field_at(thread, &object, offset).await?.value()?,
+        reference => {
+            let Some(base_type) = base_type else {
                 return Err(InternalError(
-                    "getReferenceType: Invalid int reference index".to_string(),
+                    "getReferenceType: Expected reference array".to_string(),
                 ));
             };
-            if matches!(base_type, Some(BaseType::Long)) {
-                Value::Long(i64::from(int))
-            } else {
-                Value::Int(int)
-            }
+            let bytes = read_array_bytes(&reference, offset, base_type_size(&base_type))?;
+            value_from_bytes(&base_type, &bytes)?
         }
-        Reference::LongArray(array) => {
-            let Some(long) = array.get(offset)? else {
-                return Err(InternalError(
-                    "getReferenceType: Invalid long reference index".to_string(),
-                ));
-            };
-            Value::Long(long)
+    };
+    Ok(Some(value))
+}
+
+/// Convert bytes in native byte order to a value of the base type.
+fn value_from_bytes(base_type: &BaseType, bytes: &[u8]) -> Result<Value> {
+    let invalid_length = |_| InternalError(format!("Invalid value length: {}", bytes.len()));
+    let value = match base_type {
+        BaseType::Boolean | BaseType::Byte => Value::Int(i32::from(i8::from_ne_bytes(
+            bytes.try_into().map_err(invalid_length)?,
+        ))),
+        BaseType::Char => Value::Int(i32::from(u16::from_ne_bytes(
+            bytes.try_into().map_err(invalid_length)?,
+        ))),
+        BaseType::Short => Value::Int(i32::from(i16::from_ne_bytes(
+            bytes.try_into().map_err(invalid_length)?,
+        ))),
+        BaseType::Int => Value::Int(i32::from_ne_bytes(
+            bytes.try_into().map_err(invalid_length)?,
+        )),
+        BaseType::Long => Value::Long(i64::from_ne_bytes(
+            bytes.try_into().map_err(invalid_length)?,
+        )),
+        BaseType::Float => Value::Float(f32::from_ne_bytes(
+            bytes.try_into().map_err(invalid_length)?,
+        )),
+        BaseType::Double => Value::Double(f64::from_ne_bytes(
+            bytes.try_into().map_err(invalid_length)?,
+        )),
+    };
+    Ok(value)
+}
+
+/// Convert a value of the base type to bytes in native byte order.
+#[expect(clippy::cast_possible_truncation)]
+#[expect(clippy::cast_sign_loss)]
+fn value_to_bytes(base_type: &BaseType, value: &Value) -> Result<Vec<u8>> {
+    let bytes = match base_type {
+        BaseType::Boolean | BaseType::Byte => (value.to_int()? as i8).to_ne_bytes().to_vec(),
+        BaseType::Char => (value.to_int()? as u16).to_ne_bytes().to_vec(),
+        BaseType::Short => (value.to_int()? as i16).to_ne_bytes().to_vec(),
+        BaseType::Int => value.to_int()?.to_ne_bytes().to_vec(),
+        BaseType::Long => value.to_long()?.to_ne_bytes().to_vec(),
+        BaseType::Float => value.to_float()?.to_ne_bytes().to_vec(),
+        BaseType::Double => value.to_double()?.to_ne_bytes().to_vec(),
+    };
+    Ok(bytes)
+}
+
+/// Put a value at the offset of an object field or array element.
+async fn put_reference_type(
+    thread: &Arc<Thread>,
+    mut arguments: Arguments,
+    base_type: Option<BaseType>,
+) -> Result<Option<Value>> {
+    let x = arguments.pop()?;
+    let offset = arguments.pop_long()?;
+    let Some(reference) = arguments.pop_reference()? else {
+        let Some(base_type) = base_type else {
+            return Err(InternalError(
+                "putReferenceType: Invalid reference".to_string(),
+            ));
+        };
+        let vm = thread.vm()?;
+        let native_memory = vm.native_memory();
+        for (address, byte) in (offset..).zip(value_to_bytes(&base_type, &x)?) {
+            native_memory.fill(address, 1, byte)?;
         }
-        Reference::FloatArray(array) => {
-            let Some(float) = array.get(offset)? else {
-                return Err(InternalError(
-                    "getReferenceType: Invalid float reference index".to_string(),
-                ));
-            };
-            Value::Float(float)
+        return Ok(None);
+    };
+
+    match reference {
+        Reference::Array(_class, array) => {
+            let x = x.to_reference()?;
+            array.set(reference_array_index(offset)?, x)?;
         }
-        Reference::DoubleArray(array) => {
-            let Some(double) = array.get(offset)? else {
-                return Err(InternalError(
-                    "getReferenceType: Invalid double reference index".to_string(),
-                ));
-            };
-            Value::Double(double)
+        Reference::Object(object) => {
+            field_at(thread, &object, offset).await?.set_value(x)?;
         }
-        Reference::Array(_class, array) => {
-            let Some(reference) = array.get(offset)? else {
+        reference => {
+            let Some(base_type) = base_type else {
                 return Err(InternalError(
-                    "getReferenceType: Invalid array reference index".to_string(),
+                    "putReferenceType: Expected reference array".to_string(),
                 ));
             };
-            Value::Object(reference)
-        }
-        Reference::Object(object) => {
-            let field_name = object.class().field_name(offset)?;
-            object.value(&field_name)?
+            write_array_bytes(&reference, offset, &value_to_bytes(&base_type, &x)?)?;
         }
-    };
-    Ok(Some(value))
+    }
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -833,7 +1099,7 @@ pub(crate) async fn get_boolean_volatile(
     thread: Arc<Thread>,
     arguments: Arguments,
 ) -> Result<Option<Value>> {
-    get_reference_type(thread, arguments, Some(BaseType::Boolean))
+    get_reference_type(&thread, arguments, Some(BaseType::Boolean)).await
 }
 
 #[async_recursion(?Send)]
@@ -846,7 +1112,7 @@ pub(crate) async fn get_byte_volatile(
     thread: Arc<Thread>,
     arguments: Arguments,
 ) -> Result<Option<Value>> {
-    get_reference_type(thread, arguments, Some(BaseType::Byte))
+    get_reference_type(&thread, arguments, Some(BaseType::Byte)).await
 }
 
 #[async_recursion(?Send)]
@@ -859,7 +1125,7 @@ pub(crate) async fn get_char_volatile(
     thread: Arc<Thread>,
     arguments: Arguments,
 ) -> Result<Option<Value>> {
-    get_reference_type(thread, arguments, Some(BaseType::Char))
+    get_reference_type(&thread, arguments, Some(BaseType::Char)).await
 }
 
 #[async_recursion(?Send)]
@@ -872,7 +1138,7 @@ pub(crate) async fn get_double_volatile(
     thread: Arc<Thread>,
     arguments: Arguments,
 ) -> Result<Option<Value>> {
-    get_reference_type(thread, arguments, Some(BaseType::Double))
+    get_reference_type(&thread, arguments, Some(BaseType::Double)).await
 }
 
 #[async_recursion(?Send)]
@@ -885,7 +1151,7 @@ pub(crate) async fn get_float_volatile(
     thread: Arc<Thread>,
     arguments: Arguments,
 ) -> Result<Option<Value>> {
-    get_reference_type(thread, arguments, Some(BaseType::Float))
+    get_reference_type(&thread, arguments, Some(BaseType::Float)).await
 }
 
 #[async_recursion(?Send)]
@@ -898,7 +1164,7 @@ pub(crate) async fn get_int_volatile(
     thread: Arc<Thread>,
     arguments: Arguments,
 ) -> Result<Option<Value>> {
-    get_reference_type(thread, arguments, Some(BaseType::Int))
+    get_reference_type(&thread, arguments, Some(BaseType::Int)).await
 }
 
 #[async_recursion(?Send)]
@@ -919,7 +1185,7 @@ pub(crate) async fn get_long_volatile(
     thread: Arc<Thread>,
     arguments: Arguments,
 ) -> Result<Option<Value>> {
-    get_reference_type(thread, arguments, Some(BaseType::Long))
+    get_reference_type(&thread, arguments, Some(BaseType::Long)).await
 }
 
 #[async_recursion(?Send)]
@@ -932,7 +1198,7 @@ pub(crate) async fn get_object_volatile(
     thread: Arc<Thread>,
     arguments: Arguments,
 ) -> Result<Option<Value>> {
-    get_reference_type(thread, arguments, None)
+    get_reference_type(&thread, arguments, None).await
 }
 
 #[async_recursion(?Send)]
@@ -948,7 +1214,7 @@ pub(crate) async fn get_reference_volatile(
     thread: Arc<Thread>,
     arguments: Arguments,
 ) -> Result<Option<Value>> {
-    get_reference_type(thread, arguments, None)
+    get_reference_type(&thread, arguments, None).await
 }
 
 #[async_recursion(?Send)]
@@ -961,7 +1227,7 @@ pub(crate) async fn get_short_volatile(
     thread: Arc<Thread>,
     arguments: Arguments,
 ) -> Result<Option<Value>> {
-    get_reference_type(thread, arguments, Some(BaseType::Short))
+    get_reference_type(&thread, arguments, Some(BaseType::Short)).await
 }
 
 #[async_recursion(?Send)]
@@ -984,20 +1250,38 @@ pub(crate) async fn is_big_endian_0(
     }
 }
 
+/// Ensure that no load before the fence is reordered with a load or store after the fence; an
+/// acquire fence (`LoadLoad` and `LoadStore` barriers).
 #[async_recursion(?Send)]
 pub(crate) async fn load_fence(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
+    fence(Ordering::Acquire);
     Ok(None)
 }
 
 #[async_recursion(?Send)]
 pub(crate) async fn object_field_offset_0(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    Ok(Some(Value::Long(0)))
+    let Some(Reference::Object(field)) = arguments.pop_reference()? else {
+        return Err(InternalError(
+            "objectFieldOffset0: Invalid field reference".to_string(),
+        ));
+    };
+    let Value::Object(Some(Reference::Object(class_object))) = field.value("clazz")? else {
+        return Err(InternalError(
+            "objectFieldOffset0: Invalid class reference".to_string(),
+        ));
+    };
+    let field_name: String = field.value("name")?.try_into()?;
+    let class_name = class_object_name(&class_object)?;
+    let class = thread.class(&class_name).await?;
+    let offset = class.field_offset(&field_name)?;
+    let offset = i64::try_from(offset)?;
+    Ok(Some(Value::Long(offset)))
 }
 
 #[async_recursion(?Send)]
@@ -1020,7 +1304,7 @@ pub(crate) async fn object_field_offset_1(
             "objectFieldOffset1: Invalid class reference".to_string(),
         ));
     };
-    let class_name: String = class_object.value("name")?.try_into()?;
+    let class_name = class_object_name(&class_object)?;
     let class = thread.class(&class_name).await?;
     let offset = class.field_offset(&field_name)?;
     let offset = i64::try_from(offset)?;
@@ -1036,8 +1320,36 @@ pub(crate) async fn page_size(
 }
 
 #[async_recursion(?Send)]
-pub(crate) async fn park(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.park(ZJ)V")
+pub(crate) async fn park(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let time = arguments.pop_long()?;
+    let is_absolute = arguments.pop_int()? != 0;
+    if time < 0 || (is_absolute && time == 0) {
+        return Ok(None);
+    }
+    // An absolute time is a deadline in milliseconds since the epoch; a relative time is a
+    // timeout in nanoseconds, where 0 waits until the thread is unparked or interrupted.
+    let timeout = if is_absolute {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|error| InternalError(error.to_string()))?;
+        let deadline = Duration::from_millis(u64::try_from(time)?);
+        let Some(timeout) = deadline.checked_sub(now) else {
+            return Ok(None);
+        };
+        Some(timeout)
+    } else if time == 0 {
+        None
+    } else {
+        Some(Duration::from_nanos(u64::try_from(time)?))
+    };
+
+    let mut wait = InterruptibleWait::new(&thread).notify(thread.park_notify());
+    if let Some(timeout) = timeout {
+        wait = wait.timeout(timeout);
+    }
+    // Parking returns on an interrupt without clearing the interrupt status
+    wait.wait().await;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -1050,17 +1362,10 @@ pub(crate) async fn put_boolean(
 
 #[async_recursion(?Send)]
 pub(crate) async fn put_boolean_volatile(
-    _thread: Arc<Thread>,
-    mut arguments: Arguments,
+    thread: Arc<Thread>,
+    arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let x = arguments.pop_int()? != 0;
-    let offset = usize::try_from(arguments.pop_long()?)?;
-    let Value::Object(ref mut object) = arguments.pop()? else {
-        return Err(InternalError("putBoolean: Invalid reference".to_string()));
-    };
-    let bytes = Reference::from(vec![x; offset]);
-    *object = Some(bytes);
-    Ok(None)
+    put_reference_type(&thread, arguments, Some(BaseType::Boolean)).await
 }
 
 #[async_recursion(?Send)]
@@ -1070,17 +1375,10 @@ pub(crate) async fn put_byte(thread: Arc<Thread>, arguments: Arguments) -> Resul
 
 #[async_recursion(?Send)]
 pub(crate) async fn put_byte_volatile(
-    _thread: Arc<Thread>,
-    mut arguments: Arguments,
+    thread: Arc<Thread>,
+    arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let x = i8::try_from(arguments.pop_int()?)?;
-    let offset = usize::try_from(arguments.pop_long()?)?;
-    let Value::Object(ref mut object) = arguments.pop()? else {
-        return Err(InternalError("putByte: Invalid reference".to_string()));
-    };
-    let bytes = Reference::from(vec![x; offset]);
-    *object = Some(bytes);
-    Ok(None)
+    put_reference_type(&thread, arguments, Some(BaseType::Byte)).await
 }
 
 #[async_recursion(?Send)]
@@ -1090,21 +1388,10 @@ pub(crate) async fn put_char(thread: Arc<Thread>, arguments: Arguments) -> Resul
 
 #[async_recursion(?Send)]
 pub(crate) async fn put_char_volatile(
-    _thread: Arc<Thread>,
-    mut arguments: Arguments,
+    thread: Arc<Thread>,
+    arguments: Arguments,
 ) -> Result<Option<Value>> {
-    #[expect(clippy::cast_sign_loss)]
-    let x = arguments.pop_int()? as u32;
-    let Some(x) = char::from_u32(x) else {
-        return Err(InternalError("putChar: Invalid character".to_string()));
-    };
-    let offset = usize::try_from(arguments.pop_long()?)?;
-    let Value::Object(ref mut object) = arguments.pop()? else {
-        return Err(InternalError("putChar: Invalid reference".to_string()));
-    };
-    let bytes = Reference::from(vec![x; offset]);
-    *object = Some(bytes);
-    Ok(None)
+    put_reference_type(&thread, arguments, Some(BaseType::Char)).await
 }
 
 #[async_recursion(?Send)]
@@ -1114,17 +1401,10 @@ pub(crate) async fn put_double(thread: Arc<Thread>, arguments: Arguments) -> Res
 
 #[async_recursion(?Send)]
 pub(crate) async fn put_double_volatile(
-    _thread: Arc<Thread>,
-    mut arguments: Arguments,
+    thread: Arc<Thread>,
+    arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let x = arguments.pop_double()?;
-    let offset = usize::try_from(arguments.pop_long()?)?;
-    let Value::Object(ref mut object) = arguments.pop()? else {
-        return Err(InternalError("putDouble: Invalid reference".to_string()));
-    };
-    let bytes = Reference::from(vec![x; offset]);
-    *object = Some(bytes);
-    Ok(None)
+    put_reference_type(&thread, arguments, Some(BaseType::Double)).await
 }
 
 #[async_recursion(?Send)]
@@ -1134,17 +1414,10 @@ pub(crate) async fn put_float(thread: Arc<Thread>, arguments: Arguments) -> Resu
 
 #[async_recursion(?Send)]
 pub(crate) async fn put_float_volatile(
-    _thread: Arc<Thread>,
-    mut arguments: Arguments,
+    thread: Arc<Thread>,
+    arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let x = arguments.pop_float()?;
-    let offset = usize::try_from(arguments.pop_long()?)?;
-    let Value::Object(ref mut object) = arguments.pop()? else {
-        return Err(InternalError("putFloat: Invalid reference".to_string()));
-    };
-    let bytes = Reference::from(vec![x; offset]);
-    *object = Some(bytes);
-    Ok(None)
+    put_reference_type(&thread, arguments, Some(BaseType::Float)).await
 }
 
 #[async_recursion(?Send)]
@@ -1154,17 +1427,10 @@ pub(crate) async fn put_int(thread: Arc<Thread>, arguments: Arguments) -> Result
 
 #[async_recursion(?Send)]
 pub(crate) async fn put_int_volatile(
-    _thread: Arc<Thread>,
-    mut arguments: Arguments,
+    thread: Arc<Thread>,
+    arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let x = arguments.pop_int()?;
-    let offset = usize::try_from(arguments.pop_long()?)?;
-    let Value::Object(ref mut object) = arguments.pop()? else {
-        return Err(InternalError("putInt: Invalid reference".to_string()));
-    };
-    let bytes = Reference::from(vec![x; offset]);
-    *object = Some(bytes);
-    Ok(None)
+    put_reference_type(&thread, arguments, Some(BaseType::Int)).await
 }
 
 #[async_recursion(?Send)]
@@ -1174,17 +1440,10 @@ pub(crate) async fn put_long(thread: Arc<Thread>, arguments: Arguments) -> Resul
 
 #[async_recursion(?Send)]
 pub(crate) async fn put_long_volatile(
-    _thread: Arc<Thread>,
-    mut arguments: Arguments,
+    thread: Arc<Thread>,
+    arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let x = arguments.pop_long()?;
-    let offset = usize::try_from(arguments.pop_long()?)?;
-    let Value::Object(ref mut object) = arguments.pop()? else {
-        return Err(InternalError("putlong: Invalid reference".to_string()));
-    };
-    let bytes = Reference::from(vec![x; offset]);
-    *object = Some(bytes);
-    Ok(None)
+    put_reference_type(&thread, arguments, Some(BaseType::Long)).await
 }
 
 #[async_recursion(?Send)]
@@ -1210,33 +1469,10 @@ pub(crate) async fn put_reference(
 
 #[async_recursion(?Send)]
 pub(crate) async fn put_reference_volatile(
-    _thread: Arc<Thread>,
-    mut arguments: Arguments,
+    thread: Arc<Thread>,
+    arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let x = arguments.pop()?;
-    let offset = arguments.pop_long()?;
-    let offset = usize::try_from(offset)?;
-    let Some(object) = arguments.pop_reference()? else {
-        return Err(InternalError(
-            "putReferenceVolatile: Invalid reference".to_string(),
-        ));
-    };
-    match object {
-        Reference::Array(_class, array) => {
-            let x = x.to_reference()?;
-            array.set(offset, x)?;
-        }
-        Reference::Object(object) => {
-            let field_name = object.class().field_name(offset)?;
-            object.set_value(&field_name, x)?;
-        }
-        _ => {
-            return Err(InternalError(
-                "putReferenceVolatile: Invalid reference".to_string(),
-            ));
-        }
-    }
-    Ok(None)
+    put_reference_type(&thread, arguments, None).await
 }
 
 #[async_recursion(?Send)]
@@ -1246,17 +1482,10 @@ pub(crate) async fn put_short(thread: Arc<Thread>, arguments: Arguments) -> Resu
 
 #[async_recursion(?Send)]
 pub(crate) async fn put_short_volatile(
-    _thread: Arc<Thread>,
-    mut arguments: Arguments,
+    thread: Arc<Thread>,
+    arguments: Arguments,
 ) -> Result<Option<Value>> {
-    let x = i16::try_from(arguments.pop_int()?)?;
-    let offset = usize::try_from(arguments.pop_long()?)?;
-    let Value::Object(ref mut object) = arguments.pop()? else {
-        return Err(InternalError("putShort: Invalid reference".to_string()));
-    };
-    let bytes = Reference::from(vec![x; offset]);
-    *object = Some(bytes);
-    Ok(None)
+    put_reference_type(&thread, arguments, Some(BaseType::Short)).await
 }
 
 #[async_recursion(?Send)]
@@ -1277,10 +1506,27 @@ pub(crate) async fn register_natives(
 
 #[async_recursion(?Send)]
 pub(crate) async fn set_memory_0(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.setMemory0(Ljava/lang/Object;JJB)V")
+    #[expect(clippy::cast_possible_truncation)]
+    #[expect(clippy::cast_sign_loss)]
+    let value = arguments.pop_int()? as u8;
+    let bytes = usize::try_from(arguments.pop_long()?)?;
+    let offset = arguments.pop_long()?;
+    let Some(reference) = arguments.pop_reference()? else {
+        let vm = thread.vm()?;
+        vm.native_memory().fill(offset, bytes, value)?;
+        return Ok(None);
+    };
+
+    if matches!(reference, Reference::Array(..) | Reference::Object(_)) {
+        return Err(InternalError(
+            "setMemory0: Cannot set memory of object references or fields".to_string(),
+        ));
+    }
+    write_array_bytes(&reference, offset, &vec![value; bytes])?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -1294,24 +1540,63 @@ pub(crate) async fn should_be_initialized_0(
 #[async_recursion(?Send)]
 pub(crate) async fn static_field_base_0(
     _thread: Arc<Thread>,
-    _arguments: Arguments,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.staticFieldBase0(Ljava/lang/reflect/Field;)Ljava/lang/Object;")
+    let Some(Reference::Object(field)) = arguments.pop_reference()? else {
+        return Err(InternalError(
+            "staticFieldBase0: Invalid field reference".to_string(),
+        ));
+    };
+    // The static fields of a class are accessed through the class object
+    let class_object = field.value("clazz")?;
+    if !matches!(class_object, Value::Object(Some(Reference::Object(_)))) {
+        return Err(InternalError(
+            "staticFieldBase0: Invalid class reference".to_string(),
+        ));
+    }
+    Ok(Some(class_object))
 }
 
 #[async_recursion(?Send)]
 pub(crate) async fn static_field_offset_0(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.staticFieldOffset0(Ljava/lang/reflect/Field;)J")
+    let Some(Reference::Object(field)) = arguments.pop_reference()? else {
+        return Err(InternalError(
+            "staticFieldOffset0: Invalid field reference".to_string(),
+        ));
+    };
+    let Value::Object(Some(Reference::Object(class_object))) = field.value("clazz")? else {
+        return Err(InternalError(
+            "staticFieldOffset0: Invalid class reference".to_string(),
+        ));
+    };
+    let field_name: String = field.value("name")?.try_into()?;
+    let class_name = class_object_name(&class_object)?;
+    let class = thread.class(&class_name).await?;
+    let Some(field_layout) = class
+        .field_layout()?
+        .into_iter()
+        .rev()
+        .find(|field_layout| field_layout.is_static && field_layout.name == field_name)
+    else {
+        return Err(InternalError(format!(
+            "staticFieldOffset0: Static field not found: {class_name}.{field_name}"
+        )));
+    };
+    let offset = STATIC_FIELD_OFFSET + i64::try_from(field_layout.offset)?;
+    Ok(Some(Value::Long(offset)))
 }
 
+/// Ensure that no load or store before the fence is reordered with a store after the fence; a
+/// release fence (`StoreStore` and `LoadStore` barriers).
 #[async_recursion(?Send)]
 pub(crate) async fn store_fence(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
+    fence(Ordering::Release);
     Ok(None)
 }
 
@@ -1332,8 +1617,15 @@ pub(crate) async fn unaligned_access_0(
 }
 
 #[async_recursion(?Send)]
-pub(crate) async fn unpark(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("jdk.internal.misc.Unsafe.unpark(Ljava/lang/Object;)V")
+pub(crate) async fn unpark(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let Some(java_thread) = arguments.pop_reference()? else {
+        return Ok(None);
+    };
+    // A thread that has not been started has no VM thread to unpark
+    if let Some(vm_thread) = find_thread(&thread, &java_thread).await? {
+        vm_thread.unpark();
+    }
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -1359,3 +1651,544 @@ pub(crate) async fn writeback_pre_sync_0(
 ) -> Result<Option<Value>> {
     todo!("jdk.internal.misc.Unsafe.writebackPreSync0()V")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+    use crate::native_methods::registry::RustMethod;
+    use crate::Error::JavaError;
+    use std::sync::atomic::AtomicI32;
+
+    /// Publish values from one OS thread to another with relaxed atomics that are ordered only by
+    /// the release and acquire fences; the subscriber must never observe a sequence number
+    /// without the value published before it.
+    fn publish_subscribe(
+        thread: &Arc<Thread>,
+        release: RustMethod,
+        acquire: RustMethod,
+    ) -> Result<()> {
+        const ROUNDS: i32 = 10_000;
+        let value = AtomicI32::new(0);
+        let sequence = AtomicI32::new(0);
+        let runtime = || {
+            tokio::runtime::Builder::new_current_thread()
+                .build()
+                .map_err(|error| InternalError(error.to_string()))
+        };
+
+        std::thread::scope(|scope| {
+            let publisher = scope.spawn(|| -> Result<()> {
+                let runtime = runtime()?;
+                for round in 1..=ROUNDS {
+                    value.store(round, Ordering::Relaxed);
+                    runtime.block_on(release(thread.clone(), Arguments::default()))?;
+                    sequence.store(round, Ordering::Relaxed);
+                }
+                Ok(())
+            });
+            let subscriber = scope.spawn(|| -> Result<()> {
+                let runtime = runtime()?;
+                let mut round = 0;
+                while round < ROUNDS {
+                    round = sequence.load(Ordering::Relaxed);
+                    runtime.block_on(acquire(thread.clone(), Arguments::default()))?;
+                    let current_value = value.load(Ordering::Relaxed);
+                    assert!(
+                        current_value >= round,
+                        "round {round}: value {current_value}"
+                    );
+                }
+                Ok(())
+            });
+            publisher.join().expect("publisher")?;
+            subscriber.join().expect("subscriber")
+        })
+    }
+
+    #[tokio::test]
+    async fn test_store_and_load_fences() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        publish_subscribe(&thread, store_fence, load_fence)
+    }
+
+    #[tokio::test]
+    async fn test_full_fence() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        assert_eq!(
+            None,
+            full_fence(thread.clone(), Arguments::default()).await?
+        );
+        publish_subscribe(&thread, full_fence, full_fence)
+    }
+
+    #[tokio::test]
+    async fn test_allocate_instance() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class("Simple").await?;
+        let class_object = class.to_object(&vm).await?;
+        let mut arguments = Arguments::default();
+        arguments.push(class_object);
+
+        let result = allocate_instance(thread, arguments).await?;
+        let Some(Value::Object(Some(Reference::Object(object)))) = result else {
+            panic!("expected object");
+        };
+        assert_eq!("Simple", object.class().name());
+        assert_eq!(Value::Int(0), object.value("protectedValue")?);
+        assert_eq!(Value::Int(0), object.value("defaultValue")?);
+        assert_eq!(Value::Int(0), object.value("privateValue")?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_static_field_base_and_offset() -> Result<()> {
+        let (vm, thread, _class) =
+            crate::test::class_with_host_io(Arc::new(crate::StdHostIo)).await?;
+        let class = thread.class("Simple").await?;
+        let class_object = class.to_object(&vm).await?;
+        let field = Object::new(thread.class("java/lang/reflect/Field").await?)?;
+        field.set_value("clazz", class_object.clone())?;
+        field.set_value("name", "ANSWER".to_object(&vm).await?)?;
+
+        let arguments = Arguments::new(vec![Value::from(field.clone())]);
+        let base = static_field_base_0(thread.clone(), arguments).await?;
+        assert_eq!(Some(class_object), base);
+        let Some(base) = base else {
+            panic!("expected base");
+        };
+        let arguments = Arguments::new(vec![Value::from(field)]);
+        let Some(Value::Long(offset)) = static_field_offset_0(thread.clone(), arguments).await?
+        else {
+            panic!("expected offset");
+        };
+
+        let arguments = Arguments::new(vec![base.clone(), Value::Long(offset), Value::Int(7)]);
+        assert_eq!(None, put_int(thread.clone(), arguments).await?);
+        assert_eq!(Value::Int(7), class.static_field("ANSWER")?.value()?);
+        let arguments = Arguments::new(vec![base.clone(), Value::Long(offset)]);
+        assert_eq!(
+            Some(Value::Int(7)),
+            get_int(thread.clone(), arguments).await?
+        );
+
+        let arguments = Arguments::new(vec![
+            base,
+            Value::Long(offset),
+            Value::Int(7),
+            Value::Int(42),
+        ]);
+        let result = compare_and_set_int(thread, arguments).await?;
+        assert_eq!(Some(Value::Int(1)), result);
+        assert_eq!(Value::Int(42), class.static_field("ANSWER")?.value()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_object_field_offset_0() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class("Child").await?;
+        let class_object = class.to_object(&vm).await?;
+        let field = Object::new(thread.class("java/lang/reflect/Field").await?)?;
+        field.set_value("clazz", class_object.clone())?;
+        field.set_value("name", "three".to_object(&vm).await?)?;
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(field));
+        let Some(Value::Long(offset)) = object_field_offset_0(thread.clone(), arguments).await?
+        else {
+            panic!("expected offset");
+        };
+
+        let mut arguments = Arguments::default();
+        arguments.push(class_object);
+        arguments.push("three".to_object(&vm).await?);
+        let result = object_field_offset_1(thread.clone(), arguments).await?;
+        assert_eq!(Some(Value::Long(offset)), result);
+
+        // Update the field at the offset and verify that no other field changed
+        let object = Object::new(class)?;
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(object.clone()));
+        arguments.push_long(offset);
+        arguments.push_int(0);
+        arguments.push_int(42);
+        let result = compare_and_set_int(thread, arguments).await?;
+        assert_eq!(Some(Value::Int(1)), result);
+        assert_eq!(Value::Int(42), object.value("three")?);
+        for field_name in ["zero", "one", "two"] {
+            assert_eq!(Value::Int(0), object.value(field_name)?);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_memory_0_native_memory() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push_long(16);
+        let Some(Value::Long(address)) = allocate_memory_0(thread.clone(), arguments).await? else {
+            panic!("expected address");
+        };
+
+        for (value, expected) in [(0x7F, 0x7F), (0, 0)] {
+            let mut arguments = Arguments::default();
+            arguments.push_reference(None);
+            arguments.push_long(address);
+            arguments.push_long(16);
+            arguments.push_int(value);
+            let result = set_memory_0(thread.clone(), arguments).await?;
+            assert_eq!(None, result);
+
+            for offset in 0..16 {
+                let mut arguments = Arguments::default();
+                arguments.push_reference(None);
+                arguments.push_long(address + offset);
+                let result = get_byte(thread.clone(), arguments).await?;
+                assert_eq!(Some(Value::Int(expected)), result);
+            }
+        }
+
+        let mut arguments = Arguments::default();
+        arguments.push_long(address);
+        free_memory_0(thread, arguments).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_memory_0_out_of_range() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push_long(4);
+        let Some(Value::Long(address)) = allocate_memory_0(thread.clone(), arguments).await? else {
+            panic!("expected address");
+        };
+
+        let mut arguments = Arguments::default();
+        arguments.push_reference(None);
+        arguments.push_long(address);
+        arguments.push_long(8);
+        arguments.push_int(0);
+        let result = set_memory_0(thread, arguments).await;
+        assert!(matches!(result, Err(InternalError(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_memory_0_array() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let array = Reference::from(vec![1i64, 2, 3, 4]);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array.clone()));
+        arguments.push_long(8);
+        arguments.push_long(16);
+        arguments.push_int(0);
+        set_memory_0(thread, arguments).await?;
+        let Reference::LongArray(values) = array else {
+            panic!("expected long array");
+        };
+        assert_eq!(vec![1, 0, 0, 4], values.to_vec()?);
+        Ok(())
+    }
+
+    fn copy_memory_arguments(
+        source: Option<Reference>,
+        source_offset: i64,
+        destination: Option<Reference>,
+        destination_offset: i64,
+        bytes: i64,
+    ) -> Arguments {
+        let mut arguments = Arguments::default();
+        arguments.push_reference(source);
+        arguments.push_long(source_offset);
+        arguments.push_reference(destination);
+        arguments.push_long(destination_offset);
+        arguments.push_long(bytes);
+        arguments
+    }
+
+    #[tokio::test]
+    async fn test_copy_memory_0_array() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let source = Reference::from(vec![1i8, 2, 3, 4, 5, 6, 7, 8]);
+        let destination = Reference::from(vec![0i8; 8]);
+        let arguments = copy_memory_arguments(
+            Some(source),
+            ARRAY_BASE_OFFSET + 2,
+            Some(destination.clone()),
+            ARRAY_BASE_OFFSET + 3,
+            4,
+        );
+        let result = copy_memory_0(thread, arguments).await?;
+        assert_eq!(None, result);
+        let Reference::ByteArray(values) = destination else {
+            panic!("expected byte array");
+        };
+        assert_eq!(vec![0, 0, 0, 3, 4, 5, 6, 0], values.to_vec()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_copy_memory_0_overlapping() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let array = Reference::from(vec![1i8, 2, 3, 4, 5, 6]);
+        let arguments = copy_memory_arguments(
+            Some(array.clone()),
+            ARRAY_BASE_OFFSET,
+            Some(array.clone()),
+            ARRAY_BASE_OFFSET + 2,
+            4,
+        );
+        copy_memory_0(thread, arguments).await?;
+        let Reference::ByteArray(values) = array else {
+            panic!("expected byte array");
+        };
+        assert_eq!(vec![1, 2, 1, 2, 3, 4], values.to_vec()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_array_bytes_out_of_range() -> Result<()> {
+        let array = Reference::from(vec![1i8, 2, 3, 4]);
+        let result = write_array_bytes(&array, ARRAY_BASE_OFFSET + 2, &[0; 4]);
+        assert!(matches!(result, Err(InternalError(_))));
+        let Reference::ByteArray(values) = array else {
+            panic!("expected byte array");
+        };
+        assert_eq!(vec![1, 2, 3, 4], values.to_vec()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_copy_memory_0_native_memory() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push_long(8);
+        let Some(Value::Long(address)) = allocate_memory_0(thread.clone(), arguments).await? else {
+            panic!("expected address");
+        };
+
+        // Copy the bytes of an int array to native memory and back to a byte array
+        let source = Reference::from(vec![0x0102_0304i32, 0x0506_0708]);
+        let arguments = copy_memory_arguments(Some(source), ARRAY_BASE_OFFSET, None, address, 8);
+        copy_memory_0(thread.clone(), arguments).await?;
+        let destination = Reference::from(vec![0i8; 4]);
+        let arguments = copy_memory_arguments(
+            None,
+            address + 4,
+            Some(destination.clone()),
+            ARRAY_BASE_OFFSET,
+            4,
+        );
+        copy_memory_0(thread.clone(), arguments).await?;
+        let Reference::ByteArray(values) = destination else {
+            panic!("expected byte array");
+        };
+        let bytes: Vec<u8> = values
+            .to_vec()?
+            .iter()
+            .map(|byte| byte.to_ne_bytes()[0])
+            .collect();
+        assert_eq!(0x0506_0708i32.to_ne_bytes().to_vec(), bytes);
+
+        let mut arguments = Arguments::default();
+        arguments.push_long(address);
+        free_memory_0(thread, arguments).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_copy_memory_0_out_of_range() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let source = Reference::from(vec![1i8, 2, 3, 4]);
+        let destination = Reference::from(vec![0i8; 2]);
+        let arguments = copy_memory_arguments(
+            Some(source),
+            ARRAY_BASE_OFFSET,
+            Some(destination.clone()),
+            ARRAY_BASE_OFFSET,
+            4,
+        );
+        let result = copy_memory_0(thread, arguments).await;
+        assert!(matches!(result, Err(InternalError(_))));
+        let Reference::ByteArray(values) = destination else {
+            panic!("expected byte array");
+        };
+        assert_eq!(vec![0, 0], values.to_vec()?);
+        Ok(())
+    }
+
+    async fn index_scale(class_name: &str) -> Result<i64> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class(class_name).await?;
+        let mut arguments = Arguments::default();
+        arguments.push(class.to_object(&vm).await?);
+        let Some(Value::Int(scale)) = array_index_scale_0(thread, arguments).await? else {
+            panic!("expected scale");
+        };
+        Ok(i64::from(scale))
+    }
+
+    #[tokio::test]
+    async fn test_array_index_scale_0() -> Result<()> {
+        assert_eq!(1, index_scale("[Z").await?);
+        assert_eq!(1, index_scale("[B").await?);
+        assert_eq!(2, index_scale("[C").await?);
+        assert_eq!(2, index_scale("[S").await?);
+        assert_eq!(4, index_scale("[I").await?);
+        assert_eq!(4, index_scale("[F").await?);
+        assert_eq!(8, index_scale("[J").await?);
+        assert_eq!(8, index_scale("[D").await?);
+        assert_eq!(
+            REFERENCE_INDEX_SCALE,
+            index_scale("[Ljava/lang/Object;").await?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_int_array_base_and_scale() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let Some(Value::Int(base)) =
+            array_base_offset_0(thread.clone(), Arguments::default()).await?
+        else {
+            panic!("expected base offset");
+        };
+        let scale = index_scale("[I").await?;
+        let values = vec![3, -1, 42, i32::MAX];
+        let array = Reference::from(values.clone());
+        for (index, expected) in (0i64..).zip(values) {
+            let mut arguments = Arguments::default();
+            arguments.push_reference(Some(array.clone()));
+            arguments.push_long(i64::from(base) + index * scale);
+            let result = get_int(thread.clone(), arguments).await?;
+            assert_eq!(Some(Value::Int(expected)), result);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_and_put_array_bytes() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let array = Reference::from(vec![1i32, 2]);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array.clone()));
+        arguments.push_long(0);
+        let result = get_long(thread.clone(), arguments).await?;
+        let mut expected = 1i32.to_ne_bytes().to_vec();
+        expected.extend(2i32.to_ne_bytes());
+        let expected = i64::from_ne_bytes(expected.try_into().expect("bytes"));
+        assert_eq!(Some(Value::Long(expected)), result);
+
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array.clone()));
+        arguments.push_long(4);
+        arguments.push_int(-1);
+        put_byte(thread.clone(), arguments).await?;
+        let mut expected = 2i32.to_ne_bytes();
+        expected[0] = 0xFF;
+        let Reference::IntArray(values) = array else {
+            panic!("expected int array");
+        };
+        assert_eq!(vec![1, i32::from_ne_bytes(expected)], values.to_vec()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_put_array_out_of_range() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let array = Reference::from(vec![1i16, 2]);
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(array.clone()));
+        arguments.push_long(2);
+        arguments.push_int(7);
+        let result = put_int(thread, arguments).await;
+        assert!(matches!(result, Err(InternalError(_))));
+        let Reference::ShortArray(values) = array else {
+            panic!("expected short array");
+        };
+        assert_eq!(vec![1, 2], values.to_vec()?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_arrays_equals() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        for (other, expected) in [(vec![1, 2, 3, 4, 5], true), (vec![1, 2, 3, 0, 5], false)] {
+            let result = vm
+                .try_invoke(
+                    "java.util.Arrays",
+                    "equals",
+                    "([I[I)Z",
+                    vec![Value::from(vec![1i32, 2, 3, 4, 5]), Value::from(other)],
+                )
+                .await?;
+            assert_eq!(Value::from(expected), result);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_memory_0_object() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let object = Object::new(thread.class("Child").await?)?;
+        let mut arguments = Arguments::default();
+        arguments.push(Value::from(object));
+        arguments.push_long(0);
+        arguments.push_long(1);
+        arguments.push_int(0);
+        let result = set_memory_0(thread, arguments).await;
+        assert!(matches!(result, Err(InternalError(_))));
+        Ok(())
+    }
+
+    fn park_arguments(is_absolute: bool, time: i64) -> Arguments {
+        let mut arguments = Arguments::default();
+        arguments.push_int(i32::from(is_absolute));
+        arguments.push_long(time);
+        arguments
+    }
+
+    #[tokio::test]
+    async fn test_park_unpark() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let java_thread = Reference::Object(Object::new(thread.class("java/lang/Thread").await?)?);
+        thread
+            .set_java_object(Value::Object(Some(java_thread.clone())))
+            .await;
+
+        // An unpark before the park lets the park return immediately
+        let mut arguments = Arguments::default();
+        arguments.push_reference(Some(java_thread));
+        unpark(thread.clone(), arguments).await?;
+        park(thread.clone(), park_arguments(false, 0)).await?;
+
+        park(thread.clone(), park_arguments(false, 1_000)).await?;
+        park(thread, park_arguments(true, 1)).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_park_interrupted() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        thread.interrupt();
+        park(thread.clone(), park_arguments(false, 0)).await?;
+        assert!(thread.is_interrupted());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_allocate_instance_interface() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class("SimpleInterface").await?;
+        let class_object = class.to_object(&vm).await?;
+        let mut arguments = Arguments::default();
+        arguments.push(class_object);
+
+        let result = allocate_instance(thread, arguments).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(crate::JavaError::InstantiationException(_)))
+        ));
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::arguments::Arguments;
-use crate::java_object::JavaObject;
+use crate::java_object::{class_object_name, JavaObject};
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
 use crate::Error::InternalError;
@@ -68,7 +68,7 @@ async fn get_class_access_flags(
             "getClassAccessFlags: no arguments".to_string(),
         ));
     };
-    let class_name: String = object.value("name")?.try_into()?;
+    let class_name = class_object_name(&object)?;
     let class = thread.class(&class_name).await?;
     let class_file = class.class_file();
     let access_flags = &class_file.access_flags;
@@ -1,4 +1,5 @@
 use crate::arguments::Arguments;
+use crate::java_object::JavaObject;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
 use crate::Result;
@@ -24,17 +25,59 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 }
 
 #[async_recursion(?Send)]
-async fn get_vm_temporary_directory(
-    _thread: Arc<Thread>,
+pub(crate) async fn get_vm_temporary_directory(
+    thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.VMSupport.getVMTemporaryDirectory()Ljava/lang/String;")
+    let vm = thread.vm()?;
+    let temporary_directory = std::env::temp_dir().to_string_lossy().to_string();
+    let temporary_directory = temporary_directory.to_object(&vm).await?;
+    Ok(Some(temporary_directory))
 }
 
 #[async_recursion(?Send)]
-async fn init_agent_properties(
+pub(crate) async fn init_agent_properties(
     _thread: Arc<Thread>,
-    _arguments: Arguments,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("jdk.internal.vm.VMSupport.initAgentProperties(Ljava/util/Properties;)Ljava/util/Properties;")
+    // No agent specific properties are currently supported; return the properties unmodified
+    let properties = arguments.pop()?;
+    Ok(Some(properties))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_vm_temporary_directory() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let result = get_vm_temporary_directory(thread, Arguments::default()).await?;
+        let temporary_directory: String = result.expect("temporary directory").try_into()?;
+        assert!(!temporary_directory.is_empty());
+        assert_eq!(
+            std::env::temp_dir().to_string_lossy().to_string(),
+            temporary_directory
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_init_agent_properties() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let properties = thread
+            .object("java/util/Properties", "", Vec::<Value>::new())
+            .await?;
+        let mut arguments = Arguments::default();
+        arguments.push(properties.clone());
+        let result = init_agent_properties(thread, arguments).await?;
+        let Some(Value::Object(Some(result))) = result else {
+            panic!("expected properties");
+        };
+        let Value::Object(Some(properties)) = properties else {
+            panic!("expected properties");
+        };
+        assert!(result.ptr_eq(&properties));
+        Ok(())
+    }
 }
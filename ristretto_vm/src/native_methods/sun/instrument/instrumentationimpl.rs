@@ -1,15 +1,28 @@
 use crate::arguments::Arguments;
+use crate::java_object::{class_object_name, JavaObject};
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
+use crate::Error::InternalError;
+use crate::JavaError::{NullPointerException, UnsupportedOperationException};
 use crate::Result;
 use async_recursion::async_recursion;
-use ristretto_classfile::Version;
-use ristretto_classloader::Value;
+use ristretto_classfile::{BaseType, FieldType, Version};
+use ristretto_classloader::{Reference, Value};
+use std::collections::HashSet;
 use std::sync::Arc;
 
 const JAVA_11: Version = Version::Java11 { minor: 0 };
 const JAVA_21: Version = Version::Java21 { minor: 0 };
 
+/// The size of the header of an object; a mark word and a compressed class pointer.
+const OBJECT_HEADER_SIZE: usize = 12;
+/// The size of the header of an array; the object header and the array length.
+const ARRAY_HEADER_SIZE: usize = 16;
+/// The size of a compressed object reference.
+const REFERENCE_SIZE: usize = 4;
+/// The alignment of objects in memory.
+const OBJECT_ALIGNMENT: usize = 8;
+
 /// Register all native methods for `sun.instrument.InstrumentationImpl`.
 pub(crate) fn register(registry: &mut MethodRegistry) {
     let class_name = "sun/instrument/InstrumentationImpl";
@@ -106,10 +119,37 @@ async fn append_to_class_loader_search_0(
 
 #[async_recursion(?Send)]
 async fn get_all_loaded_classes_0(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.getAllLoadedClasses0(J)[Ljava/lang/Class;")
+    let _native_agent = arguments.pop_long()?;
+    let vm = thread.vm()?;
+
+    // A class loaded through a parent class loader is also recorded by the class loaders that
+    // delegated to it; each class is only reported once.
+    let mut classes = Vec::new();
+    {
+        let class_loader_lock = vm.class_loader();
+        let class_loader = class_loader_lock.read().await;
+        let mut class_names = HashSet::new();
+        let mut current_class_loader = Some(&*class_loader);
+        while let Some(class_loader) = current_class_loader {
+            for class in class_loader.classes().await {
+                if class_names.insert(class.name().to_string()) {
+                    classes.push(class);
+                }
+            }
+            current_class_loader = class_loader.parent();
+        }
+    }
+
+    let mut class_objects = Vec::with_capacity(classes.len());
+    for class in classes {
+        class_objects.push(class.to_object(&vm).await?);
+    }
+    let class_array = thread.class("[Ljava/lang/Class;").await?;
+    let class_objects = Reference::try_from((class_array, class_objects))?;
+    Ok(Some(Value::from(class_objects)))
 }
 
 #[async_recursion(?Send)]
@@ -121,24 +161,78 @@ async fn get_initiated_classes_0(
 }
 
 #[async_recursion(?Send)]
-async fn get_object_size_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.getObjectSize0(JLjava/lang/Object;)J")
+async fn get_object_size_0(
+    _thread: Arc<Thread>,
+    mut arguments: Arguments,
+) -> Result<Option<Value>> {
+    let Some(reference) = arguments.pop_reference()? else {
+        return Err(NullPointerException("object is null".to_string()).into());
+    };
+    let _native_agent = arguments.pop_long()?;
+    let size = i64::try_from(shallow_size(&reference)?)?;
+    Ok(Some(Value::Long(size)))
+}
+
+/// Returns the shallow size of the object in bytes, laid out as on a 64-bit `HotSpot` VM with
+/// compressed class pointers and references; objects referenced by the object are not included.
+fn shallow_size(reference: &Reference) -> Result<usize> {
+    let size = match reference {
+        Reference::ByteArray(array) => ARRAY_HEADER_SIZE + array.len()?,
+        Reference::CharArray(array) => ARRAY_HEADER_SIZE + array.len()? * 2,
+        Reference::ShortArray(array) => ARRAY_HEADER_SIZE + array.len()? * 2,
+        Reference::IntArray(array) => ARRAY_HEADER_SIZE + array.len()? * 4,
+        Reference::LongArray(array) => ARRAY_HEADER_SIZE + array.len()? * 8,
+        Reference::FloatArray(array) => ARRAY_HEADER_SIZE + array.len()? * 4,
+        Reference::DoubleArray(array) => ARRAY_HEADER_SIZE + array.len()? * 8,
+        Reference::Array(_, array) => ARRAY_HEADER_SIZE + array.len()? * REFERENCE_SIZE,
+        Reference::Object(object) => {
+            let fields_size: usize = object
+                .fields()
+                .iter()
+                .map(|field| field_size(field.field_type()))
+                .sum();
+            OBJECT_HEADER_SIZE + fields_size
+        }
+    };
+    Ok(size.next_multiple_of(OBJECT_ALIGNMENT))
+}
+
+/// Returns the number of bytes used to store a field of the given type.
+fn field_size(field_type: &FieldType) -> usize {
+    match field_type {
+        FieldType::Base(BaseType::Boolean | BaseType::Byte) => 1,
+        FieldType::Base(BaseType::Char | BaseType::Short) => 2,
+        FieldType::Base(BaseType::Int | BaseType::Float) => 4,
+        FieldType::Base(BaseType::Long | BaseType::Double) => 8,
+        FieldType::Object(_) | FieldType::Array(_) => REFERENCE_SIZE,
+    }
 }
 
 #[async_recursion(?Send)]
 async fn is_modifiable_class_0(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.isModifiableClass0(JLjava/lang/Class;)Z")
+    let Some(Reference::Object(class_object)) = arguments.pop_reference()? else {
+        return Err(InternalError(
+            "isModifiableClass0: Invalid class reference".to_string(),
+        ));
+    };
+    let _native_agent = arguments.pop_long()?;
+    let class_name = class_object_name(&class_object)?;
+    let class = thread.load_class(class_name).await?;
+    // As with HotSpot, primitive and array classes are never modifiable
+    let modifiable = !class.is_primitive() && !class.is_array();
+    Ok(Some(Value::from(modifiable)))
 }
 
 #[async_recursion(?Send)]
 async fn is_retransform_classes_supported_0(
     _thread: Arc<Thread>,
-    _arguments: Arguments,
+    mut arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.isRetransformClassesSupported0(J)Z")
+    let _native_agent = arguments.pop_long()?;
+    Ok(Some(Value::from(false)))
 }
 
 #[async_recursion(?Send)]
@@ -153,7 +247,7 @@ async fn load_agent_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Opt
 
 #[async_recursion(?Send)]
 async fn redefine_classes_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.redefineClasses0(J[Ljava/lang/instrument/ClassDefinition;)V")
+    Err(UnsupportedOperationException("class redefinition is not supported".to_string()).into())
 }
 
 #[async_recursion(?Send)]
@@ -161,23 +255,27 @@ async fn retransform_classes_0(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.retransformClasses0(J[Ljava/lang/Class;)V")
+    Err(UnsupportedOperationException("class retransformation is not supported".to_string()).into())
 }
 
+/// Class file transformers are never invoked, because classes are not transformed when they are
+/// loaded; registering a transformer has no effect.
 #[async_recursion(?Send)]
 async fn set_has_retransformable_transformers(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.setHasRetransformableTransformers(JZ)V")
+    Ok(None)
 }
 
+/// Class file transformers are never invoked, because classes are not transformed when they are
+/// loaded; registering a transformer has no effect.
 #[async_recursion(?Send)]
 async fn set_has_transformers(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.setHasTransformers(JZ)V")
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -185,5 +283,107 @@ async fn set_native_method_prefixes(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("sun.instrument.InstrumentationImpl.setNativeMethodPrefixes(J[Ljava/lang/String;Z)V")
+    Err(
+        UnsupportedOperationException("native method prefixes are not supported".to_string())
+            .into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error::JavaError;
+
+    #[tokio::test]
+    async fn test_get_all_loaded_classes_0() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let _class = thread.class("java/util/AbstractMap").await?;
+        let mut arguments = Arguments::default();
+        arguments.push_long(0);
+        let result = get_all_loaded_classes_0(thread, arguments).await?;
+        let Some(Value::Object(Some(Reference::Array(_, classes)))) = result else {
+            panic!("expected class array");
+        };
+        let mut names = Vec::new();
+        for class in classes.to_vec()? {
+            let Some(Reference::Object(class)) = class else {
+                panic!("expected class object");
+            };
+            names.push(class_object_name(&class)?);
+        }
+        assert!(names.contains(&"java/lang/Object".to_string()));
+        assert!(names.contains(&"java/util/AbstractMap".to_string()));
+        let unique_names = names.iter().collect::<HashSet<_>>();
+        assert_eq!(names.len(), unique_names.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_object_size_0() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push_long(0);
+        arguments.push_reference(Some(Reference::from(vec![0i32; 3])));
+        let result = get_object_size_0(thread, arguments).await?;
+        assert_eq!(Some(Value::Long(32)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_object_size_0_null() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push_long(0);
+        arguments.push_reference(None);
+        let result = get_object_size_0(thread, arguments).await;
+        assert!(matches!(result, Err(JavaError(NullPointerException(_)))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_shallow_size() -> Result<()> {
+        assert_eq!(16, shallow_size(&Reference::from(Vec::<i8>::new()))?);
+        assert_eq!(24, shallow_size(&Reference::from(vec![0i8; 5]))?);
+        assert_eq!(32, shallow_size(&Reference::from(vec![0i64; 2]))?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_is_modifiable_class_0() -> Result<()> {
+        let (vm, thread, _class) = crate::test::class().await?;
+        for (class_name, expected) in [
+            ("java/lang/String", true),
+            ("int", false),
+            ("[Ljava/lang/String;", false),
+        ] {
+            let class = thread.class(class_name).await?;
+            let mut arguments = Arguments::default();
+            arguments.push_long(0);
+            arguments.push(class.to_object(&vm).await?);
+            let result = is_modifiable_class_0(thread.clone(), arguments).await?;
+            assert_eq!(Some(Value::from(expected)), result, "{class_name}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_is_retransform_classes_supported_0() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push_long(0);
+        let result = is_retransform_classes_supported_0(thread, arguments).await?;
+        assert_eq!(Some(Value::from(false)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redefine_classes_0() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let result = redefine_classes_0(thread, Arguments::default()).await;
+        assert!(matches!(
+            result,
+            Err(JavaError(UnsupportedOperationException(_)))
+        ));
+        Ok(())
+    }
 }
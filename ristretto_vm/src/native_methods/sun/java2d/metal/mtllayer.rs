@@ -32,30 +32,49 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn blit_texture(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.blitTexture(J)V")
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
 async fn native_create_layer(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.nativeCreateLayer()J")
+    Ok(Some(Value::Long(0)))
 }
 
 #[async_recursion(?Send)]
 async fn native_set_insets(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.nativeSetInsets(JII)V")
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
 async fn native_set_opaque(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.nativeSetOpaque(JZ)V")
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
 async fn native_set_scale(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.nativeSetScale(JD)V")
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
 async fn validate(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLLayer.validate(JLsun/java2d/metal/MTLSurfaceData;)V")
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_headless_layer() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let result = native_create_layer(thread.clone(), Arguments::default()).await?;
+        assert_eq!(Some(Value::Long(0)), result);
+        assert_eq!(
+            None,
+            native_set_scale(thread.clone(), Arguments::default()).await?
+        );
+        assert_eq!(None, validate(thread.clone(), Arguments::default()).await?);
+        assert_eq!(None, blit_texture(thread, Arguments::default()).await?);
+        Ok(())
+    }
 }
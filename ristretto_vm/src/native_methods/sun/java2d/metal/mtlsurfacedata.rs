@@ -55,7 +55,7 @@ async fn init_flip_backbuffer(
 
 #[async_recursion(?Send)]
 async fn init_ops(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.metal.MTLSurfaceData.initOps(Lsun/java2d/metal/MTLGraphicsConfig;JJJIIZ)V");
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -1,3 +1,8 @@
+//! Native methods for `sun.java2d`.  The VM has no graphics device, so the graphics
+//! configuration, surface and layer natives act as a headless backend: configuration info and
+//! layer handles are 0, capability queries report nothing available, and initialization does
+//! nothing.  Rendering natives are not implemented.
+
 pub(crate) mod cmm;
 pub(crate) mod crenderer;
 pub(crate) mod defaultdisposerrecord;
@@ -11,3 +16,28 @@ pub(crate) mod pipe;
 pub(crate) mod surfacedata;
 pub(crate) mod x11;
 pub(crate) mod xr;
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConfigurationBuilder, Result, VM};
+    use ristretto_classloader::Value;
+
+    #[tokio::test]
+    #[ignore = "GraphicsEnvironment.isHeadless() executes invokedynamic, which is not supported yet"]
+    async fn test_headless_default_toolkit() -> Result<()> {
+        let configuration = ConfigurationBuilder::new()
+            .add_system_property("java.awt.headless", "true")
+            .build()?;
+        let vm = VM::new(configuration).await?;
+        let toolkit = vm
+            .invoke(
+                "java.awt.Toolkit",
+                "getDefaultToolkit",
+                "()Ljava/awt/Toolkit;",
+                Vec::<Value>::new(),
+            )
+            .await?;
+        assert!(matches!(toolkit, Some(Value::Object(Some(_)))));
+        Ok(())
+    }
+}
@@ -42,7 +42,7 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_cgl_config_info(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLGraphicsConfig.getCGLConfigInfo(III)J")
+    Ok(Some(Value::Long(0)))
 }
 
 #[async_recursion(?Send)]
@@ -50,12 +50,12 @@ async fn get_ogl_capabilities(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLGraphicsConfig.getOGLCapabilities(J)I")
+    Ok(Some(Value::Int(0)))
 }
 
 #[async_recursion(?Send)]
 async fn init_cgl(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLGraphicsConfig.initCGL()Z")
+    Ok(Some(Value::from(false)))
 }
 
 #[async_recursion(?Send)]
@@ -63,5 +63,24 @@ async fn native_get_max_texture_size(
     _thread: Arc<Thread>,
     _arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLGraphicsConfig.nativeGetMaxTextureSize()I")
+    Ok(Some(Value::Int(0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_headless_config() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let result = init_cgl(thread.clone(), Arguments::default()).await?;
+        assert_eq!(Some(Value::from(false)), result);
+        let result = get_cgl_config_info(thread.clone(), Arguments::default()).await?;
+        assert_eq!(Some(Value::Long(0)), result);
+        let result = get_ogl_capabilities(thread.clone(), Arguments::default()).await?;
+        assert_eq!(Some(Value::Int(0)), result);
+        let result = native_get_max_texture_size(thread, Arguments::default()).await?;
+        assert_eq!(Some(Value::Int(0)), result);
+        Ok(())
+    }
 }
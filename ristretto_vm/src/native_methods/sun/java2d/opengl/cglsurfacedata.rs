@@ -60,7 +60,7 @@ async fn destroy_cgl_context(_thread: Arc<Thread>, _arguments: Arguments) -> Res
 
 #[async_recursion(?Send)]
 async fn init_ops(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.opengl.CGLSurfaceData.initOps(Lsun/java2d/opengl/OGLGraphicsConfig;JJJIIZ)V");
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -45,10 +45,10 @@ async fn init_surface(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Opt
 
 #[async_recursion(?Send)]
 async fn is_dga_available(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11SurfaceData.isDgaAvailable()Z")
+    Ok(Some(Value::from(false)))
 }
 
 #[async_recursion(?Send)]
 async fn is_shm_pm_available(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.X11SurfaceData.isShmPMAvailable()Z")
+    Ok(Some(Value::from(false)))
 }
@@ -72,7 +72,7 @@ async fn flush_native_surface(
 
 #[async_recursion(?Send)]
 async fn init_ops(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.java2d.x11.XSurfaceData.initOps(Lsun/awt/X11ComponentPeer;Lsun/awt/X11GraphicsConfig;I)V");
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
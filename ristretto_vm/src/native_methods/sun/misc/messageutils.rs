@@ -1,9 +1,10 @@
 use crate::arguments::Arguments;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
+use crate::Error::InternalError;
 use crate::Result;
 use async_recursion::async_recursion;
-use ristretto_classloader::Value;
+use ristretto_classloader::{Reference, Value};
 use std::sync::Arc;
 
 /// Register all native methods for `sun.misc.MessageUtils`.
@@ -13,12 +14,65 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     registry.register(class_name, "toStdout", "(Ljava/lang/String;)V", to_stdout);
 }
 
+/// Get the message argument; a null message is printed as "null".
+fn message(arguments: &mut Arguments) -> Result<String> {
+    let message = match arguments.pop_reference()? {
+        Some(Reference::Object(message)) => message.try_into()?,
+        None => "null".to_string(),
+        Some(_) => {
+            return Err(InternalError(
+                "Invalid message; expected string".to_string(),
+            ))
+        }
+    };
+    Ok(message)
+}
+
 #[async_recursion(?Send)]
-async fn to_stderr(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.MessageUtils.toStderr(Ljava/lang/String;)V")
+async fn to_stderr(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let message = message(&mut arguments)?;
+    let vm = thread.vm()?;
+    vm.configuration()
+        .host_io()
+        .write_stderr(message.as_bytes())
+        .map_err(|error| InternalError(error.to_string()))?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
-async fn to_stdout(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.MessageUtils.toStdout(Ljava/lang/String;)V")
+async fn to_stdout(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let message = message(&mut arguments)?;
+    let vm = thread.vm()?;
+    vm.configuration()
+        .host_io()
+        .write_stdout(message.as_bytes())
+        .map_err(|error| InternalError(error.to_string()))?;
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+    use crate::test::MemoryHostIo;
+
+    #[tokio::test]
+    async fn test_to_stdout_and_stderr() -> Result<()> {
+        let host_io = Arc::new(MemoryHostIo::default());
+        let (vm, thread, _class) = crate::test::class_with_host_io(host_io.clone()).await?;
+
+        let mut arguments = Arguments::default();
+        arguments.push("foo".to_object(&vm).await?);
+        to_stdout(thread.clone(), arguments).await?;
+        let mut arguments = Arguments::default();
+        arguments.push_reference(None);
+        to_stdout(thread.clone(), arguments).await?;
+        let mut arguments = Arguments::default();
+        arguments.push("bar".to_object(&vm).await?);
+        to_stderr(thread, arguments).await?;
+
+        assert_eq!(b"foonull".to_vec(), host_io.stdout());
+        assert_eq!(b"bar".to_vec(), host_io.stderr());
+        Ok(())
+    }
 }
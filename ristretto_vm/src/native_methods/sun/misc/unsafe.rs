@@ -344,6 +344,24 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     registry.register(class_name, "unpark", "(Ljava/lang/Object;)V", unpark);
 }
 
+/// Convert the arguments of a method that accesses an absolute address (e.g. `getInt(J)I`) to the
+/// arguments of the equivalent method that accesses an offset of an object (e.g.
+/// `getInt(Ljava/lang/Object;J)I`) with a null object.
+fn null_object(mut arguments: Arguments) -> Result<Arguments> {
+    let mut values = Vec::new();
+    while arguments.len() > 1 {
+        values.push(arguments.pop()?);
+    }
+    let address = arguments.pop_long()?;
+    let mut null_object_arguments = Arguments::default();
+    null_object_arguments.push_reference(None);
+    null_object_arguments.push_long(address);
+    for value in values.into_iter().rev() {
+        null_object_arguments.push(value);
+    }
+    Ok(null_object_arguments)
+}
+
 #[async_recursion(?Send)]
 async fn address_size(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
     jdk::internal::misc::r#unsafe::address_size_0(thread, arguments).await
@@ -375,11 +393,8 @@ async fn compare_and_swap_int(thread: Arc<Thread>, arguments: Arguments) -> Resu
 }
 
 #[async_recursion(?Send)]
-async fn compare_and_swap_long(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
-) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.compareAndSwapLong(Ljava/lang/Object;JJJ)Z")
+async fn compare_and_swap_long(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::compare_and_set_long(thread, arguments).await
 }
 
 #[async_recursion(?Send)]
@@ -427,8 +442,8 @@ async fn full_fence(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<
 }
 
 #[async_recursion(?Send)]
-async fn get_address(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getAddress(J)J")
+async fn get_address(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::get_long(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -442,8 +457,8 @@ async fn get_boolean_volatile(thread: Arc<Thread>, arguments: Arguments) -> Resu
 }
 
 #[async_recursion(?Send)]
-async fn get_byte_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getByte(J)B")
+async fn get_byte_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::get_byte(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -457,8 +472,8 @@ async fn get_byte_volatile(thread: Arc<Thread>, arguments: Arguments) -> Result<
 }
 
 #[async_recursion(?Send)]
-async fn get_char_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getChar(J)C")
+async fn get_char_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::get_char(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -472,8 +487,8 @@ async fn get_char_volatile(thread: Arc<Thread>, arguments: Arguments) -> Result<
 }
 
 #[async_recursion(?Send)]
-async fn get_double_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getDouble(J)D")
+async fn get_double_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::get_double(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -487,8 +502,8 @@ async fn get_double_volatile(thread: Arc<Thread>, arguments: Arguments) -> Resul
 }
 
 #[async_recursion(?Send)]
-async fn get_float_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getFloat(J)F")
+async fn get_float_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::get_float(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -502,8 +517,8 @@ async fn get_float_volatile(thread: Arc<Thread>, arguments: Arguments) -> Result
 }
 
 #[async_recursion(?Send)]
-async fn get_int_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getInt(J)I")
+async fn get_int_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::get_int(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -522,8 +537,8 @@ async fn get_load_average(thread: Arc<Thread>, arguments: Arguments) -> Result<O
 }
 
 #[async_recursion(?Send)]
-async fn get_long_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getLong(J)J")
+async fn get_long_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::get_long(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -547,8 +562,8 @@ async fn get_object_volatile(thread: Arc<Thread>, arguments: Arguments) -> Resul
 }
 
 #[async_recursion(?Send)]
-async fn get_short_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.getShort(J)S")
+async fn get_short_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::get_short(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -592,8 +607,8 @@ async fn park(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>
 }
 
 #[async_recursion(?Send)]
-async fn put_address(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putAddress(JJ)V")
+async fn put_address(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_long(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -607,8 +622,8 @@ async fn put_boolean_volatile(thread: Arc<Thread>, arguments: Arguments) -> Resu
 }
 
 #[async_recursion(?Send)]
-async fn put_byte_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putByte(JB)V")
+async fn put_byte_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_byte(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -622,8 +637,8 @@ async fn put_byte_volatile(thread: Arc<Thread>, arguments: Arguments) -> Result<
 }
 
 #[async_recursion(?Send)]
-async fn put_char_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putChar(JC)V")
+async fn put_char_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_char(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -637,8 +652,8 @@ async fn put_char_volatile(thread: Arc<Thread>, arguments: Arguments) -> Result<
 }
 
 #[async_recursion(?Send)]
-async fn put_double_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putDouble(JD)V")
+async fn put_double_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_double(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -652,8 +667,8 @@ async fn put_double_volatile(thread: Arc<Thread>, arguments: Arguments) -> Resul
 }
 
 #[async_recursion(?Send)]
-async fn put_float_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putFloat(JF)V")
+async fn put_float_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_float(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -667,8 +682,8 @@ async fn put_float_volatile(thread: Arc<Thread>, arguments: Arguments) -> Result
 }
 
 #[async_recursion(?Send)]
-async fn put_int_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putInt(JI)V")
+async fn put_int_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_int(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -682,8 +697,8 @@ async fn put_int_volatile(thread: Arc<Thread>, arguments: Arguments) -> Result<O
 }
 
 #[async_recursion(?Send)]
-async fn put_long_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putLong(JJ)V")
+async fn put_long_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_long(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
@@ -707,33 +722,33 @@ async fn put_object_volatile(thread: Arc<Thread>, arguments: Arguments) -> Resul
 }
 
 #[async_recursion(?Send)]
-async fn put_ordered_int(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putOrderedInt(Ljava/lang/Object;JI)V")
+async fn put_ordered_int(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_int_volatile(thread, arguments).await
 }
 
 #[async_recursion(?Send)]
-async fn put_ordered_long(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putOrderedLong(Ljava/lang/Object;JJ)V")
+async fn put_ordered_long(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_long_volatile(thread, arguments).await
 }
 
 #[async_recursion(?Send)]
-async fn put_ordered_object(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putOrderedObject(Ljava/lang/Object;JLjava/lang/Object;)V")
+async fn put_ordered_object(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_reference_volatile(thread, arguments).await
 }
 
 #[async_recursion(?Send)]
-async fn put_short_1(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putShort(JS)V")
+async fn put_short_1(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_short(thread, null_object(arguments)?).await
 }
 
 #[async_recursion(?Send)]
-async fn put_short_2(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putShort(Ljava/lang/Object;JS)V")
+async fn put_short_2(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_short(thread, arguments).await
 }
 
 #[async_recursion(?Send)]
-async fn put_short_volatile(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.misc.Unsafe.putShortVolatile(Ljava/lang/Object;JS)V")
+async fn put_short_volatile(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::misc::r#unsafe::put_short_volatile(thread, arguments).await
 }
 
 #[async_recursion(?Send)]
@@ -785,3 +800,89 @@ async fn try_monitor_enter(_thread: Arc<Thread>, _arguments: Arguments) -> Resul
 async fn unpark(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
     jdk::internal::misc::r#unsafe::unpark(thread, arguments).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::native_methods::registry::MethodRegistry;
+    use ristretto_classfile::Version;
+    use ristretto_classloader::Object;
+
+    /// Invoke a `sun.misc.Unsafe` method registered for Java 8.
+    async fn invoke(
+        thread: &Arc<Thread>,
+        method_name: &str,
+        method_descriptor: &str,
+        arguments: Arguments,
+    ) -> Result<Option<Value>> {
+        let registry = MethodRegistry::new(&Version::Java8 { minor: 0 });
+        let method = registry
+            .method("sun/misc/Unsafe", method_name, method_descriptor)
+            .expect("method");
+        method(thread.clone(), arguments).await
+    }
+
+    #[tokio::test]
+    async fn test_compare_and_swap_int() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let class = thread.class("Child").await?;
+        let offset = i64::try_from(class.field_offset("three")?)?;
+        let object = Object::new(class)?;
+
+        for (expected, x, result, value) in [(0, 42, 1, 42), (0, 7, 0, 42), (42, 7, 1, 7)] {
+            let mut arguments = Arguments::default();
+            arguments.push(Value::from(object.clone()));
+            arguments.push_long(offset);
+            arguments.push_int(expected);
+            arguments.push_int(x);
+            let swapped = invoke(
+                &thread,
+                "compareAndSwapInt",
+                "(Ljava/lang/Object;JII)Z",
+                arguments,
+            )
+            .await?;
+            assert_eq!(Some(Value::Int(result)), swapped);
+            assert_eq!(Value::Int(value), object.value("three")?);
+        }
+        for field_name in ["zero", "one", "two"] {
+            assert_eq!(Value::Int(0), object.value(field_name)?);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_put_and_get_address() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        let mut arguments = Arguments::default();
+        arguments.push_long(16);
+        let Some(Value::Long(address)) =
+            invoke(&thread, "allocateMemory", "(J)J", arguments).await?
+        else {
+            panic!("expected address");
+        };
+
+        let mut arguments = Arguments::default();
+        arguments.push_long(address);
+        arguments.push_int(42);
+        invoke(&thread, "putInt", "(JI)V", arguments).await?;
+        let mut arguments = Arguments::default();
+        arguments.push_long(address);
+        let value = invoke(&thread, "getInt", "(J)I", arguments).await?;
+        assert_eq!(Some(Value::Int(42)), value);
+
+        let mut arguments = Arguments::default();
+        arguments.push_long(address + 8);
+        arguments.push_long(address);
+        invoke(&thread, "putAddress", "(JJ)V", arguments).await?;
+        let mut arguments = Arguments::default();
+        arguments.push_long(address + 8);
+        let value = invoke(&thread, "getAddress", "(J)J", arguments).await?;
+        assert_eq!(Some(Value::Long(address)), value);
+
+        let mut arguments = Arguments::default();
+        arguments.push_long(address);
+        invoke(&thread, "freeMemory", "(J)V", arguments).await?;
+        Ok(())
+    }
+}
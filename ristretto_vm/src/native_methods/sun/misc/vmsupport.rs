@@ -1,4 +1,5 @@
 use crate::arguments::Arguments;
+use crate::native_methods::jdk;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
 use crate::Result;
@@ -25,16 +26,13 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
 
 #[async_recursion(?Send)]
 async fn get_vm_temporary_directory(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
+    thread: Arc<Thread>,
+    arguments: Arguments,
 ) -> Result<Option<Value>> {
-    todo!("sun.misc.VMSupport.getVMTemporaryDirectory()Ljava/lang/String;")
+    jdk::internal::vm::vmsupport::get_vm_temporary_directory(thread, arguments).await
 }
 
 #[async_recursion(?Send)]
-async fn init_agent_properties(
-    _thread: Arc<Thread>,
-    _arguments: Arguments,
-) -> Result<Option<Value>> {
-    todo!("sun.misc.VMSupport.initAgentProperties(Ljava/util/Properties;)Ljava/util/Properties;")
+async fn init_agent_properties(thread: Arc<Thread>, arguments: Arguments) -> Result<Option<Value>> {
+    jdk::internal::vm::vmsupport::init_agent_properties(thread, arguments).await
 }
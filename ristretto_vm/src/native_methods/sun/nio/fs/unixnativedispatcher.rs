@@ -1,16 +1,50 @@
 use crate::arguments::Arguments;
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
-use crate::Error::InternalError;
-use crate::Result;
+use crate::Error::{InternalError, Throwable};
+use crate::{Result, VM};
 use async_recursion::async_recursion;
 use bitflags::bitflags;
 use ristretto_classfile::Version;
-use ristretto_classloader::{ConcurrentVec, Reference, Value};
+use ristretto_classloader::{ConcurrentVec, Object, Reference, Value};
+use std::fs::{Metadata, OpenOptions};
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
 use std::sync::Arc;
 
+const JAVA_17: Version = Version::Java17 { minor: 0 };
 const JAVA_22: Version = Version::Java22 { minor: 0 };
 
+/// The errno values used when an I/O error does not carry an OS error code; the values are the
+/// same on Linux and macOS.
+const ENOENT: i32 = 2;
+const EIO: i32 = 5;
+const EACCES: i32 = 13;
+const EEXIST: i32 = 17;
+const ENOTDIR: i32 = 20;
+const EISDIR: i32 = 21;
+const EINVAL: i32 = 22;
+
+/// The `open` flags defined by `sun.nio.fs.UnixConstants` for the host.
+const O_WRONLY: i32 = 0o1;
+const O_RDWR: i32 = 0o2;
+#[cfg(not(target_os = "macos"))]
+const O_APPEND: i32 = 0o2000;
+#[cfg(not(target_os = "macos"))]
+const O_CREAT: i32 = 0o100;
+#[cfg(not(target_os = "macos"))]
+const O_EXCL: i32 = 0o200;
+#[cfg(not(target_os = "macos"))]
+const O_TRUNC: i32 = 0o1000;
+#[cfg(target_os = "macos")]
+const O_APPEND: i32 = 0x8;
+#[cfg(target_os = "macos")]
+const O_CREAT: i32 = 0x200;
+#[cfg(target_os = "macos")]
+const O_EXCL: i32 = 0x800;
+#[cfg(target_os = "macos")]
+const O_TRUNC: i32 = 0x400;
+
 bitflags! {
     #[derive(Clone, Copy, Debug, Eq, PartialEq)]
     pub struct SupportsFlags: i32 {
@@ -32,9 +66,27 @@ bitflags! {
 /// Register all native methods for `sun.nio.fs.UnixNativeDispatcher`.
 pub(crate) fn register(registry: &mut MethodRegistry) {
     let class_name = "sun/nio/fs/UnixNativeDispatcher";
-    let java_version = registry.java_version();
+    let java_version = registry.java_version().clone();
+
+    if java_version <= JAVA_17 {
+        registry.register(class_name, "readdir", "(J)[B", readdir_0);
+        registry.register(
+            class_name,
+            "stat0",
+            "(JLsun/nio/fs/UnixFileAttributes;)V",
+            stat_0,
+        );
+        registry.register(class_name, "stat1", "(J)I", stat_1);
+    } else {
+        registry.register(
+            class_name,
+            "stat0",
+            "(JLsun/nio/fs/UnixFileAttributes;)I",
+            stat_0,
+        );
+    }
 
-    if java_version <= &JAVA_22 {
+    if java_version <= JAVA_22 {
         registry.register(class_name, "access0", "(JI)V", access_0);
         registry.register(class_name, "exists0", "(J)Z", exists_0);
     } else {
@@ -97,12 +149,6 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     registry.register(class_name, "renameat0", "(IJIJ)V", renameat_0);
     registry.register(class_name, "rewind", "(J)V", rewind);
     registry.register(class_name, "rmdir0", "(J)V", rmdir_0);
-    registry.register(
-        class_name,
-        "stat0",
-        "(JLsun/nio/fs/UnixFileAttributes;)I",
-        stat_0,
-    );
     registry.register(
         class_name,
         "statvfs0",
@@ -117,6 +163,162 @@ pub(crate) fn register(registry: &mut MethodRegistry) {
     registry.register(class_name, "write0", "(IJI)I", write_0);
 }
 
+/// Read the path stored as a NUL-terminated string at the native memory address.
+///
+/// # Errors
+/// if the path is not a valid native string.
+fn native_path(vm: &VM, address: i64) -> Result<PathBuf> {
+    let bytes = vm.native_memory().read_c_string(address)?;
+    #[cfg(unix)]
+    let path = {
+        use std::os::unix::ffi::OsStringExt;
+        PathBuf::from(std::ffi::OsString::from_vec(bytes))
+    };
+    #[cfg(not(unix))]
+    let path = PathBuf::from(String::from_utf8_lossy(&bytes).to_string());
+    Ok(path)
+}
+
+/// Convert the bytes to a Java `byte[]`.
+fn bytes_value(bytes: &[u8]) -> Value {
+    let bytes: Vec<i8> = bytes
+        .iter()
+        .map(|byte| i8::from_be_bytes([*byte]))
+        .collect();
+    Value::from(bytes)
+}
+
+/// Get the errno the Java layer expects for the I/O error.
+fn errno(error: &io::Error) -> i32 {
+    #[cfg(unix)]
+    if let Some(errno) = error.raw_os_error() {
+        return errno;
+    }
+    match error.kind() {
+        ErrorKind::NotFound => ENOENT,
+        ErrorKind::PermissionDenied => EACCES,
+        ErrorKind::AlreadyExists => EEXIST,
+        ErrorKind::NotADirectory => ENOTDIR,
+        ErrorKind::IsADirectory => EISDIR,
+        ErrorKind::InvalidInput => EINVAL,
+        _ => EIO,
+    }
+}
+
+/// Throw a `sun.nio.fs.UnixException` with the errno of the I/O error.
+///
+/// # Errors
+/// always; the error is the exception, or the error that prevented it from being created.
+async fn throw_unix_exception<T>(thread: &Thread, error: &io::Error) -> Result<T> {
+    let exception = thread
+        .object(
+            "sun/nio/fs/UnixException",
+            "I",
+            vec![Value::Int(errno(error))],
+        )
+        .await?;
+    Err(Throwable(exception.try_into()?))
+}
+
+/// Get the options to open a file with the `open` flags and mode.
+fn open_options(flags: i32, mode: i32) -> OpenOptions {
+    let mut options = OpenOptions::new();
+    let access_mode = flags & (O_WRONLY | O_RDWR);
+    options
+        .read(access_mode != O_WRONLY)
+        .write(access_mode != 0)
+        .append(flags & O_APPEND != 0)
+        .truncate(flags & O_TRUNC != 0);
+    if flags & O_CREAT != 0 {
+        if flags & O_EXCL != 0 {
+            options.create_new(true);
+        } else {
+            options.create(true);
+        }
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(u32::from_ne_bytes(mode.to_ne_bytes()));
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+    options
+}
+
+/// Get the `st_mode` (file type and permissions) of the file.
+#[cfg(unix)]
+fn file_mode(metadata: &Metadata) -> i32 {
+    use std::os::unix::fs::MetadataExt;
+    i32::from_ne_bytes(metadata.mode().to_ne_bytes())
+}
+
+/// Get the `st_mode` (file type and permissions) of the file; hosts without Unix metadata only
+/// distinguish directories from regular files and read-only from writable files.
+#[cfg(not(unix))]
+fn file_mode(metadata: &Metadata) -> i32 {
+    const S_IFDIR: i32 = 0o040_000;
+    const S_IFREG: i32 = 0o100_000;
+    let file_type = if metadata.is_dir() { S_IFDIR } else { S_IFREG };
+    let permissions = if metadata.permissions().readonly() {
+        0o444
+    } else {
+        0o644
+    };
+    file_type | permissions
+}
+
+/// Set the fields of the `sun.nio.fs.UnixFileAttributes` object from the metadata.
+///
+/// # Errors
+/// if a field cannot be set.
+#[cfg(unix)]
+#[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn set_attributes(attributes: &Object, metadata: &Metadata) -> Result<()> {
+    use std::os::unix::fs::MetadataExt;
+    attributes.set_value("st_mode", Value::Int(file_mode(metadata)))?;
+    attributes.set_value("st_ino", Value::Long(metadata.ino() as i64))?;
+    attributes.set_value("st_dev", Value::Long(metadata.dev() as i64))?;
+    attributes.set_value("st_rdev", Value::Long(metadata.rdev() as i64))?;
+    attributes.set_value("st_nlink", Value::Int(metadata.nlink() as i32))?;
+    attributes.set_value("st_uid", Value::Int(metadata.uid() as i32))?;
+    attributes.set_value("st_gid", Value::Int(metadata.gid() as i32))?;
+    attributes.set_value("st_size", Value::Long(metadata.size() as i64))?;
+    attributes.set_value("st_atime_sec", Value::Long(metadata.atime()))?;
+    attributes.set_value("st_atime_nsec", Value::Long(metadata.atime_nsec()))?;
+    attributes.set_value("st_mtime_sec", Value::Long(metadata.mtime()))?;
+    attributes.set_value("st_mtime_nsec", Value::Long(metadata.mtime_nsec()))?;
+    attributes.set_value("st_ctime_sec", Value::Long(metadata.ctime()))?;
+    attributes.set_value("st_ctime_nsec", Value::Long(metadata.ctime_nsec()))?;
+    Ok(())
+}
+
+/// Set the fields of the `sun.nio.fs.UnixFileAttributes` object from the metadata; hosts without
+/// Unix metadata only report the file type, size and modification time.
+///
+/// # Errors
+/// if a field cannot be set.
+#[cfg(not(unix))]
+fn set_attributes(attributes: &Object, metadata: &Metadata) -> Result<()> {
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .unwrap_or_default();
+    attributes.set_value("st_mode", Value::Int(file_mode(metadata)))?;
+    attributes.set_value("st_nlink", Value::Int(1))?;
+    attributes.set_value("st_size", Value::Long(i64::try_from(metadata.len())?))?;
+    attributes.set_value(
+        "st_mtime_sec",
+        Value::Long(i64::try_from(modified.as_secs())?),
+    )?;
+    attributes.set_value(
+        "st_mtime_nsec",
+        Value::Long(i64::from(modified.subsec_nanos())),
+    )?;
+    Ok(())
+}
+
 #[async_recursion(?Send)]
 async fn access_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
     todo!("sun.nio.fs.UnixNativeDispatcher.access0(JI)I");
@@ -133,13 +335,19 @@ async fn chown_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<V
 }
 
 #[async_recursion(?Send)]
-async fn close_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.close0(I)V");
+async fn close_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let handle = arguments.pop_int()?;
+    let vm = thread.vm()?;
+    vm.file_handles().close(i64::from(handle))?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
-async fn closedir(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.closedir(J)V");
+async fn closedir(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let handle = arguments.pop_long()?;
+    let vm = thread.vm()?;
+    vm.file_handles().close_directory(handle)?;
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -148,8 +356,11 @@ async fn dup(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value
 }
 
 #[async_recursion(?Send)]
-async fn exists_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.exists0(J)Z");
+async fn exists_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let address = arguments.pop_long()?;
+    let vm = thread.vm()?;
+    let path = native_path(&vm, address)?;
+    Ok(Some(Value::from(std::fs::metadata(path).is_ok())))
 }
 
 #[async_recursion(?Send)]
@@ -268,8 +479,16 @@ async fn link_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Va
 }
 
 #[async_recursion(?Send)]
-async fn lstat_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.lstat0(JLsun/nio/fs/UnixFileAttributes;)V");
+async fn lstat_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let attributes = arguments.pop_object()?;
+    let address = arguments.pop_long()?;
+    let vm = thread.vm()?;
+    let path = native_path(&vm, address)?;
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) => set_attributes(&attributes, &metadata)?,
+        Err(error) => return throw_unix_exception(&thread, &error).await,
+    }
+    Ok(None)
 }
 
 #[async_recursion(?Send)]
@@ -288,8 +507,18 @@ async fn mknod_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<V
 }
 
 #[async_recursion(?Send)]
-async fn open_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.open0(JII)I");
+async fn open_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let mode = arguments.pop_int()?;
+    let flags = arguments.pop_int()?;
+    let address = arguments.pop_long()?;
+    let vm = thread.vm()?;
+    let path = native_path(&vm, address)?;
+    let file = match open_options(flags, mode).open(path) {
+        Ok(file) => file,
+        Err(error) => return throw_unix_exception(&thread, &error).await,
+    };
+    let handle = vm.file_handles().insert(Box::new(file))?;
+    Ok(Some(Value::Int(i32::try_from(handle)?)))
 }
 
 #[async_recursion(?Send)]
@@ -298,8 +527,16 @@ async fn openat_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<
 }
 
 #[async_recursion(?Send)]
-async fn opendir_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.opendir0(J)J");
+async fn opendir_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let address = arguments.pop_long()?;
+    let vm = thread.vm()?;
+    let path = native_path(&vm, address)?;
+    let directory = match std::fs::read_dir(path) {
+        Ok(directory) => directory,
+        Err(error) => return throw_unix_exception(&thread, &error).await,
+    };
+    let handle = vm.file_handles().insert_directory(directory)?;
+    Ok(Some(Value::Long(handle)))
 }
 
 #[async_recursion(?Send)]
@@ -308,8 +545,20 @@ async fn read_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Va
 }
 
 #[async_recursion(?Send)]
-async fn readdir_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.readdir0(J)[B");
+async fn readdir_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let handle = arguments.pop_long()?;
+    let vm = thread.vm()?;
+    let Some(file_name) = vm.file_handles().read_directory(handle)? else {
+        return Ok(Some(Value::Object(None)));
+    };
+    #[cfg(unix)]
+    let file_name = {
+        use std::os::unix::ffi::OsStringExt;
+        file_name.into_vec()
+    };
+    #[cfg(not(unix))]
+    let file_name = file_name.to_string_lossy().as_bytes().to_vec();
+    Ok(Some(bytes_value(&file_name)))
 }
 
 #[async_recursion(?Send)]
@@ -342,15 +591,41 @@ async fn rmdir_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<V
     todo!("sun.nio.fs.UnixNativeDispatcher.rmdir0(J)V");
 }
 
-#[async_recursion(?Send)]
-async fn stat_0(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
-    let Some(_attributes) = arguments.pop_reference()? else {
-        return Err(InternalError("attributes is null".to_string()));
+/// Java 17 and earlier throw a `UnixException` if the file cannot be read; later versions return
+/// the errno, or 0 on success.
+#[async_recursion(?Send)]
+async fn stat_0(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let attributes = arguments.pop_object()?;
+    let address = arguments.pop_long()?;
+    let vm = thread.vm()?;
+    let path = native_path(&vm, address)?;
+    let error = match std::fs::metadata(path) {
+        Ok(metadata) => {
+            set_attributes(&attributes, &metadata)?;
+            None
+        }
+        Err(error) => Some(error),
     };
-    let _path = arguments.pop_long()?;
-    // TODO: Implement the stat0 method
 
-    Ok(Some(Value::Int(0)))
+    if vm.java_class_file_version() <= &JAVA_17 {
+        match error {
+            Some(error) => throw_unix_exception(&thread, &error).await,
+            None => Ok(None),
+        }
+    } else {
+        let errno = error.as_ref().map_or(0, errno);
+        Ok(Some(Value::Int(errno)))
+    }
+}
+
+/// Returns the mode of the file, or 0 if the file cannot be read.
+#[async_recursion(?Send)]
+async fn stat_1(thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let address = arguments.pop_long()?;
+    let vm = thread.vm()?;
+    let path = native_path(&vm, address)?;
+    let mode = std::fs::metadata(path).map_or(0, |metadata| file_mode(&metadata));
+    Ok(Some(Value::Int(mode)))
 }
 
 #[async_recursion(?Send)]
@@ -359,8 +634,14 @@ async fn statvfs_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option
 }
 
 #[async_recursion(?Send)]
-async fn strerror(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.nio.fs.UnixNativeDispatcher.strerror(I)[B");
+async fn strerror(_thread: Arc<Thread>, mut arguments: Arguments) -> Result<Option<Value>> {
+    let errno = arguments.pop_int()?;
+    let message = io::Error::from_raw_os_error(errno).to_string();
+    // Remove the " (os error N)" suffix added by the standard library
+    let message = message
+        .rsplit_once(" (os error")
+        .map_or(message.as_str(), |(message, _)| message);
+    Ok(Some(bytes_value(message.as_bytes())))
 }
 
 #[async_recursion(?Send)]
@@ -387,3 +668,91 @@ async fn utimes_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<
 async fn write_0(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
     todo!("sun.nio.fs.UnixNativeDispatcher.write0(IJI)I");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::java_object::JavaObject;
+
+    #[tokio::test]
+    async fn test_files_exists_and_size() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let directory = tempfile::tempdir().expect("temp dir");
+        let file = directory.path().join("file.txt");
+        std::fs::write(&file, b"hello").expect("write file");
+
+        let path = file.to_string_lossy().to_string().to_object(&vm).await?;
+        let exists = vm
+            .try_invoke(
+                "NioFiles",
+                "exists",
+                "(Ljava/lang/String;)Z",
+                vec![path.clone()],
+            )
+            .await?;
+        assert_eq!(Value::Int(1), exists);
+        let size = vm
+            .try_invoke("NioFiles", "size", "(Ljava/lang/String;)J", vec![path])
+            .await?;
+        assert_eq!(Value::Long(5), size);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_files_missing() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let directory = tempfile::tempdir().expect("temp dir");
+        let file = directory.path().join("missing.txt");
+
+        let path = file.to_string_lossy().to_string().to_object(&vm).await?;
+        let exists = vm
+            .try_invoke(
+                "NioFiles",
+                "exists",
+                "(Ljava/lang/String;)Z",
+                vec![path.clone()],
+            )
+            .await?;
+        assert_eq!(Value::Int(0), exists);
+        let result = vm
+            .try_invoke("NioFiles", "size", "(Ljava/lang/String;)J", vec![path])
+            .await;
+        let Err(Throwable(throwable)) = result else {
+            panic!("expected NoSuchFileException");
+        };
+        assert_eq!(
+            "java/nio/file/NoSuchFileException",
+            throwable.class().name()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_files_directory_stream() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let directory = tempfile::tempdir().expect("temp dir");
+        std::fs::write(directory.path().join("a.txt"), b"a").expect("write file");
+        std::fs::write(directory.path().join("b.txt"), b"b").expect("write file");
+
+        let path = directory
+            .path()
+            .to_string_lossy()
+            .to_string()
+            .to_object(&vm)
+            .await?;
+        let count = vm
+            .try_invoke("NioFiles", "count", "(Ljava/lang/String;)I", vec![path])
+            .await?;
+        assert_eq!(Value::Int(2), count);
+        Ok(())
+    }
+
+    #[test]
+    fn test_errno() {
+        assert_eq!(ENOENT, errno(&io::Error::from(ErrorKind::NotFound)));
+        assert_eq!(EACCES, errno(&io::Error::from(ErrorKind::PermissionDenied)));
+        assert_eq!(EIO, errno(&io::Error::from(ErrorKind::Other)));
+        #[cfg(unix)]
+        assert_eq!(ENOTDIR, errno(&io::Error::from_raw_os_error(ENOTDIR)));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::arguments::Arguments;
-use crate::java_object::JavaObject;
+use crate::java_object::{class_object_name, JavaObject};
 use crate::native_methods::registry::MethodRegistry;
 use crate::thread::Thread;
 use crate::Error::InternalError;
@@ -45,9 +45,48 @@ async fn get_caller_class_1(thread: Arc<Thread>, _arguments: Arguments) -> Resul
     Ok(Some(class))
 }
 
+/// Returns true if the frame belongs to the reflective method invocation machinery
+/// (`Method.invoke()` and its accessor implementations); these frames are ignored when walking the
+/// stack for a caller class.
+fn is_reflection_frame(class_name: &str, method_name: &str) -> bool {
+    (class_name == "java/lang/reflect/Method" && method_name == "invoke")
+        || (class_name.starts_with("sun/reflect/")
+            && (class_name.ends_with("MethodAccessorImpl")
+                || class_name.starts_with("sun/reflect/GeneratedMethodAccessor")))
+}
+
+/// Get the class of the method `depth` frames up the stack.  Depth 0 is
+/// `Reflection.getCallerClass()` itself and depth 1 is its immediate caller; native methods do not
+/// have a frame, so depth 1 corresponds to the top frame of the thread.  A negative or out of range
+/// depth returns null.
 #[async_recursion(?Send)]
-async fn get_caller_class_2(_thread: Arc<Thread>, _arguments: Arguments) -> Result<Option<Value>> {
-    todo!("sun.reflect.Reflection.getCallerClass(I)Ljava/lang/Class;")
+async fn get_caller_class_2(
+    thread: Arc<Thread>,
+    mut arguments: Arguments,
+) -> Result<Option<Value>> {
+    let depth = arguments.pop_int()?;
+    let Ok(depth) = usize::try_from(depth) else {
+        return Ok(Some(Value::Object(None)));
+    };
+    let vm = thread.vm()?;
+    if depth == 0 {
+        let class = thread.class("sun/reflect/Reflection").await?;
+        let class = class.to_object(&vm).await?;
+        return Ok(Some(class));
+    }
+
+    let frames = thread.frames().await?;
+    let frame = frames
+        .iter()
+        .rev()
+        .filter(|frame| !is_reflection_frame(frame.class().name(), frame.method().name()))
+        .nth(depth - 1);
+    let Some(frame) = frame else {
+        return Ok(Some(Value::Object(None)));
+    };
+    let class = thread.class(frame.class().name()).await?;
+    let class = class.to_object(&vm).await?;
+    Ok(Some(class))
 }
 
 #[async_recursion(?Send)]
@@ -60,7 +99,7 @@ async fn get_class_access_flags(
             "getClassAccessFlags: no arguments".to_string(),
         ));
     };
-    let class_name: String = object.value("name")?.try_into()?;
+    let class_name = class_object_name(&object)?;
     let class = thread.class(&class_name).await?;
     let class_file = class.class_file();
     let access_flags = &class_file.access_flags;
@@ -68,3 +107,64 @@ async fn get_class_access_flags(
     let class_access_flags = access_flags.bits() as i32;
     Ok(Some(Value::Int(class_access_flags)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Frame;
+    use ristretto_classfile::MethodAccessFlags;
+    use ristretto_classloader::Method;
+
+    async fn push_frame(thread: &Arc<Thread>, class_name: &str, method_name: &str) -> Result<()> {
+        let class = thread.class(class_name).await?;
+        let method = Method::new(
+            MethodAccessFlags::PUBLIC,
+            method_name,
+            "()V",
+            0,
+            0,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )?;
+        let frame = Frame::new(
+            &Arc::downgrade(thread),
+            &class,
+            &Arc::new(method),
+            Vec::new(),
+        )?;
+        thread.push_frame(Arc::new(frame)).await;
+        Ok(())
+    }
+
+    async fn caller_class_name(thread: &Arc<Thread>, depth: i32) -> Result<Option<String>> {
+        let mut arguments = Arguments::default();
+        arguments.push_int(depth);
+        let result = get_caller_class_2(thread.clone(), arguments).await?;
+        let Some(Value::Object(Some(Reference::Object(class)))) = result else {
+            return Ok(None);
+        };
+        let class_name = class_object_name(&class)?;
+        Ok(Some(class_name))
+    }
+
+    #[tokio::test]
+    async fn test_get_caller_class_2() -> Result<()> {
+        let (_vm, thread, _class) = crate::test::class().await?;
+        push_frame(&thread, "java/lang/String", "valueOf").await?;
+        push_frame(&thread, "java/lang/reflect/Method", "invoke").await?;
+        push_frame(&thread, "java/lang/Integer", "parseInt").await?;
+
+        assert_eq!(
+            Some("java/lang/Integer".to_string()),
+            caller_class_name(&thread, 1).await?
+        );
+        assert_eq!(
+            Some("java/lang/String".to_string()),
+            caller_class_name(&thread, 2).await?
+        );
+        assert_eq!(None, caller_class_name(&thread, 3).await?);
+        assert_eq!(None, caller_class_name(&thread, -1).await?);
+        Ok(())
+    }
+}
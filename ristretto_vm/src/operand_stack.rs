@@ -139,10 +139,20 @@ impl OperandStack {
         Ok(self.stack.len()?)
     }
 
+    /// Get the values on the operand stack, from the bottom to the top of the stack.
+    pub fn to_vec(&self) -> Result<Vec<Value>> {
+        Ok(self.stack.to_vec()?)
+    }
+
     /// Check if the operand stack is empty.
     pub fn is_empty(&self) -> Result<bool> {
         Ok(self.stack.is_empty()?)
     }
+
+    /// Remove all values from the operand stack.
+    pub fn clear(&self) -> Result<()> {
+        Ok(self.stack.clear()?)
+    }
 }
 
 impl Display for OperandStack {
@@ -347,6 +357,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_to_vec() -> Result<()> {
+        let stack = OperandStack::with_max_size(2);
+        stack.push_int(1)?;
+        stack.push_long(2)?;
+        assert_eq!(vec![Value::Int(1), Value::Long(2)], stack.to_vec()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear() -> Result<()> {
+        let stack = OperandStack::with_max_size(2);
+        stack.push_int(1)?;
+        stack.push_int(2)?;
+        stack.clear()?;
+        assert!(stack.is_empty()?);
+        Ok(())
+    }
+
     #[test]
     fn test_display() -> Result<()> {
         let stack = OperandStack::with_max_size(4);
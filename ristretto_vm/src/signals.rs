@@ -0,0 +1,213 @@
+#[cfg(unix)]
+use crate::Error::InternalError;
+use crate::Error::PoisonedLock;
+use crate::{Result, VM};
+use std::collections::HashMap;
+#[cfg(unix)]
+use std::sync::OnceLock;
+use std::sync::{Mutex, Weak};
+#[cfg(unix)]
+use tracing::debug;
+
+/// The native handler that restores the default action of a signal (`SIG_DFL`).
+pub(crate) const DEFAULT_HANDLER: i64 = 0;
+/// The native handler that ignores a signal (`SIG_IGN`).
+pub(crate) const IGNORE_HANDLER: i64 = 1;
+/// The native handler that dispatches a signal to `jdk.internal.misc.Signal.dispatch`.
+pub(crate) const DISPATCH_HANDLER: i64 = 2;
+
+/// The native handlers of the signals handled by Java code (`jdk.internal.misc.Signal.handle0`).
+/// A signal is caught by the VM once a handler is first set for it; as with `HotSpot`, the
+/// signals received are dispatched by a dedicated "Signal Dispatcher" thread so that they are
+/// handled whether or not the VM is running Java code.
+#[derive(Debug)]
+pub(crate) struct Signals {
+    #[cfg_attr(not(unix), expect(dead_code))]
+    vm: Weak<VM>,
+    handlers: Mutex<HashMap<i32, i64>>,
+    #[cfg(unix)]
+    dispatcher: OnceLock<Dispatcher>,
+}
+
+/// The signal dispatcher thread, which runs its own runtime so that signals are received while
+/// the VM is idle or blocked.  The thread stops when the dispatcher is dropped with the VM.
+#[cfg(unix)]
+#[derive(Debug)]
+struct Dispatcher {
+    handle: tokio::runtime::Handle,
+    received: tokio::sync::mpsc::UnboundedSender<i32>,
+    _stop: tokio::sync::oneshot::Sender<()>,
+}
+
+impl Signals {
+    /// Create a new signal table with no signals caught.
+    pub(crate) fn new(vm: Weak<VM>) -> Self {
+        Self {
+            vm,
+            handlers: Mutex::new(HashMap::new()),
+            #[cfg(unix)]
+            dispatcher: OnceLock::new(),
+        }
+    }
+
+    /// Get the native handler of the signal; `None` if the signal is not caught.
+    ///
+    /// # Errors
+    /// if the lock is poisoned.
+    pub(crate) fn handler(&self, signal: i32) -> Result<Option<i64>> {
+        let handlers = self
+            .handlers
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        Ok(handlers.get(&signal).copied())
+    }
+
+    /// Set the native handler of the signal and return the previous native handler, or `None` if
+    /// the signal cannot be caught (e.g. `SIGKILL`).
+    ///
+    /// # Errors
+    /// if the lock is poisoned or the signal dispatcher cannot be started.
+    pub(crate) fn set_handler(&self, signal: i32, native_handler: i64) -> Result<Option<i64>> {
+        let mut handlers = self
+            .handlers
+            .lock()
+            .map_err(|error| PoisonedLock(error.to_string()))?;
+        if let Some(handler) = handlers.get_mut(&signal) {
+            let previous_handler = *handler;
+            *handler = native_handler;
+            return Ok(Some(previous_handler));
+        }
+        if native_handler == DEFAULT_HANDLER {
+            return Ok(Some(DEFAULT_HANDLER));
+        }
+
+        // The signal handler installed by tokio cannot be removed, so the signal is received for
+        // the life of the VM and the default action is emulated by the dispatcher.
+        #[cfg(unix)]
+        {
+            // The dispatcher is started under the handlers lock, so it is only started once
+            if self.dispatcher.get().is_none() {
+                let dispatcher = Dispatcher::start(self.vm.clone())?;
+                let _ = self.dispatcher.set(dispatcher);
+            }
+            let Some(dispatcher) = self.dispatcher.get() else {
+                return Err(InternalError("signal dispatcher not started".to_string()));
+            };
+            if !dispatcher.receive(signal) {
+                return Ok(None);
+            }
+            handlers.insert(signal, native_handler);
+            Ok(Some(DEFAULT_HANDLER))
+        }
+        #[cfg(not(unix))]
+        Ok(None)
+    }
+}
+
+#[cfg(unix)]
+impl Dispatcher {
+    /// Start the signal dispatcher thread; each signal received is dispatched with
+    /// [`VM::dispatch_signal`] in the order received.
+    ///
+    /// # Errors
+    /// if the runtime or the thread cannot be created.
+    fn start(vm: Weak<VM>) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|error| InternalError(error.to_string()))?;
+        let handle = runtime.handle().clone();
+        let (received, mut signals) = tokio::sync::mpsc::unbounded_channel();
+        let (stop, mut stopped) = tokio::sync::oneshot::channel::<()>();
+        std::thread::Builder::new()
+            .name("Signal Dispatcher".to_string())
+            .spawn(move || {
+                runtime.block_on(async move {
+                    loop {
+                        let signal = tokio::select! {
+                            signal = signals.recv() => signal,
+                            _ = &mut stopped => None,
+                        };
+                        let Some(signal) = signal else {
+                            break;
+                        };
+                        let Some(vm) = vm.upgrade() else {
+                            break;
+                        };
+                        if let Err(error) = vm.dispatch_signal(signal).await {
+                            debug!("signal {signal}: dispatch failed: {error}");
+                        }
+                    }
+                });
+            })
+            .map_err(|error| InternalError(error.to_string()))?;
+        Ok(Self {
+            handle,
+            received,
+            _stop: stop,
+        })
+    }
+
+    /// Receive the signal on the dispatcher thread; returns `false` if the signal cannot be
+    /// caught.
+    fn receive(&self, signal: i32) -> bool {
+        let _guard = self.handle.enter();
+        let kind = tokio::signal::unix::SignalKind::from_raw(signal);
+        let Ok(mut stream) = tokio::signal::unix::signal(kind) else {
+            return false;
+        };
+        let received = self.received.clone();
+        self.handle.spawn(async move {
+            while stream.recv().await.is_some() {
+                if received.send(signal).is_err() {
+                    break;
+                }
+            }
+        });
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_default_handler() -> Result<()> {
+        let signals = Signals::new(Weak::new());
+        assert_eq!(
+            Some(DEFAULT_HANDLER),
+            signals.set_handler(2, DEFAULT_HANDLER)?
+        );
+        assert_eq!(None, signals.handler(2)?);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_set_handler() -> Result<()> {
+        let signals = Signals::new(Weak::new());
+        // SIGUSR2 is not used by the test harness
+        let signal = tokio::signal::unix::SignalKind::user_defined2().as_raw_value();
+        assert_eq!(
+            Some(DEFAULT_HANDLER),
+            signals.set_handler(signal, DISPATCH_HANDLER)?
+        );
+        assert_eq!(
+            Some(DISPATCH_HANDLER),
+            signals.set_handler(signal, IGNORE_HANDLER)?
+        );
+        assert_eq!(Some(IGNORE_HANDLER), signals.handler(signal)?);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_set_handler_uncatchable() -> Result<()> {
+        let signals = Signals::new(Weak::new());
+        // SIGKILL cannot be caught
+        assert_eq!(None, signals.set_handler(9, DISPATCH_HANDLER)?);
+        assert_eq!(None, signals.handler(9)?);
+        Ok(())
+    }
+}
@@ -1,19 +1,63 @@
 use crate::frame::Frame;
-use crate::{Class, ConfigurationBuilder, Result, Thread, VM};
+use crate::{
+    Class, ConfigurationBuilder, HostFile, HostIo, OpenMode, Result, StdHostIo, Thread, VM,
+};
 use ristretto_classfile::{ClassFile, ConstantPool, MethodAccessFlags};
 use ristretto_classloader::{ClassPath, Method};
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Read, Write};
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::sync::OnceCell;
 
-/// Get a test class for testing.
-pub(crate) async fn class() -> Result<(Arc<VM>, Arc<Thread>, Arc<Class>)> {
+/// The VM shared by the tests of the crate; booting a VM is expensive, so it is booted once and
+/// reused by [`vm`], [`class`] and [`frame`].
+///
+/// Tests that depend on state that must not be shared with other tests (e.g. a custom
+/// configuration, host I/O or observing the initialization of a class) should create their own VM
+/// with [`isolated_vm`] or [`class_with_host_io`] instead.
+static SHARED_VM: OnceCell<Arc<VM>> = OnceCell::const_new();
+
+/// Get the class path of the test classes.
+fn class_path() -> ClassPath {
     let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     let classes_path = cargo_manifest.join("../classes");
-    let class_path = ClassPath::from(classes_path.to_string_lossy());
+    ClassPath::from(classes_path.to_string_lossy())
+}
+
+/// Get the shared test VM; the VM is booted by the first test that requests it.
+pub(crate) async fn vm() -> Result<Arc<VM>> {
+    let vm = SHARED_VM
+        .get_or_try_init(|| isolated_vm(Arc::new(StdHostIo)))
+        .await?;
+    Ok(vm.clone())
+}
+
+/// Create a new test VM that is not shared with other tests and uses the given host I/O.
+pub(crate) async fn isolated_vm(host_io: Arc<dyn HostIo>) -> Result<Arc<VM>> {
     let configuration = ConfigurationBuilder::new()
-        .class_path(class_path.clone())
+        .class_path(class_path())
+        .host_io(host_io)
         .build()?;
-    let vm = VM::new(configuration).await?;
+    VM::new(configuration).await
+}
+
+/// Get a test class for testing with the shared test VM.
+pub(crate) async fn class() -> Result<(Arc<VM>, Arc<Thread>, Arc<Class>)> {
+    let vm = vm().await?;
+    test_class(vm)
+}
+
+/// Get a test class for testing with a new VM that uses the given host I/O.
+pub(crate) async fn class_with_host_io(
+    host_io: Arc<dyn HostIo>,
+) -> Result<(Arc<VM>, Arc<Thread>, Arc<Class>)> {
+    let vm = isolated_vm(host_io).await?;
+    test_class(vm)
+}
+
+/// Create a new thread and an empty test class for the VM.
+fn test_class(vm: Arc<VM>) -> Result<(Arc<VM>, Arc<Thread>, Arc<Class>)> {
     let thread = vm.new_thread()?;
     let mut constant_pool = ConstantPool::default();
     let this_class = constant_pool.add_class("Test")?;
@@ -48,3 +92,147 @@ pub(crate) async fn frame() -> Result<(Arc<VM>, Arc<Thread>, Frame)> {
     )?;
     Ok((vm, thread, frame))
 }
+
+/// In-memory host I/O for testing; files are stored by path and the standard streams are
+/// captured so that they can be asserted on.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryHostIo {
+    files: Mutex<HashMap<String, Arc<Mutex<Vec<u8>>>>>,
+    stdin: Mutex<VecDeque<u8>>,
+    stdout: Mutex<Vec<u8>>,
+    stderr: Mutex<Vec<u8>>,
+}
+
+impl MemoryHostIo {
+    /// Create in-memory host I/O with the given standard input.
+    pub(crate) fn new(stdin: &[u8]) -> Self {
+        Self {
+            stdin: Mutex::new(stdin.iter().copied().collect()),
+            ..Default::default()
+        }
+    }
+
+    /// Get the contents of the file at the given path.
+    pub(crate) fn file(&self, path: &str) -> Option<Vec<u8>> {
+        let files = self.files.lock().expect("files");
+        let file = files.get(path)?;
+        let bytes = file.lock().expect("file").clone();
+        Some(bytes)
+    }
+
+    /// Get the bytes written to the standard output.
+    pub(crate) fn stdout(&self) -> Vec<u8> {
+        self.stdout.lock().expect("stdout").clone()
+    }
+
+    /// Get the bytes written to the standard error.
+    pub(crate) fn stderr(&self) -> Vec<u8> {
+        self.stderr.lock().expect("stderr").clone()
+    }
+}
+
+impl HostIo for MemoryHostIo {
+    fn open(&self, path: &str, mode: OpenMode) -> io::Result<Box<dyn HostFile>> {
+        let mut files = lock(&self.files)?;
+        let bytes = match mode {
+            OpenMode::Read => files
+                .get(path)
+                .cloned()
+                .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?,
+            OpenMode::Write => {
+                let bytes = Arc::new(Mutex::new(Vec::new()));
+                files.insert(path.to_string(), bytes.clone());
+                bytes
+            }
+            OpenMode::Append => files.entry(path.to_string()).or_default().clone(),
+        };
+        let position = match mode {
+            OpenMode::Append => lock(&bytes)?.len(),
+            OpenMode::Read | OpenMode::Write => 0,
+        };
+        Ok(Box::new(MemoryFile { bytes, position }))
+    }
+
+    fn read_stdin(&self, buffer: &mut [u8]) -> io::Result<usize> {
+        lock(&self.stdin)?.read(buffer)
+    }
+
+    fn write_stdout(&self, bytes: &[u8]) -> io::Result<()> {
+        lock(&self.stdout)?.write_all(bytes)
+    }
+
+    fn write_stderr(&self, bytes: &[u8]) -> io::Result<()> {
+        lock(&self.stderr)?.write_all(bytes)
+    }
+}
+
+/// Lock the mutex, mapping a poisoned lock to an I/O error.
+fn lock<T>(mutex: &Mutex<T>) -> io::Result<MutexGuard<'_, T>> {
+    mutex
+        .lock()
+        .map_err(|error| io::Error::other(error.to_string()))
+}
+
+/// A file opened by [`MemoryHostIo`].
+#[derive(Debug)]
+struct MemoryFile {
+    bytes: Arc<Mutex<Vec<u8>>>,
+    position: usize,
+}
+
+impl Read for MemoryFile {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let bytes = lock(&self.bytes)?;
+        let remaining = bytes.get(self.position..).unwrap_or_default();
+        let length = remaining.len().min(buffer.len());
+        buffer[..length].copy_from_slice(&remaining[..length]);
+        self.position += length;
+        Ok(length)
+    }
+}
+
+impl Write for MemoryFile {
+    fn write(&mut self, buffer: &[u8]) -> io::Result<usize> {
+        let mut bytes = lock(&self.bytes)?;
+        let end = self.position + buffer.len();
+        if bytes.len() < end {
+            bytes.resize(end, 0);
+        }
+        bytes[self.position..end].copy_from_slice(buffer);
+        self.position = end;
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_vm_is_shared() -> Result<()> {
+        let vm1 = vm().await?;
+        let vm2 = vm().await?;
+        assert!(Arc::ptr_eq(&vm1, &vm2));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_and_frame_use_shared_vm() -> Result<()> {
+        let (class_vm, class_thread, _class) = class().await?;
+        let (frame_vm, frame_thread, _frame) = frame().await?;
+        assert!(Arc::ptr_eq(&class_vm, &frame_vm));
+        assert!(Arc::ptr_eq(&vm().await?, &class_vm));
+        assert!(!Arc::ptr_eq(&class_thread, &frame_thread));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_isolated_vm_is_not_shared() -> Result<()> {
+        let isolated_vm = isolated_vm(Arc::new(StdHostIo)).await?;
+        assert!(!Arc::ptr_eq(&vm().await?, &isolated_vm));
+        Ok(())
+    }
+}
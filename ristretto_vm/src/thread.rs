@@ -1,13 +1,29 @@
 use crate::arguments::Arguments;
+#[cfg(feature = "debug")]
+use crate::debugger::DebugAction;
+use crate::event::VmEvent;
+use crate::java_object::BoxedValue;
 use crate::rust_value::{process_values, RustValue};
-use crate::Error::{InternalError, UnsupportedClassFileVersion};
-use crate::{Frame, Result, VM};
+use crate::Error::{
+    InternalError, InvalidArgument, InvalidArgumentCount, UnsupportedClassFileVersion,
+};
+use crate::JavaError::{ClassFormatError, NoClassDefFoundError};
+use crate::{Error, Frame, Result, VM};
 use async_recursion::async_recursion;
+use ristretto_classfile::{BaseType, FieldType};
 use ristretto_classloader::Error::MethodNotFound;
-use ristretto_classloader::{Class, Method, Object, Value};
+use ristretto_classloader::{Class, InitializationState, Method, Object, Reference, Value};
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
 use std::sync::{Arc, Weak};
-use tokio::sync::RwLock;
-use tracing::{debug, event_enabled, Level};
+use tokio::sync::{Notify, RwLock};
+use tracing::{debug, event_enabled, field, trace, trace_span, Instrument, Level};
+
+/// The minimum priority of a thread; matches `java.lang.Thread.MIN_PRIORITY`.
+pub(crate) const MIN_PRIORITY: i32 = 1;
+/// The default priority of a thread; matches `java.lang.Thread.NORM_PRIORITY`.
+pub(crate) const NORM_PRIORITY: i32 = 5;
+/// The maximum priority of a thread; matches `java.lang.Thread.MAX_PRIORITY`.
+pub(crate) const MAX_PRIORITY: i32 = 10;
 
 /// A thread is a single sequential flow of control within a program. It has its own call stack
 /// and program counter.
@@ -22,11 +38,17 @@ pub struct Thread {
     name: Arc<RwLock<String>>,
     java_object: Arc<RwLock<Value>>,
     frames: Arc<RwLock<Vec<Arc<Frame>>>>,
+    priority: AtomicI32,
+    interrupted: AtomicBool,
+    interrupt_notify: Notify,
+    park_notify: Notify,
+    #[cfg(feature = "debug")]
+    debug_action: std::sync::Mutex<DebugAction>,
 }
 
 impl Thread {
     /// Create a new thread.
-    pub fn new(vm: &Weak<VM>) -> Result<Arc<Self>> {
+    pub(crate) fn new(vm: &Weak<VM>) -> Result<Arc<Self>> {
         let vm_ref = vm.clone();
         let vm = vm
             .upgrade()
@@ -41,6 +63,12 @@ impl Thread {
             name: Arc::new(RwLock::new(name)),
             java_object: Arc::new(RwLock::new(java_object)),
             frames: Arc::new(RwLock::new(Vec::new())),
+            priority: AtomicI32::new(NORM_PRIORITY),
+            interrupted: AtomicBool::new(false),
+            interrupt_notify: Notify::new(),
+            park_notify: Notify::new(),
+            #[cfg(feature = "debug")]
+            debug_action: std::sync::Mutex::new(DebugAction::default()),
         });
         Ok(thread)
     }
@@ -50,6 +78,25 @@ impl Thread {
         self.id
     }
 
+    /// Get the action the debugger chose when the thread last stopped.
+    #[cfg(feature = "debug")]
+    pub(crate) fn debug_action(&self) -> DebugAction {
+        *self
+            .debug_action
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Set the action the debugger chose when the thread stopped.
+    #[cfg(feature = "debug")]
+    pub(crate) fn set_debug_action(&self, action: DebugAction) {
+        let mut debug_action = self
+            .debug_action
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        *debug_action = action;
+    }
+
     /// Get the virtual machine that owns the thread.
     ///
     /// # Errors
@@ -74,6 +121,53 @@ impl Thread {
         *name = new_name.to_string();
     }
 
+    /// Get the priority of the thread (between `java.lang.Thread.MIN_PRIORITY` and
+    /// `java.lang.Thread.MAX_PRIORITY`).  Tokio does not support task priorities, so the priority
+    /// is only used as a scheduling hint (e.g. when the thread yields).
+    pub fn priority(&self) -> i32 {
+        self.priority.load(Ordering::Relaxed)
+    }
+
+    /// Set the priority of the thread; the priority is clamped to the range of
+    /// `java.lang.Thread.MIN_PRIORITY` to `java.lang.Thread.MAX_PRIORITY`.
+    pub fn set_priority(&self, priority: i32) {
+        let priority = priority.clamp(MIN_PRIORITY, MAX_PRIORITY);
+        self.priority.store(priority, Ordering::Relaxed);
+    }
+
+    /// Interrupt the thread; waits of the thread (e.g. `Thread.sleep`, `Object.wait` and
+    /// `Unsafe.park`) are woken.
+    pub(crate) fn interrupt(&self) {
+        self.interrupted.store(true, Ordering::SeqCst);
+        self.interrupt_notify.notify_waiters();
+    }
+
+    /// Returns `true` if the thread has been interrupted.
+    pub(crate) fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::SeqCst)
+    }
+
+    /// Clear the interrupt status of the thread and return the previous status.
+    pub(crate) fn clear_interrupt(&self) -> bool {
+        self.interrupted.swap(false, Ordering::SeqCst)
+    }
+
+    /// Get the notifier that is signaled when the thread is interrupted.
+    pub(crate) fn interrupt_notify(&self) -> &Notify {
+        &self.interrupt_notify
+    }
+
+    /// Get the notifier used to park the thread; an unpark before the thread parks is stored as a
+    /// permit, so the next park returns immediately.
+    pub(crate) fn park_notify(&self) -> &Notify {
+        &self.park_notify
+    }
+
+    /// Unpark the thread, or let the next park of the thread return immediately.
+    pub(crate) fn unpark(&self) {
+        self.park_notify.notify_one();
+    }
+
     /// Get the Java object for this thread.
     pub async fn java_object(&self) -> Value {
         let object = self.java_object.read().await;
@@ -105,15 +199,37 @@ impl Thread {
         Ok(frame.clone())
     }
 
-    /// Get a class.
+    /// Add a frame to the top of the thread's call stack.
+    pub(crate) async fn push_frame(&self, frame: Arc<Frame>) {
+        let mut frames = self.frames.write().await;
+        frames.push(frame);
+    }
+
+    /// Remove the frame at the top of the thread's call stack.
+    pub(crate) async fn pop_frame(&self) -> Option<Arc<Frame>> {
+        let mut frames = self.frames.write().await;
+        frames.pop()
+    }
+
+    /// Get a class; the class is loaded, linked and initialized if necessary.
     ///
     /// See: <https://docs.oracle.com/javase/specs/jls/se23/html/jls-12.html#jls-12.4.1>
     ///
     /// # Errors
-    /// if the class cannot be loaded
+    /// if the class cannot be loaded or initialized
     #[expect(clippy::multiple_bound_locations)]
     #[async_recursion(?Send)]
     pub(crate) async fn class<S: AsRef<str>>(&self, class_name: S) -> Result<Arc<Class>> {
+        let class = self.load_class(class_name).await?;
+        self.initialize_class(&class).await?;
+        Ok(class)
+    }
+
+    /// Load and link a class without initializing it.
+    ///
+    /// # Errors
+    /// if the class cannot be loaded
+    pub(crate) async fn load_class<S: AsRef<str>>(&self, class_name: S) -> Result<Arc<Class>> {
         let class_name = class_name.as_ref();
         let class_load_result = {
             let vm = self.vm()?;
@@ -124,8 +240,8 @@ impl Thread {
 
         let class = match class_load_result {
             Ok((class, previously_loaded)) => {
-                // Determine if the class has already been loaded.  If the class has already been loaded,
-                // return the class. Otherwise, the class must be initialized.
+                // If the class has already been loaded, return the class. Otherwise, the class
+                // must be linked.
                 if previously_loaded {
                     return Ok(class);
                 }
@@ -144,26 +260,92 @@ impl Thread {
                     self.register_class(array_class.clone()).await?;
                     array_class
                 } else {
-                    return Err(error.into());
+                    return Err(class_load_error(class_name, error));
                 }
             }
         };
 
         let classes = self.prepare_class_initialization(&class).await?;
-        for current_class in classes {
-            if let Some(class_initializer) = current_class.class_initializer() {
-                // Execute the class initializer on the current thread.
-                self.execute(
-                    &current_class,
-                    &class_initializer,
-                    Vec::<Value>::new(),
-                    true,
-                )
-                .await?;
-            }
+        let vm = self.vm()?;
+        for current_class in &classes {
+            vm.publish(&VmEvent::ClassLoaded {
+                class_name: current_class.name().to_string(),
+            });
         }
         Ok(class)
     }
+
+    /// Initialize a class if it has not already been initialized.  The superclass and interfaces
+    /// are initialized before the class initializer (`<clinit>`) is executed; the class
+    /// initializer is executed at most once while holding the initialization lock of the class.
+    /// If the class is already being initialized by this thread (e.g. the class initializer
+    /// references its own class), this returns immediately.
+    ///
+    /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.5>
+    ///
+    /// # Errors
+    /// if the class, or a superclass or interface, cannot be initialized
+    #[async_recursion(?Send)]
+    pub(crate) async fn initialize_class(&self, class: &Arc<Class>) -> Result<()> {
+        match class.initialization_state()? {
+            InitializationState::Initialized => return Ok(()),
+            InitializationState::BeingInitialized(thread_id) if thread_id == self.id => {
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        // Wait for any other thread initializing the class to finish
+        let initialization_lock = class.initialization_lock();
+        let _initialization_guard = initialization_lock.lock().await;
+        match class.initialization_state()? {
+            InitializationState::Initialized => return Ok(()),
+            InitializationState::Erroneous => {
+                return Err(NoClassDefFoundError(format!(
+                    "Could not initialize class {}",
+                    class.name()
+                ))
+                .into());
+            }
+            InitializationState::NotInitialized | InitializationState::BeingInitialized(_) => {}
+        }
+
+        class.set_initialization_state(InitializationState::BeingInitialized(self.id))?;
+        let result = self.execute_class_initializers(class).await;
+        let initialization_state = if result.is_ok() {
+            InitializationState::Initialized
+        } else {
+            InitializationState::Erroneous
+        };
+        class.set_initialization_state(initialization_state)?;
+        result?;
+
+        let vm = self.vm()?;
+        vm.publish(&VmEvent::ClassInitialized {
+            class_name: class.name().to_string(),
+        });
+        Ok(())
+    }
+
+    /// Initialize the superclass and interfaces of the class and execute the class initializer.
+    ///
+    /// # Errors
+    /// if the superclass, interfaces or class initializer fail
+    async fn execute_class_initializers(&self, class: &Arc<Class>) -> Result<()> {
+        if let Some(parent) = class.parent()? {
+            self.initialize_class(&parent).await?;
+        }
+        for interface in class.interfaces()? {
+            self.initialize_class(&interface).await?;
+        }
+        if let Some(class_initializer) = class.class_initializer() {
+            // Execute the class initializer on the current thread.
+            self.execute(class, &class_initializer, Vec::<Value>::new(), true)
+                .await?;
+        }
+        Ok(())
+    }
+
     /// Prepare class initialization.
     ///
     /// # Errors
@@ -193,8 +375,10 @@ impl Thread {
                 let interface_name = current_class
                     .constant_pool()
                     .try_get_class(*interface_index)?;
-                let (interface_class, previously_loaded) =
-                    class_loader.load_with_status(interface_name).await?;
+                let (interface_class, previously_loaded) = class_loader
+                    .load_with_status(interface_name)
+                    .await
+                    .map_err(|error| class_load_error(interface_name, error))?;
                 interfaces.push(interface_class.clone());
                 if !previously_loaded && !classes.contains(&interface_class) {
                     classes.push(interface_class);
@@ -217,8 +401,10 @@ impl Thread {
                 constant_pool.try_get_class(super_class_index)?
             };
 
-            let (super_class, previously_loaded) =
-                class_loader.load_with_status(super_class_name).await?;
+            let (super_class, previously_loaded) = class_loader
+                .load_with_status(super_class_name)
+                .await
+                .map_err(|error| class_load_error(super_class_name, error))?;
             current_class.set_parent(Some(super_class.clone()))?;
             if !previously_loaded && !classes.contains(&super_class) {
                 classes.push(super_class);
@@ -258,6 +444,32 @@ impl Thread {
         method: &Arc<Method>,
         arguments: Vec<impl RustValue>,
         remove_frame: bool,
+    ) -> Result<Option<Value>> {
+        let span = trace_span!(
+            "execute",
+            method = %format_args!("{}.{}{}", class.name(), method.name(), method.descriptor()),
+            arguments = arguments.len(),
+            result = field::Empty,
+        );
+        // The span is instrumented rather than entered so that it is correctly exited across await
+        // points; it is closed when it is dropped, regardless of whether the method threw.
+        let result = self
+            .execute_method(class, method, arguments, remove_frame)
+            .instrument(span.clone())
+            .await;
+        if !span.is_disabled() {
+            span.record("result", result_kind(&result));
+        }
+        result
+    }
+
+    /// Invoke the method; see [`Thread::execute`].
+    async fn execute_method(
+        &self,
+        class: &Arc<Class>,
+        method: &Arc<Method>,
+        arguments: Vec<impl RustValue>,
+        remove_frame: bool,
     ) -> Result<Option<Value>> {
         let class_name = class.name();
         let method_name = method.name();
@@ -265,6 +477,15 @@ impl Thread {
         let vm = self.vm()?;
         let arguments = process_values(&vm, arguments).await?;
 
+        if event_enabled!(Level::TRACE) {
+            let arguments = arguments
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            trace!("arguments: {class_name}.{method_name}{method_descriptor}; [{arguments}]");
+        }
+
         if event_enabled!(Level::DEBUG) {
             let access_flags = method.access_flags();
             debug!("execute: {class_name}.{method_name}{method_descriptor} {access_flags}");
@@ -291,12 +512,9 @@ impl Thread {
             let arguments = Thread::adjust_arguments(arguments);
             let frame = Arc::new(Frame::new(&self.thread, class, method, arguments)?);
 
-            // Limit the scope of the write lock to just adding the frame to the thread. This
-            // is necessary because the thread is re-entrant.
-            {
-                let mut frames = self.frames.write().await;
-                frames.push(frame.clone());
-            }
+            // The write lock is only held while adding the frame to the thread. This is
+            // necessary because the thread is re-entrant.
+            self.push_frame(frame.clone()).await;
             let result = frame.execute().await;
             (result, remove_frame)
         };
@@ -320,8 +538,7 @@ impl Thread {
         }
 
         if frame_added {
-            let mut frames = self.frames.write().await;
-            frames.pop();
+            self.pop_frame().await;
         }
 
         result
@@ -364,6 +581,97 @@ impl Thread {
         arguments
     }
 
+    /// Box the primitive arguments passed to [`VM::invoke_on`] where the method expects a reference
+    /// (e.g. an `int` passed as a `java.lang.Object` arrives as a `java.lang.Integer`), so that the
+    /// caller need not box them.  Arguments passed between Java methods are never boxed.
+    ///
+    /// # Errors
+    /// if an argument cannot be boxed.
+    pub(crate) async fn box_arguments(
+        vm: &VM,
+        method: &Method,
+        mut arguments: Vec<Value>,
+    ) -> Result<Vec<Value>> {
+        let parameters = method.parameters();
+        let receiver = usize::from(!method.is_static());
+        for (index, argument) in arguments.iter_mut().enumerate() {
+            if matches!(argument, Value::Object(_)) {
+                continue;
+            }
+            let Some(parameter) = index
+                .checked_sub(receiver)
+                .and_then(|index| parameters.get(index))
+            else {
+                continue;
+            };
+            if matches!(parameter, FieldType::Object(_)) {
+                *argument = Box::pin(Value::boxed_as(vm, parameter, argument.clone())).await?;
+            }
+        }
+        Ok(arguments)
+    }
+
+    /// Coerce the arguments passed to [`VM::invoke_on`] to the parameter types of the method,
+    /// applying the widening primitive conversions allowed by Java (e.g. `int` to `long`).  For
+    /// instance methods, the first argument is the object the method is invoked on.  The arguments
+    /// are validated before the method is dispatched so that a mismatch is reported with the
+    /// method and argument index rather than as an invalid operand deep within a native method.
+    ///
+    /// See: <https://docs.oracle.com/javase/specs/jls/se23/html/jls-5.html#jls-5.1.2>
+    ///
+    /// # Errors
+    /// if the number of arguments does not match the method descriptor or an argument cannot be
+    /// converted to the parameter type.
+    pub(crate) fn coerce_arguments(
+        class: &Class,
+        method: &Method,
+        arguments: Vec<Value>,
+    ) -> Result<Vec<Value>> {
+        let method_name = || format!("{}.{}{}", class.name(), method.name(), method.descriptor());
+        let parameters = method.parameters();
+        let receiver = usize::from(!method.is_static());
+        if arguments.len() != parameters.len() + receiver {
+            return Err(InvalidArgumentCount {
+                method: method_name(),
+                expected: parameters.len() + receiver,
+                actual: arguments.len(),
+            });
+        }
+
+        let mut coerced_arguments = Vec::with_capacity(arguments.len());
+        for (index, argument) in arguments.into_iter().enumerate() {
+            let Some(parameter) = index
+                .checked_sub(receiver)
+                .and_then(|index| parameters.get(index))
+            else {
+                // The object the method is invoked on must be a reference
+                if !matches!(argument, Value::Object(_)) {
+                    return Err(InvalidArgument {
+                        method: method_name(),
+                        index,
+                        expected: class.name().to_string(),
+                        actual: argument.to_string(),
+                    });
+                }
+                coerced_arguments.push(argument);
+                continue;
+            };
+            let argument = match coerce_argument(parameter, argument) {
+                Ok(argument) => argument,
+                Err(argument) => {
+                    return Err(InvalidArgument {
+                        method: method_name(),
+                        index,
+                        expected: parameter.to_string(),
+                        actual: argument.to_string(),
+                    });
+                }
+            };
+            coerced_arguments.push(argument);
+        }
+        Ok(coerced_arguments)
+    }
+
     /// Create a new VM Object by invoking the constructor of the specified class.
     ///
     /// # Errors
@@ -399,8 +707,10 @@ impl Thread {
 
         let object = {
             self.execute(&class, &constructor, arguments, false).await?;
-            let mut frames = self.frames.write().await;
-            let frame = frames.pop().ok_or(InternalError("No frame".to_string()))?;
+            let frame = self
+                .pop_frame()
+                .await
+                .ok_or(InternalError("No frame".to_string()))?;
             let locals = frame.locals();
             locals.get(0)?
         };
@@ -409,6 +719,95 @@ impl Thread {
     }
 }
 
+/// Convert an argument to the given parameter type; returns the argument as the error if the
+/// conversion is not allowed.  The `boolean`, `byte`, `char` and `short` types are represented as
+/// `int` values.
+#[expect(clippy::cast_precision_loss)]
+fn coerce_argument(parameter: &FieldType, argument: Value) -> Result<Value, Value> {
+    let value = match (parameter, argument) {
+        (
+            FieldType::Base(
+                BaseType::Boolean
+                | BaseType::Byte
+                | BaseType::Char
+                | BaseType::Int
+                | BaseType::Short,
+            ),
+            Value::Int(value),
+        ) => Value::Int(value),
+        (FieldType::Base(BaseType::Long), Value::Int(value)) => Value::Long(i64::from(value)),
+        (FieldType::Base(BaseType::Long), Value::Long(value)) => Value::Long(value),
+        (FieldType::Base(BaseType::Float), Value::Int(value)) => Value::Float(value as f32),
+        (FieldType::Base(BaseType::Float), Value::Long(value)) => Value::Float(value as f32),
+        (FieldType::Base(BaseType::Float), Value::Float(value)) => Value::Float(value),
+        (FieldType::Base(BaseType::Double), Value::Int(value)) => Value::Double(f64::from(value)),
+        (FieldType::Base(BaseType::Double), Value::Long(value)) => Value::Double(value as f64),
+        (FieldType::Base(BaseType::Double), Value::Float(value)) => Value::Double(f64::from(value)),
+        (FieldType::Base(BaseType::Double), Value::Double(value)) => Value::Double(value),
+        (FieldType::Object(_), Value::Object(value)) => Value::Object(value),
+        (FieldType::Array(component_type), Value::Object(value)) => {
+            if value
+                .as_ref()
+                .is_some_and(|reference| !is_array_of(component_type, reference))
+            {
+                return Err(Value::Object(value));
+            }
+            Value::Object(value)
+        }
+        (_, argument) => return Err(argument),
+    };
+    Ok(value)
+}
+
+/// Returns true if the reference is an array that can be assigned to an array with the given
+/// component type; the component types of object arrays are not checked.  `boolean` arrays are
+/// represented as `byte` arrays.
+fn is_array_of(component_type: &FieldType, reference: &Reference) -> bool {
+    matches!(
+        (component_type, reference),
+        (
+            FieldType::Base(BaseType::Boolean | BaseType::Byte),
+            Reference::ByteArray(_)
+        ) | (FieldType::Base(BaseType::Char), Reference::CharArray(_))
+            | (FieldType::Base(BaseType::Short), Reference::ShortArray(_))
+            | (FieldType::Base(BaseType::Int), Reference::IntArray(_))
+            | (FieldType::Base(BaseType::Long), Reference::LongArray(_))
+            | (FieldType::Base(BaseType::Float), Reference::FloatArray(_))
+            | (FieldType::Base(BaseType::Double), Reference::DoubleArray(_))
+            | (
+                FieldType::Object(_) | FieldType::Array(_),
+                Reference::Array(_, _)
+            )
+    )
+}
+
+/// Convert an error loading a class into the error raised by the VM; a class file that is
+/// structurally invalid (e.g. truncated) is a `ClassFormatError`.
+fn class_load_error(class_name: &str, error: ristretto_classloader::Error) -> Error {
+    match error {
+        ristretto_classloader::Error::ClassFileError(error) => {
+            ClassFormatError(format!("{class_name}: {error}")).into()
+        }
+        error => error.into(),
+    }
+}
+
+/// Get the kind of result returned by a method invocation for tracing.
+fn result_kind(result: &Result<Option<Value>>) -> &'static str {
+    match result {
+        Ok(None) => "void",
+        Ok(Some(Value::Int(_))) => "int",
+        Ok(Some(Value::Long(_))) => "long",
+        Ok(Some(Value::Float(_))) => "float",
+        Ok(Some(Value::Double(_))) => "double",
+        Ok(Some(Value::Object(None))) => "null",
+        Ok(Some(Value::Object(Some(_)))) => "object",
+        Ok(Some(Value::ReturnAddress(_))) => "returnAddress",
+        Ok(Some(Value::Unused)) => "unused",
+        Err(_) => "error",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -434,6 +833,33 @@ mod tests {
         VM::new(configuration).await
     }
 
+    #[test]
+    fn test_result_kind() {
+        assert_eq!("void", result_kind(&Ok(None)));
+        assert_eq!("int", result_kind(&Ok(Some(Value::Int(42)))));
+        assert_eq!("null", result_kind(&Ok(Some(Value::Object(None)))));
+        assert_eq!(
+            "error",
+            result_kind(&Err(InternalError("test".to_string())))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_truncated_class_format_error() -> Result<()> {
+        let bytes = include_bytes!("../../classes/HelloWorld.class");
+        let mut class_path = classes_jar_class_path();
+        class_path.add_memory_class("Truncated", bytes[..bytes.len() / 2].to_vec());
+        let configuration = ConfigurationBuilder::new().class_path(class_path).build()?;
+        let vm = VM::new(configuration).await?;
+        let thread = vm.new_thread()?;
+        let result = thread.class("Truncated").await;
+        let Err(Error::JavaError(ClassFormatError(message))) = result else {
+            panic!("expected ClassFormatError");
+        };
+        assert!(message.starts_with("Truncated: "));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_hello_world_class() -> Result<()> {
         let vm = test_vm().await?;
@@ -468,6 +894,142 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_invoke_widens_int_to_long() -> Result<()> {
+        let vm = test_vm().await?;
+        let result = vm
+            .invoke("java.lang.Math", "abs", "(J)J", vec![-42i32])
+            .await?;
+        assert_eq!(Some(Value::Long(42)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invoke_widens_int_to_double() -> Result<()> {
+        let vm = test_vm().await?;
+        let result = vm
+            .invoke("java.lang.Math", "abs", "(D)D", vec![-42i32])
+            .await?;
+        assert_eq!(Some(Value::Double(42.0)), result);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invoke_invalid_argument() -> Result<()> {
+        let vm = test_vm().await?;
+        let result = vm
+            .invoke(
+                "java.lang.Integer",
+                "parseInt",
+                "(Ljava/lang/String;)I",
+                vec![42i32],
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(InvalidArgument { method, index, expected, actual })
+                if method == "java/lang/Integer.parseInt(Ljava/lang/String;)I"
+                    && index == 0
+                    && expected == "java/lang/String"
+                    && actual == "int(42)"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invoke_invalid_argument_count() -> Result<()> {
+        let vm = test_vm().await?;
+        let result = vm
+            .invoke("java.lang.Math", "abs", "(J)J", vec![1i64, 2i64])
+            .await;
+        assert!(matches!(
+            result,
+            Err(InvalidArgumentCount {
+                expected: 1,
+                actual: 2,
+                ..
+            })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invoke_invalid_receiver() -> Result<()> {
+        let vm = test_vm().await?;
+        let thread = vm.new_thread()?;
+        let result = vm
+            .invoke_on(&thread, "java.lang.String", "length", "()I", vec![42i32])
+            .await;
+        assert!(matches!(
+            result,
+            Err(InvalidArgument { method, index, expected, actual })
+                if method == "java/lang/String.length()I"
+                    && index == 0
+                    && expected == "java/lang/String"
+                    && actual == "int(42)"
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invoke_invalid_array_argument() -> Result<()> {
+        let vm = test_vm().await?;
+        let result = vm
+            .invoke(
+                "java.util.Arrays",
+                "hashCode",
+                "([I)I",
+                vec![Value::Object(Some(Reference::from(vec![1i64, 2i64])))],
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(InvalidArgument { method, index, expected, .. })
+                if method == "java/util/Arrays.hashCode([I)I"
+                    && index == 0
+                    && expected == "int[]"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_coerce_argument() {
+        let long = FieldType::Base(BaseType::Long);
+        let float = FieldType::Base(BaseType::Float);
+        let string = FieldType::Object("java/lang/String".to_string());
+        assert_eq!(Ok(Value::Long(1)), coerce_argument(&long, Value::Int(1)));
+        assert_eq!(
+            Ok(Value::Float(2.0)),
+            coerce_argument(&float, Value::Long(2))
+        );
+        assert_eq!(
+            Ok(Value::Object(None)),
+            coerce_argument(&string, Value::Object(None))
+        );
+        // Narrowing conversions are not allowed
+        let int = FieldType::Base(BaseType::Int);
+        assert_eq!(Err(Value::Long(1)), coerce_argument(&int, Value::Long(1)));
+        assert_eq!(
+            Err(Value::Double(2.0)),
+            coerce_argument(&float, Value::Double(2.0))
+        );
+        assert_eq!(Err(Value::Int(3)), coerce_argument(&string, Value::Int(3)));
+
+        // Arrays must have a matching component type
+        let bytes = Value::Object(Some(Reference::from(vec![1i8])));
+        let boolean_array = FieldType::Array(Box::new(FieldType::Base(BaseType::Boolean)));
+        let int_array = FieldType::Array(Box::new(FieldType::Base(BaseType::Int)));
+        assert_eq!(
+            Ok(bytes.clone()),
+            coerce_argument(&boolean_array, bytes.clone())
+        );
+        assert_eq!(
+            Ok(Value::Object(None)),
+            coerce_argument(&int_array, Value::Object(None))
+        );
+        assert_eq!(Err(bytes.clone()), coerce_argument(&int_array, bytes));
+    }
+
     #[test]
     fn test_adjust_arguments() {
         let arguments = vec![
@@ -1,20 +1,28 @@
+#[cfg(feature = "debug")]
+use crate::debugger::{Breakpoint, DebugState, Debugger};
+use crate::event::{EventBus, VmEvent, VmEventListener};
+use crate::file_handles::FileHandles;
+use crate::instruction::try_get_virtual_method;
 use crate::java_object::JavaObject;
+use crate::native_memory::NativeMemory;
 use crate::native_methods::MethodRegistry;
-use crate::rust_value::RustValue;
+use crate::rust_value::{process_values, RustValue};
+use crate::signals::{Signals, DEFAULT_HANDLER, DISPATCH_HANDLER};
 use crate::thread::Thread;
-use crate::Error::InternalError;
+use crate::Error::{ClassPreloadError, ConfigurationError, InternalError};
 use crate::{Configuration, ConfigurationBuilder, Result};
 use dashmap::DashMap;
 use ristretto_classfile::Version;
 use ristretto_classloader::manifest::MAIN_CLASS;
+use ristretto_classloader::Error::RuntimeNotInstalled;
 use ristretto_classloader::{
     runtime, Class, ClassLoader, ClassPath, ClassPathEntry, ConcurrentVec, Object, Reference, Value,
 };
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::{Arc, Weak};
-use tokio::sync::RwLock;
+use std::sync::{Arc, OnceLock, Weak};
+use tokio::sync::{Notify, RwLock};
 use tracing::debug;
 
 const JAVA_8: Version = Version::Java8 { minor: 0 };
@@ -33,6 +41,18 @@ pub struct VM {
     method_registry: MethodRegistry,
     next_thread_id: AtomicU64,
     threads: DashMap<u64, Arc<Thread>>,
+    class_objects: DashMap<usize, (Arc<Class>, Value)>,
+    interned_strings: DashMap<String, Value>,
+    event_bus: EventBus,
+    #[cfg(feature = "debug")]
+    debug_state: DebugState,
+    native_memory: NativeMemory,
+    file_handles: FileHandles,
+    signals: Signals,
+    signal_dispatcher: tokio::sync::OnceCell<Arc<Thread>>,
+    boot_loader_unnamed_module: OnceLock<Value>,
+    monitor_notify: Notify,
+    exit_code: OnceLock<i32>,
 }
 
 /// VM
@@ -49,8 +69,18 @@ impl VM {
     pub async fn new(configuration: Configuration) -> Result<Arc<Self>> {
         let (java_home, java_version, bootstrap_class_loader) =
             if let Some(java_version) = configuration.java_version() {
-                let (java_home, java_version, boostrap_class_loader) =
-                    runtime::version_class_loader(java_version).await?;
+                let (java_home, java_version, boostrap_class_loader) = if configuration.offline() {
+                    runtime::installed_version_class_loader(java_version)
+                        .await
+                        .map_err(|error| match error {
+                            RuntimeNotInstalled { .. } => ConfigurationError(format!(
+                                "{error}; the runtime cannot be downloaded in offline mode"
+                            )),
+                            error => error.into(),
+                        })?
+                } else {
+                    runtime::version_class_loader(java_version).await?
+                };
                 (java_home, java_version, boostrap_class_loader)
             } else if let Some(java_home) = configuration.java_home() {
                 let (java_home, java_version, boostrap_class_loader) =
@@ -125,6 +155,18 @@ impl VM {
             method_registry,
             next_thread_id: AtomicU64::new(1),
             threads: DashMap::new(),
+            class_objects: DashMap::new(),
+            interned_strings: DashMap::new(),
+            event_bus: EventBus::new(),
+            #[cfg(feature = "debug")]
+            debug_state: DebugState::new(),
+            native_memory: NativeMemory::new(),
+            file_handles: FileHandles::new(),
+            signals: Signals::new(vm.clone()),
+            signal_dispatcher: tokio::sync::OnceCell::new(),
+            boot_loader_unnamed_module: OnceLock::new(),
+            monitor_notify: Notify::new(),
+            exit_code: OnceLock::new(),
         });
         vm.initialize().await?;
         Ok(vm)
@@ -210,12 +252,268 @@ impl VM {
     ///
     /// # Errors
     /// if the thread cannot be created
-    pub(crate) fn new_thread(&self) -> Result<Arc<Thread>> {
+    pub fn new_thread(&self) -> Result<Arc<Thread>> {
         let thread = Thread::new(&self.vm)?;
         self.threads.insert(thread.id(), thread.clone());
+        self.publish(&VmEvent::ThreadStarted {
+            thread_id: thread.id(),
+        });
         Ok(thread)
     }
 
+    /// Subscribe a listener to VM events (class loading and initialization, thread creation and
+    /// thrown exceptions).  Events are delivered synchronously and on a best-effort basis; the
+    /// listener only receives events emitted after it is subscribed.
+    pub fn subscribe(&self, listener: Arc<dyn VmEventListener>) {
+        self.event_bus.subscribe(listener);
+    }
+
+    /// Returns true if any listeners are subscribed to VM events.
+    pub(crate) fn has_event_listeners(&self) -> bool {
+        self.event_bus.has_listeners()
+    }
+
+    /// Deliver an event to the subscribed listeners.
+    pub(crate) fn publish(&self, event: &VmEvent) {
+        self.event_bus.publish(event);
+    }
+
+    /// Set the debugger that receives control when a thread stops at a breakpoint or while
+    /// single-stepping.
+    #[cfg(feature = "debug")]
+    pub fn set_debugger(&self, debugger: Arc<dyn Debugger>) {
+        self.debug_state.set_debugger(debugger);
+    }
+
+    /// Add a breakpoint at which threads stop before executing the instruction; returns false if
+    /// the breakpoint already exists.  Threads only stop if a debugger is set.
+    #[cfg(feature = "debug")]
+    pub fn add_breakpoint(&self, breakpoint: Breakpoint) -> bool {
+        self.debug_state.add_breakpoint(breakpoint)
+    }
+
+    /// Remove a breakpoint; returns false if the breakpoint does not exist.
+    #[cfg(feature = "debug")]
+    pub fn remove_breakpoint(&self, breakpoint: &Breakpoint) -> bool {
+        self.debug_state.remove_breakpoint(breakpoint)
+    }
+
+    /// Get the debugger and breakpoints of the VM
+    #[cfg(feature = "debug")]
+    pub(crate) fn debug_state(&self) -> &DebugState {
+        &self.debug_state
+    }
+
+    /// Get the off-heap memory allocated by the VM
+    pub(crate) fn native_memory(&self) -> &NativeMemory {
+        &self.native_memory
+    }
+
+    /// Get the files opened by the VM
+    pub(crate) fn file_handles(&self) -> &FileHandles {
+        &self.file_handles
+    }
+
+    /// Get the signals handled by the VM
+    pub(crate) fn signals(&self) -> &Signals {
+        &self.signals
+    }
+
+    /// Get the unnamed module of the bootstrap class loader; `None` until the module system sets it
+    /// with `BootLoader.setBootLoaderUnnamedModule0`.
+    pub(crate) fn boot_loader_unnamed_module(&self) -> Option<&Value> {
+        self.boot_loader_unnamed_module.get()
+    }
+
+    /// Set the unnamed module of the bootstrap class loader.
+    ///
+    /// # Errors
+    /// if the unnamed module has already been set
+    pub(crate) fn set_boot_loader_unnamed_module(&self, module: Value) -> Result<()> {
+        self.boot_loader_unnamed_module
+            .set(module)
+            .map_err(|_| InternalError("boot loader unnamed module already set".to_string()))
+    }
+
+    /// Dispatch a signal received by the signal dispatcher; signals with the dispatch handler are
+    /// passed to `Signal.dispatch` on the "Signal Dispatcher" thread, which starts a thread to run
+    /// the Java handler, and signals with the default handler shut down the VM with the exit code
+    /// of a process terminated by the signal.
+    ///
+    /// # Errors
+    /// if the signal cannot be dispatched
+    pub(crate) async fn dispatch_signal(&self, signal: i32) -> Result<()> {
+        let native_handler = self.signals.handler(signal)?;
+        debug!("signal {signal}: handler {native_handler:?}");
+        match native_handler {
+            Some(DEFAULT_HANDLER) => self.shutdown(128 + signal).await,
+            Some(DISPATCH_HANDLER) => {
+                let class_name = if self.java_class_file_version <= JAVA_8 {
+                    "sun.misc.Signal"
+                } else {
+                    "jdk.internal.misc.Signal"
+                };
+                let thread = self
+                    .signal_dispatcher
+                    .get_or_try_init(|| async {
+                        let thread = self.new_thread()?;
+                        self.initialize_thread_object(&thread).await?;
+                        Ok::<_, crate::Error>(thread)
+                    })
+                    .await?;
+                self.invoke_on(
+                    thread,
+                    class_name,
+                    "dispatch",
+                    "(I)V",
+                    vec![Value::Int(signal)],
+                )
+                .await?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the `java.lang.Class` object of the class, if it has been created.  Class objects are
+    /// cached per class rather than per class name, since classes with the same name may be
+    /// defined by different class loaders.
+    pub(crate) fn class_object(&self, class: &Arc<Class>) -> Option<Value> {
+        self.class_objects
+            .get(&(Arc::as_ptr(class) as usize))
+            .map(|class_object| class_object.value().1.clone())
+    }
+
+    /// Cache the `java.lang.Class` object of the class and return the cached object; if an object
+    /// was cached first (e.g. by another thread), that object is returned so that every reference
+    /// to the class is the same object.  The class is held by the cache so that its address is not
+    /// reused by another class.
+    pub(crate) fn cache_class_object(&self, class: &Arc<Class>, class_object: Value) -> Value {
+        self.class_objects
+            .entry(Arc::as_ptr(class) as usize)
+            .or_insert_with(|| (class.clone(), class_object))
+            .value()
+            .1
+            .clone()
+    }
+
+    /// Get the interned `java.lang.String` object with the value, if the value has been interned.
+    pub(crate) fn interned_string(&self, value: &str) -> Option<Value> {
+        self.interned_strings
+            .get(value)
+            .map(|string| string.value().clone())
+    }
+
+    /// Intern a `java.lang.String` object with the value and return the interned object; if a
+    /// string with the same value was interned first, that object is returned so that equal
+    /// string literals and `String.intern()` results are the same object.
+    ///
+    /// See: <https://docs.oracle.com/javase/specs/jls/se23/html/jls-3.html#jls-3.10.5>
+    pub(crate) fn intern_string(&self, value: String, string: Value) -> Value {
+        self.interned_strings
+            .entry(value)
+            .or_insert(string)
+            .value()
+            .clone()
+    }
+
+    /// Get the notifier signaled by `Object.notify` and `Object.notifyAll`.  Object monitors are
+    /// not implemented, so every waiting thread is woken; the Java Language Specification permits
+    /// such spurious wakeups.
+    pub(crate) fn monitor_notify(&self) -> &Notify {
+        &self.monitor_notify
+    }
+
+    /// Get the exit code the VM was shut down with; `None` if the VM has not been shut down.
+    #[must_use]
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code.get().copied()
+    }
+
+    /// Shut down the VM as `System.exit` does, without terminating the host process.  The
+    /// application shutdown hooks (registered with `Runtime.addShutdownHook`) and the system
+    /// shutdown hooks are run, then `System.out` and `System.err` are flushed.  Shutting down is
+    /// idempotent; only the first call runs the hooks and records the exit code.
+    ///
+    /// # Errors
+    /// if a shutdown hook throws an exception or the standard streams cannot be flushed; the
+    /// remaining hooks are run and the streams are flushed regardless.
+    pub async fn shutdown(&self, exit_code: i32) -> Result<()> {
+        if self.exit_code.set(exit_code).is_err() {
+            return Ok(());
+        }
+        debug!("shutdown: exit code {exit_code}");
+        let hooks_result = self.run_shutdown_hooks().await;
+        let flush_result = self.flush_standard_streams().await;
+        hooks_result?;
+        flush_result
+    }
+
+    /// Run the application shutdown hooks followed by the system shutdown hooks.
+    ///
+    /// # Errors
+    /// with the first exception thrown by a shutdown hook
+    async fn run_shutdown_hooks(&self) -> Result<()> {
+        let mut result = Ok(());
+        let hooks_class = self.class("java.lang.ApplicationShutdownHooks").await?;
+        let hooks = hooks_class.static_field("hooks")?.value()?;
+        if matches!(hooks, Value::Object(Some(_))) {
+            // Thread.start() does not run the thread, so each hook is run on the primordial
+            // thread instead.  The hooks are stored as the keys of an IdentityHashMap, whose table
+            // alternates between keys and values.
+            let thread = self.primordial_thread()?;
+            let hooks_map: Object = hooks.clone().try_into()?;
+            let table: Reference = hooks_map.value("table")?.try_into()?;
+            let Reference::Array(_, table) = table else {
+                return Err(InternalError(
+                    "IdentityHashMap.table is not an object array".to_string(),
+                ));
+            };
+            for reference in table.to_vec()?.into_iter().step_by(2).flatten() {
+                let Reference::Object(hook) = &reference else {
+                    continue;
+                };
+                let (class, method) = try_get_virtual_method(hook.class(), "run", "()V")?;
+                let run_result = thread
+                    .execute(&class, &method, vec![Value::Object(Some(reference))], true)
+                    .await;
+                if let Err(error) = run_result {
+                    debug!("shutdown hook failed: {error}");
+                    if result.is_ok() {
+                        result = Err(error);
+                    }
+                }
+            }
+            // The hooks have been run; clear them so that they are not started again by the
+            // system shutdown hooks.
+            self.invoke("java.util.IdentityHashMap", "clear", "()V", vec![hooks])
+                .await?;
+        }
+
+        self.invoke("java.lang.Shutdown", "shutdown", "()V", Vec::<Value>::new())
+            .await?;
+        result
+    }
+
+    /// Flush `System.out` and `System.err`.
+    ///
+    /// # Errors
+    /// if a stream cannot be flushed
+    async fn flush_standard_streams(&self) -> Result<()> {
+        let system_class = self.class("java.lang.System").await?;
+        for stream_name in ["out", "err"] {
+            let stream = system_class.static_field(stream_name)?.value()?;
+            if matches!(stream, Value::Object(None)) {
+                continue;
+            }
+            let stream_object: Object = stream.clone().try_into()?;
+            let (class, method) = try_get_virtual_method(stream_object.class(), "flush", "()V")?;
+            let thread = self.primordial_thread()?;
+            thread.execute(&class, &method, vec![stream], true).await?;
+        }
+        Ok(())
+    }
+
     /// Initialize the VM
     ///
     /// # Errors
@@ -231,6 +529,7 @@ impl VM {
             )
             .await?;
         } else {
+            self.initialize_unsafe_constants().await?;
             self.invoke("java.lang.System", "initPhase1", "()V", Vec::<Value>::new())
                 .await?;
 
@@ -257,15 +556,67 @@ impl VM {
                 .await?;
         }
 
+        self.preload_classes().await
+    }
+
+    /// Set the constants of `jdk.internal.misc.UnsafeConstants`; the class initializer sets every
+    /// constant to zero and the VM is expected to inject the values for the host before
+    /// `jdk.internal.misc.Unsafe` reads them.
+    ///
+    /// # Errors
+    /// if the class cannot be initialized or a constant cannot be set
+    async fn initialize_unsafe_constants(&self) -> Result<()> {
+        let class = self
+            .initialize_class("jdk.internal.misc.UnsafeConstants")
+            .await?;
+        let constants = [
+            ("ADDRESS_SIZE0", Value::Int(8)), // 64-bit pointers
+            ("PAGE_SIZE", Value::Int(4096)),
+            ("BIG_ENDIAN", Value::from(cfg!(target_endian = "big"))),
+            ("UNALIGNED_ACCESS", Value::from(true)),
+            ("DATA_CACHE_LINE_FLUSH_SIZE", Value::Int(0)),
+        ];
+        for (name, value) in constants {
+            class.static_field(name)?.set_value(value)?;
+        }
         Ok(())
     }
 
+    /// Preload the classes specified in the configuration so that they are available before any
+    /// user code runs.
+    ///
+    /// # Errors
+    /// if any of the classes cannot be loaded; the error lists every class that failed to load
+    async fn preload_classes(&self) -> Result<()> {
+        let mut failed_classes = Vec::new();
+        for class_name in self.configuration.preload_classes() {
+            if let Err(error) = self.class(class_name).await {
+                debug!("Unable to preload class {class_name}: {error}");
+                failed_classes.push(class_name.clone());
+            }
+        }
+
+        if failed_classes.is_empty() {
+            Ok(())
+        } else {
+            Err(ClassPreloadError(failed_classes))
+        }
+    }
+
     /// Initialize the primordial thread
     ///
     /// # Errors
     /// if the primordial thread cannot be initialized
     async fn initialize_primordial_thread(&self) -> Result<()> {
         let thread = self.new_thread()?;
+        self.initialize_thread_object(&thread).await
+    }
+
+    /// Create the `java.lang.Thread` object of a thread started by the VM
+    ///
+    /// # Errors
+    /// if the thread object cannot be created
+    async fn initialize_thread_object(&self, thread: &Arc<Thread>) -> Result<()> {
         let thread_id = i64::try_from(thread.id())?;
         let thread_group = thread
             .object("java/lang/ThreadGroup", "", Vec::<Value>::new())
@@ -327,7 +678,8 @@ impl VM {
         class_name.replace('.', "/")
     }
 
-    /// Load a class (e.g. "java.lang.Object").
+    /// Load a class (e.g. "java.lang.Object"); the class is initialized if it has not already
+    /// been initialized.
     ///
     /// # Errors
     /// if the class cannot be loaded
@@ -337,6 +689,23 @@ impl VM {
         thread.class(class_name).await
     }
 
+    /// Load a class (e.g. "java.lang.Object") and initialize it, as `Class.forName(name)` does.
+    /// The class initializer (`<clinit>`) is executed at most once; if the class is already
+    /// initialized or is being initialized by the current thread, the class is returned without
+    /// executing the class initializer.
+    ///
+    /// See: <https://docs.oracle.com/javase/specs/jvms/se23/html/jvms-5.html#jvms-5.5>
+    ///
+    /// # Errors
+    /// if the class cannot be loaded or initialized
+    pub async fn initialize_class<S: AsRef<str>>(&self, class_name: S) -> Result<Arc<Class>> {
+        let class_name = Self::get_class_name(class_name);
+        let thread = self.primordial_thread()?;
+        let class = thread.load_class(class_name).await?;
+        thread.initialize_class(&class).await?;
+        Ok(class)
+    }
+
     /// Invoke the main method of the main class associated with the VM. The main method must have
     /// the signature `public static void main(String[] args)`.
     ///
@@ -398,9 +767,47 @@ impl VM {
         M: AsRef<str>,
         D: AsRef<str>,
     {
-        let class = self.class(class).await?;
-        let method = class.try_get_method(method, descriptor)?;
         let thread = self.primordial_thread()?;
+        self.invoke_on(&thread, class, method, descriptor, arguments)
+            .await
+    }
+
+    /// Invoke a method on the frame stack of a thread of this VM (see [`VM::new_thread`]); the
+    /// class is initialized on the thread if it has not already been initialized.  To invoke a
+    /// method on an object reference, the object reference must be the first argument in the
+    /// arguments vector.
+    ///
+    /// # Errors
+    /// if the thread does not belong to this VM or the method cannot be invoked
+    pub async fn invoke_on<C, M, D>(
+        &self,
+        thread: &Arc<Thread>,
+        class: C,
+        method: M,
+        descriptor: D,
+        arguments: Vec<impl RustValue>,
+    ) -> Result<Option<Value>>
+    where
+        C: AsRef<str>,
+        M: AsRef<str>,
+        D: AsRef<str>,
+    {
+        let is_vm_thread = self
+            .threads
+            .get(&thread.id())
+            .is_some_and(|entry| Arc::ptr_eq(entry.value(), thread));
+        if !is_vm_thread {
+            return Err(InternalError(format!(
+                "Thread {} does not belong to this VM",
+                thread.id()
+            )));
+        }
+        let class_name = Self::get_class_name(class);
+        let class = thread.class(class_name).await?;
+        let method = class.try_get_method(method, descriptor)?;
+        let arguments = process_values(self, arguments).await?;
+        let arguments = Thread::box_arguments(self, &method, arguments).await?;
+        let arguments = Thread::coerce_arguments(&class, &method, arguments)?;
         thread.execute(&class, &method, arguments, true).await
     }
 
@@ -451,8 +858,11 @@ impl VM {
 mod tests {
     use super::*;
     use crate::configuration::ConfigurationBuilder;
-    use ristretto_classloader::{ClassPath, DEFAULT_JAVA_VERSION};
+    use crate::signals::IGNORE_HANDLER;
+    use crate::test::{isolated_vm, MemoryHostIo};
+    use ristretto_classloader::{ClassPath, InitializationState, DEFAULT_JAVA_VERSION};
     use std::path::PathBuf;
+    use std::time::Duration;
 
     fn classes_jar_path() -> PathBuf {
         let cargo_manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -497,6 +907,48 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_vm_new_offline() -> Result<()> {
+        let vm = test_vm().await?;
+        let configuration = ConfigurationBuilder::new()
+            .java_version(DEFAULT_JAVA_VERSION)
+            .offline(true)
+            .build()?;
+        let offline_vm = VM::new(configuration).await?;
+        assert_eq!(vm.java_home(), offline_vm.java_home());
+        assert_eq!(vm.java_version(), offline_vm.java_version());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_vm_new_offline_java_home() -> Result<()> {
+        let vm = test_vm().await?;
+        let configuration = ConfigurationBuilder::new()
+            .java_home(vm.java_home().clone())
+            .offline(true)
+            .build()?;
+        let offline_vm = VM::new(configuration).await?;
+        assert_eq!(vm.java_version(), offline_vm.java_version());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_vm_new_offline_not_installed() -> Result<()> {
+        let configuration = ConfigurationBuilder::new()
+            .java_version("0.0.0")
+            .offline(true)
+            .build()?;
+        let result = tokio::time::timeout(Duration::from_secs(10), VM::new(configuration))
+            .await
+            .expect("offline mode should not perform network I/O");
+        let Err(ConfigurationError(message)) = result else {
+            panic!("expected a configuration error");
+        };
+        assert!(message.contains("0.0.0"));
+        assert!(message.contains("offline mode"));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_vm_set_main_class() -> Result<()> {
         let class_path = classes_jar_class_path();
@@ -528,6 +980,124 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_vm_memory_class_path() -> Result<()> {
+        let mut classes = HashMap::new();
+        classes.insert(
+            "HelloWorld".to_string(),
+            include_bytes!("../../classes/HelloWorld.class").to_vec(),
+        );
+        let class_path_entry = ClassPathEntry::from_memory("memory", classes);
+        let configuration = ConfigurationBuilder::new()
+            .class_path(ClassPath::new(vec![class_path_entry]))
+            .build()?;
+        let vm = VM::new(configuration).await?;
+        let class = vm.class("HelloWorld").await?;
+        assert_eq!("HelloWorld", class.name());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_vm_initialize_class() -> Result<()> {
+        let (vm, _thread, _class) = crate::test::class().await?;
+        for _ in 0..2 {
+            let class = vm.initialize_class("StaticInitializer").await?;
+            assert_eq!(
+                InitializationState::Initialized,
+                class.initialization_state()?
+            );
+            // The class initializer is executed once
+            let initializations = class.static_field("initializations")?.value()?;
+            assert_eq!(Value::Int(1), initializations);
+            // The class initializer reads a static field of its own class while the class is
+            // being initialized
+            assert_eq!(Value::Int(21), class.static_field("value")?.value()?);
+            assert_eq!(Value::Int(42), class.static_field("doubled")?.value()?);
+        }
+        Ok(())
+    }
+
+    #[derive(Default)]
+    struct RecordingListener {
+        events: std::sync::Mutex<Vec<VmEvent>>,
+    }
+
+    impl VmEventListener for RecordingListener {
+        fn on_event(&self, event: &VmEvent) {
+            self.events.lock().expect("events").push(event.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_vm_subscribe() -> Result<()> {
+        let vm = test_vm().await?;
+        let listener = Arc::new(RecordingListener::default());
+        vm.subscribe(listener.clone());
+
+        let thread = vm.new_thread()?;
+        let _ = vm.class("HelloWorld").await?;
+        let value = "foo".to_object(&vm).await?;
+        let result = vm
+            .invoke(
+                "java.lang.Integer",
+                "parseInt",
+                "(Ljava/lang/String;)I",
+                vec![value],
+            )
+            .await;
+        assert!(result.is_err());
+
+        let events = listener.events.lock().expect("events").clone();
+        assert!(events.contains(&VmEvent::ThreadStarted {
+            thread_id: thread.id()
+        }));
+        assert!(events.contains(&VmEvent::ClassLoaded {
+            class_name: "HelloWorld".to_string()
+        }));
+        assert!(events.contains(&VmEvent::ClassInitialized {
+            class_name: "HelloWorld".to_string()
+        }));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            VmEvent::ExceptionThrown { exception_class_name, .. }
+                if exception_class_name == "java/lang/NumberFormatException"
+        )));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_vm_preload_classes() -> Result<()> {
+        let class_path = classes_jar_class_path();
+        let configuration = ConfigurationBuilder::new()
+            .class_path(class_path)
+            .preload_classes(vec!["HelloWorld".to_string()])
+            .build()?;
+        let vm = VM::new(configuration).await?;
+        let class_loader = vm.class_loader();
+        let class_loader = class_loader.read().await;
+        assert!(class_loader.is_loaded("HelloWorld").await);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_vm_preload_classes_error() -> Result<()> {
+        let class_path = classes_jar_class_path();
+        let configuration = ConfigurationBuilder::new()
+            .class_path(class_path)
+            .preload_classes(vec![
+                "Foo".to_string(),
+                "HelloWorld".to_string(),
+                "Bar".to_string(),
+            ])
+            .build()?;
+        let result = VM::new(configuration).await;
+        assert!(matches!(
+            result,
+            Err(ClassPreloadError(classes)) if classes == vec!["Foo".to_string(), "Bar".to_string()]
+        ));
+        Ok(())
+    }
+
     async fn test_load_primitive_class(class_name: &str) -> Result<()> {
         let vm = VM::default().await?;
         let class = vm.class(class_name).await?;
@@ -611,4 +1181,248 @@ mod tests {
         assert_eq!("foo", value);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_new_object_string_from_bytes() -> Result<()> {
+        let vm = test_vm().await?;
+        let bytes = "foo".as_bytes().to_vec();
+        let object = vm.object("java.lang.String", "[B", vec![bytes]).await?;
+        let value: String = object.try_into()?;
+        assert_eq!("foo", value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_new_object_string_from_bytes_charset() -> Result<()> {
+        let vm = test_vm().await?;
+        let charset_name = "UTF-8".to_object(&vm).await?;
+        let charset = vm
+            .try_invoke(
+                "java.nio.charset.Charset",
+                "forName",
+                "(Ljava/lang/String;)Ljava/nio/charset/Charset;",
+                vec![charset_name],
+            )
+            .await?;
+        let bytes = Value::from("héllo".as_bytes().to_vec());
+        let object = vm
+            .object(
+                "java.lang.String",
+                "[BLjava/nio/charset/Charset;",
+                vec![bytes, charset],
+            )
+            .await?;
+        let value: String = object.try_into()?;
+        assert_eq!("héllo", value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_new_object_string_from_string_builder() -> Result<()> {
+        let vm = test_vm().await?;
+        let string_builder = vm
+            .object("java.lang.StringBuilder", "Ljava/lang/String;", vec!["foo"])
+            .await?;
+        vm.invoke(
+            "java.lang.StringBuilder",
+            "append",
+            "(Ljava/lang/String;)Ljava/lang/StringBuilder;",
+            vec![string_builder.clone(), "bar".to_object(&vm).await?],
+        )
+        .await?;
+        let object = vm
+            .object(
+                "java.lang.String",
+                "Ljava/lang/StringBuilder;",
+                vec![string_builder],
+            )
+            .await?;
+        let value: String = object.try_into()?;
+        assert_eq!("foobar", value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_new_object_string_from_chars_offset_count() -> Result<()> {
+        let vm = test_vm().await?;
+        let characters = Value::from("foobar".chars().collect::<Vec<char>>());
+        let object = vm
+            .object(
+                "java.lang.String",
+                "[CII",
+                vec![characters, Value::Int(1), Value::Int(4)],
+            )
+            .await?;
+        let value: String = object.try_into()?;
+        assert_eq!("ooba", value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_string_get_bytes_to_vec() -> Result<()> {
+        let vm = test_vm().await?;
+        let string = "héllo".to_object(&vm).await?;
+        let bytes = vm
+            .try_invoke("java.lang.String", "getBytes", "()[B", vec![string])
+            .await?;
+        let value: Vec<i8> = bytes.try_into()?;
+        #[expect(clippy::cast_possible_wrap)]
+        let expected: Vec<i8> = "héllo".bytes().map(|byte| byte as i8).collect();
+        assert_eq!(expected, value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invoke_boxes_primitive_arguments() -> Result<()> {
+        let vm = test_vm().await?;
+        let value = vm
+            .try_invoke(
+                "java.util.Objects",
+                "requireNonNull",
+                "(Ljava/lang/Object;)Ljava/lang/Object;",
+                vec![42],
+            )
+            .await?;
+        let Value::Object(Some(ref reference)) = value else {
+            panic!("expected object");
+        };
+        assert_eq!("java/lang/Integer", reference.class_name());
+        let value: i32 = value.try_into()?;
+        assert_eq!(42, value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invoke_boxes_primitive_arguments_to_wrapper() -> Result<()> {
+        let vm = test_vm().await?;
+        let long = 42i64.to_object(&vm).await?;
+        let value = vm
+            .try_invoke(
+                "java.lang.Long",
+                "compareTo",
+                "(Ljava/lang/Long;)I",
+                vec![long, Value::Int(42)],
+            )
+            .await?;
+        assert_eq!(Value::Int(0), value);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invoke_on() -> Result<()> {
+        let (vm, thread1, frame) = crate::test::frame().await?;
+        thread1.push_frame(Arc::new(frame)).await;
+        let thread2 = vm.new_thread()?;
+
+        let arguments = vec![Value::Int(1), Value::Int(2)];
+        let result = vm
+            .invoke_on(&thread1, "StaticCall", "add", "(II)I", arguments.clone())
+            .await?;
+        assert_eq!(Some(Value::Int(3)), result);
+        let result = vm
+            .invoke_on(&thread2, "StaticCall", "add", "(II)I", arguments)
+            .await?;
+        assert_eq!(Some(Value::Int(3)), result);
+
+        // Each invocation pushes and pops a frame on the stack of its own thread only
+        let frames = thread1.frames().await?;
+        assert_eq!(1, frames.len());
+        assert_eq!("test", frames[0].method().name());
+        assert!(thread2.frames().await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invoke_on_unregistered_thread() -> Result<()> {
+        let vm = crate::test::vm().await?;
+        let thread = Thread::new(&vm.vm)?;
+        let result = vm
+            .invoke_on(&thread, "StaticCall", "add", "(II)I", vec![1, 2])
+            .await;
+        assert!(matches!(result, Err(InternalError(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_string_to_char_array_to_vec() -> Result<()> {
+        let vm = test_vm().await?;
+        let string = "héllo".to_object(&vm).await?;
+        let characters = vm
+            .try_invoke("java.lang.String", "toCharArray", "()[C", vec![string])
+            .await?;
+        let value: Vec<u16> = characters.clone().try_into()?;
+        assert_eq!("héllo".encode_utf16().collect::<Vec<u16>>(), value);
+        let value: Vec<char> = characters.clone().try_into()?;
+        assert_eq!("héllo".chars().collect::<Vec<char>>(), value);
+        let result: std::result::Result<Vec<i8>, _> = characters.try_into();
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shutdown() -> Result<()> {
+        let host_io = Arc::new(MemoryHostIo::default());
+        let vm = isolated_vm(host_io.clone()).await?;
+        vm.invoke("ShutdownHooks", "register", "()V", Vec::<Value>::new())
+            .await?;
+        let class = vm.class("ShutdownHooks").await?;
+        assert_eq!(Value::Int(0), class.static_field("value")?.value()?);
+        assert_eq!(None, vm.exit_code());
+
+        vm.shutdown(3).await?;
+        assert_eq!(Value::Int(42), class.static_field("value")?.value()?);
+        assert_eq!(b"shutdown hook".to_vec(), host_io.stdout());
+        assert_eq!(Some(3), vm.exit_code());
+
+        // Shutting down again does not run the hooks again or change the exit code
+        class.static_field("value")?.set_value(Value::Int(0))?;
+        vm.shutdown(4).await?;
+        assert_eq!(Value::Int(0), class.static_field("value")?.value()?);
+        assert_eq!(Some(3), vm.exit_code());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_signal_default_handler() -> Result<()> {
+        let vm = isolated_vm(Arc::new(MemoryHostIo::default())).await?;
+        let signal = tokio::signal::unix::SignalKind::user_defined1().as_raw_value();
+        vm.signals().set_handler(signal, IGNORE_HANDLER)?;
+        vm.dispatch_signal(signal).await?;
+        assert_eq!(None, vm.exit_code());
+
+        // The signal is dispatched while no Java method is running
+        vm.signals().set_handler(signal, DEFAULT_HANDLER)?;
+        vm.dispatch_signal(signal).await?;
+        assert_eq!(Some(128 + signal), vm.exit_code());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_hook_exception() -> Result<()> {
+        let host_io = Arc::new(MemoryHostIo::default());
+        let vm = isolated_vm(host_io.clone()).await?;
+        vm.invoke(
+            "ShutdownHooks",
+            "registerFailing",
+            "()V",
+            Vec::<Value>::new(),
+        )
+        .await?;
+        vm.invoke("ShutdownHooks", "register", "()V", Vec::<Value>::new())
+            .await?;
+
+        let result = vm.shutdown(1).await;
+        let Err(crate::Error::Throwable(throwable)) = result else {
+            panic!("expected the exception thrown by the hook");
+        };
+        assert_eq!("java/lang/IllegalStateException", throwable.class().name());
+
+        // The remaining hooks are still run and the streams are flushed
+        let class = vm.class("ShutdownHooks").await?;
+        assert_eq!(Value::Int(42), class.static_field("value")?.value()?);
+        assert_eq!(b"shutdown hook".to_vec(), host_io.stdout());
+        assert_eq!(Some(1), vm.exit_code());
+        Ok(())
+    }
 }